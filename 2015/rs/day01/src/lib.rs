@@ -0,0 +1,74 @@
+// one step per parenthesis, up or down a floor
+fn parse(data: &str) -> Vec<i32> {
+    data.chars()
+        .filter_map(|c| match c {
+            '(' => Some(1),
+            ')' => Some(-1),
+            _ => None,
+        })
+        .collect()
+}
+
+fn solve_one(steps: &[i32]) -> i32 {
+    steps.iter().sum()
+}
+
+pub fn part_one(data: &str) -> i32 {
+    solve_one(&parse(data))
+}
+
+fn solve_two(steps: &[i32]) -> usize {
+    let mut floors = 0;
+    let mut position = 0;
+
+    for (index, step) in steps.iter().enumerate() {
+        floors += step;
+
+        if floors == -1 {
+            position = index;
+            break;
+        }
+    }
+
+    position + 1
+}
+
+pub fn part_two(data: &str) -> usize {
+    solve_two(&parse(data))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 1;
+
+    type PartOne = i32;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Vec<i32>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<i32>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<i32>) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,83 @@
+/// A present's dimensions, smallest first.
+#[derive(Debug)]
+pub struct Package(i32, i32, i32);
+
+fn parse_line(line: &str) -> Package {
+    let mut dimensions = [0; 3];
+
+    for (index, dim) in line.split('x').enumerate() {
+        dimensions[index] = dim.parse().expect("failed to parse number");
+    }
+
+    dimensions.sort();
+
+    Package(dimensions[0], dimensions[1], dimensions[2])
+}
+
+fn parse(input: &str) -> Vec<Package> {
+    input.lines().map(parse_line).collect()
+}
+
+fn solve_one(packages: &[Package]) -> i32 {
+    let mut total_area = 0;
+
+    for &Package(l, w, h) in packages {
+        total_area += l * w * 2 + l * h * 2 + w * h * 2 + l * w;
+    }
+
+    total_area
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(packages: &[Package]) -> i32 {
+    let mut total_area = 0;
+
+    for &Package(l, w, h) in packages {
+        total_area += l * 2 + w * 2 + l * w * h;
+    }
+
+    total_area
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 2;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Package>;
+
+    fn parse(input: &str) -> Result<Vec<Package>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Package>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Package>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
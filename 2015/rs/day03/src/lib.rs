@@ -0,0 +1,112 @@
+use std::collections::HashSet;
+
+#[derive(Hash, Eq, PartialEq)]
+struct House(i32, i32);
+
+fn parse(directions: &str) -> Vec<(i32, i32)> {
+    directions
+        .chars()
+        .filter_map(|dir| match dir {
+            'v' => Some((0, -1)),
+            '^' => Some((0, 1)),
+            '>' => Some((1, 0)),
+            '<' => Some((-1, 0)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn solve_one(moves: &[(i32, i32)]) -> usize {
+    let mut houses = HashSet::from([House(0, 0)]);
+    let mut x = 0;
+    let mut y = 0;
+
+    for (dx, dy) in moves {
+        x += dx;
+        y += dy;
+
+        houses.insert(House(x, y));
+    }
+
+    houses.len()
+}
+
+pub fn part_one(directions: &str) -> usize {
+    solve_one(&parse(directions))
+}
+
+struct Santa(i32, i32);
+
+fn solve_two(moves: &[(i32, i32)]) -> usize {
+    let mut houses = HashSet::from([House(0, 0)]);
+    let mut santa = Santa(0, 0);
+    let mut robo = Santa(0, 0);
+    let mut turn = 0;
+
+    for (dx, dy) in moves {
+        let person = if turn == 0 { &mut santa } else { &mut robo };
+        turn ^= 1;
+
+        person.0 += dx;
+        person.1 += dy;
+
+        houses.insert(House(person.0, person.1));
+    }
+
+    houses.len()
+}
+
+pub fn part_two(directions: &str) -> usize {
+    solve_two(&parse(directions))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 3;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<(i32, i32)>;
+
+    fn parse(input: &str) -> Result<Vec<(i32, i32)>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<(i32, i32)>) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<(i32, i32)>) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_starting_house() {
+        // the starting house gets a present before any move
+        assert_eq!(part_one(""), 1);
+        assert_eq!(part_one("^v"), 2);
+        assert_eq!(part_two(""), 1);
+        assert_eq!(part_two("^v"), 3);
+        assert_eq!(part_two("^^vv"), 2);
+    }
+}
@@ -0,0 +1,70 @@
+fn parse(input: &str) -> String {
+    input.trim().to_string()
+}
+
+fn mine(key: &str, sought: &str) -> i32 {
+    let mut result = 0;
+
+    loop {
+        let string = format!("{}{}", key, result);
+        let hash = format!("{:x}", md5::compute(string.as_bytes()));
+        if hash.starts_with(sought) {
+            break;
+        }
+        result += 1;
+    }
+
+    result
+}
+
+fn solve_one(key: &str) -> i32 {
+    mine(key, "00000")
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(key: &str) -> i32 {
+    mine(key, "000000")
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 4;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &String) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &String) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,146 @@
+fn parse(input: &str) -> Vec<String> {
+    input.lines().map(str::to_string).collect()
+}
+
+fn part_one_is_nice(line: &str) -> bool {
+    let mut vowel_count = 0;
+    let mut has_double = false;
+    let mut it = line.chars().peekable();
+    while let Some(c) = it.next() {
+        if let Some(next) = it.peek() {
+            if c == *next {
+                has_double = true;
+            }
+        }
+        match c {
+            'a' => {
+                vowel_count += 1;
+                if matches!(it.peek(), Some(&'b')) {
+                    return false;
+                }
+            }
+            'c' => {
+                if matches!(it.peek(), Some(&'d')) {
+                    return false;
+                }
+            }
+            'p' => {
+                if matches!(it.peek(), Some(&'q')) {
+                    return false;
+                }
+            }
+            'x' => {
+                if matches!(it.peek(), Some(&'y')) {
+                    return false;
+                }
+            }
+            'e' | 'i' | 'o' | 'u' => vowel_count += 1,
+            _ => (),
+        }
+    }
+
+    has_double && vowel_count >= 3
+}
+
+fn solve_one(lines: &[String]) -> i32 {
+    let mut nice_count = 0;
+
+    for line in lines {
+        nice_count += part_one_is_nice(line) as i32;
+    }
+
+    nice_count
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn part_two_is_nice(line: &str) -> bool {
+    let mut pairs = Vec::<(char, char, usize)>::new();
+
+    let mut has_double_double = false;
+    let mut has_split_pair = false;
+
+    let mut previous: char;
+    let mut current = '\0';
+    let mut next: char;
+
+    let mut it = line.chars().enumerate().peekable();
+
+    while let Some((index, c)) = it.next() {
+        previous = current;
+        current = c;
+        if let Some((_, next_c)) = it.peek() {
+            next = *next_c;
+
+            if !has_double_double {
+                for (a, b, loc) in &pairs {
+                    if *a == current && *b == next && index - loc != 1 {
+                        has_double_double = true;
+                    }
+                }
+
+                pairs.push((current, next, index));
+            }
+        } else {
+            next = '\0';
+        }
+
+        if previous == next {
+            has_split_pair = true;
+        }
+    }
+
+    has_double_double && has_split_pair
+}
+
+fn solve_two(lines: &[String]) -> i32 {
+    let mut nice_count = 0;
+
+    for line in lines {
+        nice_count += part_two_is_nice(line) as i32;
+    }
+
+    nice_count
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 5;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<String>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<String>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,162 @@
+fn str_to_usize(str: &str) -> usize {
+    str.parse().expect("could not convert str to usize")
+}
+
+type Coords = (usize, usize);
+
+fn parse_coords(coords: Option<&str>) -> Result<Coords, ()> {
+    let mut parts = coords.expect("coords were empty").split(',');
+
+    let x = parts.next().map(str_to_usize).unwrap();
+    let y = parts.next().map(str_to_usize).unwrap();
+
+    Ok((x, y))
+}
+
+#[derive(Debug)]
+enum Action {
+    Toggle,
+    TurnOn,
+    TurnOff,
+}
+
+/// Sets, clears or toggles every light in a rectangle, corners included.
+pub struct Instruction {
+    action: Action,
+    start: Coords,
+    end: Coords,
+}
+
+fn parse_instruction(input: &str) -> Instruction {
+    let mut parts = input.split(' ');
+
+    let action = match parts.next().expect("failed to retrieve action") {
+        "turn" => match parts.next() {
+            Some("on") => Action::TurnOn,
+            Some("off") => Action::TurnOff,
+            _ => unreachable!("mode was not on or off"),
+        },
+        "toggle" => Action::Toggle,
+        _ => unreachable!("action was not turn or toggle"),
+    };
+
+    let start = parse_coords(parts.next()).expect("failed to parse start");
+    parts.next().expect("consuming through failed");
+    let end = parse_coords(parts.next()).expect("failed to parse end");
+
+    Instruction { action, start, end }
+}
+
+#[derive(Copy, Clone)]
+enum State {
+    Off,
+    On,
+}
+
+fn parse(input: &str) -> Vec<Instruction> {
+    input.lines().map(parse_instruction).collect()
+}
+
+fn solve_one(instructions: &[Instruction]) -> i32 {
+    let mut grid = vec![[State::Off; 1000]; 1000];
+    let mut lit = 0;
+
+    for inst in instructions {
+        let fun: fn(State) -> State = match inst.action {
+            Action::Toggle => |state| match state {
+                State::On => State::Off,
+                _ => State::On,
+            },
+            Action::TurnOff => |_| State::Off,
+            Action::TurnOn => |_| State::On,
+        };
+
+        for x in inst.start.0..=inst.end.0 {
+            for y in inst.start.1..=inst.end.1 {
+                let row = grid.get_mut(y).unwrap();
+                let light = row.get_mut(x).unwrap();
+                let new_state = fun(*light);
+                match (*light, new_state) {
+                    (State::On, State::Off) => lit -= 1,
+                    (State::Off, State::On) => lit += 1,
+                    _ => (),
+                }
+                *light = new_state;
+            }
+        }
+    }
+
+    lit
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(instructions: &[Instruction]) -> i32 {
+    let mut grid = vec![[0; 1000]; 1000];
+    let mut lit = 0;
+
+    for inst in instructions {
+        let modifier: i32 = match inst.action {
+            Action::TurnOn => 1,
+            Action::TurnOff => -1,
+            Action::Toggle => 2,
+        };
+
+        for x in inst.start.0..=inst.end.0 {
+            for y in inst.start.1..=inst.end.1 {
+                let row = grid.get_mut(y).unwrap();
+                let light = row.get_mut(x).unwrap();
+                let old = *light;
+                *light = match *light + modifier {
+                    n if n < 0 => 0,
+                    n => n,
+                };
+                lit += *light - old;
+            }
+        }
+    }
+
+    lit
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 6;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Instruction>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Instruction>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,256 @@
+use std::collections::{HashMap, VecDeque};
+use std::str::Split;
+
+/// One gate of the circuit, driving the wire named last.
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Not(String, String),
+    OneAnd(String, String),
+    LeftShift(String, usize, String),
+    RightShift(String, usize, String),
+    And(String, String, String),
+    Or(String, String, String),
+    Literal(u16, String),
+    Set(String, String),
+}
+
+fn not_instruction(mut parts: Split<'_, char>) -> Option<Instruction> {
+    let arg = parts.next()?;
+    assert!(parts.next()? == "->");
+    let target = parts.next()?;
+
+    Some(Instruction::Not(arg.to_string(), target.to_string()))
+}
+
+fn one_and_instruction(mut parts: Split<'_, char>) -> Option<Instruction> {
+    assert!(parts.next()? == "AND");
+    let arg = parts.next()?;
+    assert!(parts.next()? == "->");
+    let target = parts.next()?;
+
+    Some(Instruction::OneAnd(arg.to_string(), target.to_string()))
+}
+
+fn literal_instruction(operand1: &str, mut parts: Split<'_, char>) -> Option<Instruction> {
+    let target = parts.next()?.to_string();
+
+    Some(match operand1.parse::<u16>() {
+        Ok(value) => Instruction::Literal(value, target),
+        _ => Instruction::Set(operand1.to_string(), target),
+    })
+}
+
+fn binary_instruction(operand1: &str, mut parts: Split<'_, char>) -> Option<Instruction> {
+    let op = parts.next()?;
+    if op == "->" {
+        return literal_instruction(operand1, parts);
+    }
+    let operand2 = parts.next()?;
+    assert!(parts.next()? == "->");
+    let (operand1, target) = (operand1.to_string(), parts.next()?.to_string());
+
+    Some(match op {
+        "LSHIFT" => {
+            let count: usize = operand2.parse().unwrap();
+            Instruction::LeftShift(operand1, count, target)
+        }
+        "RSHIFT" => {
+            let count: usize = operand2.parse().unwrap();
+            Instruction::RightShift(operand1, count, target)
+        }
+        "OR" => Instruction::Or(operand1, operand2.to_string(), target),
+        "AND" => Instruction::And(operand1, operand2.to_string(), target),
+        _ => unreachable!("{}", op),
+    })
+}
+
+// the signal on every wire once the circuit settles
+fn evaluate_wires(input: &VecDeque<Instruction>) -> HashMap<String, u16> {
+    let mut instructions = input.clone();
+    let mut registers = HashMap::<String, u16>::new();
+
+    while let Some(inst) = instructions.pop_front() {
+        let handled = match &inst {
+            Instruction::Literal(value, target) => {
+                registers.insert(target.clone(), *value);
+                true
+            }
+            Instruction::Set(operand, target) => {
+                if let Some(&value) = registers.get(operand) {
+                    registers.insert(target.clone(), value);
+                    true
+                } else {
+                    false
+                }
+            }
+            Instruction::Not(operand, target) => {
+                if let Some(&value) = registers.get(operand) {
+                    registers.insert(target.clone(), !value);
+                    true
+                } else {
+                    false
+                }
+            }
+            Instruction::OneAnd(operand, target) => {
+                if let Some(&value) = registers.get(operand) {
+                    registers.insert(target.clone(), 1 & value);
+                    true
+                } else {
+                    false
+                }
+            }
+            Instruction::LeftShift(operand, amount, target) => {
+                if let Some(&value) = registers.get(operand) {
+                    registers.insert(target.clone(), value << *amount);
+                    true
+                } else {
+                    false
+                }
+            }
+            Instruction::RightShift(operand, amount, target) => {
+                if let Some(&value) = registers.get(operand) {
+                    registers.insert(target.clone(), value >> *amount);
+                    true
+                } else {
+                    false
+                }
+            }
+            Instruction::And(operand1, operand2, target) => {
+                match (registers.get(operand1), registers.get(operand2)) {
+                    (Some(&value1), Some(&value2)) => {
+                        registers.insert(target.clone(), value1 & value2);
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            Instruction::Or(operand1, operand2, target) => {
+                match (registers.get(operand1), registers.get(operand2)) {
+                    (Some(&value1), Some(&value2)) => {
+                        registers.insert(target.clone(), value1 | value2);
+                        true
+                    }
+                    _ => false,
+                }
+            }
+        };
+
+        if !handled {
+            instructions.push_back(inst);
+        }
+    }
+
+    registers
+}
+
+fn solve_one(instructions: &VecDeque<Instruction>) -> u16 {
+    evaluate_wires(instructions)["a"]
+}
+
+pub fn part_one(input: &str) -> u16 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(instructions: &VecDeque<Instruction>) -> u16 {
+    let mut instructions = instructions.clone();
+    let result = evaluate_wires(&instructions)["a"];
+    loop {
+        let inst = instructions.pop_front().unwrap();
+        if matches!(&inst, Instruction::Literal(_, target) if target == "b") {
+            instructions.push_back(Instruction::Literal(result, "b".to_string()));
+            break;
+        } else {
+            instructions.push_back(inst);
+        }
+    }
+    evaluate_wires(&instructions)["a"]
+}
+
+pub fn part_two(input: &str) -> u16 {
+    solve_two(&parse(input))
+}
+
+fn parse_instruction(line: &str) -> Option<Instruction> {
+    let mut parts = line.split(' ');
+
+    match parts.next()? {
+        "NOT" => not_instruction(parts),
+        "1" => one_and_instruction(parts),
+        operand1 => binary_instruction(operand1, parts),
+    }
+}
+
+fn parse(input: &str) -> VecDeque<Instruction> {
+    input
+        .lines()
+        .map(|line| parse_instruction(line).expect(line))
+        .collect()
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 7;
+
+    type PartOne = u16;
+    type PartTwo = u16;
+
+    fn part_one(input: &str) -> u16 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> u16 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = VecDeque<Instruction>;
+
+    fn parse(input: &str) -> Result<VecDeque<Instruction>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &VecDeque<Instruction>) -> u16 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &VecDeque<Instruction>) -> u16 {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate_wires() {
+        // the example has no wire `a`, so its wires are checked directly
+        let input = "123 -> x
+456 -> y
+x AND y -> d
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT x -> h
+NOT y -> i";
+        let wires = evaluate_wires(&parse(input));
+        let expected = [
+            ("d", 72),
+            ("e", 507),
+            ("f", 492),
+            ("g", 114),
+            ("h", 65412),
+            ("i", 65079),
+            ("x", 123),
+            ("y", 456),
+        ];
+        let expected = expected
+            .into_iter()
+            .map(|(wire, signal)| (wire.to_string(), signal))
+            .collect();
+        assert_eq!(wires, expected);
+    }
+}
@@ -0,0 +1,92 @@
+fn parse(input: &str) -> Vec<String> {
+    input.lines().map(str::to_string).collect()
+}
+
+fn solve_one(lines: &[String]) -> usize {
+    let mut code_length = 0;
+    let mut string_length = 0;
+
+    for line in lines {
+        let len = line.len();
+        code_length += line.len();
+        let mut it = line[1..(len - 1)].chars();
+        while let Some(c) = it.next() {
+            match c {
+                '\\' => {
+                    if let Some('x') = it.next() {
+                        it.next();
+                        it.next();
+                        string_length += 1;
+                    } else {
+                        string_length += 1;
+                    }
+                }
+                _ => string_length += 1,
+            }
+        }
+    }
+
+    code_length - string_length
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(lines: &[String]) -> usize {
+    let mut original_length = 0;
+    let mut new_length = 0;
+
+    for line in lines {
+        original_length += line.len();
+        new_length += 2;
+        for c in line.chars() {
+            if matches!(c, '\\' | '"') {
+                new_length += 1;
+            }
+            new_length += 1;
+        }
+    }
+
+    new_length - original_length
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 8;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<String>) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<String>) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
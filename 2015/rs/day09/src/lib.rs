@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+/// The distance to every directly connected location, both ways round.
+pub type Graph = HashMap<String, Vec<(String, usize)>>;
+
+fn parse(input: &str) -> Graph {
+    let mut graph: Graph = HashMap::new();
+
+    for line in input.lines() {
+        let mut it = line.split_ascii_whitespace();
+        let src = it.next().unwrap();
+        it.next();
+        let dest = it.next().unwrap();
+        it.next();
+        let dist = it.next().unwrap().parse().unwrap();
+
+        let edges = graph.entry(src.to_string()).or_default();
+        edges.push((dest.to_string(), dist));
+        let edges = graph.entry(dest.to_string()).or_default();
+        edges.push((src.to_string(), dist));
+    }
+
+    graph
+}
+
+fn permute(mut locations: Vec<&str>) -> Vec<Vec<&str>> {
+    let mut permutations = vec![locations.clone()];
+    let len = locations.len();
+    let mut c = vec![0; len];
+    let mut i = 0;
+
+    while i < len {
+        if c[i] < i {
+            match i % 2 {
+                0 => locations.swap(0, i),
+                _ => locations.swap(c[i], i),
+            }
+
+            permutations.push(locations.clone());
+            c[i] += 1;
+            i = 0;
+        } else {
+            c[i] = 0;
+            i += 1;
+        }
+    }
+
+    permutations
+}
+
+fn walk(graph: &Graph, locations: Vec<&str>) -> usize {
+    let mut distance = 0;
+
+    for i in 0..locations.len() - 1 {
+        let edges = graph.get(locations[i]).unwrap();
+        distance += edges
+            .iter()
+            .find(|edge| edge.0 == locations[i + 1])
+            .unwrap()
+            .1;
+    }
+
+    distance
+}
+
+fn calculate_lengths(graph: &Graph) -> Vec<usize> {
+    let locations: Vec<&str> = graph.keys().map(String::as_str).collect();
+
+    let permutations = permute(locations);
+
+    let mut lengths = Vec::new();
+
+    for perm in permutations {
+        lengths.push(walk(graph, perm));
+    }
+
+    lengths.sort();
+    lengths
+}
+
+fn solve_one(graph: &Graph) -> usize {
+    *calculate_lengths(graph).first().unwrap()
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(graph: &Graph) -> usize {
+    *calculate_lengths(graph).last().unwrap()
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 9;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Graph;
+
+    fn parse(input: &str) -> Result<Graph, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Graph) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Graph) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,98 @@
+fn parse(input: &str) -> String {
+    input.trim().to_string()
+}
+
+fn see_and_say(digits: &str, iterations: usize) -> usize {
+    let mut current_string = digits.to_owned();
+    let mut next_string = "".to_owned();
+
+    for _ in 0..iterations {
+        let mut count = 1;
+        let mut it = current_string.chars();
+        let mut current_char = it.next().unwrap();
+
+        for c in it {
+            if c == current_char {
+                count += 1;
+            } else {
+                next_string += count.to_string().as_str();
+                next_string.push(current_char);
+
+                current_char = c;
+                count = 1;
+            }
+        }
+
+        next_string += count.to_string().as_str();
+        next_string.push(current_char);
+        current_string = next_string;
+        next_string = "".to_owned();
+    }
+
+    current_string.len()
+}
+
+fn solve_one(digits: &str) -> usize {
+    see_and_say(digits, 40)
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(digits: &str) -> usize {
+    see_and_say(digits, 50)
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 10;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &String) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &String) -> usize {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_see_and_say() {
+        assert_eq!(see_and_say("1", 1), "11".len());
+        assert_eq!(see_and_say("11", 1), "21".len());
+        assert_eq!(see_and_say("21", 1), "1211".len());
+        assert_eq!(see_and_say("1211", 1), "111221".len());
+        assert_eq!(see_and_say("111221", 1), "312211".len());
+        assert_eq!(see_and_say("1", 5), "312211".len());
+    }
+}
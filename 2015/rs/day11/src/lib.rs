@@ -0,0 +1,137 @@
+/// The password as its ASCII bytes, incremented like a base 26 number.
+#[derive(Clone)]
+pub struct Password(Vec<u8>);
+
+impl std::fmt::Debug for Password {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(std::str::from_utf8(&self.0).unwrap())
+    }
+}
+
+impl Password {
+    fn from(string: String) -> Self {
+        Self(string.as_bytes().to_owned())
+    }
+
+    fn increment(&mut self) {
+        for byte in self.0.iter_mut().rev() {
+            *byte += 1;
+            if *byte > b'z' {
+                *byte = b'a';
+                continue;
+            } else if matches!(*byte, b'i' | b'o' | b'l') {
+                *byte += 1;
+            }
+
+            break;
+        }
+
+        let mut should_fill = false;
+        for byte in self.0.iter_mut() {
+            if should_fill {
+                *byte = b'a';
+            } else if matches!(*byte, b'i' | b'o' | b'l') {
+                *byte += 1;
+                should_fill = true;
+            }
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        let mut has_run = false;
+
+        for i in 0..(self.0.len() - 2) {
+            if self.0[i] + 1 == self.0[i + 1] && self.0[i] + 2 == self.0[i + 2] {
+                has_run = true;
+                break;
+            }
+        }
+
+        if !has_run {
+            return false;
+        }
+
+        let mut double_index = None;
+
+        for i in 0..(self.0.len() - 1) {
+            if self.0[i] == self.0[i + 1] {
+                match double_index {
+                    None => double_index = Some(i),
+                    Some(index) if i - index >= 2 => return true,
+                    Some(_) => {}
+                }
+            }
+        }
+
+        false
+    }
+}
+
+fn parse(input: &str) -> Password {
+    Password::from(input.trim().to_owned())
+}
+
+fn solve_one(password: &Password) -> String {
+    let mut password = password.clone();
+
+    while !password.is_valid() {
+        password.increment();
+    }
+
+    format!("{:?}", password)
+}
+
+pub fn part_one(input: &str) -> String {
+    solve_one(&parse(input))
+}
+
+fn solve_two(password: &Password) -> String {
+    let mut password = Password::from(solve_one(password));
+    password.increment();
+
+    while !password.is_valid() {
+        password.increment();
+    }
+
+    format!("{:?}", password)
+}
+
+pub fn part_two(input: &str) -> String {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 11;
+
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn part_one(input: &str) -> String {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> String {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Password;
+
+    fn parse(input: &str) -> Result<Password, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Password) -> String {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Password) -> String {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
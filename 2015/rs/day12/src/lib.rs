@@ -0,0 +1,104 @@
+use serde_json::Value;
+
+fn sum_numbers(input: &Value) -> i64 {
+    match input {
+        Value::Number(number) => number.as_i64().unwrap(),
+        Value::Array(array) => array.iter().fold(0, |acc, value| acc + sum_numbers(value)),
+        Value::Object(object) => object
+            .values()
+            .fold(0, |acc, value| acc + sum_numbers(value)),
+        _ => 0,
+    }
+}
+
+fn sum_non_red_numbers(input: &Value) -> i64 {
+    match input {
+        Value::Number(number) => number.as_i64().unwrap(),
+        Value::Array(array) => array
+            .iter()
+            .fold(0, |acc, value| acc + sum_non_red_numbers(value)),
+        Value::Object(object) => {
+            let contains_red = object
+                .values()
+                .any(|value| matches!(value.as_str(), Some("red")));
+            if contains_red {
+                0
+            } else {
+                object
+                    .values()
+                    .fold(0, |acc, value| acc + sum_non_red_numbers(value))
+            }
+        }
+        _ => 0,
+    }
+}
+
+fn parse(input: &str) -> Value {
+    serde_json::from_str(input).expect("input is not valid json")
+}
+
+fn solve_one(document: &Value) -> i64 {
+    sum_numbers(document)
+}
+
+pub fn part_one(input: &str) -> i64 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(document: &Value) -> i64 {
+    sum_non_red_numbers(document)
+}
+
+pub fn part_two(input: &str) -> i64 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 12;
+
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn part_one(input: &str) -> i64 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i64 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Value;
+
+    fn parse(input: &str) -> Result<Value, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Value) -> i64 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Value) -> i64 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_red() {
+        // only objects with a "red" value are left out, with everything in them
+        assert_eq!(part_two(r#"{"a":"blue","b":2}"#), 2);
+        assert_eq!(part_two(r#"[{"a":[3,{"b":"red","c":5}],"d":1}]"#), 4);
+        assert_eq!(part_two(r#"{"a":{"b":["red",7]},"c":"red"}"#), 0);
+        assert_eq!(part_two(r#"{"red":1}"#), 1);
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+/// How much each person's happiness changes next to each other person.
+pub type Graph = HashMap<String, Vec<(String, i32)>>;
+
+fn parse(input: &str) -> Graph {
+    let mut output: Graph = HashMap::new();
+
+    for line in input.lines() {
+        let mut it = line.split_ascii_whitespace();
+
+        let name = it.next().unwrap();
+
+        it.next(); // consume 'would'
+
+        let multiplier = if matches!(it.next(), Some("gain")) {
+            1
+        } else {
+            -1
+        };
+
+        let amount = it.next().unwrap().parse::<i32>().unwrap() * multiplier;
+
+        it.next(); // consume 'happiness'
+        it.next(); // consume 'units'
+        it.next(); // consume 'by'
+        it.next(); // consume 'sitting'
+        it.next(); // consume 'next'
+        it.next(); // consume 'to'
+
+        let neighbor = it.next().unwrap();
+        let neighbor = &neighbor[0..neighbor.len() - 1]; // remove period
+
+        let entry = output.entry(name.to_string()).or_default();
+        entry.push((neighbor.to_string(), amount))
+    }
+
+    output
+}
+
+fn permute(size: usize) -> Vec<Vec<usize>> {
+    let mut base_array: Vec<usize> = (0..size).collect();
+    let mut permutations = vec![base_array.clone()];
+    let mut c = vec![0; size];
+    let mut i = 0;
+
+    while i < size {
+        if c[i] < i {
+            match i % 2 {
+                0 => base_array.swap(0, i),
+                _ => base_array.swap(c[i], i),
+            }
+
+            permutations.push(base_array.clone());
+            c[i] += 1;
+            i = 0;
+        } else {
+            c[i] = 0;
+            i += 1;
+        }
+    }
+
+    permutations
+}
+
+fn calculate_happiness(graph: &Graph, order: Vec<usize>, people: &Vec<&str>) -> Option<i32> {
+    let mut total = 0;
+    let len = order.len();
+    for (first, second) in (0..len).zip(1..len + 1) {
+        let a = people.get(*order.get(first)?)?;
+        let b = people.get(*order.get(second % len)?)?;
+
+        total += graph.get(*a)?.iter().find(|(name, _)| name == b)?.1;
+        total += graph.get(*b)?.iter().find(|(name, _)| name == a)?.1;
+    }
+
+    Some(total)
+}
+
+fn find_optimal_happiness(graph: &Graph, input: Vec<Vec<usize>>) -> i32 {
+    let people: Vec<&str> = graph.keys().map(String::as_str).collect();
+
+    input
+        .into_iter()
+        .map(|order| calculate_happiness(graph, order, &people).unwrap())
+        .max()
+        .unwrap()
+}
+
+fn solve_one(graph: &Graph) -> i32 {
+    let possibilities = permute(graph.keys().len());
+
+    find_optimal_happiness(graph, possibilities)
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(graph: &Graph) -> i32 {
+    let mut graph = graph.clone();
+
+    let people: Vec<String> = graph.keys().cloned().collect();
+    for person in people {
+        graph
+            .entry(person.clone())
+            .and_modify(|vec| vec.push(("me".to_string(), 0)));
+        graph.entry("me".to_string()).or_default().push((person, 0));
+    }
+
+    let possibilities = permute(graph.keys().len());
+
+    find_optimal_happiness(&graph, possibilities)
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 13;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Graph;
+
+    fn parse(input: &str) -> Result<Graph, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Graph) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Graph) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,163 @@
+/// A reindeer's flying pattern, and how far it has got in the race.
+#[derive(Clone)]
+pub struct Reindeer {
+    speed: i32,
+    flight_seconds: i32,
+    rest_seconds: i32,
+    distance_traveled: i32,
+    time_traveled: i32,
+}
+
+impl Reindeer {
+    fn from(string: &str) -> Self {
+        let mut it = string.split_ascii_whitespace();
+
+        it.next().unwrap(); // name
+        it.next(); // can
+        it.next(); // fly
+
+        let speed: i32 = it.next().unwrap().parse().unwrap();
+
+        it.next(); // km/s
+        it.next(); // for
+
+        let flight_seconds: i32 = it.next().unwrap().parse().unwrap();
+
+        it.next(); // seconds
+        it.next(); // but
+        it.next(); // then
+        it.next(); // must
+        it.next(); // rest
+        it.next(); // for
+
+        let rest_seconds: i32 = it.next().unwrap().parse().unwrap();
+
+        Self {
+            speed,
+            flight_seconds,
+            rest_seconds,
+            distance_traveled: 0,
+            time_traveled: 0,
+        }
+    }
+
+    fn travel(&mut self, seconds: i32) -> i32 {
+        let cycle_time = self.rest_seconds + self.flight_seconds;
+
+        for _ in 0..seconds {
+            let remainder = self.time_traveled % cycle_time;
+            let in_travel_mode = remainder < self.flight_seconds;
+            if in_travel_mode {
+                self.distance_traveled += self.speed;
+            }
+            self.time_traveled += 1;
+        }
+
+        self.distance_traveled
+    }
+}
+
+fn parse(input: &str) -> Vec<Reindeer> {
+    input.lines().map(Reindeer::from).collect()
+}
+
+const RACE_SECONDS: i32 = 2503;
+
+fn farthest_distance(reindeers: &[Reindeer], seconds: i32) -> i32 {
+    let mut farthest_distance = 0;
+
+    for mut reindeer in reindeers.iter().cloned() {
+        let distance_traveled = reindeer.travel(seconds);
+        farthest_distance = farthest_distance.max(distance_traveled);
+    }
+
+    farthest_distance
+}
+
+fn most_points(reindeers: &[Reindeer], seconds: i32) -> i32 {
+    let mut reindeers = reindeers.to_vec();
+    let mut distances = vec![0; reindeers.len()];
+    let mut scoreboard = vec![0i32; reindeers.len()];
+    let mut current_leader = 0;
+
+    for _ in 0..seconds {
+        for (index, reindeer) in reindeers.iter_mut().enumerate() {
+            let distance_traveled = reindeer.travel(1);
+            distances[index] = distance_traveled;
+            current_leader = current_leader.max(distance_traveled);
+        }
+
+        for (index, distance) in distances.iter().enumerate() {
+            if *distance == current_leader {
+                scoreboard[index] += 1;
+            }
+        }
+    }
+
+    scoreboard.into_iter().max().unwrap()
+}
+
+fn solve_one(reindeers: &[Reindeer]) -> i32 {
+    farthest_distance(reindeers, RACE_SECONDS)
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(reindeers: &[Reindeer]) -> i32 {
+    most_points(reindeers, RACE_SECONDS)
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 14;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Reindeer>;
+
+    fn parse(input: &str) -> Result<Vec<Reindeer>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Reindeer>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Reindeer>) -> i32 {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str =
+        "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.
+Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.";
+
+    #[test]
+    fn test_race() {
+        assert_eq!(farthest_distance(&parse(EXAMPLE), 1000), 1120);
+        assert_eq!(most_points(&parse(EXAMPLE), 1000), 689);
+    }
+}
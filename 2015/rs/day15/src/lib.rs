@@ -0,0 +1,176 @@
+/// An ingredient's properties per teaspoon.
+#[derive(Debug, Copy, Clone)]
+pub struct Ingredient {
+    capacity: i32,
+    durability: i32,
+    flavor: i32,
+    texture: i32,
+    calories: i32,
+}
+
+fn parse_with_comma(string: Option<&str>) -> i32 {
+    let value = string.unwrap();
+    let value = &value[0..value.len() - 1];
+    value.parse().unwrap()
+}
+
+impl Ingredient {
+    fn from(string: &str) -> Self {
+        let mut it = string.split_ascii_whitespace();
+
+        it.next().unwrap();
+        // let name = name[0..name.len() - 1].to_owned();
+
+        it.next();
+        let capacity = parse_with_comma(it.next());
+
+        it.next();
+        let durability = parse_with_comma(it.next());
+
+        it.next();
+        let flavor = parse_with_comma(it.next());
+
+        it.next();
+        let texture = parse_with_comma(it.next());
+
+        it.next();
+        let calories = it.next().unwrap().parse().unwrap();
+
+        Self {
+            capacity,
+            durability,
+            flavor,
+            texture,
+            calories,
+        }
+    }
+}
+
+struct PermutationGenerator {
+    total: i32,
+    amounts: Vec<i32>,
+}
+
+impl PermutationGenerator {
+    fn new(total: i32, size: usize) -> Self {
+        Self {
+            total,
+            amounts: vec![0; size],
+        }
+    }
+}
+
+impl Iterator for PermutationGenerator {
+    type Item = Vec<i32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut i = 0;
+        while i < self.amounts.len() {
+            if self.amounts[i] < self.total {
+                self.amounts[i] += 1;
+                i = 0;
+            } else {
+                self.amounts[i] = 0;
+                i += 1;
+                continue;
+            }
+
+            if self.amounts.iter().sum::<i32>() == self.total {
+                return Some(self.amounts.clone());
+            }
+        }
+
+        None
+    }
+}
+
+fn find_high_score(ingredients: &[Ingredient], caloric_target: Option<i32>) -> i32 {
+    let mut max_score = 0;
+
+    for perm in PermutationGenerator::new(100, ingredients.len()) {
+        if let Some(target) = caloric_target {
+            let calories = perm.iter().enumerate().fold(0, |acc, (index, amount)| {
+                acc + ingredients[index].calories * amount
+            });
+
+            if calories != target {
+                continue;
+            }
+        }
+
+        let capacity = perm.iter().enumerate().fold(0, |acc, (index, amount)| {
+            acc + ingredients[index].capacity * amount
+        });
+        let durability = perm.iter().enumerate().fold(0, |acc, (index, amount)| {
+            acc + ingredients[index].durability * amount
+        });
+        let flavor = perm.iter().enumerate().fold(0, |acc, (index, amount)| {
+            acc + ingredients[index].flavor * amount
+        });
+        let texture = perm.iter().enumerate().fold(0, |acc, (index, amount)| {
+            acc + ingredients[index].texture * amount
+        });
+
+        let score = capacity.max(0) * durability.max(0) * flavor.max(0) * texture.max(0);
+
+        max_score = score.max(max_score);
+    }
+
+    max_score
+}
+
+fn parse(input: &str) -> Vec<Ingredient> {
+    input.lines().map(Ingredient::from).collect()
+}
+
+fn solve_one(ingredients: &[Ingredient]) -> i32 {
+    find_high_score(ingredients, None)
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(ingredients: &[Ingredient]) -> i32 {
+    find_high_score(ingredients, Some(500))
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 15;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Ingredient>;
+
+    fn parse(input: &str) -> Result<Vec<Ingredient>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Ingredient>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Ingredient>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
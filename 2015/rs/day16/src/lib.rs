@@ -0,0 +1,154 @@
+/// What is remembered about one Aunt Sue, `None` where nothing is.
+#[derive(Debug, Default)]
+pub struct Sue {
+    children: Option<usize>,
+    cats: Option<usize>,
+    samoyeds: Option<usize>,
+    pomeranians: Option<usize>,
+    akitas: Option<usize>,
+    vizslas: Option<usize>,
+    goldfish: Option<usize>,
+    trees: Option<usize>,
+    cars: Option<usize>,
+    perfumes: Option<usize>,
+}
+
+const SOUGHT: Sue = Sue {
+    children: Some(3),
+    cats: Some(7),
+    samoyeds: Some(2),
+    pomeranians: Some(3),
+    akitas: Some(0),
+    vizslas: Some(0),
+    goldfish: Some(5),
+    trees: Some(3),
+    cars: Some(2),
+    perfumes: Some(1),
+};
+
+fn parse_sue(line: &str) -> Sue {
+    let mut it = line.split_ascii_whitespace();
+
+    it.next();
+    it.next();
+
+    let mut sue: Sue = Default::default();
+
+    for _ in 0..3 {
+        let field = it.next().unwrap();
+
+        let value = it.next().unwrap();
+        let end = if value.ends_with(",") {
+            value.len() - 1
+        } else {
+            value.len()
+        };
+        let value: usize = value[0..end].parse().unwrap();
+
+        match field {
+            "children:" => sue.children = Some(value),
+            "cats:" => sue.cats = Some(value),
+            "samoyeds:" => sue.samoyeds = Some(value),
+            "pomeranians:" => sue.pomeranians = Some(value),
+            "akitas:" => sue.akitas = Some(value),
+            "vizslas:" => sue.vizslas = Some(value),
+            "goldfish:" => sue.goldfish = Some(value),
+            "trees:" => sue.trees = Some(value),
+            "cars:" => sue.cars = Some(value),
+            "perfumes:" => sue.perfumes = Some(value),
+            _ => unreachable!(),
+        }
+    }
+
+    sue
+}
+
+fn parse(input: &str) -> Vec<Sue> {
+    let mut sues = Vec::with_capacity(500);
+
+    for line in input.lines() {
+        sues.push(parse_sue(line))
+    }
+
+    sues
+}
+
+fn solve_one(sues: &[Sue]) -> usize {
+    let sought = &SOUGHT;
+    sues.iter()
+        .position(|sue| {
+            (sue.children.is_none() || sue.children == sought.children)
+                && (sue.cats.is_none() || sue.cats == sought.cats)
+                && (sue.samoyeds.is_none() || sue.samoyeds == sought.samoyeds)
+                && (sue.pomeranians.is_none() || sue.pomeranians == sought.pomeranians)
+                && (sue.akitas.is_none() || sue.akitas == sought.akitas)
+                && (sue.vizslas.is_none() || sue.vizslas == sought.vizslas)
+                && (sue.goldfish.is_none() || sue.goldfish == sought.goldfish)
+                && (sue.trees.is_none() || sue.trees == sought.trees)
+                && (sue.cars.is_none() || sue.cars == sought.cars)
+                && (sue.perfumes.is_none() || sue.perfumes == sought.perfumes)
+        })
+        .unwrap()
+        + 1
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(sues: &[Sue]) -> usize {
+    let sought = &SOUGHT;
+    sues.iter()
+        .position(|sue| {
+            (sue.children.is_none() || sue.children == sought.children)
+                && (sue.cats.is_none() || sue.cats > sought.cats)
+                && (sue.samoyeds.is_none() || sue.samoyeds == sought.samoyeds)
+                && (sue.pomeranians.is_none() || sue.pomeranians < sought.pomeranians)
+                && (sue.akitas.is_none() || sue.akitas == sought.akitas)
+                && (sue.vizslas.is_none() || sue.vizslas == sought.vizslas)
+                && (sue.goldfish.is_none() || sue.goldfish < sought.goldfish)
+                && (sue.trees.is_none() || sue.trees > sought.trees)
+                && (sue.cars.is_none() || sue.cars == sought.cars)
+                && (sue.perfumes.is_none() || sue.perfumes == sought.perfumes)
+        })
+        .unwrap()
+        + 1
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 16;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Sue>;
+
+    fn parse(input: &str) -> Result<Vec<Sue>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Sue>) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Sue>) -> usize {
+        solve_two(input)
+    }
+}
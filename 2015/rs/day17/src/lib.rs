@@ -0,0 +1,122 @@
+use std::cmp;
+
+// the ways to fill containers of `sizes` with exactly `liters`, and the
+// ways using as few containers as possible
+fn find_solutions(sizes: &[i32], liters: i32) -> (i32, i32) {
+    let mut stack = Vec::new();
+
+    let mut i = 0;
+    let mut part_one_solutions = 0;
+    let mut part_two_solutions = 0;
+
+    let mut min_size = sizes.len();
+
+    loop {
+        let sum = stack.iter().map(|(_, value)| value).sum::<i32>();
+
+        match sum.cmp(&liters) {
+            cmp::Ordering::Equal => {
+                part_one_solutions += 1;
+                let stack_size = stack.len();
+
+                match stack_size.cmp(&min_size) {
+                    cmp::Ordering::Equal => part_two_solutions += 1,
+                    cmp::Ordering::Less => {
+                        part_two_solutions = 1;
+                        min_size = stack_size
+                    }
+                    cmp::Ordering::Greater => (),
+                }
+
+                let (index, _) = stack.pop().unwrap();
+                i = index + 1;
+            }
+            cmp::Ordering::Greater => {
+                let (index, _) = stack.pop().unwrap();
+                i = index + 1;
+            }
+            cmp::Ordering::Less => {
+                if i == sizes.len() {
+                    let (index, _) = stack.pop().unwrap();
+                    i = index + 1;
+                } else {
+                    stack.push((i, sizes[i]));
+                    i += 1;
+                }
+            }
+        };
+
+        if i == sizes.len() && stack.is_empty() {
+            break;
+        }
+    }
+
+    (part_one_solutions, part_two_solutions)
+}
+
+fn parse(input: &str) -> Vec<i32> {
+    input
+        .lines()
+        .map(|line| line.parse::<i32>().unwrap())
+        .collect()
+}
+
+fn solve_one(sizes: &[i32]) -> i32 {
+    find_solutions(sizes, 150).0
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(sizes: &[i32]) -> i32 {
+    find_solutions(sizes, 150).1
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 17;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Vec<i32>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<i32>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<i32>) -> i32 {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_solutions() {
+        assert_eq!(find_solutions(&[20, 15, 10, 5, 5], 25), (4, 3));
+    }
+}
@@ -0,0 +1,201 @@
+const GRID_SIZE: usize = 100;
+const STEPS: usize = 100;
+const NEIGHBORS: [(i32, i32); 8] = [
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+#[derive(Copy, Clone, PartialEq, Default)]
+enum Cell {
+    #[default]
+    Dark,
+    Light,
+}
+
+/// An `N` by `N` square of lights.
+#[derive(Clone, Copy)]
+pub struct Grid<const N: usize>([[Cell; N]; N]);
+
+impl<const N: usize> Grid<N> {
+    fn count_lights(&self) -> i32 {
+        let mut total = 0;
+
+        for row in &self.0 {
+            for c in row {
+                if *c == Cell::Light {
+                    total += 1;
+                }
+            }
+        }
+
+        total
+    }
+
+    fn get_neighbor(&self, x: usize, y: usize, neighbor: &(i32, i32)) -> Option<Cell> {
+        let neighbor_y = if neighbor.1.is_negative() {
+            y.checked_sub(neighbor.1.wrapping_abs() as usize)?
+        } else {
+            y + neighbor.1 as usize
+        };
+        let neighbor_x = if neighbor.0.is_negative() {
+            x.checked_sub(neighbor.0.wrapping_abs() as usize)?
+        } else {
+            x + neighbor.0 as usize
+        };
+        let row = self.0.get(neighbor_y)?;
+        let cell = row.get(neighbor_x)?;
+        Some(*cell)
+    }
+
+    fn cycle(self) -> Self {
+        let mut storage = [[Cell::Dark; N]; N];
+
+        for (y, row) in storage.iter_mut().enumerate() {
+            for (x, cell) in row.iter_mut().enumerate() {
+                let light_count = NEIGHBORS.iter().fold(0, |acc, neighbor| {
+                    match self.get_neighbor(x, y, neighbor) {
+                        Some(Cell::Light) => acc + 1,
+                        _ => acc,
+                    }
+                });
+
+                *cell = match self.0[y][x] {
+                    Cell::Light => match light_count {
+                        2 | 3 => Cell::Light,
+                        _ => Cell::Dark,
+                    },
+                    Cell::Dark => match light_count {
+                        3 => Cell::Light,
+                        _ => Cell::Dark,
+                    },
+                }
+            }
+        }
+
+        Self(storage)
+    }
+
+    fn from(string: &str) -> Self {
+        let mut storage = [[Cell::Dark; N]; N];
+
+        for (y, line) in string.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                storage[y][x] = match c {
+                    '.' => Cell::Dark,
+                    '#' => Cell::Light,
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        Self(storage)
+    }
+
+    fn light_corners(&mut self) {
+        self.0[0][0] = Cell::Light;
+        self.0[N - 1][0] = Cell::Light;
+        self.0[0][N - 1] = Cell::Light;
+        self.0[N - 1][N - 1] = Cell::Light;
+    }
+}
+
+fn animate<const N: usize>(grid: &Grid<N>, steps: usize) -> i32 {
+    let mut current = *grid;
+
+    for _ in 0..steps {
+        current = current.cycle();
+    }
+
+    current.count_lights()
+}
+
+fn animate_stuck<const N: usize>(grid: &Grid<N>, steps: usize) -> i32 {
+    let mut current = *grid;
+
+    for _ in 0..steps {
+        current.light_corners();
+        current = current.cycle();
+    }
+
+    current.light_corners();
+
+    current.count_lights()
+}
+
+fn parse(input: &str) -> Grid<GRID_SIZE> {
+    Grid::from(input)
+}
+
+fn solve_one(grid: &Grid<GRID_SIZE>) -> i32 {
+    animate(grid, STEPS)
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(grid: &Grid<GRID_SIZE>) -> i32 {
+    animate_stuck(grid, STEPS)
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 18;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Grid<GRID_SIZE>;
+
+    fn parse(input: &str) -> Result<Grid<GRID_SIZE>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Grid<GRID_SIZE>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Grid<GRID_SIZE>) -> i32 {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = ".#.#.#
+...##.
+#....#
+..#...
+#.#..#
+####..";
+
+    #[test]
+    fn test_animate() {
+        assert_eq!(animate(&Grid::<6>::from(EXAMPLE), 4), 4);
+        assert_eq!(animate_stuck(&Grid::<6>::from(EXAMPLE), 5), 17);
+    }
+}
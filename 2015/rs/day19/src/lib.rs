@@ -0,0 +1,137 @@
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+use std::collections::{HashMap, HashSet};
+
+/// Every replacement for each element, and the medicine molecule.
+pub type ParsedData = (HashMap<String, Vec<String>>, String);
+
+fn parse(input: &str) -> ParsedData {
+    let mut counts = HashMap::<String, Vec<String>>::new();
+    let mut molecule = String::new();
+
+    let mut line_it = input.lines();
+    while let Some(line) = line_it.next() {
+        let mut it = line.split_ascii_whitespace();
+        if let Some(name) = it.next() {
+            let entry = counts.entry(name.to_string()).or_default();
+            it.next();
+            entry.push(it.next().unwrap().to_string());
+        } else {
+            molecule = line_it.next().unwrap().to_string();
+        }
+    }
+
+    (counts, molecule)
+}
+
+fn solve_one((map, molecule): &ParsedData) -> usize {
+    let mut combinations = HashSet::new();
+
+    let mut string = String::new();
+
+    let mut it = molecule.chars().enumerate().peekable();
+    while let Some((index, c)) = it.next() {
+        let slice_until = index;
+        let mut slice_from = index + 1;
+        string.clear();
+        string.push(c);
+
+        let replacements = map
+            .get(string.as_str())
+            .or_else(|| {
+                let (_, next) = it.peek()?;
+                slice_from += 1;
+                string.push(*next);
+                map.get(string.as_str())
+            })
+            .map(|vec| vec.as_slice())
+            .unwrap_or(&[]);
+
+        for r in replacements {
+            let mut new_variant = String::with_capacity(r.len() + molecule.len());
+            new_variant.push_str(&molecule[..slice_until]);
+            new_variant.push_str(r);
+            new_variant.push_str(&molecule[slice_from..]);
+            combinations.insert(new_variant);
+        }
+    }
+
+    combinations.len()
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn reduce_to_electron(replacement_list: &mut Vec<(&str, &str)>, molecule: &str) -> i32 {
+    replacement_list.shuffle(&mut thread_rng());
+    let mut target = molecule.to_string();
+    let mut steps = 0;
+
+    while target != "e" {
+        let steps_before = steps;
+        for (element, replacement) in replacement_list.iter() {
+            if target.contains(*element) {
+                target = target.replacen(*element, replacement, 1);
+                steps += 1;
+            }
+        }
+
+        if steps_before == steps {
+            return reduce_to_electron(replacement_list, molecule);
+        }
+    }
+
+    steps
+}
+
+fn solve_two((map, molecule): &ParsedData) -> i32 {
+    let mut replacement_list = Vec::new();
+    for (key, values) in map {
+        for value in values {
+            replacement_list.push((value.as_str(), key.as_str()))
+        }
+    }
+
+    reduce_to_electron(&mut replacement_list, molecule)
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 19;
+
+    type PartOne = usize;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = ParsedData;
+
+    fn parse(input: &str) -> Result<ParsedData, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &ParsedData) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &ParsedData) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,92 @@
+fn parse(input: &str) -> usize {
+    input.trim().parse().unwrap()
+}
+
+fn solve_one(target: &usize) -> usize {
+    let target = *target;
+    let max_size = target / 10;
+
+    let mut houses = vec![0; max_size];
+
+    for i in 1..max_size {
+        for j in (i..max_size).step_by(i) {
+            houses[j] += i * 10;
+        }
+    }
+
+    houses.into_iter().position(|v| v >= target).unwrap()
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(target: &usize) -> usize {
+    let target = *target;
+    let max_size = target / 10;
+
+    let mut houses = vec![0; max_size];
+
+    for i in 1..max_size {
+        for j in 1..=50 {
+            let index = j * i;
+            if index >= max_size {
+                break;
+            }
+            houses[index] += i * 11;
+        }
+    }
+
+    houses.into_iter().position(|v| v >= target).unwrap()
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 20;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = usize;
+
+    fn parse(input: &str) -> Result<usize, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &usize) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &usize) -> usize {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_house() {
+        // houses 1 to 9 get 10, 30, 40, 70, 60, 120, 80, 150 and 130 presents
+        assert_eq!(part_one("70"), 4);
+        assert_eq!(part_one("100"), 6);
+        assert_eq!(part_one("130"), 8);
+    }
+}
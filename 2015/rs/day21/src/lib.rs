@@ -0,0 +1,251 @@
+/// Hit points, damage and armor.
+#[derive(Clone, Copy, Debug)]
+pub struct Person(i32, i32, i32);
+
+impl Person {
+    fn attack(&self, other: &mut Self) {
+        if self.is_alive() && other.is_alive() {
+            other.0 -= (self.1 - other.2).max(1)
+        }
+    }
+
+    fn is_alive(&self) -> bool {
+        self.0 > 0
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Item(&'static str, i32, i32, i32);
+
+impl Item {
+    fn cost(&self) -> i32 {
+        self.1
+    }
+
+    fn damage(&self) -> i32 {
+        self.2
+    }
+
+    fn armor(&self) -> i32 {
+        self.3
+    }
+}
+
+const WEAPONS: [Item; 5] = [
+    Item("Dagger", 8, 4, 0),
+    Item("Shortsword", 10, 5, 0),
+    Item("Warhammer", 25, 6, 0),
+    Item("Longsword", 40, 7, 0),
+    Item("Greataxe", 74, 8, 0),
+];
+
+const ARMORS: [Item; 5] = [
+    Item("Leather", 13, 0, 1),
+    Item("Chainmail", 31, 0, 2),
+    Item("Splintmail", 53, 0, 3),
+    Item("Bandedmail", 75, 0, 4),
+    Item("Platemail", 102, 0, 5),
+];
+
+const RINGS: [Item; 6] = [
+    Item("Damage +1", 25, 1, 0),
+    Item("Damage +2", 50, 2, 0),
+    Item("Damage +3", 100, 3, 0),
+    Item("Defense +1", 20, 0, 1),
+    Item("Defense +2", 40, 0, 2),
+    Item("Defense +3", 80, 0, 3),
+];
+
+#[derive(Debug, Copy, Clone)]
+struct Loadout {
+    weapon: Item,
+    armor: Option<Item>,
+    ring1: Option<Item>,
+    ring2: Option<Item>,
+}
+
+impl Loadout {
+    fn cost(&self) -> i32 {
+        self.weapon.cost()
+            + self.armor.map(|i| i.cost()).unwrap_or(0)
+            + self.ring1.map(|i| i.cost()).unwrap_or(0)
+            + self.ring2.map(|i| i.cost()).unwrap_or(0)
+    }
+
+    fn damage(&self) -> i32 {
+        self.weapon.damage()
+            + self.ring1.map(|i| i.damage()).unwrap_or(0)
+            + self.ring2.map(|i| i.damage()).unwrap_or(0)
+    }
+
+    fn armor(&self) -> i32 {
+        self.armor.map(|i| i.armor()).unwrap_or(0)
+            + self.ring1.map(|i| i.armor()).unwrap_or(0)
+            + self.ring2.map(|i| i.armor()).unwrap_or(0)
+    }
+}
+
+fn generate_ring_combos(loadouts: &mut Vec<Loadout>, weapon: Item, armor: Option<Item>) {
+    for ring1 in RINGS {
+        let base = Loadout {
+            weapon,
+            armor,
+            ring1: Some(ring1),
+            ring2: None,
+        };
+        loadouts.push(base);
+
+        for ring2 in RINGS {
+            if ring1 != ring2 {
+                loadouts.push(Loadout {
+                    ring1: Some(ring1),
+                    ring2: Some(ring2),
+                    ..base
+                });
+            }
+        }
+    }
+}
+
+fn generate_loadouts() -> Vec<Loadout> {
+    let mut loadouts = Vec::new();
+
+    for weapon in WEAPONS {
+        let base = Loadout {
+            weapon,
+            armor: None,
+            ring1: None,
+            ring2: None,
+        };
+        loadouts.push(base);
+
+        for armor in ARMORS {
+            loadouts.push(Loadout {
+                armor: Some(armor),
+                ..base
+            });
+
+            generate_ring_combos(&mut loadouts, weapon, Some(armor));
+        }
+
+        generate_ring_combos(&mut loadouts, weapon, None);
+    }
+
+    loadouts
+}
+
+fn parse(input: &str) -> Person {
+    let mut lines_it = input.lines();
+    let line = lines_it.next().unwrap();
+    let mut it = line.split_ascii_whitespace();
+    it.next();
+    it.next();
+    let hp = it.next().unwrap().parse().unwrap();
+
+    let line = lines_it.next().unwrap();
+    let mut it = line.split_ascii_whitespace();
+    it.next();
+    let damage = it.next().unwrap().parse().unwrap();
+
+    let line = lines_it.next().unwrap();
+    let mut it = line.split_ascii_whitespace();
+    it.next();
+    let armor = it.next().unwrap().parse().unwrap();
+
+    Person(hp, damage, armor)
+}
+
+// fights to the end, the player attacking first, returning whether the
+// player is left standing
+fn player_wins(mut me: Person, mut boss: Person) -> bool {
+    while me.is_alive() && boss.is_alive() {
+        me.attack(&mut boss);
+        boss.attack(&mut me);
+    }
+
+    me.is_alive()
+}
+
+fn solve_one(boss: &Person) -> i32 {
+    let mut price = i32::MAX;
+
+    for loadout in generate_loadouts() {
+        let me = Person(100, loadout.damage(), loadout.armor());
+        if player_wins(me, *boss) {
+            price = price.min(loadout.cost());
+        }
+    }
+
+    price
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(boss: &Person) -> i32 {
+    let mut price = 0;
+
+    for loadout in generate_loadouts() {
+        let me = Person(100, loadout.damage(), loadout.armor());
+        if !player_wins(me, *boss) {
+            price = price.max(loadout.cost());
+        }
+    }
+
+    price
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 21;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Person;
+
+    fn parse(input: &str) -> Result<Person, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Person) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Person) -> i32 {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_player_wins() {
+        assert!(player_wins(Person(8, 5, 5), Person(12, 7, 2)));
+        assert!(!player_wins(Person(8, 5, 5), Person(13, 7, 2)));
+    }
+
+    #[test]
+    fn test_minimum_damage() {
+        assert!(!player_wins(Person(1, 1, 10), Person(2, 5, 0)));
+    }
+}
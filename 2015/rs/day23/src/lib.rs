@@ -0,0 +1,199 @@
+/// One of the computer's two registers.
+#[derive(Clone, Copy)]
+pub enum Register {
+    A,
+    B,
+}
+
+impl Register {
+    fn from(string: &str) -> Self {
+        match string {
+            "a" | "a," => Self::A,
+            "b" | "b," => Self::B,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// An instruction, with jump offsets relative to itself.
+#[derive(Clone, Copy)]
+pub enum Instruction {
+    Half(Register),
+    Triple(Register),
+    Increment(Register),
+    Jump(i32),
+    JumpIfEven(Register, i32),
+    JumpIfOne(Register, i32),
+}
+
+struct Computer {
+    a: u32,
+    b: u32,
+    ip: usize,
+}
+
+impl Computer {
+    fn new() -> Self {
+        Self { a: 0, b: 0, ip: 0 }
+    }
+
+    fn register_for(&mut self, register: &Register) -> &mut u32 {
+        match register {
+            Register::A => &mut self.a,
+            Register::B => &mut self.b,
+        }
+    }
+
+    fn process(&mut self, instruction: &Instruction) {
+        let increment = match instruction {
+            Instruction::Half(register) => {
+                *self.register_for(register) /= 2;
+                1
+            }
+            Instruction::Triple(register) => {
+                *self.register_for(register) *= 3;
+                1
+            }
+            Instruction::Increment(register) => {
+                *self.register_for(register) += 1;
+                1
+            }
+            Instruction::Jump(offset) => *offset,
+            Instruction::JumpIfEven(register, offset) => {
+                if (*self.register_for(register)).is_multiple_of(2) {
+                    *offset
+                } else {
+                    1
+                }
+            }
+            Instruction::JumpIfOne(register, offset) => {
+                if *self.register_for(register) == 1 {
+                    *offset
+                } else {
+                    1
+                }
+            }
+        };
+
+        if increment.is_negative() {
+            self.ip -= increment.wrapping_abs() as usize
+        } else {
+            self.ip += increment as usize
+        }
+    }
+
+    fn evaluate(instructions: &[Instruction]) -> Self {
+        let mut computer = Self::new();
+
+        while let Some(instruction) = instructions.get(computer.ip) {
+            computer.process(instruction);
+        }
+
+        computer
+    }
+}
+
+fn parse(string: &str) -> Vec<Instruction> {
+    let mut output = Vec::new();
+
+    for line in string.lines() {
+        let mut it = line.split_ascii_whitespace();
+
+        let instruction = match it.next().unwrap() {
+            inst @ ("hlf" | "tpl" | "inc") => {
+                let register = Register::from(it.next().unwrap());
+
+                match inst {
+                    "hlf" => Instruction::Half(register),
+                    "tpl" => Instruction::Triple(register),
+                    "inc" => Instruction::Increment(register),
+                    _ => unreachable!(),
+                }
+            }
+            "jmp" => {
+                let offset: i32 = it.next().unwrap().parse().unwrap();
+
+                Instruction::Jump(offset)
+            }
+            inst @ ("jie" | "jio") => {
+                let register = Register::from(it.next().unwrap());
+                let offset: i32 = it.next().unwrap().parse().unwrap();
+
+                match inst {
+                    "jie" => Instruction::JumpIfEven(register, offset),
+                    "jio" => Instruction::JumpIfOne(register, offset),
+                    _ => unreachable!(),
+                }
+            }
+            _ => unreachable!(),
+        };
+
+        output.push(instruction)
+    }
+
+    output
+}
+
+fn solve_one(instructions: &[Instruction]) -> u32 {
+    Computer::evaluate(instructions).b
+}
+
+pub fn part_one(input: &str) -> u32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(instructions: &[Instruction]) -> u32 {
+    let mut instructions = instructions.to_vec();
+    instructions.insert(0, Instruction::Increment(Register::A));
+    Computer::evaluate(&instructions).b
+}
+
+pub fn part_two(input: &str) -> u32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 23;
+
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn part_one(input: &str) -> u32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> u32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Instruction>) -> u32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Instruction>) -> u32 {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate() {
+        let instructions = parse("inc a\njio a, +2\ntpl a\ninc a");
+        let computer = Computer::evaluate(&instructions);
+        assert_eq!((computer.a, computer.b), (2, 0));
+    }
+}
@@ -0,0 +1,212 @@
+#[derive(Debug)]
+struct GroupGenerator {
+    stack: Vec<(usize, i32)>,
+    running_total: i32,
+    target: i32,
+    index: usize,
+    packages: Vec<i32>,
+}
+
+impl GroupGenerator {
+    fn new(packages: Vec<i32>, target: i32) -> Self {
+        Self {
+            index: 0,
+            running_total: 0,
+            stack: Vec::new(),
+            packages,
+            target,
+        }
+    }
+}
+
+impl Iterator for GroupGenerator {
+    type Item = Vec<i32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let package_count = self.packages.len();
+
+        if self.running_total == self.target {
+            if let Some((old_index, old_value)) = self.stack.pop() {
+                self.running_total -= old_value;
+                self.index = old_index + 1;
+            } else {
+                return None;
+            }
+        }
+
+        loop {
+            if self.index == package_count {
+                if let Some((old_index, old_value)) = self.stack.pop() {
+                    self.running_total -= old_value;
+                    self.index = old_index + 1;
+                    continue;
+                } else {
+                    return None;
+                }
+            }
+
+            let current = self.packages[self.index];
+            let mut return_value = None;
+
+            if self.running_total + current <= self.target {
+                self.running_total += current;
+
+                self.stack.push((self.index, current));
+
+                if self.running_total == self.target {
+                    return_value = Some(
+                        self.stack
+                            .iter()
+                            .map(|&(_, value)| value)
+                            .collect::<Vec<i32>>(),
+                    );
+                    self.running_total -= current;
+                    self.stack.pop();
+                }
+            }
+
+            self.index += 1;
+            if return_value.is_some() {
+                return return_value;
+            }
+        }
+    }
+}
+
+fn find_entanglement(packages: &[i32], group_count: usize) -> i128 {
+    let mut min_entanglement = i128::MAX;
+    let mut min_length = usize::MAX;
+    let target = packages.iter().sum::<i32>() / group_count as i32;
+    let init = GroupGenerator::new(packages.to_vec(), target);
+    let mut iterators = Vec::from([init]);
+    let mut groups: Vec<Vec<i32>> = Vec::new();
+
+    let mut current_entanglement = 0;
+
+    while !iterators.is_empty() {
+        if iterators.len() != group_count - 1 {
+            let current_iterator = iterators.last_mut().unwrap();
+            if let Some(next_group) = current_iterator.next() {
+                if groups.is_empty() {
+                    if next_group.len() > min_length {
+                        continue;
+                    }
+
+                    current_entanglement = next_group.iter().fold(1, |acc, v| acc * *v as i128);
+
+                    if current_entanglement > min_entanglement {
+                        continue;
+                    }
+                }
+
+                groups.push(next_group);
+                let filtered = packages
+                    .iter()
+                    .filter_map(|p| {
+                        for group in &groups {
+                            if group.contains(p) {
+                                return None;
+                            }
+                        }
+
+                        Some(*p)
+                    })
+                    .collect::<Vec<i32>>();
+                iterators.push(GroupGenerator::new(filtered, target));
+            } else {
+                groups.pop();
+                iterators.pop();
+            }
+            continue;
+        }
+
+        if let Some(mut it) = iterators.pop() {
+            for group in it.by_ref() {
+                let last_group_length: usize = packages
+                    .iter()
+                    .map(|p| {
+                        for group in &groups {
+                            if group.contains(p) {
+                                return 0;
+                            }
+                        }
+
+                        if group.contains(p) {
+                            return 0;
+                        }
+
+                        1
+                    })
+                    .sum();
+
+                let groups_len =
+                    groups.iter().fold(0, |acc, g| acc + g.len()) + group.len() + last_group_length;
+                if groups_len == packages.len() {
+                    min_length = groups[0].len();
+                    min_entanglement = current_entanglement;
+                }
+            }
+        }
+        groups.pop();
+    }
+
+    min_entanglement
+}
+
+fn parse(input: &str) -> Vec<i32> {
+    let mut packages: Vec<i32> = input.lines().map(|line| line.parse().unwrap()).collect();
+    packages.sort_by(|a, b| b.cmp(a));
+    packages
+}
+
+fn solve_one(packages: &[i32]) -> i128 {
+    find_entanglement(packages, 3)
+}
+
+pub fn part_one(input: &str) -> i128 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(packages: &[i32]) -> i128 {
+    find_entanglement(packages, 4)
+}
+
+pub fn part_two(input: &str) -> i128 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 24;
+
+    type PartOne = i128;
+    type PartTwo = i128;
+
+    fn part_one(input: &str) -> i128 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i128 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Vec<i32>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<i32>) -> i128 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<i32>) -> i128 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
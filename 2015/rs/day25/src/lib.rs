@@ -0,0 +1,67 @@
+fn parse(input: &str) -> (usize, usize) {
+    let mut x = String::new();
+    let mut y = String::new();
+
+    let mut it = input.split_ascii_whitespace();
+
+    while let Some(string) = it.next() {
+        if string == "column" {
+            let mut chars = it.next().unwrap().chars();
+            chars.next_back();
+            x = chars.as_str().to_owned();
+        } else if string == "row" {
+            let mut chars = it.next().unwrap().chars();
+            chars.next_back();
+            y = chars.as_str().to_owned();
+        }
+    }
+
+    (x.parse().unwrap(), y.parse().unwrap())
+}
+
+fn solve_one(&(x, y): &(usize, usize)) -> u64 {
+    let code_count = (0..(x + y - 1)).sum::<usize>() + x;
+
+    (0..code_count - 1).fold(20151125u64, |acc, _| (acc * 252533) % 33554393)
+}
+
+pub fn part_one(input: &str) -> u64 {
+    solve_one(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 25;
+    const PARTS: u8 = 1;
+
+    type PartOne = u64;
+    type PartTwo = aoc_core::NoAnswer;
+
+    fn part_one(input: &str) -> u64 {
+        part_one(input)
+    }
+
+    fn part_two(_input: &str) -> aoc_core::NoAnswer {
+        aoc_core::NoAnswer
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = (usize, usize);
+
+    fn parse(input: &str) -> Result<(usize, usize), aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &(usize, usize)) -> u64 {
+        solve_one(input)
+    }
+
+    fn solve_two(_input: &(usize, usize)) -> aoc_core::NoAnswer {
+        aoc_core::NoAnswer
+    }
+}
+
+aoc_core::examples!(Solver);
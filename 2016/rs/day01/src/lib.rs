@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+
+/// Which way to turn before walking.
+pub enum Direction {
+    Left,
+    Right,
+}
+/// A turn and the number of blocks to walk after it.
+pub type Step = (Direction, i32);
+
+fn parse(input: &str) -> Vec<Step> {
+    input
+        .trim()
+        .split(", ")
+        .map(|step| {
+            let mut it = step.chars();
+            let left_or_right = it.next().unwrap();
+            let amount = it.collect::<String>().parse().unwrap();
+            match left_or_right {
+                'L' => (Direction::Left, amount),
+                'R' => (Direction::Right, amount),
+                _ => unreachable!(),
+            }
+        })
+        .collect()
+}
+
+fn solve_one(steps: &[Step]) -> i32 {
+    let mut bearing = 0;
+    let mut northing = 0;
+    let mut easting = 0;
+
+    for step in steps {
+        match step.0 {
+            Direction::Right => bearing = (bearing + 90) % 360,
+            Direction::Left => bearing = (360 + bearing - 90) % 360,
+        }
+
+        match bearing {
+            0 => northing += step.1,
+            90 => easting += step.1,
+            180 => northing -= step.1,
+            270 => easting -= step.1,
+            _ => unreachable!(),
+        }
+    }
+
+    i32::abs(northing) + i32::abs(easting)
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(steps: &[Step]) -> i32 {
+    let mut bearing = 0;
+    let mut northing = 0;
+    let mut easting = 0;
+    let mut places_visited = HashSet::new();
+    places_visited.insert((0, 0));
+
+    for step in steps {
+        match step.0 {
+            Direction::Right => bearing = (bearing + 90) % 360,
+            Direction::Left => bearing = (360 + bearing - 90) % 360,
+        }
+
+        for _ in 0..step.1 {
+            let (to_change, step_amount) = match bearing {
+                0 => (&mut northing, 1),
+                90 => (&mut easting, 1),
+                180 => (&mut northing, -1),
+                270 => (&mut easting, -1),
+                _ => unreachable!(),
+            };
+            *to_change += step_amount;
+            if !places_visited.insert((northing, easting)) {
+                return i32::abs(northing) + i32::abs(easting);
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 1;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Step>;
+
+    fn parse(input: &str) -> Result<Vec<Step>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Step>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Step>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
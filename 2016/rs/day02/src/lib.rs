@@ -0,0 +1,122 @@
+use std::cmp;
+
+fn parse(input: &str) -> Vec<String> {
+    input.lines().map(str::to_string).collect()
+}
+
+fn solve_one(lines: &[String]) -> String {
+    const KEYPAD: [[char; 3]; 3] = [['1', '2', '3'], ['4', '5', '6'], ['7', '8', '9']];
+
+    let mut result = String::new();
+
+    let mut x: i32 = 1;
+    let mut y: i32 = 1;
+    for line in lines {
+        for c in line.chars() {
+            match c {
+                'U' => y = cmp::max(0, y - 1),
+                'D' => y = cmp::min(2, y + 1),
+                'R' => x = cmp::min(2, x + 1),
+                'L' => x = cmp::max(0, x - 1),
+                _ => unreachable!(),
+            }
+        }
+
+        result.push(KEYPAD[y as usize][x as usize]);
+    }
+
+    result
+}
+
+pub fn part_one(input: &str) -> String {
+    solve_one(&parse(input))
+}
+
+fn solve_two(lines: &[String]) -> String {
+    const KEYPAD: [[Option<char>; 5]; 5] = [
+        [None, None, Some('1'), None, None],
+        [None, Some('2'), Some('3'), Some('4'), None],
+        [Some('5'), Some('6'), Some('7'), Some('8'), Some('9')],
+        [None, Some('A'), Some('B'), Some('C'), None],
+        [None, None, Some('D'), None, None],
+    ];
+    let mut result = String::new();
+
+    let mut x: i32 = 0;
+    let mut y: i32 = 2;
+    for line in lines {
+        for c in line.chars() {
+            match c {
+                'U' => {
+                    let new_y = cmp::max(0, y - 1);
+                    if KEYPAD[new_y as usize][x as usize].is_some() {
+                        y = new_y
+                    }
+                }
+                'D' => {
+                    let new_y = cmp::min(4, y + 1);
+                    if KEYPAD[new_y as usize][x as usize].is_some() {
+                        y = new_y
+                    }
+                }
+                'R' => {
+                    let new_x = cmp::min(4, x + 1);
+                    if KEYPAD[y as usize][new_x as usize].is_some() {
+                        x = new_x
+                    }
+                }
+                'L' => {
+                    let new_x = cmp::max(0, x - 1);
+                    if KEYPAD[y as usize][new_x as usize].is_some() {
+                        x = new_x
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        result.push(KEYPAD[y as usize][x as usize].unwrap())
+    }
+
+    result
+}
+
+pub fn part_two(input: &str) -> String {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 2;
+
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn part_one(input: &str) -> String {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> String {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<String>) -> String {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<String>) -> String {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,75 @@
+fn is_valid(a: i32, b: i32, c: i32) -> bool {
+    a + b > c && b + c > a && a + c > b
+}
+
+fn parse(input: &str) -> Vec<[i32; 3]> {
+    input
+        .lines()
+        .map(|line| {
+            let mut it = line.split_ascii_whitespace();
+            [(); 3].map(|_| it.next().unwrap().parse().unwrap())
+        })
+        .collect()
+}
+
+fn solve_one(rows: &[[i32; 3]]) -> i32 {
+    rows.iter()
+        .fold(0, |acc, &[a, b, c]| acc + is_valid(a, b, c) as i32)
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+// triangles run down the columns, three rows at a time
+fn solve_two(rows: &[[i32; 3]]) -> i32 {
+    let mut result = 0;
+
+    for chunk in rows.chunks(3) {
+        for ((&a, &b), &c) in chunk[0].iter().zip(&chunk[1]).zip(&chunk[2]) {
+            result += is_valid(a, b, c) as i32
+        }
+    }
+
+    result
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 3;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<[i32; 3]>;
+
+    fn parse(input: &str) -> Result<Vec<[i32; 3]>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<[i32; 3]>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<[i32; 3]>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
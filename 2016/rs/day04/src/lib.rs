@@ -0,0 +1,142 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// A room's encrypted name, its sector ID and its checksum.
+#[derive(Debug)]
+pub struct RoomName {
+    raw: String,
+    char_counts: HashMap<char, i32>,
+    sector_id: i32,
+    checksum: String,
+}
+
+impl RoomName {
+    fn from(line: &str) -> Self {
+        let mut frequencies = HashMap::new();
+        let mut sector_id = String::new();
+        let mut it = line.chars().enumerate();
+        for (_, c) in it.by_ref() {
+            match c {
+                'a'..='z' => *frequencies.entry(c).or_default() += 1,
+                '0'..='9' => sector_id.push(c),
+                '[' => break,
+                _ => (),
+            }
+        }
+
+        let (checksum_start, _) = it.next().unwrap();
+
+        Self {
+            raw: line.to_string(),
+            char_counts: frequencies,
+            sector_id: sector_id.parse().unwrap(),
+            checksum: line[checksum_start..checksum_start + 5].to_string(),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        let mut entries: Vec<(char, i32)> =
+            self.char_counts.iter().map(|(k, v)| (*k, *v)).collect();
+        entries.sort_by(|a, b| match b.1.cmp(&a.1) {
+            result @ (Ordering::Less | Ordering::Greater) => result,
+            _ => a.0.cmp(&b.0),
+        });
+
+        for (index, c) in self.checksum.chars().enumerate() {
+            if entries[index].0 != c {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn decrypt(&self) -> String {
+        let mut result = String::new();
+
+        for mut c in self.raw.chars() {
+            if c.is_ascii_digit() {
+                break;
+            }
+            for _ in 0..self.sector_id {
+                c = match c {
+                    'a'..='y' => (c as u8 + 1) as char,
+                    'z' => 'a',
+                    '-' => ' ',
+                    _ => c,
+                };
+            }
+
+            result.push(c)
+        }
+
+        result
+    }
+}
+
+fn parse(input: &str) -> Vec<RoomName> {
+    input.lines().map(RoomName::from).collect()
+}
+
+fn solve_one(room_names: &[RoomName]) -> i32 {
+    room_names.iter().fold(0, |acc, name| {
+        acc + if name.is_valid() { name.sector_id } else { 0 }
+    })
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(room_names: &[RoomName]) -> i32 {
+    for room_name in room_names {
+        if room_name.is_valid() {
+            let decrypted = room_name.decrypt();
+            if decrypted.starts_with("northpole object storage") {
+                return room_name.sector_id;
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 4;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<RoomName>;
+
+    fn parse(input: &str) -> Result<Vec<RoomName>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<RoomName>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<RoomName>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,88 @@
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+// the sixth and seventh hex digits of the hashes of `door_id` followed by an
+// index that start with five zeroes, in order of index
+fn interesting_hashes(door_id: &str) -> impl Iterator<Item = (u8, u8)> + '_ {
+    let mut string = door_id.to_string();
+    (0u64..).filter_map(move |i| {
+        string.truncate(door_id.len());
+        string.push_str(&i.to_string());
+        let digest = md5::compute(&string);
+        if digest[0] == 0 && digest[1] == 0 && digest[2] >> 4 == 0 {
+            Some((digest[2] & 0xf, digest[3] >> 4))
+        } else {
+            None
+        }
+    })
+}
+
+fn parse(input: &str) -> String {
+    input.trim().to_string()
+}
+
+fn solve_one(door_id: &str) -> String {
+    interesting_hashes(door_id)
+        .take(8)
+        .map(|(sixth, _)| HEX[sixth as usize] as char)
+        .collect()
+}
+
+pub fn part_one(door_id: &str) -> String {
+    solve_one(&parse(door_id))
+}
+
+fn solve_two(door_id: &str) -> String {
+    let mut password = [None; 8];
+
+    for (position, character) in interesting_hashes(door_id) {
+        if let Some(slot) = password.get_mut(position as usize) {
+            slot.get_or_insert(HEX[character as usize] as char);
+        }
+
+        if password.iter().all(|c| c.is_some()) {
+            break;
+        }
+    }
+
+    password.map(|opt| opt.unwrap()).into_iter().collect()
+}
+
+pub fn part_two(door_id: &str) -> String {
+    solve_two(&parse(door_id))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 5;
+
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn part_one(input: &str) -> String {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> String {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &String) -> String {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &String) -> String {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
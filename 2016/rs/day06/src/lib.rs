@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+type Comparer = dyn Fn(&(&char, &i32), &(&char, &i32)) -> std::cmp::Ordering;
+
+fn parse(input: &str) -> Vec<String> {
+    input.lines().map(str::to_string).collect()
+}
+
+fn decipher(lines: &[String], cmp: &Comparer) -> String {
+    let mut repetitions: HashMap<usize, HashMap<char, i32>> = HashMap::new();
+    let mut max_len = 0;
+
+    for line in lines {
+        max_len = line.len();
+        for (index, c) in line.char_indices() {
+            let sub_map = repetitions.entry(index).or_default();
+            *sub_map.entry(c).or_default() += 1;
+        }
+    }
+
+    (0..max_len)
+        .map(|index| {
+            repetitions
+                .get(&index)
+                .unwrap()
+                .iter()
+                .max_by(cmp)
+                .map(|(k, _)| *k)
+                .unwrap()
+        })
+        .collect()
+}
+
+fn solve_one(lines: &[String]) -> String {
+    decipher(lines, &|a: &(&char, &i32), b: &(&char, &i32)| a.1.cmp(b.1))
+}
+
+pub fn part_one(input: &str) -> String {
+    solve_one(&parse(input))
+}
+
+fn solve_two(lines: &[String]) -> String {
+    decipher(lines, &|a: &(&char, &i32), b: &(&char, &i32)| b.1.cmp(a.1))
+}
+
+pub fn part_two(input: &str) -> String {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 6;
+
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn part_one(input: &str) -> String {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> String {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<String>) -> String {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<String>) -> String {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,167 @@
+use std::collections::VecDeque;
+
+fn parse(input: &str) -> Vec<String> {
+    input.lines().map(str::to_string).collect()
+}
+
+fn solve_one(lines: &[String]) -> i32 {
+    let mut total = 0;
+
+    let mut queue = VecDeque::new();
+
+    let mut in_hypernet_sequence: bool;
+    let mut abba_found;
+
+    for line in lines {
+        queue.clear();
+        in_hypernet_sequence = false;
+        abba_found = false;
+
+        for c in line.chars() {
+            if queue.len() < 3 {
+                queue.push_back(c);
+                continue;
+            }
+
+            match c {
+                '[' => {
+                    in_hypernet_sequence = true;
+                    queue.clear()
+                }
+                ']' => {
+                    in_hypernet_sequence = false;
+                    queue.clear()
+                }
+                _ => {
+                    queue.push_back(c);
+                    let first = *queue.front().unwrap();
+                    let second = *queue.get(1).unwrap();
+                    let third = *queue.get(2).unwrap();
+                    let fourth = *queue.get(3).unwrap();
+                    if first == fourth && second == third && first != second {
+                        if in_hypernet_sequence {
+                            abba_found = false;
+                            break;
+                        }
+                        abba_found = true;
+                    }
+                    queue.pop_front();
+                }
+            }
+        }
+
+        if abba_found {
+            total += 1;
+        }
+    }
+
+    total
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn flip_sequence(&(a, b, _): &(char, char, char)) -> (char, char, char) {
+    (b, a, b)
+}
+
+fn solve_two(lines: &[String]) -> i32 {
+    let mut total = 0;
+
+    let mut hypernet_sequences = Vec::new();
+    let mut supernet_sequences = Vec::new();
+    let mut in_hypernet_sequence: bool;
+
+    let mut queue = VecDeque::new();
+
+    for line in lines {
+        in_hypernet_sequence = false;
+        hypernet_sequences.clear();
+        supernet_sequences.clear();
+        queue.clear();
+
+        for c in line.chars() {
+            if queue.len() < 2 {
+                queue.push_back(c);
+                continue;
+            }
+
+            match c {
+                '[' => {
+                    in_hypernet_sequence = true;
+                    queue.clear()
+                }
+                ']' => {
+                    in_hypernet_sequence = false;
+                    queue.clear()
+                }
+                _ => {
+                    queue.push_back(c);
+                    let first = *queue.front().unwrap();
+                    let second = *queue.get(1).unwrap();
+                    let third = *queue.get(2).unwrap();
+                    if first == third && first != second {
+                        let triple = (first, second, third);
+                        if in_hypernet_sequence {
+                            hypernet_sequences.push(triple);
+                        } else {
+                            supernet_sequences.push(triple);
+                        }
+                    }
+                    queue.pop_front();
+                }
+            }
+        }
+
+        for seq in &supernet_sequences {
+            let flipped = flip_sequence(seq);
+            if hypernet_sequences.contains(&flipped) {
+                total += 1;
+                break;
+            }
+        }
+    }
+
+    total
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 7;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<String>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<String>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,197 @@
+struct Screen<const W: usize = 50, const H: usize = 6>([[char; W]; H]);
+
+/// Whether a rotation shifts a row or a column.
+#[derive(Debug)]
+pub enum RotationType {
+    Row,
+    Column,
+}
+
+/// One line of the input: light a rectangle, or rotate a row or column.
+#[derive(Debug)]
+pub enum Command {
+    Rect(usize, usize),
+    Rotate(RotationType, usize, usize),
+}
+
+impl<const W: usize, const H: usize> std::fmt::Display for Screen<W, H> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, line) in self.0.iter().enumerate() {
+            if index != 0 {
+                writeln!(f)?;
+            }
+            for pixel in line {
+                write!(f, "{}", pixel)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<const W: usize, const H: usize> Screen<W, H> {
+    fn new() -> Self {
+        Self([['.'; W]; H])
+    }
+
+    fn process(&mut self, command: &Command) {
+        match *command {
+            Command::Rect(x, y) => self.make_rectangle(x, y),
+            Command::Rotate(RotationType::Row, number, amount) => self.rotate_row(number, amount),
+            Command::Rotate(RotationType::Column, number, amount) => {
+                self.rotate_column(number, amount)
+            }
+        }
+    }
+
+    fn make_rectangle(&mut self, x: usize, y: usize) {
+        for y in 0..y {
+            for x in 0..x {
+                self.0[y][x] = '#'
+            }
+        }
+    }
+
+    fn rotate_row(&mut self, number: usize, amount: usize) {
+        let row = &mut self.0[number];
+        let row_len = row.len();
+        for _ in 0..amount {
+            let temp = row[row_len - 1];
+            for i in (0..row_len - 1).rev() {
+                let copy = row[i];
+                row[i + 1] = copy;
+            }
+            row[0] = temp;
+        }
+    }
+
+    fn rotate_column(&mut self, number: usize, amount: usize) {
+        let row_count = self.0.len();
+        for _ in 0..amount {
+            let temp = self.0[row_count - 1][number];
+
+            for i in (0..row_count - 1).rev() {
+                let copy = self.0[i][number];
+                self.0[i + 1][number] = copy;
+            }
+
+            self.0[0][number] = temp;
+        }
+    }
+}
+
+fn parse_command(line: &str) -> Command {
+    let mut it = line.split_ascii_whitespace();
+
+    match it.next().unwrap() {
+        "rect" => {
+            let mut dimesions = it.next().unwrap().split("x");
+            let x = dimesions.next().unwrap().parse().unwrap();
+            let y = dimesions.next().unwrap().parse().unwrap();
+            Command::Rect(x, y)
+        }
+        "rotate" => {
+            let rotation_type = match it.next().unwrap() {
+                "row" => RotationType::Row,
+                "column" => RotationType::Column,
+                _ => unreachable!(),
+            };
+
+            let number = it.next().unwrap()[2..].parse().unwrap();
+            it.next();
+            let amount = it.next().unwrap().parse().unwrap();
+
+            Command::Rotate(rotation_type, number, amount)
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn parse(input: &str) -> Vec<Command> {
+    input.lines().map(parse_command).collect()
+}
+
+fn process<const W: usize, const H: usize>(commands: &[Command]) -> (i32, Screen<W, H>) {
+    let mut screen = Screen::new();
+    for command in commands {
+        screen.process(command);
+    }
+
+    let mut total = 0;
+
+    for line in &screen.0 {
+        for c in line {
+            if *c == '#' {
+                total += 1;
+            }
+        }
+    }
+
+    (total, screen)
+}
+
+fn solve_one(commands: &[Command]) -> i32 {
+    process::<50, 6>(commands).0
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(commands: &[Command]) -> String {
+    process::<50, 6>(commands).1.to_string()
+}
+
+pub fn part_two(input: &str) -> String {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 8;
+
+    type PartOne = i32;
+    type PartTwo = String;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> String {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Vec<Command>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Command>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Command>) -> String {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process() {
+        let input = "rect 3x2
+rotate column x=1 by 1
+rotate row y=0 by 4
+rotate column x=1 by 1";
+        let (total, screen) = process::<7, 3>(&parse(input));
+        assert_eq!(total, 6);
+        assert_eq!(screen.to_string(), ".#..#.#\n#.#....\n.#.....");
+    }
+}
@@ -0,0 +1,115 @@
+fn calculate_decompressed_length(input: &str, recurse: bool) -> usize {
+    let mut len = 0;
+
+    let mut chars = input.char_indices();
+
+    let mut length_buffer = String::new();
+    let mut repeat_amount_buffer = String::new();
+
+    while let Some((_, c)) = chars.next() {
+        match c {
+            '(' => {
+                let length = loop {
+                    match chars.next().unwrap().1 {
+                        'x' => break length_buffer.parse().unwrap(),
+                        num => length_buffer.push(num),
+                    }
+                };
+
+                let (start_index, repeat_amount) = loop {
+                    match chars.next().unwrap() {
+                        (i, ')') => break (i + 1, repeat_amount_buffer.parse::<usize>().unwrap()),
+                        (_, num) => repeat_amount_buffer.push(num),
+                    }
+                };
+
+                for _ in 0..length {
+                    chars.next();
+                }
+
+                if recurse {
+                    len += calculate_decompressed_length(
+                        &input[start_index..start_index + length],
+                        true,
+                    ) * repeat_amount;
+                } else {
+                    len += length * repeat_amount
+                }
+
+                length_buffer.clear();
+                repeat_amount_buffer.clear();
+            }
+            _ => len += 1,
+        }
+    }
+
+    len
+}
+
+#[test]
+fn test_calculate_decompressed_length() -> std::io::Result<()> {
+    assert_eq!(calculate_decompressed_length("ADVENT", true), 6);
+    assert_eq!(calculate_decompressed_length("A(1x5)BC", true), 7);
+    assert_eq!(calculate_decompressed_length("(3x3)XYZ", true), 9);
+    assert_eq!(calculate_decompressed_length("A(2x2)BCD(2x2)EFG", true), 11);
+    assert_eq!(calculate_decompressed_length("(6x1)(1x3)A", true), 3);
+    assert_eq!(calculate_decompressed_length("X(8x2)(3x3)ABCY", true), 20);
+    assert_eq!(calculate_decompressed_length("X(8x2)(3x3)ABCY", false), 18);
+    Ok(())
+}
+
+fn parse(input: &str) -> String {
+    input.trim().to_string()
+}
+
+fn solve_one(file: &str) -> usize {
+    calculate_decompressed_length(file, false)
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(file: &str) -> usize {
+    calculate_decompressed_length(file, true)
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 9;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &String) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &String) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
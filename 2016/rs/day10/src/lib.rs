@@ -0,0 +1,231 @@
+use std::collections::{HashMap, VecDeque};
+
+/// Where a bot passes a chip.
+#[derive(Clone, Copy, Debug)]
+pub enum Receiver {
+    Bot(usize),
+    Output(usize),
+}
+
+/// A chip going to a bot from an input bin, or a bot's rule for passing its two chips on.
+#[derive(Clone, Copy, Debug)]
+pub enum Instruction {
+    Direct(usize, i32),
+    Pass(usize, Receiver, Receiver),
+}
+
+#[derive(Debug)]
+struct Bot {
+    high: Option<i32>,
+    low: Option<i32>,
+    instruction: Instruction,
+}
+
+impl Bot {
+    fn new(instruction: Instruction) -> Self {
+        Self {
+            instruction,
+            high: None,
+            low: None,
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        self.high.is_some() && self.low.is_some()
+    }
+
+    fn take(&mut self, value: i32) {
+        if self.high.is_none() {
+            self.high = Some(value);
+        } else if self.high.unwrap() < value {
+            self.low = self.high;
+            self.high = Some(value);
+        } else {
+            self.low = Some(value);
+        }
+    }
+}
+
+fn parse_instruction(line: &str) -> Instruction {
+    let mut words = line.split_ascii_whitespace();
+    match words.next().unwrap() {
+        "value" => {
+            let value = words.next().unwrap().parse().unwrap();
+
+            words.next();
+            words.next();
+            words.next();
+
+            let bot = words.next().unwrap().parse().unwrap();
+
+            Instruction::Direct(bot, value)
+        }
+        "bot" => {
+            let giver = words.next().unwrap().parse().unwrap();
+
+            words.next();
+            words.next();
+            words.next();
+
+            let bot_or_output = words.next().unwrap();
+            let bot_or_output_id = words.next().unwrap().parse().unwrap();
+
+            let receiver_one = match bot_or_output {
+                "bot" => Receiver::Bot(bot_or_output_id),
+                "output" => Receiver::Output(bot_or_output_id),
+                _ => unreachable!(),
+            };
+
+            words.next();
+            words.next();
+            words.next();
+
+            let bot_or_output = words.next().unwrap();
+            let bot_or_output_id = words.next().unwrap().parse().unwrap();
+
+            let receiver_two = match bot_or_output {
+                "bot" => Receiver::Bot(bot_or_output_id),
+                "output" => Receiver::Output(bot_or_output_id),
+                _ => unreachable!(),
+            };
+
+            Instruction::Pass(giver, receiver_one, receiver_two)
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn parse(input: &str) -> Vec<Instruction> {
+    input.lines().map(parse_instruction).collect()
+}
+
+fn run_bots(instructions: &[Instruction]) -> (HashMap<usize, Bot>, HashMap<usize, i32>) {
+    let mut instruction_queue = VecDeque::new();
+    let mut bots = HashMap::new();
+
+    for &instruction in instructions {
+        match instruction {
+            Instruction::Direct(..) => instruction_queue.push_back(instruction),
+            Instruction::Pass(giver, ..) => {
+                bots.insert(giver, Bot::new(instruction));
+            }
+        }
+    }
+
+    let mut outputs = HashMap::new();
+
+    while let Some(instruction) = instruction_queue.pop_front() {
+        match instruction {
+            Instruction::Direct(bot_id, value) => {
+                let bot = bots.get_mut(&bot_id).unwrap();
+                bot.take(value);
+
+                if bot.is_full() {
+                    instruction_queue.push_back(bot.instruction);
+                }
+            }
+            Instruction::Pass(giver_id, receiver_one, receiver_two) => {
+                let bot = bots.get(&giver_id).unwrap();
+
+                for (receiver, value) in [
+                    (receiver_one, bot.low.unwrap()),
+                    (receiver_two, bot.high.unwrap()),
+                ] {
+                    match receiver {
+                        Receiver::Bot(bot_id) => {
+                            let receiver = bots.get_mut(&bot_id).unwrap();
+                            receiver.take(value);
+
+                            if receiver.is_full() {
+                                instruction_queue.push_back(receiver.instruction);
+                            }
+                        }
+                        Receiver::Output(output_id) => {
+                            outputs.insert(output_id, value);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    (bots, outputs)
+}
+
+// the bot that compared chips `low` and `high`
+fn comparing_bot(bots: &HashMap<usize, Bot>, low: i32, high: i32) -> usize {
+    *bots
+        .iter()
+        .find(|(_, bot)| bot.low == Some(low) && bot.high == Some(high))
+        .unwrap()
+        .0
+}
+
+fn solve_one(instructions: &[Instruction]) -> usize {
+    comparing_bot(&run_bots(instructions).0, 17, 61)
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(instructions: &[Instruction]) -> i32 {
+    let (_, outputs) = run_bots(instructions);
+    outputs.get(&0).unwrap() * outputs.get(&1).unwrap() * outputs.get(&2).unwrap()
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 10;
+
+    type PartOne = usize;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Instruction>) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Instruction>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comparing_bot() {
+        let input = "value 5 goes to bot 2
+bot 2 gives low to bot 1 and high to bot 0
+value 3 goes to bot 1
+bot 1 gives low to output 1 and high to bot 0
+bot 0 gives low to output 2 and high to output 0
+value 2 goes to bot 2";
+        assert_eq!(comparing_bot(&run_bots(&parse(input)).0, 2, 5), 2);
+    }
+}
@@ -0,0 +1,300 @@
+use std::collections::{HashMap, VecDeque};
+
+/// A microchip or generator, by the index of its element.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Item {
+    Microchip(u8),
+    Generator(u8),
+    None,
+}
+
+impl Item {
+    fn is_none(&self) -> bool {
+        *self == Item::None
+    }
+}
+
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+struct Floor<const N: usize>([Item; N]);
+
+impl<const N: usize> Floor<N> {
+    fn insert(&mut self, item: Item) {
+        for slot in &mut self.0 {
+            if slot.is_none() {
+                *slot = item;
+                return;
+            }
+        }
+
+        unreachable!("unable to insert {:?} into {:?}", item, self)
+    }
+
+    fn remove_index(&mut self, index: usize) {
+        self.0[index] = Item::None;
+    }
+
+    fn sort(&mut self) {
+        self.0.sort();
+    }
+
+    fn is_valid(&self) -> bool {
+        if self.0.iter().any(|item| matches!(item, Item::Generator(_))) {
+            for item in self.0.iter() {
+                match item {
+                    Item::Microchip(kind) => {
+                        if !self.0.contains(&Item::Generator(*kind)) {
+                            return false;
+                        }
+                    }
+                    _ => continue,
+                }
+            }
+        }
+
+        true
+    }
+}
+
+// the items on each floor, numbering the elements in the order they're seen
+fn parse(input: &str) -> Vec<Vec<Item>> {
+    let mut elements = HashMap::new();
+    let mut element = |name| {
+        let next = elements.len() as u8;
+        *elements.entry(name).or_insert(next)
+    };
+
+    input
+        .lines()
+        .take(4)
+        .map(|line| {
+            let mut words = line.split_ascii_whitespace();
+            let mut items = Vec::new();
+
+            words.next(); // the
+            words.next(); // nth
+            words.next(); // floor
+            words.next(); // contains
+
+            if words.next() == Some("nothing") {
+                return items;
+            }
+
+            while let Some(identifier) = words.next() {
+                let kind = words.next().unwrap();
+                items.push(match kind {
+                    "microchip" | "microchip," | "microchip." => {
+                        Item::Microchip(element(identifier.split('-').next().unwrap()))
+                    }
+                    "generator" | "generator," | "generator." => {
+                        Item::Generator(element(identifier))
+                    }
+                    _ => unreachable!(),
+                });
+
+                if kind.ends_with('.') {
+                    break;
+                }
+
+                if words.next() == Some("and") {
+                    words.next();
+                }
+            }
+
+            items
+        })
+        .collect()
+}
+
+fn floors<const N: usize>(items: &[Vec<Item>]) -> [Floor<N>; 4] {
+    let mut floors = [Floor([Item::None; N]); 4];
+
+    for (floor, items) in floors.iter_mut().zip(items) {
+        for &item in items {
+            floor.insert(item);
+        }
+    }
+
+    floors
+}
+
+struct HashQueue<const N: usize> {
+    queue: VecDeque<([Floor<N>; 4], usize, i32)>,
+    hash_map: HashMap<([Floor<N>; 4], usize), i32>,
+}
+
+impl<const N: usize> HashQueue<N> {
+    fn new(init: ([Floor<N>; 4], usize, i32)) -> Self {
+        Self {
+            hash_map: HashMap::from([((init.0, init.1), init.2)]),
+            queue: VecDeque::from([init]),
+        }
+    }
+
+    fn pop(&mut self) -> Option<([Floor<N>; 4], usize, i32)> {
+        self.queue.pop_front()
+    }
+
+    fn push(&mut self, mut item: ([Floor<N>; 4], usize, i32)) {
+        for floor in &mut item.0 {
+            if !floor.is_valid() {
+                return;
+            }
+            floor.sort()
+        }
+
+        let entry = self.hash_map.entry((item.0, item.1)).or_insert(i32::MAX);
+
+        if item.2 < *entry {
+            *entry = item.2;
+            self.queue.push_back(item);
+        }
+    }
+}
+
+fn make_moves<const N: usize>(
+    hash_queue: &mut HashQueue<N>,
+    floors: [Floor<N>; 4],
+    steps: i32,
+    current_floor: usize,
+    next_floor: usize,
+) {
+    let floor = &floors[current_floor];
+
+    for (index, item) in floor.0.iter().enumerate() {
+        if item.is_none() {
+            continue;
+        }
+
+        let mut to_push = floors;
+        to_push[current_floor].remove_index(index);
+        to_push[next_floor].insert(*item);
+        hash_queue.push((to_push, next_floor, steps));
+
+        for (index2, item2) in floor.0.iter().enumerate().skip(index + 1) {
+            if item2.is_none() {
+                continue;
+            }
+
+            let mut to_push = to_push;
+            to_push[current_floor].remove_index(index2);
+            to_push[next_floor].insert(*item2);
+            hash_queue.push((to_push, next_floor, steps));
+        }
+    }
+}
+
+fn find_solution<const N: usize>(floors: [Floor<N>; 4]) -> i32 {
+    let mut hash_queue = HashQueue::new((floors, 0, 0));
+
+    let mut min_steps = i32::MAX;
+
+    while let Some((floors, elevator_floor, steps)) = hash_queue.pop() {
+        if steps > min_steps {
+            continue;
+        }
+
+        if floors[3].0.iter().rev().all(|item| !item.is_none()) {
+            min_steps = min_steps.min(steps)
+        }
+
+        let new_steps = steps + 1;
+
+        if elevator_floor == 0 || elevator_floor == 1 || elevator_floor == 2 {
+            make_moves(
+                &mut hash_queue,
+                floors,
+                new_steps,
+                elevator_floor,
+                elevator_floor + 1,
+            )
+        }
+
+        if elevator_floor == 1 || elevator_floor == 2 || elevator_floor == 3 {
+            make_moves(
+                &mut hash_queue,
+                floors,
+                new_steps,
+                elevator_floor,
+                elevator_floor - 1,
+            )
+        }
+    }
+
+    min_steps
+}
+
+fn solve_one(items: &[Vec<Item>]) -> i32 {
+    find_solution(floors::<10>(items))
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(items: &[Vec<Item>]) -> i32 {
+    let mut floors = floors::<14>(items);
+
+    // elerium and dilithium come after every element already numbered
+    let elerium = items.iter().flatten().count() as u8 / 2;
+    let dilithium = elerium + 1;
+    floors[0].insert(Item::Generator(elerium));
+    floors[0].insert(Item::Microchip(elerium));
+    floors[0].insert(Item::Generator(dilithium));
+    floors[0].insert(Item::Microchip(dilithium));
+    floors[0].sort();
+
+    find_solution(floors)
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 11;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Vec<Item>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<Item>>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Vec<Item>>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Vec<Item>>) -> i32 {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_solution() {
+        let input = "The first floor contains a hydrogen-compatible microchip and a lithium-compatible microchip.
+The second floor contains a hydrogen generator.
+The third floor contains a lithium generator.
+The fourth floor contains nothing relevant.
+";
+        assert_eq!(find_solution(floors::<4>(&parse(input))), 11);
+    }
+}
@@ -0,0 +1,209 @@
+/// One of the four registers.
+#[derive(Clone, Copy)]
+pub enum Register {
+    A,
+    B,
+    C,
+    D,
+}
+
+impl std::str::FromStr for Register {
+    type Err = ();
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "a" => Ok(Self::A),
+            "b" => Ok(Self::B),
+            "c" => Ok(Self::C),
+            "d" => Ok(Self::D),
+            _ => Err(()),
+        }
+    }
+}
+
+/// An assembunny instruction, with jump offsets relative to itself.
+#[derive(Clone, Copy)]
+pub enum Instruction {
+    CopyValue(i32, Register),
+    CopyRegister(Register, Register),
+    Increment(Register),
+    Decrement(Register),
+    JumpIfNotZeroValue(i32, i32),
+    JumpIfNotZero(Register, i32),
+}
+
+fn parse(input: &str) -> Vec<Instruction> {
+    let mut instructions = Vec::new();
+
+    for line in input.lines() {
+        let mut parts = line.split_ascii_whitespace();
+
+        let inst = match parts.next().unwrap() {
+            "cpy" => {
+                let register_or_value = parts.next().unwrap();
+                let destination = parts.next().unwrap().parse().unwrap();
+
+                match register_or_value {
+                    "a" => Instruction::CopyRegister(Register::A, destination),
+                    "b" => Instruction::CopyRegister(Register::B, destination),
+                    "c" => Instruction::CopyRegister(Register::C, destination),
+                    "d" => Instruction::CopyRegister(Register::D, destination),
+                    _ => Instruction::CopyValue(register_or_value.parse().unwrap(), destination),
+                }
+            }
+            "inc" => Instruction::Increment(parts.next().unwrap().parse().unwrap()),
+            "dec" => Instruction::Decrement(parts.next().unwrap().parse().unwrap()),
+            "jnz" => {
+                let value_or_register = parts.next().unwrap();
+                let offset = parts.next().unwrap().parse().unwrap();
+
+                if let Ok(register) = value_or_register.parse() {
+                    Instruction::JumpIfNotZero(register, offset)
+                } else {
+                    Instruction::JumpIfNotZeroValue(value_or_register.parse().unwrap(), offset)
+                }
+            }
+            _ => unreachable!(),
+        };
+
+        instructions.push(inst);
+    }
+
+    instructions
+}
+
+struct Computer {
+    a: i32,
+    b: i32,
+    c: i32,
+    d: i32,
+    pc: usize,
+
+    instructions: Vec<Instruction>,
+}
+
+impl Computer {
+    fn new(instructions: &[Instruction]) -> Self {
+        let instructions = instructions.to_vec();
+
+        Self {
+            a: 0,
+            b: 0,
+            c: 0,
+            d: 0,
+            pc: 0,
+            instructions,
+        }
+    }
+
+    fn get_register_mut(&mut self, reg: Register) -> &mut i32 {
+        match reg {
+            Register::A => &mut self.a,
+            Register::B => &mut self.b,
+            Register::C => &mut self.c,
+            Register::D => &mut self.d,
+        }
+    }
+
+    fn get_register(&mut self, reg: Register) -> &i32 {
+        match reg {
+            Register::A => &self.a,
+            Register::B => &self.b,
+            Register::C => &self.c,
+            Register::D => &self.d,
+        }
+    }
+
+    fn run(&mut self) -> i32 {
+        while let Some(instruction) = self.instructions.get(self.pc) {
+            match *instruction {
+                Instruction::CopyValue(value, reg) => *self.get_register_mut(reg) = value,
+                Instruction::CopyRegister(src, dest) => {
+                    *self.get_register_mut(dest) = *self.get_register(src)
+                }
+                Instruction::Increment(reg) => *self.get_register_mut(reg) += 1,
+                Instruction::Decrement(reg) => *self.get_register_mut(reg) -= 1,
+                Instruction::JumpIfNotZero(reg, offset) => {
+                    if *self.get_register(reg) != 0 {
+                        if offset.is_negative() {
+                            self.pc -= offset.wrapping_abs() as usize
+                        } else {
+                            self.pc += offset as usize
+                        }
+
+                        continue;
+                    }
+                }
+                Instruction::JumpIfNotZeroValue(value, offset) => {
+                    if value != 0 {
+                        if offset.is_negative() {
+                            self.pc -= offset.wrapping_abs() as usize
+                        } else {
+                            self.pc += offset as usize
+                        }
+
+                        continue;
+                    }
+                }
+            }
+
+            self.pc += 1;
+        }
+
+        self.a
+    }
+}
+
+fn solve_one(instructions: &[Instruction]) -> i32 {
+    Computer::new(instructions).run()
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(instructions: &[Instruction]) -> i32 {
+    let mut computer = Computer::new(instructions);
+    computer.c = 1;
+    computer.run()
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 12;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Instruction>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Instruction>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,134 @@
+use std::collections::{HashSet, VecDeque};
+
+fn get_char((x, y): (u32, u32), number: u32) -> char {
+    let result = (x * x + 3 * x + 2 * x * y + y + y * y) + number;
+
+    if result.count_ones().is_multiple_of(2) {
+        '.'
+    } else {
+        '#'
+    }
+}
+
+fn parse(input: &str) -> u32 {
+    input.trim().parse().unwrap()
+}
+
+// the fewest steps from 1,1 to `(target_x, target_y)`
+fn fewest_steps(number: u32, (target_x, target_y): (u32, u32)) -> i32 {
+    let start = (1u32, 1u32);
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    let mut min_steps = i32::MAX;
+
+    while let Some(((x, y), steps)) = queue.pop_front() {
+        if steps > min_steps || seen.contains(&(x, y)) {
+            continue;
+        }
+
+        seen.insert((x, y));
+
+        if x == target_x && y == target_y {
+            min_steps = min_steps.min(steps)
+        }
+
+        let left = (x.saturating_sub(1), y);
+        let right = (x + 1, y);
+        let up = (x, y.saturating_sub(1));
+        let down = (x, y + 1);
+
+        for coord in [left, right, up, down] {
+            let next_char = get_char(coord, number);
+            if next_char == '.' {
+                queue.push_back((coord, steps + 1));
+            }
+        }
+    }
+
+    min_steps
+}
+
+fn solve_one(number: &u32) -> i32 {
+    fewest_steps(*number, (31, 39))
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(&number: &u32) -> usize {
+    let start = (1u32, 1u32);
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some(((x, y), steps)) = queue.pop_front() {
+        if steps > 50 || seen.contains(&(x, y)) {
+            continue;
+        }
+
+        seen.insert((x, y));
+
+        let left = (x.saturating_sub(1), y);
+        let right = (x + 1, y);
+        let up = (x, y.saturating_sub(1));
+        let down = (x, y + 1);
+
+        for coord in [left, right, up, down] {
+            let next_char = get_char(coord, number);
+            if next_char == '.' {
+                queue.push_back((coord, steps + 1));
+            }
+        }
+    }
+
+    seen.len()
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 13;
+
+    type PartOne = i32;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = u32;
+
+    fn parse(input: &str) -> Result<u32, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &u32) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &u32) -> usize {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fewest_steps() {
+        assert_eq!(fewest_steps(10, (7, 4)), 11);
+    }
+}
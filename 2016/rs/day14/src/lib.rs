@@ -0,0 +1,157 @@
+use std::collections::VecDeque;
+
+fn compute_md5(string: &str) -> String {
+    format!("{:x}", md5::compute(string))
+}
+
+fn remove_previous_number(string: &mut String, number: usize) {
+    let mut length = 1;
+    let mut current = number;
+    while current % 10 != current {
+        current /= 10;
+        length += 1;
+    }
+
+    string.replace_range((string.len() - length).., "");
+}
+
+fn find_triple(string: &str) -> Option<char> {
+    let mut chars = string.chars();
+
+    let mut first: char;
+    let mut second = chars.next().unwrap();
+    let mut third = chars.next().unwrap();
+
+    for next in chars {
+        first = second;
+        second = third;
+        third = next;
+
+        if first == second && second == third {
+            return Some(first);
+        }
+    }
+
+    None
+}
+
+fn find_quintuple(string: &str, c: char) -> bool {
+    let mut chars = string.chars();
+
+    let mut first: char;
+    let mut second = chars.next().unwrap();
+    let mut third = chars.next().unwrap();
+    let mut fourth = chars.next().unwrap();
+    let mut fifth = chars.next().unwrap();
+
+    for next in chars {
+        first = second;
+        second = third;
+        third = fourth;
+        fourth = fifth;
+        fifth = next;
+
+        if c == first && first == second && second == third && third == fourth && fourth == fifth {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn find_index_64(salt: &str, additional_hashings: usize) -> usize {
+    let mut to_hash = salt.to_owned();
+
+    let mut soughts = VecDeque::new();
+
+    let mut key_count = 0;
+
+    for current_index in 0.. {
+        to_hash.push_str(current_index.to_string().as_str());
+        let mut hash = compute_md5(&to_hash);
+        for _ in 0..additional_hashings {
+            hash = compute_md5(&hash);
+        }
+
+        for _ in 0..soughts.len() {
+            let (c, original_index, max_index) = soughts.pop_front().unwrap();
+            if current_index > max_index {
+                continue;
+            }
+
+            if find_quintuple(&hash, c) {
+                key_count += 1;
+
+                if key_count == 64 {
+                    return original_index;
+                }
+            } else {
+                soughts.push_back((c, original_index, max_index))
+            }
+        }
+
+        if let Some(c) = find_triple(&hash) {
+            soughts.push_back((c, current_index, current_index + 1000))
+        }
+
+        remove_previous_number(&mut to_hash, current_index);
+    }
+
+    unreachable!()
+}
+
+fn parse(input: &str) -> String {
+    input.trim().to_string()
+}
+
+fn solve_one(salt: &str) -> usize {
+    find_index_64(salt, 0)
+}
+
+pub fn part_one(salt: &str) -> usize {
+    solve_one(&parse(salt))
+}
+
+fn solve_two(salt: &str) -> usize {
+    find_index_64(salt, 2016)
+}
+
+pub fn part_two(salt: &str) -> usize {
+    solve_two(&parse(salt))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 14;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &String) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &String) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
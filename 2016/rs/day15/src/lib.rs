@@ -0,0 +1,119 @@
+/// A spinning disc, with its position at time 0.
+#[derive(Debug, Clone, Copy)]
+pub struct Disc {
+    position_count: usize,
+    starting_position: usize,
+}
+
+impl Disc {
+    fn get_position(&self, time: usize) -> usize {
+        (self.starting_position + time) % self.position_count
+    }
+}
+
+fn parse(input: &str) -> Vec<Disc> {
+    let mut discs = Vec::new();
+
+    for line in input.lines() {
+        let mut words = line.split_ascii_whitespace();
+
+        words.next();
+        words.next();
+        words.next();
+
+        let position_count = words.next().unwrap().parse().unwrap();
+
+        words.next();
+        words.next();
+        words.next();
+        words.next();
+        words.next();
+        words.next();
+        words.next();
+
+        let starting_position = words.next().unwrap();
+        let starting_position = starting_position[..starting_position.len() - 1]
+            .parse()
+            .unwrap();
+
+        discs.push(Disc {
+            position_count,
+            starting_position,
+        })
+    }
+
+    discs
+}
+
+fn find_capsule_time(discs: &[Disc]) -> usize {
+    for i in 0.. {
+        if discs
+            .iter()
+            .enumerate()
+            .all(|(offset, disc)| disc.get_position(i + offset + 1) == 0)
+        {
+            return i;
+        }
+    }
+
+    unreachable!()
+}
+
+fn solve_one(discs: &[Disc]) -> usize {
+    find_capsule_time(discs)
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(discs: &[Disc]) -> usize {
+    let mut discs = discs.to_vec();
+
+    discs.push(Disc {
+        starting_position: 0,
+        position_count: 11,
+    });
+
+    find_capsule_time(&discs)
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 15;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Disc>;
+
+    fn parse(input: &str) -> Result<Vec<Disc>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Disc>) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Disc>) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,110 @@
+fn generate_data(input: &str, length: usize) -> String {
+    let mut result = input.to_string();
+
+    while result.len() < length {
+        let mut buffer = String::with_capacity(result.len() * 2 + 1);
+        buffer.push_str(&result);
+        buffer.push('0');
+
+        let b = result.chars().rev().map(|c| match c {
+            '0' => '1',
+            _ => '0',
+        });
+
+        buffer.extend(b);
+        result = buffer;
+    }
+
+    result.replace_range(length.., "");
+
+    result
+}
+
+#[test]
+fn test_generate_data() {
+    assert_eq!(generate_data("1", 3), "100");
+    assert_eq!(generate_data("0", 3), "001");
+    assert_eq!(generate_data("11111", 11), "11111000000");
+    assert_eq!(
+        generate_data("111100001010", 25),
+        "1111000010100101011110000"
+    );
+    assert_eq!(generate_data("10000", 20), "10000011110010000111");
+}
+
+fn find_checksum(input: &str, length: usize) -> String {
+    let mut current = generate_data(input, length);
+
+    while current.len().is_multiple_of(2) {
+        current = current
+            .as_bytes()
+            .chunks(2)
+            .map(|chunk| match chunk {
+                [b'1', b'1'] | [b'0', b'0'] => '1',
+                _ => '0',
+            })
+            .collect();
+    }
+
+    current
+}
+
+#[test]
+fn test_find_checksum() {
+    assert_eq!(find_checksum("110010110100", 12), "100");
+    assert_eq!(find_checksum("10000011110010000111", 20), "01100");
+}
+
+fn parse(input: &str) -> String {
+    input.to_string()
+}
+
+fn solve_one(input: &str) -> String {
+    find_checksum(input, 272)
+}
+
+fn solve_two(input: &str) -> String {
+    find_checksum(input, 35651584)
+}
+
+pub fn part_one(input: &str) -> String {
+    solve_one(&parse(input))
+}
+
+pub fn part_two(input: &str) -> String {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 16;
+
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn part_one(input: &str) -> String {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> String {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &String) -> String {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &String) -> String {
+        solve_two(input)
+    }
+}
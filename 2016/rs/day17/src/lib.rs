@@ -0,0 +1,186 @@
+use std::cmp;
+use std::collections::VecDeque;
+
+fn compute_md5(string: &str) -> String {
+    format!("{:x}", md5::compute(string))
+}
+
+fn parse(input: &str) -> String {
+    input.trim().to_string()
+}
+
+fn solve_one(input: &str) -> String {
+    let mut min_steps = i32::MAX;
+    let mut shortest = String::new();
+
+    let mut base = input.to_owned();
+
+    let mut queue = VecDeque::new();
+    queue.push_back((String::new(), (0, 0), 0));
+
+    while let Some((mut path, (x, y), steps)) = queue.pop_front() {
+        if x == 3 && y == 3 {
+            min_steps = cmp::min(min_steps, steps);
+            shortest = path;
+            continue;
+        }
+
+        if steps > min_steps {
+            continue;
+        }
+
+        base.push_str(&path);
+
+        for tuple in compute_md5(&base).chars().enumerate().take(4) {
+            let next_char;
+            let mut next_x = x;
+            let mut next_y = y;
+
+            match tuple {
+                (0, 'b'..='f') => {
+                    next_y = cmp::max(0, y - 1);
+                    next_char = 'U';
+                }
+                (1, 'b'..='f') => {
+                    next_y = cmp::min(3, y + 1);
+                    next_char = 'D';
+                }
+                (2, 'b'..='f') => {
+                    next_x = cmp::max(0, x - 1);
+                    next_char = 'L';
+                }
+                (3, 'b'..='f') => {
+                    next_x = cmp::min(3, x + 1);
+                    next_char = 'R';
+                }
+                _ => continue,
+            }
+
+            if next_x == x && next_y == y {
+                continue;
+            }
+
+            path.push(next_char);
+            queue.push_back((path.clone(), (next_x, next_y), steps + 1));
+            path.pop();
+        }
+
+        base.replace_range(input.len().., "");
+    }
+
+    shortest
+}
+
+pub fn part_one(input: &str) -> String {
+    solve_one(&parse(input))
+}
+
+#[test]
+fn test_part_one() {
+    assert_eq!(part_one("ihgpwlah"), "DDRRRD");
+    assert_eq!(part_one("kglvqrro"), "DDUDRLRRUDRD");
+    assert_eq!(part_one("ulqzkmiv"), "DRURDRUDDLLDLUURRDULRLDUUDDDRR");
+}
+
+fn solve_two(input: &str) -> i32 {
+    let mut max_steps = 0;
+
+    let mut base = input.to_owned();
+
+    let mut queue = VecDeque::new();
+    queue.push_back((String::new(), (0, 0), 0));
+
+    while let Some((mut path, (x, y), steps)) = queue.pop_front() {
+        if x == 3 && y == 3 {
+            max_steps = cmp::max(max_steps, steps);
+            continue;
+        }
+
+        base.push_str(&path);
+
+        for tuple in compute_md5(&base).chars().enumerate().take(4) {
+            let next_char;
+            let mut next_x = x;
+            let mut next_y = y;
+
+            match tuple {
+                (0, 'b'..='f') => {
+                    next_y = cmp::max(0, y - 1);
+                    next_char = 'U';
+                }
+                (1, 'b'..='f') => {
+                    next_y = cmp::min(3, y + 1);
+                    next_char = 'D';
+                }
+                (2, 'b'..='f') => {
+                    next_x = cmp::max(0, x - 1);
+                    next_char = 'L';
+                }
+                (3, 'b'..='f') => {
+                    next_x = cmp::min(3, x + 1);
+                    next_char = 'R';
+                }
+                _ => continue,
+            }
+
+            if next_x == x && next_y == y {
+                continue;
+            }
+
+            path.push(next_char);
+            queue.push_back((path.clone(), (next_x, next_y), steps + 1));
+            path.pop();
+        }
+
+        base.replace_range(input.len().., "");
+    }
+
+    max_steps
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+#[test]
+fn test_part_two() {
+    assert_eq!(part_two("ihgpwlah"), 370);
+    assert_eq!(part_two("kglvqrro"), 492);
+    assert_eq!(part_two("ulqzkmiv"), 830);
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 17;
+
+    type PartOne = String;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> String {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &String) -> String {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &String) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,135 @@
+fn get_next_row(input: &[char], buffer: &mut [char]) {
+    let mut chars = input.iter();
+
+    let mut left = Some(&'.');
+    let mut center = chars.next();
+    let mut right = chars.next();
+
+    let mut i = 0;
+
+    while i < input.len() {
+        let result = match (left, center, right) {
+            (Some('^'), Some('^'), Some('.'))
+            | (Some('.'), Some('^'), Some('^'))
+            | (Some('^'), Some('.'), Some('.'))
+            | (Some('.'), Some('.'), Some('^')) => '^',
+            _ => '.',
+        };
+
+        buffer[i] = result;
+        i += 1;
+
+        left = center;
+        center = right;
+        right = chars.next().or(Some(&'.'))
+    }
+}
+
+#[test]
+fn test_get_next_row() {
+    let row = |row: &str| row.chars().collect::<Vec<_>>();
+
+    let mut buffer = row(".....");
+    get_next_row(&row("..^^."), &mut buffer);
+    assert_eq!(buffer, row(".^^^^"));
+    get_next_row(&row(".^^^^"), &mut buffer);
+    assert_eq!(buffer, row("^^..^"));
+
+    let ten_by_ten = [
+        ".^^.^.^^^^",
+        "^^^...^..^",
+        "^.^^.^.^^.",
+        "..^^...^^^",
+        ".^^^^.^^.^",
+        "^^..^.^^..",
+        "^^^^..^^^.",
+        "^..^^^^.^^",
+        ".^^^..^.^^",
+        "^^.^^^..^^",
+    ];
+
+    let mut buffer = row(ten_by_ten[0]);
+    for rows in ten_by_ten.windows(2) {
+        get_next_row(&row(rows[0]), &mut buffer);
+        assert_eq!(buffer, row(rows[1]))
+    }
+}
+
+fn count_safe_tiles(input: &str, iterations: i32) -> i32 {
+    let mut safe = 0;
+
+    let mut current: Vec<char> = input.chars().collect();
+    let mut buffer = current.clone();
+
+    for _ in 0..iterations {
+        for c in current.iter() {
+            if *c == '.' {
+                safe += 1;
+            }
+        }
+
+        get_next_row(&current, &mut buffer);
+        std::mem::swap(&mut current, &mut buffer);
+    }
+
+    safe
+}
+
+#[test]
+fn test_count_safe_tiles() {
+    assert_eq!(count_safe_tiles(".^^.^.^^^^", 10), 38);
+}
+
+fn parse(input: &str) -> String {
+    input.trim().to_string()
+}
+
+fn solve_one(input: &str) -> i32 {
+    count_safe_tiles(input, 40)
+}
+
+fn solve_two(input: &str) -> i32 {
+    count_safe_tiles(input, 400000)
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 18;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &String) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &String) -> i32 {
+        solve_two(input)
+    }
+}
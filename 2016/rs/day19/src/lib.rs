@@ -0,0 +1,108 @@
+use std::collections::VecDeque;
+
+#[derive(Debug)]
+struct Elf {
+    id: usize,
+    has_present: bool,
+}
+
+fn parse(input: &str) -> usize {
+    input.trim().parse().unwrap()
+}
+
+fn solve_one(elf_count: &usize) -> usize {
+    let elf_count = *elf_count;
+    let mut queue = (1..=elf_count)
+        .map(|id| Elf {
+            id,
+            has_present: true,
+        })
+        .collect::<VecDeque<_>>();
+
+    let mut current = queue.pop_front().unwrap();
+    let mut next = queue.pop_front().unwrap();
+
+    while queue.len() != 1 {
+        if current.has_present {
+            next.has_present = false;
+            queue.push_back(current);
+        }
+
+        current = next;
+        next = queue.pop_front().unwrap();
+    }
+
+    queue[0].id
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(elf_count: &usize) -> usize {
+    let elf_count = *elf_count;
+    let mut front_half = VecDeque::with_capacity(elf_count / 2);
+    let mut back_half = VecDeque::with_capacity(elf_count / 2);
+
+    for i in 1..=elf_count {
+        if i <= elf_count / 2 {
+            front_half.push_back(i);
+        } else {
+            back_half.push_back(i);
+        }
+    }
+
+    while front_half.len() != 1 {
+        let current = front_half.pop_front().unwrap();
+        if front_half.len() == back_half.len() {
+            front_half.pop_back();
+        } else {
+            back_half.pop_front();
+        }
+
+        back_half.push_back(current);
+        front_half.push_back(back_half.pop_front().unwrap());
+    }
+
+    front_half[0]
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 19;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = usize;
+
+    fn parse(input: &str) -> Result<usize, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &usize) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &usize) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
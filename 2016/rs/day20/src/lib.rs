@@ -0,0 +1,123 @@
+use std::cmp;
+
+/// An inclusive range of blocked addresses.
+#[derive(Clone, Copy, Debug)]
+pub struct Range(u32, u32);
+
+impl Range {
+    fn from(string: &str) -> Self {
+        let mut parts = string.split('-');
+
+        Self(
+            parts.next().unwrap().parse().unwrap(),
+            parts.next().unwrap().parse().unwrap(),
+        )
+    }
+
+    fn contains(&self, value: u32) -> bool {
+        self.0 <= value && self.1 >= value
+    }
+
+    fn lt(&self, value: u32) -> bool {
+        self.1 < value
+    }
+
+    fn overlaps(&self, other: &Range) -> bool {
+        self.contains(other.0) || self.contains(other.1)
+    }
+
+    fn abuts(&self, other: &Range) -> bool {
+        if self.1 == u32::MAX || other.1 == u32::MAX {
+            return false;
+        }
+
+        self.1 + 1 == other.0 || other.1 + 1 == self.0
+    }
+
+    fn merge(self, other: Self) -> Self {
+        Self(cmp::min(self.0, other.0), cmp::max(self.1, other.1))
+    }
+}
+
+fn parse(input: &str) -> Vec<Range> {
+    let mut ranges: Vec<_> = input.lines().map(Range::from).collect();
+    ranges.sort_by_key(|a| a.0);
+    ranges
+}
+
+fn solve_one(ranges: &[Range]) -> u32 {
+    let mut lowest = 0;
+
+    for range in ranges {
+        if range.contains(lowest) {
+            lowest = range.1 + 1;
+        } else if range.lt(lowest) {
+            continue;
+        } else {
+            break;
+        }
+    }
+
+    lowest
+}
+
+pub fn part_one(input: &str) -> u32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(ranges: &[Range]) -> u32 {
+    let mut allowed = 0;
+    let mut iterator = ranges.iter().copied();
+    let mut current = iterator.next().unwrap();
+
+    for next in iterator {
+        if current.overlaps(&next) || current.abuts(&next) {
+            current = current.merge(next);
+        } else {
+            allowed += next.0 - current.1 - 1;
+            current = next;
+        }
+    }
+
+    allowed
+}
+
+pub fn part_two(input: &str) -> u32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 20;
+
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn part_one(input: &str) -> u32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> u32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Range>;
+
+    fn parse(input: &str) -> Result<Vec<Range>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Range>) -> u32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Range>) -> u32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,227 @@
+use std::collections::{HashSet, VecDeque};
+
+/// Storage nodes, indexed by row and then column.
+pub type Grid = Vec<Vec<Node>>;
+
+/// A storage node and its disk usage.
+#[derive(Debug)]
+pub struct Node {
+    x: usize,
+    y: usize,
+    used: usize,
+    avail: usize,
+    size: usize,
+}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Node) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+impl Node {
+    fn new(x: usize, y: usize, used: usize, avail: usize) -> Self {
+        Self {
+            x,
+            y,
+            used,
+            avail,
+            size: used + avail,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.used == 0
+    }
+
+    fn can_contain(&self, other: &Self) -> bool {
+        self.avail > other.used
+    }
+
+    fn could_contain(&self, other: &Self) -> bool {
+        self.size > other.used
+    }
+}
+
+fn parse(input: &str) -> Grid {
+    let mut grid = Vec::new();
+
+    // skips the prompt and the header, of which the example only has the header
+    for line in input.lines().filter(|line| line.starts_with("/dev/grid")) {
+        let mut words = line.split_ascii_whitespace();
+
+        let mut id_parts = words.next().unwrap().split('-');
+        id_parts.next();
+
+        let x: usize = id_parts.next().unwrap()[1..].parse().unwrap();
+        let y: usize = id_parts.next().unwrap()[1..].parse().unwrap();
+
+        let row = match grid.get_mut(y) {
+            Some(row) => row,
+            None => {
+                grid.push(Vec::new());
+                grid.get_mut(y).unwrap()
+            }
+        };
+
+        words.next();
+        let used = words.next().unwrap();
+        let avail = words.next().unwrap();
+
+        row.push(Node::new(
+            x,
+            y,
+            used[..used.len() - 1].parse().unwrap(),
+            avail[..avail.len() - 1].parse().unwrap(),
+        ))
+    }
+
+    grid
+}
+
+fn solve_one(grid: &Grid) -> i32 {
+    let mut viable_pairs = 0;
+
+    for row1 in grid.iter() {
+        for node1 in row1 {
+            for row2 in grid.iter() {
+                for node2 in row2 {
+                    if !node1.is_empty() && node1 != node2 && node2.can_contain(node1) {
+                        viable_pairs += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    viable_pairs
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+struct State {
+    x: usize,
+    y: usize,
+    steps: usize,
+}
+
+fn reduce_coord(x_or_y: usize, diff: i32) -> usize {
+    match diff {
+        -1 => x_or_y.saturating_sub(1),
+        0 => x_or_y,
+        1 => x_or_y + 1,
+        _ => unreachable!(),
+    }
+}
+
+fn get_next_coords(grid: &Grid, state: &State, x: i32, y: i32) -> Option<(usize, usize)> {
+    if x == 0 && y == 0 {
+        return None;
+    }
+
+    let new_x = reduce_coord(state.x, x);
+    let new_y = reduce_coord(state.y, y);
+
+    if new_y == state.y && new_x == state.x {
+        return None;
+    }
+
+    if let Some(row) = grid.get(new_y) {
+        if let Some(neighbor) = row.get(new_x) {
+            if grid[state.y][state.x].could_contain(neighbor) {
+                return Some((new_x, new_y));
+            }
+        }
+    }
+
+    None
+}
+
+const NEIGHBORS: [[i32; 2]; 4] = [[1, 0], [-1, 0], [0, 1], [0, -1]];
+
+fn solve_two(grid: &Grid) -> usize {
+    let mut empty_location = None;
+
+    for row in grid {
+        for node in row {
+            if node.used == 0 {
+                empty_location = Some((node.x, node.y));
+                break;
+            }
+        }
+    }
+
+    let (x, y) = empty_location.expect("failed to find starting location");
+    let initial_state = State { x, y, steps: 0 };
+    let mut queue = VecDeque::from([initial_state]);
+    let mut seen = HashSet::from([(x, y)]);
+    let mut steps_taken_to_corner = None;
+
+    let max_x = grid[0].len() - 2;
+    while let Some(state) = queue.pop_front() {
+        if state.x == max_x && state.y == 0 {
+            steps_taken_to_corner = Some(state.steps);
+            break;
+        }
+
+        for [x, y] in NEIGHBORS {
+            if let Some(coords) = get_next_coords(grid, &state, x, y) {
+                if seen.contains(&coords) {
+                    continue;
+                }
+                seen.insert(coords);
+                queue.push_back(State {
+                    x: coords.0,
+                    y: coords.1,
+                    steps: state.steps + 1,
+                })
+            }
+        }
+    }
+
+    steps_taken_to_corner.expect("failed to find corner") // steps to spot just before corner
+        + 1 // move to corner
+        + max_x * 5 // it takes 5 steps per movement of the target data
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 22;
+
+    type PartOne = i32;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Grid, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Grid) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Grid) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
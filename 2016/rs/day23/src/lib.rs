@@ -0,0 +1,222 @@
+use aoc_core::{
+    error::{self, Token, Tokens},
+    Error,
+};
+
+/// One of the four registers.
+#[derive(Clone, Copy, Debug)]
+pub enum Register {
+    A,
+    B,
+    C,
+    D,
+}
+
+/// An instruction operand.
+#[derive(Clone, Copy, Debug)]
+pub enum Argument {
+    Literal(i32),
+    Register(Register),
+}
+
+impl TryFrom<Token<'_>> for Argument {
+    type Error = Error;
+
+    fn try_from(token: Token) -> Result<Self, Error> {
+        match token.text {
+            "a" => Ok(Self::Register(Register::A)),
+            "b" => Ok(Self::Register(Register::B)),
+            "c" => Ok(Self::Register(Register::C)),
+            "d" => Ok(Self::Register(Register::D)),
+            _ => token.parse("register or value").map(Self::Literal),
+        }
+    }
+}
+
+/// A single assembunny instruction.
+#[derive(Clone, Copy, Debug)]
+pub enum Instruction {
+    Copy(Argument, Argument),
+    Increment(Argument),
+    Decrement(Argument),
+    JumpIfNotZero(Argument, Argument),
+    Toggle(Argument),
+}
+
+impl std::str::FromStr for Instruction {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut tokens = Tokens::new(line);
+        let token = tokens.next("instruction")?;
+        let mut argument = || Argument::try_from(tokens.next("argument")?);
+
+        let inst = match token.text {
+            "cpy" => Instruction::Copy(argument()?, argument()?),
+            "inc" => Instruction::Increment(argument()?),
+            "dec" => Instruction::Decrement(argument()?),
+            "jnz" => Instruction::JumpIfNotZero(argument()?, argument()?),
+            "tgl" => Instruction::Toggle(argument()?),
+            _ => return Err(token.error(format!("unknown instruction `{}`", token.text))),
+        };
+        tokens.end()?;
+
+        Ok(inst)
+    }
+}
+
+impl Instruction {
+    fn toggle(&self) -> Self {
+        match self {
+            Self::Increment(arg) => Self::Decrement(*arg),
+            Self::Decrement(arg) | Self::Toggle(arg) => Self::Increment(*arg),
+            Self::JumpIfNotZero(arg1, arg2) => Self::Copy(*arg1, *arg2),
+            Self::Copy(arg1, arg2) => Self::JumpIfNotZero(*arg1, *arg2),
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Instruction>, Error> {
+    error::parse_lines(input, str::parse)
+}
+
+struct Computer {
+    a: i32,
+    b: i32,
+    c: i32,
+    d: i32,
+    pc: usize,
+
+    instructions: Vec<Instruction>,
+}
+
+impl Computer {
+    fn new(instructions: &[Instruction]) -> Self {
+        Self {
+            a: 0,
+            b: 0,
+            c: 0,
+            d: 0,
+            pc: 0,
+            instructions: instructions.to_vec(),
+        }
+    }
+
+    fn get_register_mut(&mut self, reg: Register) -> &mut i32 {
+        match reg {
+            Register::A => &mut self.a,
+            Register::B => &mut self.b,
+            Register::C => &mut self.c,
+            Register::D => &mut self.d,
+        }
+    }
+
+    fn get_register(&self, reg: Register) -> &i32 {
+        match reg {
+            Register::A => &self.a,
+            Register::B => &self.b,
+            Register::C => &self.c,
+            Register::D => &self.d,
+        }
+    }
+
+    fn get_value(&self, arg: Argument) -> i32 {
+        match arg {
+            Argument::Literal(value) => value,
+            Argument::Register(reg) => *self.get_register(reg),
+        }
+    }
+
+    fn run(&mut self) -> i32 {
+        while let Some(instruction) = self.instructions.get(self.pc) {
+            let mut offset = 1;
+
+            match *instruction {
+                Instruction::Copy(src, Argument::Register(dest)) => {
+                    *self.get_register_mut(dest) = self.get_value(src)
+                }
+                Instruction::Increment(Argument::Register(reg)) => *self.get_register_mut(reg) += 1,
+                Instruction::Decrement(Argument::Register(reg)) => *self.get_register_mut(reg) -= 1,
+                Instruction::JumpIfNotZero(value, jump) => {
+                    if self.get_value(value) != 0 {
+                        offset = self.get_value(jump);
+                    }
+                }
+                Instruction::Toggle(arg) => {
+                    let target = self.pc.checked_add_signed(self.get_value(arg) as isize);
+                    if let Some(inst) = target.and_then(|target| self.instructions.get_mut(target))
+                    {
+                        *inst = inst.toggle()
+                    }
+                }
+                // toggling can leave instructions writing to a value, which are skipped
+                Instruction::Copy(_, Argument::Literal(_))
+                | Instruction::Increment(Argument::Literal(_))
+                | Instruction::Decrement(Argument::Literal(_)) => (),
+            }
+
+            match self.pc.checked_add_signed(offset as isize) {
+                Some(pc) => self.pc = pc,
+                None => break,
+            }
+        }
+
+        self.a
+    }
+}
+
+fn solve_one(instructions: &[Instruction]) -> i32 {
+    let mut computer = Computer::new(instructions);
+    computer.a = 7;
+    computer.run()
+}
+
+fn solve_two(instructions: &[Instruction]) -> i32 {
+    let mut computer = Computer::new(instructions);
+    computer.a = 12;
+    computer.run()
+}
+
+pub fn part_one(input: &str) -> Result<i32, Error> {
+    Ok(solve_one(&parse(input)?))
+}
+
+pub fn part_two(input: &str) -> Result<i32, Error> {
+    Ok(solve_two(&parse(input)?))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 23;
+
+    type PartOne = Result<i32, Error>;
+    type PartTwo = Result<i32, Error>;
+
+    fn part_one(input: &str) -> Result<i32, Error> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Result<i32, Error> {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, aoc_core::Error> {
+        parse(input)
+    }
+
+    fn solve_one(input: &Vec<Instruction>) -> Result<i32, Error> {
+        Ok(solve_one(input))
+    }
+
+    fn solve_two(input: &Vec<Instruction>) -> Result<i32, Error> {
+        Ok(solve_two(input))
+    }
+}
+
+aoc_core::examples!(Solver);
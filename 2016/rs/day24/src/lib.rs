@@ -0,0 +1,184 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+fn noop(value: usize) -> usize {
+    value
+}
+
+fn inc(value: usize) -> usize {
+    value + 1
+}
+
+fn dec(value: usize) -> usize {
+    value - 1
+}
+
+type Movement = fn(usize) -> usize;
+
+const MOVEMENTS: [(Movement, Movement); 4] = [(noop, inc), (noop, dec), (inc, noop), (dec, noop)];
+
+fn permute(size: usize) -> Vec<Vec<usize>> {
+    let mut base_array: Vec<usize> = (0..size).collect();
+    let mut permutations = vec![base_array.clone()];
+    let mut c = vec![0; size];
+    let mut i = 0;
+
+    while i < size {
+        if c[i] < i {
+            match i % 2 {
+                0 => base_array.swap(0, i),
+                _ => base_array.swap(c[i], i),
+            }
+
+            permutations.push(base_array.clone());
+            c[i] += 1;
+            i = 0;
+        } else {
+            c[i] = 0;
+            i += 1;
+        }
+    }
+
+    permutations
+}
+
+fn parse(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|line| line.chars().collect()).collect()
+}
+
+fn calculate_distance(map: &[Vec<char>], should_return: bool) -> usize {
+    let mut number_locations = HashMap::new();
+    for (y, row) in map.iter().enumerate() {
+        for (x, &c) in row.iter().enumerate() {
+            if c.is_ascii_digit() {
+                number_locations.insert(c, (x, y));
+            }
+        }
+    }
+
+    let mut distances_map = HashMap::<usize, Vec<usize>>::new();
+    let number_count = number_locations.len();
+
+    for (&c, &(x, y)) in number_locations.iter() {
+        let mut distances = vec![0; number_count];
+        let mut seen = HashSet::from([(x, y)]);
+        let mut states = VecDeque::from([(x, y, 0)]);
+
+        while let Some((x, y, steps)) = states.pop_front() {
+            if let Some(row) = map.get(y) {
+                if let Some(c @ '0'..='9') = row.get(x) {
+                    let number = c.to_digit(10).expect("failed to convert to digit") as usize;
+                    distances[number] = steps;
+                }
+            }
+
+            for (x_action, y_action) in MOVEMENTS {
+                let new_x = x_action(x);
+                let new_y = y_action(y);
+                if seen.contains(&(new_x, new_y)) {
+                    continue;
+                }
+                if let Some(row) = map.get(new_y) {
+                    match row.get(new_x) {
+                        Some('#') => (),
+                        _ => {
+                            seen.insert((new_x, new_y));
+                            states.push_back((new_x, new_y, steps + 1))
+                        }
+                    }
+                }
+            }
+        }
+
+        distances_map.insert(c.to_digit(10).unwrap() as usize, distances);
+    }
+
+    let mut shortest = usize::MAX;
+
+    for permutation in permute(number_count) {
+        if permutation.first() != Some(&0) {
+            continue;
+        }
+        let mut length = 0;
+        for i in 0..number_count - 1 {
+            match (permutation.get(i), permutation.get(i + 1)) {
+                (Some(current), Some(next)) => match distances_map.get(current) {
+                    Some(distances) => match distances.get(*next) {
+                        Some(distance) => length += distance,
+                        _ => unreachable!(),
+                    },
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            }
+        }
+
+        if should_return {
+            length += match permutation.last() {
+                Some(number) => match distances_map.get(number) {
+                    Some(distances) => match distances.first() {
+                        Some(value) => value,
+                        _ => unreachable!(),
+                    },
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            };
+        }
+
+        shortest = shortest.min(length);
+    }
+
+    shortest
+}
+
+fn solve_one(map: &[Vec<char>]) -> usize {
+    calculate_distance(map, false)
+}
+
+fn solve_two(map: &[Vec<char>]) -> usize {
+    calculate_distance(map, true)
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 24;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<char>>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Vec<char>>) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Vec<char>>) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,101 @@
+fn parse(input: &str) -> String {
+    input.trim().to_string()
+}
+
+fn solve_one(input: &str) -> u32 {
+    let zipped = input.chars().zip(input.chars().cycle().skip(1));
+
+    let mut sum = 0;
+
+    for (current, next) in zipped {
+        if current == next {
+            sum += current.to_digit(10).unwrap()
+        }
+    }
+
+    sum
+}
+
+pub fn part_one(input: &str) -> u32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(input: &str) -> u32 {
+    let half_len = input.len() / 2;
+
+    let first_half_chars = input[..half_len].chars();
+    let second_half_chars = input[half_len..].chars();
+    let zipped = first_half_chars.zip(second_half_chars);
+
+    let mut sum = 0;
+
+    for (c1, c2) in zipped {
+        if c1 == c2 {
+            sum += c1.to_digit(10).unwrap() * 2;
+        }
+    }
+
+    sum
+}
+
+pub fn part_two(input: &str) -> u32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 1;
+
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn part_one(input: &str) -> u32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> u32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &String) -> u32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &String) -> u32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
+
+#[cfg(test)]
+mod tests {
+    use crate::{part_one, part_two};
+
+    #[test]
+    fn part_one_test() {
+        assert_eq!(part_one("1122"), 3);
+        assert_eq!(part_one("1111"), 4);
+        assert_eq!(part_one("1234"), 0);
+        assert_eq!(part_one("91212129"), 9);
+    }
+
+    #[test]
+    fn part_two_test() {
+        assert_eq!(part_two("1212"), 6);
+        assert_eq!(part_two("1221"), 0);
+        assert_eq!(part_two("123425"), 4);
+        assert_eq!(part_two("123123"), 12);
+        assert_eq!(part_two("12131415"), 4);
+    }
+}
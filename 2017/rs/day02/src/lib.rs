@@ -0,0 +1,121 @@
+fn parse(input: &str) -> Vec<Vec<i32>> {
+    input
+        .lines()
+        .map(|line| {
+            line.split_ascii_whitespace()
+                .map(|value| value.parse().unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+fn solve_one(rows: &[Vec<i32>]) -> i32 {
+    let mut sum = 0;
+
+    for row in rows {
+        let mut min = i32::MAX;
+        let mut max = i32::MIN;
+
+        for &num in row {
+            min = min.min(num);
+            max = max.max(num);
+        }
+
+        sum += max - min;
+    }
+
+    sum
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(rows: &[Vec<i32>]) -> i32 {
+    rows.iter()
+        .map(|nums| {
+            for (start, value1) in nums.iter().enumerate() {
+                for value2 in nums.iter().skip(start + 1) {
+                    if value1 % value2 == 0 {
+                        return value1 / value2;
+                    }
+
+                    if value2 % value1 == 0 {
+                        return value2 / value1;
+                    }
+                }
+            }
+
+            unreachable!()
+        })
+        .sum()
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 2;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i32>>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Vec<i32>>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Vec<i32>>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
+
+#[cfg(test)]
+mod tests {
+    use crate::{part_one, part_two};
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(
+            part_one(
+                "5 1 9 5
+7 5 3
+2 4 6 8"
+            ),
+            18
+        );
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(
+            part_two(
+                "5 9 2 8
+9 4 7 3
+3 8 6 5"
+            ),
+            9
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+fn parse(input: &str) -> Vec<String> {
+    input.lines().map(str::to_string).collect()
+}
+
+fn solve_one(lines: &[String]) -> i32 {
+    let mut frequency_counts = HashMap::<&str, i32>::new();
+    let mut valid_count = 0;
+
+    for line in lines {
+        frequency_counts.clear();
+        valid_count += 1;
+
+        for word in line.split_ascii_whitespace() {
+            let entry = frequency_counts.entry(word).or_default();
+            if *entry != 0 {
+                valid_count -= 1;
+                break;
+            }
+            *entry += 1;
+        }
+    }
+
+    valid_count
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(lines: &[String]) -> i32 {
+    let mut frequency_counts = HashMap::<String, i32>::new();
+    let mut valid_count = 0;
+
+    for line in lines {
+        frequency_counts.clear();
+        valid_count += 1;
+
+        for word in line.split_ascii_whitespace() {
+            let mut chars = word.chars().collect::<Vec<char>>();
+            chars.sort();
+
+            let entry = frequency_counts
+                .entry(chars.into_iter().collect())
+                .or_default();
+            if *entry != 0 {
+                valid_count -= 1;
+                break;
+            }
+            *entry += 1;
+        }
+    }
+
+    valid_count
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 4;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<String>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<String>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
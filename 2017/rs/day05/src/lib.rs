@@ -0,0 +1,75 @@
+fn parse(input: &str) -> Vec<i32> {
+    input.lines().map(|line| line.parse().unwrap()).collect()
+}
+
+fn solve_one(offsets: &[i32]) -> i32 {
+    let mut offsets = offsets.to_vec();
+    let mut steps = 0;
+    let mut pc = 0i32;
+
+    while let Some(offset) = offsets.get_mut(pc as usize) {
+        steps += 1;
+        pc += *offset;
+        *offset += 1;
+    }
+
+    steps
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(offsets: &[i32]) -> i32 {
+    let mut offsets = offsets.to_vec();
+    let mut steps = 0;
+    let mut pc = 0i32;
+
+    while let Some(offset) = offsets.get_mut(pc as usize) {
+        steps += 1;
+        pc += *offset;
+        *offset += if *offset >= 3 { -1 } else { 1 };
+    }
+
+    steps
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 5;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Vec<i32>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<i32>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<i32>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
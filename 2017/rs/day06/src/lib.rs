@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+fn parse(input: &str) -> Vec<i32> {
+    input
+        .split_ascii_whitespace()
+        .map(|l| l.parse().unwrap())
+        .collect()
+}
+
+fn redistribute(banks: &[i32]) -> (i32, i32) {
+    let mut banks = banks.to_vec();
+
+    let mut iterations = 0;
+    let mut seen = HashMap::new();
+
+    while !seen.contains_key(&banks) {
+        seen.insert(banks.clone(), iterations);
+        iterations += 1;
+
+        let mut to_redistribute = *banks.iter().max().unwrap();
+        let mut index = banks.iter().position(|&v| v == to_redistribute).unwrap();
+
+        banks[index] = 0;
+
+        while to_redistribute != 0 {
+            index = (index + 1) % banks.len();
+            banks[index] += 1;
+            to_redistribute -= 1;
+        }
+    }
+
+    let loop_size = iterations - seen.get(&banks).unwrap();
+    (iterations, loop_size)
+}
+
+pub fn solve(input: &str) -> (i32, i32) {
+    redistribute(&parse(input))
+}
+
+fn solve_one(banks: &[i32]) -> i32 {
+    redistribute(banks).0
+}
+
+fn solve_two(banks: &[i32]) -> i32 {
+    redistribute(banks).1
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 6;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Vec<i32>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<i32>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<i32>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
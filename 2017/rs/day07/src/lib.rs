@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+
+/// A program tower node and the programs it holds up.
+#[derive(Debug)]
+pub struct Program {
+    name: String,
+    weight: i32,
+    holds: Option<Vec<String>>,
+}
+
+fn parse(input: &str) -> Vec<Program> {
+    input
+        .lines()
+        .map(|line| {
+            let mut words = line.split_ascii_whitespace();
+
+            let name = words.next().unwrap().to_string();
+            let weight = words.next().unwrap();
+            let weight: i32 = weight[1..weight.len() - 1].parse().unwrap();
+
+            if words.next().is_none() {
+                return Program {
+                    name,
+                    weight,
+                    holds: None,
+                };
+            }
+
+            let mut holds = vec![];
+            for name in words {
+                holds.push(name.strip_suffix(',').unwrap_or(name).to_string());
+            }
+
+            Program {
+                name,
+                weight,
+                holds: Some(holds),
+            }
+        })
+        .collect()
+}
+
+fn find_root(programs: &[Program]) -> &str {
+    let mut current = programs.first().unwrap();
+
+    while let Some(program) = programs.iter().find(|p| match &p.holds {
+        None => false,
+        Some(list) => list.contains(&current.name),
+    }) {
+        current = program;
+    }
+
+    &current.name
+}
+
+fn solve_one(programs: &[Program]) -> String {
+    find_root(programs).to_string()
+}
+
+pub fn part_one(input: &str) -> String {
+    solve_one(&parse(input))
+}
+
+fn find_sum(programs: &HashMap<&str, &Program>, name: &str) -> i32 {
+    let program = programs.get(name).unwrap();
+
+    program.weight
+        + match &program.holds {
+            None => 0,
+            Some(list) => list
+                .iter()
+                .map(|held| find_sum(programs, held))
+                .sum::<i32>(),
+        }
+}
+
+fn solve_two(programs: &[Program]) -> i32 {
+    let root = find_root(programs);
+    let map: HashMap<&str, &Program> = programs.iter().map(|p| (p.name.as_str(), p)).collect();
+    let mut current = map.get(root).unwrap();
+    let mut expected_weight = 0;
+    let mut frequencies = HashMap::<i32, i32>::new();
+
+    while let Some(list) = &current.holds {
+        frequencies.clear();
+        let sums: Vec<i32> = list
+            .iter()
+            .map(|name| {
+                let sum = find_sum(&map, name);
+                let entry = frequencies.entry(sum).or_default();
+                *entry += 1;
+                sum
+            })
+            .collect();
+
+        let mut off_weight = None;
+        if frequencies.len() != 1 {
+            for (&weight, &freq) in frequencies.iter() {
+                if freq != 1 {
+                    expected_weight = weight;
+                } else {
+                    off_weight = Some(weight);
+                }
+            }
+        }
+
+        if let Some(off_weight) = off_weight {
+            let index = sums
+                .into_iter()
+                .position(|weight| weight == off_weight)
+                .unwrap();
+
+            let name = list[index].as_str();
+            current = map.get(name).unwrap();
+        } else {
+            return expected_weight - sums.into_iter().sum::<i32>();
+        }
+    }
+
+    unreachable!()
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 7;
+
+    type PartOne = String;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> String {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Program>;
+
+    fn parse(input: &str) -> Result<Vec<Program>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Program>) -> String {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Program>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
+
+#[cfg(test)]
+mod tests {
+    use crate::part_one;
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(
+            part_one(
+                "pbga (66)
+xhth (57)
+ebii (61)
+havc (66)
+ktlj (57)
+fwft (72) -> ktlj, cntj, xhth
+qoyq (66)
+padx (45) -> pbga, havc, qoyq
+tknk (41) -> ugml, padx, fwft
+jptl (61)
+ugml (68) -> gyxo, ebii, jptl
+gyxo (61)
+cntj (57)"
+            ),
+            "tknk"
+        )
+    }
+}
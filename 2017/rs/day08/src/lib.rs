@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+
+type Registers<'a> = HashMap<&'a str, i32>;
+
+/// How a condition compares a register against a value.
+pub enum ComparisonKind {
+    Lte,
+    Lt,
+    Ne,
+    Gt,
+    Gte,
+    Eq,
+}
+
+/// The condition guarding an instruction.
+pub struct Comparison {
+    register: String,
+    value: i32,
+    kind: ComparisonKind,
+}
+
+impl Comparison {
+    fn from(comparison: &str, register: String, value: i32) -> Self {
+        match comparison {
+            "<=" => Self {
+                register,
+                value,
+                kind: ComparisonKind::Lte,
+            },
+            "<" => Self {
+                register,
+                value,
+                kind: ComparisonKind::Lt,
+            },
+            "!=" => Self {
+                register,
+                value,
+                kind: ComparisonKind::Ne,
+            },
+            ">" => Self {
+                register,
+                value,
+                kind: ComparisonKind::Gt,
+            },
+            ">=" => Self {
+                register,
+                value,
+                kind: ComparisonKind::Gte,
+            },
+            "==" => Self {
+                register,
+                value,
+                kind: ComparisonKind::Eq,
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    fn evaluate(&self, registers: &Registers) -> bool {
+        let register_value = *registers.get(self.register.as_str()).unwrap_or(&0);
+        match self.kind {
+            ComparisonKind::Eq => register_value == self.value,
+            ComparisonKind::Ne => register_value != self.value,
+            ComparisonKind::Lt => register_value < self.value,
+            ComparisonKind::Lte => register_value <= self.value,
+            ComparisonKind::Gt => register_value > self.value,
+            ComparisonKind::Gte => register_value >= self.value,
+        }
+    }
+}
+
+/// A conditional register increment or decrement.
+pub enum Instruction {
+    Inc(String, i32, Comparison),
+    Dec(String, i32, Comparison),
+}
+
+fn parse_instruction(line: &str) -> Instruction {
+    let mut words = line.split_ascii_whitespace();
+
+    let register = words.next().unwrap().to_string();
+    let action = words.next().unwrap();
+    let amount = words.next().unwrap().parse().unwrap();
+
+    words.next();
+
+    let register2 = words.next().unwrap().to_string();
+    let comparison = words.next().unwrap();
+    let value = words.next().unwrap().parse().unwrap();
+
+    match action {
+        "inc" => Instruction::Inc(
+            register,
+            amount,
+            Comparison::from(comparison, register2, value),
+        ),
+        "dec" => Instruction::Dec(
+            register,
+            amount,
+            Comparison::from(comparison, register2, value),
+        ),
+        _ => unreachable!(),
+    }
+}
+
+fn parse(input: &str) -> Vec<Instruction> {
+    input.lines().map(parse_instruction).collect()
+}
+
+fn execute(instructions: &[Instruction]) -> (i32, i32) {
+    let mut registers: Registers = HashMap::new();
+    let mut all_time_max = i32::MIN;
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Inc(register, amount, comparison) => {
+                if comparison.evaluate(&registers) {
+                    let entry = registers.entry(register).or_default();
+                    *entry += *amount;
+                    all_time_max = all_time_max.max(*entry);
+                }
+            }
+            Instruction::Dec(register, amount, comparison) => {
+                if comparison.evaluate(&registers) {
+                    let entry = registers.entry(register).or_default();
+                    *entry -= *amount;
+                    all_time_max = all_time_max.max(*entry);
+                }
+            }
+        }
+    }
+
+    (*registers.values().max().unwrap(), all_time_max)
+}
+
+pub fn solve(input: &str) -> (i32, i32) {
+    execute(&parse(input))
+}
+
+fn solve_one(instructions: &[Instruction]) -> i32 {
+    execute(instructions).0
+}
+
+fn solve_two(instructions: &[Instruction]) -> i32 {
+    execute(instructions).1
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 8;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Instruction>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Instruction>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
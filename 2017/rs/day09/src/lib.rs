@@ -0,0 +1,142 @@
+fn parse_garbage(chars: &mut std::str::Chars) {
+    while let Some(c) = chars.next() {
+        match c {
+            '>' => break,
+            '!' => {
+                chars.next();
+            }
+            _ => continue,
+        }
+    }
+}
+
+fn parse_group(chars: &mut std::str::Chars, depth: i32) -> i32 {
+    let mut score = 0;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => score += parse_group(chars, depth + 1),
+            '<' => parse_garbage(chars),
+            '}' => break,
+            _ => continue,
+        }
+    }
+
+    depth + score
+}
+
+fn parse(input: &str) -> String {
+    input.to_string()
+}
+
+fn solve_one(input: &str) -> i32 {
+    let mut chars = input.chars();
+    chars.next();
+
+    parse_group(&mut chars, 1)
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn count_patch(chars: &mut std::str::Chars) -> i32 {
+    let mut count = 0;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '>' => break,
+            '!' => {
+                chars.next();
+            }
+            _ => count += 1,
+        }
+    }
+
+    count
+}
+
+fn count_garbage(chars: &mut std::str::Chars) -> i32 {
+    let mut score = 0;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => score += count_garbage(chars),
+            '<' => score += count_patch(chars),
+            '}' => break,
+            _ => continue,
+        }
+    }
+
+    score
+}
+
+fn solve_two(input: &str) -> i32 {
+    let mut chars = input.chars();
+
+    count_garbage(&mut chars)
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 9;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &String) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &String) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
+
+#[cfg(test)]
+mod tests {
+    use crate::{part_one, part_two};
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(part_one("{}"), 1);
+        assert_eq!(part_one("{{{}}}"), 6);
+        assert_eq!(part_one("{{},{}}"), 5);
+        assert_eq!(part_one("{{{},{},{{}}}}"), 16);
+        assert_eq!(part_one("{<a>,<a>,<a>,<a>}"), 1);
+        assert_eq!(part_one("{{<ab>},{<ab>},{<ab>},{<ab>}}"), 9);
+        assert_eq!(part_one("{{<!!>},{<!!>},{<!!>},{<!!>}}"), 9);
+        assert_eq!(part_one("{{<a!>},{<a!>},{<a!>},{<ab>}}"), 3);
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(part_two("{<>}"), 0);
+        assert_eq!(part_two("{<random characters>}"), 17);
+        assert_eq!(part_two("{<<<<>}"), 3);
+    }
+}
@@ -0,0 +1,154 @@
+/// A step on the hex grid.
+#[derive(Clone, Copy)]
+pub enum Direction {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+impl std::str::FromStr for Direction {
+    type Err = ();
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let direction = match string {
+            "n" => Direction::North,
+            "ne" => Direction::NorthEast,
+            "nw" => Direction::NorthWest,
+            "s" => Direction::South,
+            "se" => Direction::SouthEast,
+            "sw" => Direction::SouthWest,
+            _ => return Err(()),
+        };
+
+        Ok(direction)
+    }
+}
+
+#[derive(Default)]
+struct Grid {
+    q: i32,
+    r: i32,
+    s: i32,
+}
+
+impl Grid {
+    fn new() -> Self {
+        Default::default()
+    }
+
+    fn distance_from_origin(&self) -> i32 {
+        self.q.abs().max(self.r.abs()).max(self.s.abs())
+    }
+
+    fn step(&mut self, direction: Direction) {
+        match direction {
+            Direction::North => {
+                self.r -= 1;
+                self.s += 1;
+            }
+            Direction::NorthEast => {
+                self.q += 1;
+                self.r -= 1;
+            }
+            Direction::SouthEast => {
+                self.q += 1;
+                self.s -= 1;
+            }
+            Direction::South => {
+                self.r += 1;
+                self.s -= 1;
+            }
+            Direction::SouthWest => {
+                self.q -= 1;
+                self.r += 1;
+            }
+            Direction::NorthWest => {
+                self.q -= 1;
+                self.s += 1;
+            }
+        }
+    }
+}
+
+fn parse(input: &str) -> Vec<Direction> {
+    input
+        .trim()
+        .split(',')
+        .map(|string| match string.parse() {
+            Ok(dir) => dir,
+            _ => unreachable!("{}", string),
+        })
+        .collect()
+}
+
+fn walk(directions: &[Direction]) -> (i32, i32) {
+    let mut grid = Grid::new();
+    let mut max_distance = 0;
+
+    for &dir in directions {
+        grid.step(dir);
+
+        max_distance = max_distance.max(grid.distance_from_origin());
+    }
+
+    (grid.distance_from_origin(), max_distance)
+}
+
+pub fn solve(input: &str) -> (i32, i32) {
+    walk(&parse(input))
+}
+
+fn solve_one(directions: &[Direction]) -> i32 {
+    walk(directions).0
+}
+
+fn solve_two(directions: &[Direction]) -> i32 {
+    walk(directions).1
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 11;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Direction>;
+
+    fn parse(input: &str) -> Result<Vec<Direction>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Direction>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Direction>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
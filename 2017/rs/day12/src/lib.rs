@@ -0,0 +1,119 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Each program's id and the ids it can talk to.
+pub struct Graph {
+    map: HashMap<String, Vec<String>>,
+}
+
+impl Graph {
+    fn from(string: &str) -> Self {
+        let mut map = HashMap::new();
+
+        for line in string.lines() {
+            let mut parts = line.split(" <-> ");
+            let id = parts.next().unwrap();
+            let connections = parts.next().unwrap();
+
+            let vec = connections.split(", ").map(str::to_string).collect();
+
+            map.insert(id.to_string(), vec);
+        }
+
+        Self { map }
+    }
+}
+
+fn parse(input: &str) -> Graph {
+    Graph::from(input)
+}
+
+fn solve_one(graph: &Graph) -> usize {
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::from(["0"]);
+
+    while let Some(current) = queue.pop_front() {
+        seen.insert(current);
+        if let Some(vec) = graph.map.get(current) {
+            for conn in vec {
+                if !seen.contains(conn.as_str()) {
+                    queue.push_back(conn.as_str());
+                }
+            }
+        }
+    }
+
+    seen.len()
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(graph: &Graph) -> usize {
+    let mut group_count = 0;
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::new();
+
+    for node in graph.map.keys() {
+        if seen.contains(node) {
+            continue;
+        }
+
+        queue.push_back(node);
+
+        while let Some(current) = queue.pop_front() {
+            seen.insert(current);
+            if let Some(vec) = graph.map.get(current) {
+                for conn in vec {
+                    if !seen.contains(conn) {
+                        queue.push_back(conn);
+                    }
+                }
+            }
+        }
+
+        group_count += 1;
+    }
+
+    group_count
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 12;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Graph;
+
+    fn parse(input: &str) -> Result<Graph, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Graph) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Graph) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
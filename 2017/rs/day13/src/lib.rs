@@ -0,0 +1,127 @@
+use std::cmp::Ordering;
+
+/// A firewall scanner at a given depth.
+#[derive(Debug)]
+pub struct Scanner {
+    index: i32,
+    range: i32,
+    half_circuit: i32,
+}
+
+impl Scanner {
+    fn new(index: i32, range: i32) -> Self {
+        Self {
+            index,
+            range,
+            half_circuit: range - 1,
+        }
+    }
+
+    fn location_after_delay(&self, delay: i32) -> i32 {
+        let current_position = (self.index + delay) % (self.half_circuit * 2);
+
+        match current_position.cmp(&self.half_circuit) {
+            Ordering::Equal | Ordering::Less => current_position,
+            Ordering::Greater => self.range - (current_position - self.half_circuit),
+        }
+    }
+
+    fn location(&self) -> i32 {
+        self.location_after_delay(0)
+    }
+
+    fn severity(&self) -> i32 {
+        self.index * self.range
+    }
+}
+
+fn parse(input: &str) -> Vec<Scanner> {
+    let mut scanners = Vec::new();
+
+    for line in input.lines() {
+        let mut parts = line.split(": ");
+        let index: i32 = parts.next().unwrap().parse().unwrap();
+        let depth = parts.next().unwrap().parse().unwrap();
+
+        scanners.push(Scanner::new(index, depth))
+    }
+
+    scanners
+}
+
+fn solve_one(scanners: &[Scanner]) -> i32 {
+    let mut severity = 0;
+
+    for scanner in scanners {
+        if scanner.location() == 0 {
+            severity += scanner.severity()
+        }
+    }
+
+    severity
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+#[inline(always)]
+fn try_delay(scanners: &[Scanner], delay: i32) -> Result<(), ()> {
+    for scanner in scanners {
+        if scanner.location_after_delay(delay) == 0 {
+            return Err(());
+        }
+    }
+
+    Ok(())
+}
+
+fn solve_two(scanners: &[Scanner]) -> i32 {
+    for delay in 0.. {
+        if try_delay(scanners, delay).is_ok() {
+            return delay;
+        }
+    }
+
+    unreachable!()
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 13;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Scanner>;
+
+    fn parse(input: &str) -> Result<Vec<Scanner>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Scanner>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Scanner>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
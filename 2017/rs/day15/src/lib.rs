@@ -0,0 +1,102 @@
+#[derive(Debug)]
+struct Generator {
+    factor: u64,
+    current: u64,
+    multiple: u64,
+}
+
+impl Generator {
+    fn new(factor: u64, multiple: u64, start: u64) -> Self {
+        Self {
+            factor,
+            multiple,
+            current: start,
+        }
+    }
+}
+
+impl Iterator for Generator {
+    type Item = u16;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.current = (self.current * self.factor) % 2147483647;
+            if self.multiple == 1 || self.current.is_multiple_of(self.multiple) {
+                return Some(self.current as u16);
+            }
+        }
+    }
+}
+
+// the starting values of generators A and B
+fn parse(input: &str) -> (u64, u64) {
+    let mut starts = input.lines().map(|line| {
+        line.split_ascii_whitespace()
+            .nth(4)
+            .unwrap()
+            .parse()
+            .unwrap()
+    });
+
+    (starts.next().unwrap(), starts.next().unwrap())
+}
+
+fn solve_one(&(a, b): &(u64, u64)) -> i32 {
+    Generator::new(16807, 1, a)
+        .zip(Generator::new(48271, 1, b))
+        .take(40_000_000)
+        .map(|(a, b)| if a == b { 1 } else { 0 })
+        .sum()
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(&(a, b): &(u64, u64)) -> i32 {
+    Generator::new(16807, 4, a)
+        .zip(Generator::new(48271, 8, b))
+        .take(5_000_000)
+        .map(|(a, b)| if a == b { 1 } else { 0 })
+        .sum()
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 15;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = (u64, u64);
+
+    fn parse(input: &str) -> Result<(u64, u64), aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &(u64, u64)) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &(u64, u64)) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,152 @@
+use std::collections::VecDeque;
+
+/// A single dance move.
+pub enum Move {
+    Spin(usize),
+    Exchange(usize, usize),
+    Partner(char, char),
+}
+
+fn chars_to_usize(chars: &mut std::str::Chars) -> usize {
+    let mut amount = 0;
+
+    for c in chars.by_ref() {
+        match c {
+            '0'..='9' => {
+                amount *= 10;
+                amount += match c.to_digit(10) {
+                    Some(value) => value as usize,
+                    _ => unreachable!(),
+                }
+            }
+            _ => break,
+        }
+    }
+
+    amount
+}
+
+impl std::str::FromStr for Move {
+    type Err = String;
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut chars = string.chars();
+
+        match chars.next() {
+            Some('s') => Ok(Self::Spin(chars_to_usize(&mut chars))),
+            Some('x') => Ok(Move::Exchange(
+                chars_to_usize(&mut chars),
+                chars_to_usize(&mut chars),
+            )),
+            Some('p') => {
+                let a = chars.next().ok_or_else(|| string.to_owned())?;
+                chars.next();
+
+                Ok(Move::Partner(
+                    a,
+                    chars.next().ok_or_else(|| string.to_owned())?,
+                ))
+            }
+            _ => Err(string.to_owned()),
+        }
+    }
+}
+
+fn parse(input: &str) -> Vec<Move> {
+    input.split(',').map(|part| part.parse().unwrap()).collect()
+}
+
+// dances the programs from a to `last` through `rounds` dances
+fn dance(moves: &[Move], last: char, rounds: usize) -> String {
+    let mut dancers: VecDeque<char> = ('a'..=last).collect();
+
+    let mut seen = Vec::from([dancers.iter().collect()]);
+
+    for _ in 0..rounds {
+        for mv in moves {
+            match *mv {
+                Move::Spin(amount) => {
+                    for _ in 0..amount {
+                        let temp = dancers.pop_back().unwrap();
+                        dancers.push_front(temp);
+                    }
+                }
+                Move::Exchange(a, b) => dancers.swap(a, b),
+                Move::Partner(a, b) => {
+                    let pos_a = dancers.iter().position(|c| *c == a).unwrap();
+                    let pos_b = dancers.iter().position(|c| *c == b).unwrap();
+
+                    dancers.swap(pos_a, pos_b)
+                }
+            }
+        }
+
+        let order = dancers.iter().collect();
+        if seen.contains(&order) {
+            break;
+        }
+        seen.push(order);
+    }
+
+    seen.remove(rounds % seen.len())
+}
+
+fn solve_one(moves: &[Move]) -> String {
+    dance(moves, 'p', 1)
+}
+
+fn solve_two(moves: &[Move]) -> String {
+    dance(moves, 'p', 1_000_000_000)
+}
+
+pub fn part_one(input: &str) -> String {
+    solve_one(&parse(input))
+}
+
+pub fn part_two(input: &str) -> String {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 16;
+
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn part_one(input: &str) -> String {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> String {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Move>;
+
+    fn parse(input: &str) -> Result<Vec<Move>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Move>) -> String {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Move>) -> String {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dance() {
+        assert_eq!(dance(&parse("s1,x3/4,pe/b"), 'e', 1), "baedc");
+        assert_eq!(dance(&parse("s1,x3/4,pe/b"), 'e', 2), "ceadb");
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::VecDeque;
+
+fn parse(input: &str) -> i32 {
+    input.trim().parse().unwrap()
+}
+
+fn solve_one(&input: &i32) -> i32 {
+    let mut queue = VecDeque::from([0]);
+
+    for i in 1..=2017 {
+        for _ in 0..input + 1 {
+            let temp = queue.pop_front().unwrap();
+            queue.push_back(temp);
+        }
+
+        queue.push_front(i)
+    }
+
+    *queue.get(1).unwrap()
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(&input: &i32) -> i32 {
+    let mut queue = VecDeque::with_capacity(50_000_001);
+    queue.push_front(0);
+
+    for i in 1..=50_000_000 {
+        for _ in 0..input + 1 {
+            let temp = queue.pop_front().unwrap();
+            queue.push_back(temp);
+        }
+
+        queue.push_front(i)
+    }
+
+    let index = queue.iter().position(|&v| v == 0).unwrap();
+
+    match queue.get(index + 1) {
+        Some(&value) => value,
+        _ => panic!(),
+    }
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 17;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = i32;
+
+    fn parse(input: &str) -> Result<i32, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &i32) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &i32) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
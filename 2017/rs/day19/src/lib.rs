@@ -0,0 +1,213 @@
+/// A single square of the routing diagram.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Tile {
+    Pipe,
+    Dash,
+    Cross,
+    Letter(char),
+    Wall,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    fn left(self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Left => Self::Down,
+            Self::Down => Self::Right,
+            Self::Right => Self::Up,
+        }
+    }
+
+    fn right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Left => Self::Up,
+            Self::Down => Self::Left,
+            Self::Right => Self::Down,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Diagram {
+    layout: Vec<Vec<Tile>>,
+    direction: Direction,
+    coord: (usize, usize),
+    current_tile: Tile,
+    letters: String,
+    steps: i32,
+}
+
+impl Diagram {
+    fn new(layout: &[Vec<Tile>]) -> Self {
+        let layout = layout.to_vec();
+        let x = layout[0].iter().position(|t| *t == Tile::Pipe).unwrap();
+
+        Self {
+            layout,
+            direction: Direction::Down,
+            coord: (x, 0),
+            current_tile: Tile::Pipe,
+            letters: String::new(),
+            steps: 0,
+        }
+    }
+
+    fn next_coord(&self) -> (usize, usize) {
+        let (x, y) = self.coord;
+
+        match self.direction {
+            Direction::Up => (x, y.saturating_sub(1)),
+            Direction::Down => (x, y + 1),
+            Direction::Left => (x.saturating_sub(1), y),
+            Direction::Right => (x + 1, y),
+        }
+    }
+
+    fn turn(&mut self) -> (usize, usize) {
+        let current = self.direction;
+        self.direction = current.left();
+        let (x, y) = self.next_coord();
+
+        if let Some(row) = self.layout.get(y) {
+            match row.get(x) {
+                Some(Tile::Wall) => (),
+                Some(_) => return (x, y),
+                None => (),
+            }
+        }
+
+        self.direction = current.right();
+        let (x, y) = self.next_coord();
+
+        if let Some(row) = self.layout.get(y) {
+            match row.get(x) {
+                Some(Tile::Wall) => (),
+                Some(_) => return (x, y),
+                None => (),
+            }
+        }
+
+        unreachable!()
+    }
+
+    fn tick(&mut self) -> Option<()> {
+        self.steps += 1;
+        self.coord = match self.current_tile {
+            Tile::Pipe | Tile::Dash => self.next_coord(),
+            Tile::Letter(c) => {
+                self.letters.push(c);
+                self.next_coord()
+            }
+            Tile::Cross => self.turn(),
+            _ => unreachable!(),
+        };
+
+        let (x, y) = self.coord;
+        self.current_tile = match self.layout.get(y) {
+            Some(row) => match row.get(x) {
+                Some(tile @ (Tile::Cross | Tile::Dash | Tile::Letter(_) | Tile::Pipe)) => *tile,
+                Some(Tile::Wall) => {
+                    if matches!(self.current_tile, Tile::Letter(_)) {
+                        return None;
+                    }
+
+                    unreachable!()
+                }
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        };
+
+        Some(())
+    }
+}
+
+fn parse(input: &str) -> Vec<Vec<Tile>> {
+    input
+        .lines()
+        .map(|l| {
+            l.chars()
+                .map(|c| match c {
+                    '|' => Tile::Pipe,
+                    '-' => Tile::Dash,
+                    '+' => Tile::Cross,
+                    'A'..='Z' => Tile::Letter(c),
+                    _ => Tile::Wall,
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn follow(layout: &[Vec<Tile>]) -> (String, i32) {
+    let mut diagram = Diagram::new(layout);
+
+    while diagram.tick().is_some() {}
+
+    (diagram.letters, diagram.steps)
+}
+
+pub fn solve(input: &str) -> (String, i32) {
+    follow(&parse(input))
+}
+
+fn solve_one(layout: &[Vec<Tile>]) -> String {
+    follow(layout).0
+}
+
+fn solve_two(layout: &[Vec<Tile>]) -> i32 {
+    follow(layout).1
+}
+
+pub fn part_one(input: &str) -> String {
+    solve_one(&parse(input))
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 19;
+
+    type PartOne = String;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> String {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Vec<Tile>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<Tile>>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Vec<Tile>>) -> String {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Vec<Tile>>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
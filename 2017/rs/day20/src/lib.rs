@@ -0,0 +1,194 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// A three dimensional vector.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Coord {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+impl Coord {
+    fn magnitude(&self) -> i32 {
+        self.x.abs() + self.y.abs() + self.z.abs()
+    }
+}
+
+impl std::str::FromStr for Coord {
+    type Err = String;
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut triples = [(0, 0); 3];
+        let mut current = 0;
+
+        for (index, c) in string.char_indices() {
+            match c {
+                ',' => {
+                    triples[current].1 = index;
+                    current += 1;
+                    triples[current].0 = index + 1
+                }
+                'p' | 'v' | 'a' | '=' => (),
+                '<' => triples[0].0 = index + 1,
+                '>' => triples[2].1 = index,
+                _ => (),
+            }
+        }
+
+        let [(x1, x2), (y1, y2), (z1, z2)] = triples;
+
+        Ok(Coord {
+            x: string[x1..x2]
+                .trim()
+                .parse()
+                .map_err(|_| string.to_owned())?,
+            y: string[y1..y2]
+                .trim()
+                .parse()
+                .map_err(|_| string.to_owned())?,
+            z: string[z1..z2]
+                .trim()
+                .parse()
+                .map_err(|_| string.to_owned())?,
+        })
+    }
+}
+
+/// A particle and its motion.
+#[derive(Clone, Debug)]
+pub struct Particle {
+    id: usize,
+    position: Coord,
+    velocity: Coord,
+    acceleration: Coord,
+}
+
+impl Particle {
+    fn tick(&mut self) {
+        self.velocity.x += self.acceleration.x;
+        self.velocity.y += self.acceleration.y;
+        self.velocity.z += self.acceleration.z;
+        self.position.x += self.velocity.x;
+        self.position.y += self.velocity.y;
+        self.position.z += self.velocity.z;
+    }
+}
+
+impl std::str::FromStr for Particle {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut parts = string.split(", ");
+
+        Ok(Self {
+            id: 0,
+            position: parts.next().ok_or_else(|| string.to_owned())?.parse()?,
+            velocity: parts.next().ok_or_else(|| string.to_owned())?.parse()?,
+            acceleration: parts.next().ok_or_else(|| string.to_owned())?.parse()?,
+        })
+    }
+}
+
+fn parse(input: &str) -> Vec<Particle> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| {
+            let mut particle = l.parse::<Particle>().unwrap();
+            particle.id = i;
+            particle
+        })
+        .collect()
+}
+
+fn solve_one(particles: &[Particle]) -> usize {
+    let mut particles = particles.to_vec();
+
+    particles.sort_by(
+        |a, b| match a.acceleration.magnitude().cmp(&b.acceleration.magnitude()) {
+            Ordering::Equal => match a.velocity.magnitude().cmp(&b.velocity.magnitude()) {
+                Ordering::Equal => a.position.magnitude().cmp(&b.position.magnitude()),
+                ordering => ordering,
+            },
+            ordering => ordering,
+        },
+    );
+
+    particles.first().unwrap().id
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(particles: &[Particle]) -> usize {
+    let mut particles = particles.to_vec();
+
+    let mut position_map = HashMap::<Coord, i32>::new();
+
+    let mut len = particles.len();
+    let mut unchanged_count = 0;
+
+    while unchanged_count < 100 {
+        position_map.clear();
+        for p in &particles {
+            *position_map.entry(p.position).or_default() += 1;
+        }
+
+        particles.retain(|p| position_map.get(&p.position) == Some(&1));
+
+        if particles.len() == len {
+            unchanged_count += 1;
+        } else {
+            unchanged_count = 0;
+        }
+
+        len = particles.len();
+
+        for p in &mut particles {
+            p.tick()
+        }
+    }
+
+    particles.len()
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 20;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Particle>;
+
+    fn parse(input: &str) -> Result<Vec<Particle>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Particle>) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Particle>) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
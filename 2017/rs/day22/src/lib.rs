@@ -1,8 +1,4 @@
 use std::collections::HashMap;
-use std::env;
-use std::fs;
-use std::path::Path;
-use std::time::SystemTime;
 
 #[derive(Debug)]
 enum Direction {
@@ -36,8 +32,10 @@ impl Direction {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-enum State {
+/// The infection state of a node.
+#[derive(Clone, Copy, Debug, Default)]
+pub enum State {
+    #[default]
     Clean,
     Weakened,
     Infected,
@@ -56,24 +54,22 @@ impl TryFrom<char> for State {
     }
 }
 
-impl Default for State {
-    fn default() -> Self {
-        Self::Clean
-    }
-}
-
 impl State {
-    fn next(&self) -> Self {
-        match self {
-            Self::Flagged => Self::Clean,
-            Self::Clean => Self::Weakened,
-            Self::Weakened => Self::Infected,
-            Self::Infected => Self::Flagged,
+    fn next(&self, evolved: bool) -> Self {
+        match (self, evolved) {
+            (Self::Clean, false) => Self::Infected,
+            (Self::Infected, false) => Self::Clean,
+            (Self::Flagged, _) => Self::Clean,
+            (Self::Clean, true) => Self::Weakened,
+            (Self::Weakened, _) => Self::Infected,
+            (Self::Infected, true) => Self::Flagged,
         }
     }
 }
 
-struct Grid(HashMap<(i32, i32), State>);
+/// The state of every known node, keyed by position.
+#[derive(Clone)]
+pub struct Grid(HashMap<(i32, i32), State>);
 
 impl std::str::FromStr for Grid {
     type Err = String;
@@ -112,7 +108,7 @@ impl Carrier {
 }
 
 impl Carrier {
-    fn tick(&mut self, grid: &mut Grid) {
+    fn tick(&mut self, grid: &mut Grid, evolved: bool) {
         let entry = grid.0.entry(self.location).or_default();
         match *entry {
             State::Clean => self.direction = self.direction.left(),
@@ -120,7 +116,7 @@ impl Carrier {
             State::Infected => self.direction = self.direction.right(),
             State::Flagged => self.direction = self.direction.reverse(),
         };
-        *entry = entry.next();
+        *entry = entry.next(evolved);
 
         if matches!(*entry, State::Infected) {
             self.infections_caused += 1;
@@ -136,8 +132,12 @@ impl Carrier {
     }
 }
 
-fn part_two(input: &str) -> i32 {
-    let mut grid: Grid = input.parse().unwrap();
+fn parse(input: &str) -> Grid {
+    input.parse().unwrap()
+}
+
+fn infect(grid: &Grid, bursts: usize, evolved: bool) -> i32 {
+    let mut grid = grid.clone();
 
     let mut middle = 0;
 
@@ -147,28 +147,61 @@ fn part_two(input: &str) -> i32 {
 
     let mut carrier = Carrier::new((middle / 2, middle / 2));
 
-    for _ in 0..10000000 {
-        carrier.tick(&mut grid);
+    for _ in 0..bursts {
+        carrier.tick(&mut grid, evolved);
     }
 
     carrier.infections_caused
 }
 
-fn time_it<F, T>(fun: F) -> T
-where
-    F: Fn() -> T,
-{
-    let start = SystemTime::now();
-    let result = fun();
-    println!("Time elapsed: {} µs", start.elapsed().unwrap().as_micros());
-    result
+fn solve_one(grid: &Grid) -> i32 {
+    infect(grid, 10000, false)
 }
 
-fn main() -> std::io::Result<()> {
-    let file_path = env::current_dir()?.join(Path::new("data.txt"));
-    let input = fs::read_to_string(file_path)?;
+fn solve_two(grid: &Grid) -> i32 {
+    infect(grid, 10000000, true)
+}
 
-    time_it(|| println!("part 2: {}", part_two(&input)));
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
 
-    Ok(())
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
 }
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 22;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Grid, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Grid) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Grid) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,238 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// A register name or a literal value.
+#[derive(Clone, Copy, Debug)]
+pub enum Arg {
+    Val(i64),
+    Reg(char),
+}
+
+impl Arg {}
+
+impl std::str::FromStr for Arg {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.parse::<i64>() {
+            Ok(value) => Ok(Self::Val(value)),
+            _ => match string.chars().nth(0) {
+                Some(c) => Ok(Self::Reg(c)),
+                _ => Err(string.to_owned()),
+            },
+        }
+    }
+}
+
+/// A single coprocessor instruction.
+#[derive(Clone, Copy, Debug)]
+pub enum Instruction {
+    Set(Arg, Arg),
+    Mul(Arg, Arg),
+    Sub(Arg, Arg),
+    Jnz(Arg, Arg),
+}
+
+impl std::str::FromStr for Instruction {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut words = string.split_ascii_whitespace();
+        let kind = words.next();
+
+        let arg1 = match words.next() {
+            Some(word) => match word.parse() {
+                Ok(arg) => arg,
+                _ => return Err(string.to_owned()),
+            },
+            _ => return Err(string.to_owned()),
+        };
+
+        let arg2 = match words.next() {
+            Some(word) => match word.parse() {
+                Ok(value) => value,
+                _ => return Err(string.to_owned()),
+            },
+            _ => return Err(string.to_owned()),
+        };
+
+        match kind {
+            Some("set") => Ok(Self::Set(arg1, arg2)),
+            Some("sub") => Ok(Self::Sub(arg1, arg2)),
+            Some("mul") => Ok(Self::Mul(arg1, arg2)),
+            Some("jnz") => Ok(Self::Jnz(arg1, arg2)),
+            _ => Err(string.to_owned()),
+        }
+    }
+}
+
+struct Program {
+    pc: usize,
+    registers: HashMap<char, i64>,
+    instructions: Vec<Instruction>,
+    mul_invocations: i32,
+}
+
+impl Program {
+    fn new(instructions: Vec<Instruction>, debug_mode: bool) -> Self {
+        let mut registers: HashMap<_, _> = ('a'..='h').map(|ch| (ch, 0)).collect();
+
+        if debug_mode {
+            *registers.entry('a').or_default() = 1;
+        }
+
+        Self {
+            pc: 0,
+            mul_invocations: 0,
+            registers,
+            instructions,
+        }
+    }
+
+    fn jump(&mut self, amount: i64) {
+        match amount.cmp(&0) {
+            Ordering::Equal => (),
+            Ordering::Greater => self.pc += amount as usize,
+            Ordering::Less => self.pc -= amount.wrapping_abs() as usize,
+        }
+    }
+
+    fn get_register_mut(&mut self, name: char) -> &mut i64 {
+        self.registers.entry(name).or_default()
+    }
+
+    fn get_register(&mut self, name: char) -> &i64 {
+        self.registers.entry(name).or_default()
+    }
+
+    fn run(&mut self) {
+        while let Some(&instruction) = self.instructions.get(self.pc) {
+            // println!("{:?}", self.registers);
+            match instruction {
+                Instruction::Set(Arg::Reg(register), Arg::Val(number)) => {
+                    *self.get_register_mut(register) = number
+                }
+                Instruction::Set(Arg::Reg(dest), Arg::Reg(src)) => {
+                    let number = *self.get_register(src);
+                    *self.get_register_mut(dest) = number
+                }
+                Instruction::Sub(Arg::Reg(register), Arg::Val(number)) => {
+                    *self.get_register_mut(register) -= number
+                }
+                Instruction::Sub(Arg::Reg(register), Arg::Reg(value)) => {
+                    let value = *self.get_register(value);
+                    *self.get_register_mut(register) -= value
+                }
+                Instruction::Mul(Arg::Reg(register), Arg::Val(value)) => {
+                    self.mul_invocations += 1;
+                    *self.get_register_mut(register) *= value
+                }
+                Instruction::Mul(Arg::Reg(register), Arg::Reg(value)) => {
+                    self.mul_invocations += 1;
+                    let value = *self.get_register(value);
+                    *self.get_register_mut(register) *= value
+                }
+                Instruction::Jnz(Arg::Reg(register), Arg::Val(amount)) => {
+                    if *self.get_register(register) != 0 {
+                        self.jump(amount);
+                        continue;
+                    }
+                }
+                Instruction::Jnz(Arg::Reg(register), Arg::Reg(value)) => {
+                    if *self.get_register(register) != 0 {
+                        let amount = *self.get_register(value);
+                        self.jump(amount);
+                        continue;
+                    }
+                }
+                Instruction::Jnz(Arg::Val(cmp), Arg::Val(amount)) => {
+                    if cmp != 0 {
+                        self.jump(amount);
+                        continue;
+                    }
+                }
+                _ => unreachable!("{:?}", instruction),
+            }
+
+            self.pc += 1
+        }
+    }
+}
+
+fn parse(input: &str) -> Vec<Instruction> {
+    input.lines().map(|l| l.parse().unwrap()).collect()
+}
+
+fn solve_one(instructions: &[Instruction]) -> i32 {
+    let mut program = Program::new(instructions.to_vec(), false);
+
+    program.run();
+
+    program.mul_invocations
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn is_prime(number: i64) -> bool {
+    for d in 2..(number as f64).sqrt() as i64 {
+        if number % d == 0 {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn solve_two(instructions: &[Instruction]) -> i32 {
+    let mut program = Program::new(instructions[..8].to_vec(), true);
+
+    program.run();
+
+    let start = *program.registers.get(&'b').unwrap();
+    let end = *program.registers.get(&'c').unwrap();
+
+    (start..=end)
+        .step_by(17)
+        .map(|n| if is_prime(n) { 0 } else { 1 })
+        .sum()
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 23;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Instruction>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Instruction>) -> i32 {
+        solve_two(input)
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::HashSet;
+
+/// A bridge component and its two port sizes.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Component(i32, i32);
+
+impl Component {
+    fn has_port(&self, value: i32) -> bool {
+        self.0 == value || self.1 == value
+    }
+
+    fn free_port(&self, used_port: i32) -> i32 {
+        if self.0 == used_port {
+            self.1
+        } else {
+            self.0
+        }
+    }
+}
+
+impl std::str::FromStr for Component {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut parts = string.split('/');
+
+        Ok(Component(
+            parts
+                .next()
+                .ok_or_else(|| string.to_owned())?
+                .parse::<i32>()
+                .map_err(|_| string.to_owned())?,
+            parts
+                .next()
+                .ok_or_else(|| string.to_owned())?
+                .parse::<i32>()
+                .map_err(|_| string.to_owned())?,
+        ))
+    }
+}
+
+#[derive(Debug)]
+struct Node {
+    used_port: i32,
+    free_port: i32,
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn new(component: &Component, used_port: i32) -> Self {
+        Self {
+            used_port,
+            free_port: component.free_port(used_port),
+            children: Vec::new(),
+        }
+    }
+
+    fn add_children(&mut self, components: &[Component], seen: HashSet<&Component>) {
+        for component in components {
+            if !seen.contains(component) && component.has_port(self.free_port) {
+                let mut node = Node::new(component, self.free_port);
+                let mut clone = seen.clone();
+                clone.insert(component);
+                node.add_children(components, clone);
+                self.children.push(node);
+            }
+        }
+    }
+
+    fn find_strengths(&self, running_total: i32, strengths: &mut Vec<i32>) {
+        let strength = self.free_port + self.used_port;
+
+        if self.children.is_empty() {
+            strengths.push(running_total + strength)
+        } else {
+            for child in &self.children {
+                child.find_strengths(running_total + strength, strengths)
+            }
+        }
+    }
+
+    fn find_depths_and_strengths(
+        &self,
+        depth: i32,
+        running_total: i32,
+        strengths: &mut Vec<(i32, i32)>,
+    ) {
+        let strength = self.free_port + self.used_port;
+
+        if self.children.is_empty() {
+            strengths.push((depth, running_total + strength))
+        } else {
+            for child in &self.children {
+                child.find_depths_and_strengths(depth + 1, running_total + strength, strengths)
+            }
+        }
+    }
+}
+
+fn parse(input: &str) -> Vec<Component> {
+    input
+        .lines()
+        .map(|l| l.parse::<Component>().unwrap())
+        .collect()
+}
+
+fn build_tree(components: &[Component]) -> Node {
+    let mut root = Node::new(&Component(0, 0), 0);
+    root.add_children(components, HashSet::new());
+
+    root
+}
+
+fn solve_one(components: &[Component]) -> i32 {
+    let root = build_tree(components);
+    let mut strengths = Vec::new();
+
+    root.find_strengths(0, &mut strengths);
+    strengths.sort();
+
+    *strengths.last().unwrap()
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(components: &[Component]) -> i32 {
+    let root = build_tree(components);
+    let mut depths_and_strengths = Vec::new();
+
+    root.find_depths_and_strengths(0, 0, &mut depths_and_strengths);
+
+    depths_and_strengths.sort_by(|a, b| match a.0.cmp(&b.0) {
+        std::cmp::Ordering::Equal => a.1.cmp(&b.1),
+        ordering => ordering,
+    });
+
+    depths_and_strengths.last().unwrap().1
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 24;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Component>;
+
+    fn parse(input: &str) -> Result<Vec<Component>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Component>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Component>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
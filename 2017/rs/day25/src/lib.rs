@@ -0,0 +1,200 @@
+use std::collections::{HashMap, HashSet};
+
+fn penultimate_char(line: &str) -> char {
+    line.chars().nth_back(1).unwrap()
+}
+
+fn parse_preamble(string: &str) -> (char, i32) {
+    let mut lines = string.lines();
+
+    let state = penultimate_char(lines.next().unwrap());
+
+    let steps = lines
+        .next()
+        .unwrap()
+        .chars()
+        .filter(|ch| char::is_numeric(*ch))
+        .collect::<String>()
+        .parse()
+        .unwrap();
+
+    (state, steps)
+}
+
+/// Which way the tape head moves.
+#[derive(Clone, Copy, Debug)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+/// A value on the tape.
+#[derive(Clone, Copy, Debug)]
+pub enum Value {
+    Zero,
+    One,
+}
+
+/// The actions of a single state.
+#[derive(Debug)]
+pub struct Step {
+    state: char,
+    if_0: (Value, Direction, char),
+    if_1: (Value, Direction, char),
+}
+
+fn parse_substep(lines: &mut std::str::Lines) -> (Value, Direction, char) {
+    lines.next();
+    let value = if lines.next().unwrap().ends_with("1.") {
+        Value::One
+    } else {
+        Value::Zero
+    };
+
+    let direction = if lines.next().unwrap().ends_with("right.") {
+        Direction::Right
+    } else {
+        Direction::Left
+    };
+
+    let next_state = penultimate_char(lines.next().unwrap());
+
+    (value, direction, next_state)
+}
+
+fn parse_step(string: &str) -> Step {
+    let mut lines = string.lines();
+
+    let state = penultimate_char(lines.next().unwrap());
+
+    Step {
+        state,
+        if_0: parse_substep(&mut lines),
+        if_1: parse_substep(&mut lines),
+    }
+}
+
+struct Tape {
+    ones: HashSet<i32>,
+    position: i32,
+}
+
+impl Tape {
+    fn new() -> Self {
+        Self {
+            ones: HashSet::new(),
+            position: 0,
+        }
+    }
+
+    fn current_value(&self) -> Value {
+        if self.ones.contains(&self.position) {
+            Value::One
+        } else {
+            Value::Zero
+        }
+    }
+
+    fn write_value(&mut self, value: Value) {
+        match value {
+            Value::Zero => self.ones.remove(&self.position),
+            Value::One => self.ones.insert(self.position),
+        };
+    }
+
+    fn step(&mut self, direction: Direction) {
+        match direction {
+            Direction::Left => self.position -= 1,
+            Direction::Right => self.position += 1,
+        }
+    }
+
+    fn checksum(&self) -> i32 {
+        self.ones.len() as i32
+    }
+}
+
+/// The starting state, the step count and the rules of the Turing machine.
+pub struct Blueprint {
+    state: char,
+    iterations: i32,
+    steps: HashMap<char, Step>,
+}
+
+fn parse(input: &str) -> Blueprint {
+    let mut parts = input.split("\n\n");
+
+    let (state, iterations) = parse_preamble(parts.next().unwrap());
+
+    let steps = parts
+        .map(parse_step)
+        .map(|step| (step.state, step))
+        .collect();
+
+    Blueprint {
+        state,
+        iterations,
+        steps,
+    }
+}
+
+fn solve_one(blueprint: &Blueprint) -> i32 {
+    let steps = &blueprint.steps;
+    let mut tape = Tape::new();
+    let mut current_state = blueprint.state;
+
+    for _ in 0..blueprint.iterations {
+        let step = steps.get(&current_state).unwrap();
+        let (value, direction, next_state) = match tape.current_value() {
+            Value::Zero => step.if_0,
+            Value::One => step.if_1,
+        };
+
+        tape.write_value(value);
+        tape.step(direction);
+        current_state = next_state;
+    }
+
+    tape.checksum()
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 25;
+    const PARTS: u8 = 1;
+
+    type PartOne = i32;
+    type PartTwo = aoc_core::NoAnswer;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(_input: &str) -> aoc_core::NoAnswer {
+        aoc_core::NoAnswer
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Blueprint;
+
+    fn parse(input: &str) -> Result<Blueprint, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Blueprint) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(_input: &Blueprint) -> aoc_core::NoAnswer {
+        aoc_core::NoAnswer
+    }
+}
+
+aoc_core::examples!(Solver);
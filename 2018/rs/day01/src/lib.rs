@@ -0,0 +1,68 @@
+use std::collections::HashSet;
+
+fn parse(input: &str) -> Vec<i32> {
+    input.lines().map(|line| line.parse().unwrap()).collect()
+}
+
+fn solve_one(nums: &[i32]) -> i32 {
+    nums.iter().sum()
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(nums: &[i32]) -> i32 {
+    let mut frequencies = HashSet::from([0]);
+    let mut total = 0;
+
+    loop {
+        for num in nums.iter() {
+            total += num;
+
+            if !frequencies.insert(total) {
+                return total;
+            }
+        }
+    }
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 1;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Vec<i32>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<i32>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<i32>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
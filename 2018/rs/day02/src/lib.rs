@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+fn count_occurrences(map: &mut HashMap<char, i32>, id: &str) -> (i32, i32) {
+    for ch in id.chars() {
+        let counter = map.entry(ch).or_insert(0);
+        *counter += 1;
+    }
+
+    let mut result = (0, 0);
+
+    for v in map.values() {
+        match v {
+            2 => result.0 = 1,
+            3 => result.1 = 1,
+            _ => (),
+        }
+    }
+
+    result
+}
+
+fn parse(input: &str) -> Vec<String> {
+    input.lines().map(str::to_string).collect()
+}
+
+fn solve_one(lines: &[String]) -> i32 {
+    let mut map = HashMap::<char, i32>::new();
+    let (double, triple): (i32, i32) = lines.iter().fold((0, 0), |(d, t), line| {
+        map.clear();
+        let result = count_occurrences(&mut map, line);
+        (d + result.0, t + result.1)
+    });
+
+    double * triple
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn get_common_chars(a: &str, b: &str) -> String {
+    a.chars()
+        .zip(b.chars())
+        .filter_map(|(a, b)| match a == b {
+            true => Some(a),
+            false => None,
+        })
+        .collect()
+}
+
+fn only_one_difference(a: &str, b: &str) -> bool {
+    let mut diff_found = false;
+    for (char_a, char_b) in a.chars().zip(b.chars()) {
+        if char_a != char_b {
+            if diff_found {
+                return false;
+            }
+            diff_found = true;
+        }
+    }
+
+    true
+}
+
+fn solve_two(ids: &[String]) -> String {
+    for (i, id) in ids.iter().enumerate() {
+        for id2 in ids.iter().skip(i + 1) {
+            if only_one_difference(id, id2) {
+                return get_common_chars(id, id2);
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+pub fn part_two(input: &str) -> String {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 2;
+
+    type PartOne = i32;
+    type PartTwo = String;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> String {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<String>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<String>) -> String {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,136 @@
+use std::collections::HashSet;
+
+const GRID_SIZE: usize = 1000;
+
+type Grid = Vec<Vec<Vec<u16>>>;
+
+/// A rectangular claim on the fabric.
+#[derive(Debug, Default)]
+pub struct Claim {
+    pub x: usize,
+    pub y: usize,
+    pub rise: usize,
+    pub run: usize,
+    pub holder: u16,
+}
+
+fn parse_string(string: &str) -> Claim {
+    let mut claim: Claim = Default::default();
+    let mut iter = string.split_whitespace();
+
+    claim.holder = iter.next().unwrap()[1..].parse::<u16>().unwrap();
+    iter.next();
+
+    let coords = iter.next().unwrap();
+    for (i, v) in coords[..coords.len() - 1].split(",").enumerate() {
+        let parsed = v.parse().unwrap();
+        if i == 0 {
+            claim.x = parsed
+        } else {
+            claim.y = parsed
+        }
+    }
+
+    let rise_run = iter.next().unwrap();
+    for (i, v) in rise_run.split("x").enumerate() {
+        let parsed = v.parse().unwrap();
+        if i == 0 {
+            claim.run = parsed
+        } else {
+            claim.rise = parsed
+        }
+    }
+
+    claim
+}
+
+fn parse(input: &str) -> Vec<Claim> {
+    input.lines().map(parse_string).collect()
+}
+
+fn overlap(claims: &[Claim]) -> (i32, u16) {
+    let mut grid: Grid = (0..GRID_SIZE)
+        .map(|_| (0..GRID_SIZE).map(|_| Vec::new()).collect())
+        .collect();
+    let mut overlaps = 0;
+    let mut unique_claimants: HashSet<u16> = claims.iter().map(|claim| claim.holder).collect();
+
+    for claim in claims {
+        for column in &mut grid[claim.x..(claim.x + claim.run)] {
+            for claimants in &mut column[claim.y..(claim.y + claim.rise)] {
+                claimants.push(claim.holder);
+                let len = claimants.len();
+                if len > 1 {
+                    if len == 2 {
+                        overlaps += 1;
+                    }
+                    for claimant in claimants {
+                        unique_claimants.remove(claimant);
+                    }
+                }
+            }
+        }
+    }
+
+    if unique_claimants.len() != 1 {
+        panic!("at the disco");
+    }
+
+    (overlaps, *unique_claimants.iter().next().unwrap())
+}
+
+pub fn solve(input: &str) -> (i32, u16) {
+    overlap(&parse(input))
+}
+
+fn solve_one(claims: &[Claim]) -> i32 {
+    overlap(claims).0
+}
+
+fn solve_two(claims: &[Claim]) -> u16 {
+    overlap(claims).1
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+pub fn part_two(input: &str) -> u16 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 3;
+
+    type PartOne = i32;
+    type PartTwo = u16;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> u16 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Claim>;
+
+    fn parse(input: &str) -> Result<Vec<Claim>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Claim>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Claim>) -> u16 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
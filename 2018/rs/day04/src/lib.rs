@@ -0,0 +1,190 @@
+use chrono::Timelike;
+use std::collections::HashMap;
+
+/// A single nap, from falling asleep to waking up.
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct SleepLog {
+    pub asleep_at: chrono::NaiveDateTime,
+    pub awake_at: chrono::NaiveDateTime,
+}
+
+/// Every nap a guard took, and their total time asleep.
+#[derive(Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct GuardLog {
+    pub id: i32,
+    pub minutes_asleep: i64,
+    pub sleep_logs: Vec<SleepLog>,
+}
+
+fn parse_date(date: &str) -> chrono::NaiveDateTime {
+    chrono::NaiveDateTime::parse_from_str(date, "%Y-%m-%d %H:%M").unwrap()
+}
+
+fn parse_logs(lines: Vec<&str>) -> Vec<GuardLog> {
+    let mut logs: Vec<GuardLog> = Vec::new();
+    let mut date = String::new();
+    let mut asleep_at: Option<chrono::NaiveDateTime> = None;
+    let mut token = String::new();
+    let mut pointer: usize = 0;
+
+    for line in lines {
+        token.clear();
+        let mut id_found = false;
+        for ch in line.chars() {
+            match ch {
+                '[' => continue,
+                '#' => {
+                    token.clear();
+                    id_found = true;
+                }
+                ']' => {
+                    date = token;
+                    token = String::new();
+                }
+                ' ' => {
+                    if id_found {
+                        id_found = false;
+                        let parsed_id: i32 = token.parse().unwrap();
+                        pointer = if let Some(index) = logs.iter().position(|l| l.id == parsed_id) {
+                            index
+                        } else {
+                            logs.push(GuardLog {
+                                id: parsed_id,
+                                minutes_asleep: 0,
+                                sleep_logs: Vec::new(),
+                            });
+                            logs.len() - 1
+                        };
+                        token.clear();
+                    } else if !token.is_empty() {
+                        token.push(ch);
+                    }
+                }
+                _ => {
+                    token.push(ch);
+                }
+            }
+        }
+
+        match token.as_str() {
+            "falls asleep" => {
+                asleep_at = Some(parse_date(&date));
+                date.clear();
+            }
+            "wakes up" => {
+                let awake_at = parse_date(&date);
+                let log = &mut logs[pointer];
+                log.minutes_asleep += awake_at
+                    .signed_duration_since(asleep_at.unwrap())
+                    .num_minutes();
+                log.sleep_logs.push(SleepLog {
+                    asleep_at: asleep_at.unwrap(),
+                    awake_at,
+                });
+                date.clear();
+            }
+            _ => (),
+        }
+    }
+
+    logs.sort_by_key(|log| std::cmp::Reverse(log.minutes_asleep));
+    logs
+}
+
+// the guard logs, sorted by time asleep
+fn parse(input: &str) -> Vec<GuardLog> {
+    let mut lines: Vec<_> = input.lines().collect();
+
+    lines.sort();
+
+    parse_logs(lines)
+}
+
+fn solve_one(logs: &[GuardLog]) -> i32 {
+    let log = &logs[0];
+
+    let mut minute_map: HashMap<u32, i32> = HashMap::new();
+    let mut most_frequent: u32 = 60;
+    let mut count = 0;
+
+    for sleep_log in &log.sleep_logs {
+        for minute in sleep_log.asleep_at.minute()..sleep_log.awake_at.minute() {
+            let counter = minute_map.entry(minute).or_insert(0);
+            *counter += 1;
+            if count < *counter {
+                most_frequent = minute;
+                count = *counter;
+            }
+        }
+    }
+
+    log.id * most_frequent as i32
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(logs: &[GuardLog]) -> i32 {
+    let mut output_id: i32 = 0;
+    let mut most_frequent: u32 = 60;
+    let mut count = 0;
+
+    for log in logs {
+        let mut minute_map: HashMap<u32, i32> = HashMap::new();
+
+        for sleep_log in &log.sleep_logs {
+            for minute in sleep_log.asleep_at.minute()..sleep_log.awake_at.minute() {
+                let counter = minute_map.entry(minute).or_insert(0);
+                *counter += 1;
+                if count < *counter {
+                    output_id = log.id;
+                    most_frequent = minute;
+                    count = *counter;
+                }
+            }
+        }
+    }
+
+    output_id * most_frequent as i32
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 4;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<GuardLog>;
+
+    fn parse(input: &str) -> Result<Vec<GuardLog>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<GuardLog>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<GuardLog>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,112 @@
+fn parse(input: &str) -> String {
+    input.trim().to_string()
+}
+
+fn solve_one(polymers: &str) -> usize {
+    let mut output: Vec<char> = Vec::with_capacity(polymers.len());
+
+    let min_maj_diff: i32 = 'a' as i32 - 'A' as i32;
+
+    for ch in polymers.chars() {
+        output.push(ch);
+
+        let length = output.len();
+        if length < 2 {
+            continue;
+        }
+
+        let diff = (output[length - 1] as i32 - output[length - 2] as i32).abs();
+        if diff == min_maj_diff {
+            output.pop();
+            output.pop();
+        }
+    }
+
+    output.len()
+}
+
+pub fn part_one(polymers: &str) -> usize {
+    solve_one(&parse(polymers))
+}
+
+fn solve_two(polymers: &str) -> usize {
+    let min_maj_diff: i32 = (b'a' - b'A') as i32;
+
+    let tuples: Vec<(char, char)> = (b'a'..=b'z')
+        .map(|b| (char::from(b), char::from(b - 32)))
+        .collect();
+
+    let mut shortest = polymers.len();
+    let mut output: Vec<char> = Vec::with_capacity(polymers.len());
+
+    for (lower, upper) in tuples {
+        for ch in polymers.chars() {
+            if ch == upper || ch == lower {
+                continue;
+            }
+
+            output.push(ch);
+
+            let length = output.len();
+            if length < 2 {
+                continue;
+            }
+
+            let diff = (output[length - 1] as i32 - output[length - 2] as i32).abs();
+            if diff == min_maj_diff {
+                output.pop();
+                output.pop();
+            }
+        }
+
+        shortest = if shortest > output.len() {
+            output.len()
+        } else {
+            shortest
+        };
+
+        output.clear();
+    }
+
+    shortest
+}
+
+pub fn part_two(polymers: &str) -> usize {
+    solve_two(&parse(polymers))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 5;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &String) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &String) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
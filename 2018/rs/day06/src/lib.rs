@@ -0,0 +1,167 @@
+#[derive(Clone, Debug)]
+struct Coordinate {
+    x: i32,
+    y: i32,
+    count: i32,
+}
+
+impl Coordinate {
+    fn from_string(string: &str) -> Self {
+        let mut parts = string.split(", ");
+        Self {
+            x: parts.next().unwrap().parse().unwrap(),
+            y: parts.next().unwrap().parse().unwrap(),
+            count: 0,
+        }
+    }
+
+    #[allow(dead_code)]
+    fn distance_to(&self, x: i32, y: i32) -> i32 {
+        (self.x - x).abs() + (self.y - y).abs()
+    }
+}
+
+/// The coordinates and the bounding box around them.
+#[derive(Clone, Debug, Default)]
+pub struct CoordinateMap {
+    leftmost: Option<i32>,
+    rightmost: Option<i32>,
+    topmost: Option<i32>,
+    bottommost: Option<i32>,
+    coords: Vec<Coordinate>,
+}
+
+impl CoordinateMap {
+    fn new() -> Self {
+        Default::default()
+    }
+
+    fn add(&mut self, coord_str: &str) {
+        let coord = Coordinate::from_string(coord_str);
+
+        self.leftmost = if self.leftmost.unwrap_or(999999) > coord.x {
+            Some(coord.x)
+        } else {
+            self.leftmost
+        };
+
+        self.topmost = if self.topmost.unwrap_or(0) < coord.y {
+            Some(coord.x)
+        } else {
+            self.topmost
+        };
+
+        self.bottommost = if self.bottommost.unwrap_or(999999) > coord.y {
+            Some(coord.x)
+        } else {
+            self.bottommost
+        };
+
+        self.rightmost = if self.rightmost.unwrap_or(0) < coord.x {
+            Some(coord.x)
+        } else {
+            self.rightmost
+        };
+
+        self.coords.push(coord);
+    }
+}
+
+fn parse(input: &str) -> CoordinateMap {
+    input.lines().fold(CoordinateMap::new(), |mut acc, line| {
+        acc.add(line);
+        acc
+    })
+}
+
+fn solve_one(map: &CoordinateMap) -> i32 {
+    let mut map = map.clone();
+
+    for i in map.leftmost.unwrap()..=map.rightmost.unwrap() {
+        for j in map.bottommost.unwrap()..=map.topmost.unwrap() {
+            let mut tie = false;
+            let mut shortest_distance = 999999;
+            let mut closest: Option<&mut Coordinate> = None;
+
+            for coord in map.coords.iter_mut() {
+                let current_distance = coord.distance_to(i, j);
+                if current_distance < shortest_distance {
+                    shortest_distance = current_distance;
+                    closest = Some(coord);
+                    tie = false;
+                } else if shortest_distance == current_distance {
+                    tie = true;
+                }
+            }
+
+            if !tie {
+                closest.unwrap().count += 1;
+            }
+        }
+    }
+
+    map.coords.iter().max_by_key(|c| c.count).unwrap().count
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(map: &CoordinateMap) -> i32 {
+    let mut safe_squares = 0;
+
+    for i in 0..=map.rightmost.unwrap() {
+        for j in 0..=map.topmost.unwrap() {
+            safe_squares += match map
+                .coords
+                .iter()
+                .fold(0, |sum, coord| sum + coord.distance_to(i, j))
+            {
+                n if n < 10000 => 1,
+                _ => 0,
+            }
+        }
+    }
+
+    safe_squares
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 6;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = CoordinateMap;
+
+    fn parse(input: &str) -> Result<CoordinateMap, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &CoordinateMap) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &CoordinateMap) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,182 @@
+use std::collections::HashSet;
+
+#[derive(Clone, Debug)]
+struct Step {
+    name: char,
+    constraints: HashSet<char>,
+}
+
+/// Every step, the steps each one waits on and the steps available from the start.
+#[derive(Debug)]
+pub struct Instructions {
+    steps: Vec<Step>,
+    all_steps: HashSet<char>,
+    available_steps: Vec<char>,
+}
+
+fn parse(input: &str) -> Instructions {
+    let mut parsed: Vec<Step> = Vec::with_capacity(26);
+    let mut all_steps: HashSet<char> = Default::default();
+    let mut steps_with_constraints: HashSet<char> = Default::default();
+
+    for line in input.lines() {
+        let mut chars = line.chars();
+        let constraint = chars.nth(5).unwrap();
+        let name = chars.nth(30).unwrap();
+        all_steps.insert(name);
+        steps_with_constraints.insert(name);
+        all_steps.insert(constraint);
+        if let Some(index) = parsed.iter().position(|step| step.name == name) {
+            parsed[index].constraints.insert(constraint);
+        } else {
+            let mut constraints = HashSet::new();
+            constraints.insert(constraint);
+            parsed.push(Step { name, constraints });
+        }
+    }
+
+    let available_steps = all_steps
+        .difference(&steps_with_constraints)
+        .cloned()
+        .collect();
+
+    Instructions {
+        steps: parsed,
+        all_steps,
+        available_steps,
+    }
+}
+
+fn solve_one(instructions: &Instructions) -> String {
+    let mut parsed = instructions.steps.clone();
+    let mut available_steps = instructions.available_steps.clone();
+
+    let mut result = String::new();
+
+    while !available_steps.is_empty() {
+        available_steps.sort();
+        let current = available_steps.remove(0);
+        result.push(current);
+
+        for step in parsed.iter_mut() {
+            if step.constraints.remove(&current) && step.constraints.is_empty() {
+                available_steps.push(step.name);
+            }
+        }
+    }
+
+    result
+}
+
+pub fn part_one(input: &str) -> String {
+    solve_one(&parse(input))
+}
+
+#[derive(Default, Debug)]
+struct Worker {
+    time_remaining: u32,
+    task: Option<char>,
+}
+
+impl Worker {
+    fn assign(&mut self, c: &char) {
+        self.task = Some(*c);
+        self.time_remaining = 60 + *c as u32 - b'A' as u32;
+    }
+
+    fn tick(&mut self) {
+        if self.task.is_some() && self.time_remaining > 0 {
+            self.time_remaining -= 1;
+        }
+    }
+
+    fn get_result(&mut self) -> Option<char> {
+        let mut option = None;
+        if self.task.is_some() && self.time_remaining == 0 {
+            option = self.task;
+            self.task = None;
+        }
+
+        option
+    }
+
+    fn needs_task(&self) -> bool {
+        self.task.is_none()
+    }
+}
+
+fn solve_two(instructions: &Instructions) -> i32 {
+    let mut parsed = instructions.steps.clone();
+    let all_steps = &instructions.all_steps;
+    let mut available_steps = instructions.available_steps.clone();
+
+    let mut time_elapsed = 0;
+    let mut completed_steps = String::new();
+    let mut workers: [Worker; 5] = Default::default();
+
+    while completed_steps.len() != all_steps.len() {
+        available_steps.sort();
+
+        let work_assignable = !available_steps.is_empty();
+
+        for worker in &mut workers {
+            worker.tick();
+            if let Some(current) = worker.get_result() {
+                completed_steps.push(current);
+
+                for step in &mut parsed {
+                    if step.constraints.remove(&current) && step.constraints.is_empty() {
+                        available_steps.push(step.name);
+                    }
+                }
+            } else if worker.needs_task() && work_assignable && !available_steps.is_empty() {
+                let new_task = available_steps.remove(0);
+                worker.assign(&new_task);
+            }
+        }
+
+        time_elapsed += 1;
+    }
+
+    time_elapsed
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 7;
+
+    type PartOne = String;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> String {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Instructions;
+
+    fn parse(input: &str) -> Result<Instructions, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Instructions) -> String {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Instructions) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
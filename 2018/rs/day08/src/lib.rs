@@ -0,0 +1,103 @@
+// the tree's numbers in reverse, so nodes can be popped off the end
+fn parse(input: &str) -> Vec<i32> {
+    input
+        .split_ascii_whitespace()
+        .flat_map(|n| n.parse())
+        .rev()
+        .collect()
+}
+
+fn solve_one(numbers: &[i32]) -> i32 {
+    fn parse_node(numbers: &mut Vec<i32>) -> i32 {
+        let child_count = numbers.pop().unwrap();
+        let metadata_count = numbers.pop().unwrap();
+        let mut current_total = 0;
+        for _ in 0..child_count {
+            current_total += parse_node(numbers);
+        }
+
+        for _ in 0..metadata_count {
+            current_total += numbers.pop().unwrap();
+        }
+
+        current_total
+    }
+
+    let mut numbers = numbers.to_vec();
+
+    parse_node(&mut numbers)
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(numbers: &[i32]) -> i32 {
+    fn parse_node(numbers: &mut Vec<i32>) -> i32 {
+        let child_count = numbers.pop().unwrap();
+        let metadata_count = numbers.pop().unwrap();
+        let mut current_total = 0;
+        let mut child_values = Vec::<i32>::new();
+
+        for _ in 0..child_count {
+            child_values.push(parse_node(numbers));
+        }
+
+        for _ in 0..metadata_count {
+            let current = numbers.pop().unwrap();
+            if child_count > 0 {
+                if current > 0 && current <= child_count {
+                    current_total += child_values[(current - 1) as usize];
+                }
+            } else {
+                current_total += current;
+            }
+        }
+
+        current_total
+    }
+
+    let mut numbers = numbers.to_vec();
+
+    parse_node(&mut numbers)
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 8;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Vec<i32>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<i32>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<i32>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
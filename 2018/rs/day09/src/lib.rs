@@ -0,0 +1,81 @@
+use std::collections::VecDeque;
+
+fn play_game(player_count: usize, marble_count: usize) -> usize {
+    let mut marbles = VecDeque::new();
+    marbles.push_back(0);
+    let mut scores = vec![0; player_count];
+
+    for next_marble in 1..=marble_count {
+        if next_marble % 23 != 0 {
+            marbles.rotate_left(1);
+            marbles.rotate_left(1);
+            marbles.push_front(next_marble);
+        } else {
+            marbles.rotate_right(7);
+            scores[next_marble % player_count] += marbles.pop_front().unwrap() + next_marble;
+        }
+    }
+
+    *scores.iter().max().unwrap()
+}
+
+fn parse(input: &str) -> (usize, usize) {
+    let mut parts = input.split_ascii_whitespace();
+
+    let player_count = parts.next().unwrap().parse().unwrap();
+    let marble_count = parts.nth(5).unwrap().parse().unwrap();
+
+    (player_count, marble_count)
+}
+
+fn solve_one(&(player_count, marble_count): &(usize, usize)) -> usize {
+    play_game(player_count, marble_count)
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(&(player_count, marble_count): &(usize, usize)) -> usize {
+    play_game(player_count, marble_count * 100)
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 9;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = (usize, usize);
+
+    fn parse(input: &str) -> Result<(usize, usize), aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &(usize, usize)) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &(usize, usize)) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,172 @@
+/// A point of light and its velocity.
+#[derive(Clone, Debug, Default)]
+pub struct Dot {
+    x: i32,
+    y: i32,
+    dx: i32,
+    dy: i32,
+}
+
+impl Dot {
+    fn new() -> Self {
+        Default::default()
+    }
+}
+
+fn get_max(dots: &[Dot]) -> (i32, i32) {
+    dots.iter().fold((0, 0), |(x, y), curr| {
+        (x.max(curr.x.abs()), y.max(curr.y.abs()))
+    })
+}
+
+fn parse(input: &str) -> Vec<Dot> {
+    input
+        .lines()
+        .map(|line| {
+            let mut dot = Dot::new();
+            let mut numbers = line
+                .split(['<', '>', ','])
+                .filter_map(|part| part.trim().parse().ok());
+
+            dot.x = numbers.next().unwrap();
+            dot.y = numbers.next().unwrap();
+            dot.dx = numbers.next().unwrap();
+            dot.dy = numbers.next().unwrap();
+
+            dot
+        })
+        .collect()
+}
+
+fn solve_one(dots: &[Dot]) -> String {
+    let mut dots = dots.to_vec();
+
+    let (mut largest_x, mut largest_y) = get_max(&dots);
+
+    loop {
+        let mut new_largest_x = 0;
+        let mut new_largest_y = 0;
+
+        for dot in &mut dots {
+            dot.x += dot.dx;
+            dot.y += dot.dy;
+            new_largest_x = new_largest_x.max(dot.x.abs());
+            new_largest_y = new_largest_y.max(dot.y.abs());
+        }
+
+        if new_largest_x <= largest_x && new_largest_y <= largest_y {
+            largest_x = new_largest_x;
+            largest_y = new_largest_y;
+        } else {
+            for dot in &mut dots {
+                dot.x -= dot.dx;
+                dot.y -= dot.dy;
+            }
+            break;
+        }
+    }
+
+    let (smallest_x, smallest_y) = dots.iter().fold((i32::MAX, i32::MAX), |(x, y), curr| {
+        (x.min(curr.x), y.min(curr.y))
+    });
+
+    let (largest_x, largest_y) = get_max(&dots);
+
+    let mut output = Vec::<Vec<i32>>::new();
+
+    for _ in 0..=(largest_y - smallest_y) {
+        output.push(vec![0; (largest_x - smallest_x + 1) as usize]);
+    }
+
+    for dot in &mut dots {
+        dot.x -= smallest_x;
+        dot.y -= smallest_y;
+        output[dot.y as usize][dot.x as usize] = 1;
+    }
+
+    let mut result = String::new();
+
+    for line in output {
+        for num in line {
+            if num == 0 {
+                result.push(' ')
+            } else {
+                result.push('#')
+            }
+        }
+        result.push('\n')
+    }
+
+    result
+}
+
+pub fn part_one(input: &str) -> String {
+    solve_one(&parse(input))
+}
+
+fn solve_two(dots: &[Dot]) -> i32 {
+    let mut dots = dots.to_vec();
+
+    let (mut largest_x, mut largest_y) = get_max(&dots);
+    let mut ticks = 0;
+
+    loop {
+        let mut new_largest_x = 0;
+        let mut new_largest_y = 0;
+
+        for dot in &mut dots {
+            dot.x += dot.dx;
+            dot.y += dot.dy;
+            new_largest_x = new_largest_x.max(dot.x.abs());
+            new_largest_y = new_largest_y.max(dot.y.abs());
+        }
+
+        if new_largest_x <= largest_x && new_largest_y <= largest_y {
+            largest_x = new_largest_x;
+            largest_y = new_largest_y;
+        } else {
+            return ticks;
+        }
+        ticks += 1;
+    }
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 10;
+
+    type PartOne = String;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> String {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Dot>;
+
+    fn parse(input: &str) -> Result<Vec<Dot>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Dot>) -> String {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Dot>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,143 @@
+struct Grid {
+    storage: Vec<Vec<i32>>,
+    serial_number: i32,
+}
+
+impl Grid {
+    fn new(capacity: usize, serial_number: i32) -> Self {
+        Self {
+            serial_number,
+            storage: vec![Vec::with_capacity(capacity); capacity],
+        }
+    }
+
+    fn get_memoized(&mut self, x: usize, y: usize) -> i32 {
+        if let Some(num) = self.storage[y].get(x) {
+            *num
+        } else {
+            let amount = self.get_power_level(x, y);
+            self.storage[y].push(amount);
+            amount
+        }
+    }
+    fn get_power_level(&self, x: usize, y: usize) -> i32 {
+        let rack_id = (x + 10) as i32;
+        let mut power_level = rack_id * y as i32;
+        power_level += self.serial_number;
+        power_level *= rack_id;
+        power_level = (power_level / 100) % 10;
+        power_level - 5
+    }
+}
+
+fn parse(input: &str) -> i32 {
+    input.trim().parse().unwrap()
+}
+
+fn solve_one(&serial_number: &i32) -> String {
+    let mut grid = Grid::new(300, serial_number);
+
+    let mut result_x: usize = 0;
+    let mut result_y: usize = 0;
+    let mut largest_grid = 0;
+
+    for y in 0..297 {
+        for x in 0..297 {
+            let mut grid_power_level = 0;
+            for yy in 0..3 {
+                for xx in 0..3 {
+                    grid_power_level += grid.get_memoized(x + xx, y + yy);
+                }
+            }
+
+            if grid_power_level > largest_grid {
+                result_x = x;
+                result_y = y;
+                largest_grid = grid_power_level;
+            }
+        }
+    }
+
+    format!("{},{}", result_x, result_y)
+}
+
+pub fn part_one(input: &str) -> String {
+    solve_one(&parse(input))
+}
+
+fn solve_two(&serial_number: &i32) -> String {
+    let mut grid = Grid::new(300, serial_number);
+
+    let mut result_x: usize = 0;
+    let mut result_y: usize = 0;
+    let mut largest_grid = 0;
+    let mut result_size = 0;
+    let mut unchanged_count = 0;
+
+    for size in 0..300 {
+        if unchanged_count == 10 {
+            break;
+        }
+        for y in 0..(300 - size) {
+            for x in 0..(300 - size) {
+                let mut grid_power_level = 0;
+                for yy in 0..size {
+                    for xx in 0..size {
+                        grid_power_level += grid.get_memoized(x + xx, y + yy);
+                    }
+                }
+
+                if grid_power_level >= largest_grid {
+                    result_x = x;
+                    result_y = y;
+                    largest_grid = grid_power_level;
+                    result_size = size;
+                    unchanged_count = 0;
+                }
+            }
+        }
+        unchanged_count += 1;
+    }
+
+    format!("{},{},{}", result_x, result_y, result_size)
+}
+
+pub fn part_two(input: &str) -> String {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 11;
+
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn part_one(input: &str) -> String {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> String {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = i32;
+
+    fn parse(input: &str) -> Result<i32, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &i32) -> String {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &i32) -> String {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
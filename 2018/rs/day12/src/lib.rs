@@ -0,0 +1,205 @@
+use std::collections::VecDeque;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+enum State {
+    Dead,
+    Live,
+}
+
+#[derive(Clone, Debug)]
+struct Plant {
+    location: i32,
+    state: State,
+}
+
+impl Plant {
+    fn new(location: i32, state: State) -> Self {
+        Self { location, state }
+    }
+}
+
+impl std::convert::TryFrom<char> for State {
+    type Error = char;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        match ch {
+            '.' => Ok(Self::Dead),
+            '#' => Ok(Self::Live),
+            _ => Err(ch),
+        }
+    }
+}
+
+fn parse_combinations(lines: std::str::Lines) -> Vec<([State; 5], State)> {
+    lines
+        .map(|line| {
+            let mut parts = line.split(" => ");
+            let mut states = parts.next().unwrap().chars();
+
+            (
+                [
+                    State::try_from(states.next().unwrap()).unwrap(),
+                    State::try_from(states.next().unwrap()).unwrap(),
+                    State::try_from(states.next().unwrap()).unwrap(),
+                    State::try_from(states.next().unwrap()).unwrap(),
+                    State::try_from(states.next().unwrap()).unwrap(),
+                ],
+                State::try_from(parts.next().unwrap().chars().next().unwrap()).unwrap(),
+            )
+        })
+        .collect()
+}
+
+fn count_plants(plants: &VecDeque<Plant>) -> usize {
+    plants.iter().fold(0, |acc, plant| {
+        acc + match plant.state {
+            State::Live => plant.location,
+            _ => 0,
+        }
+    }) as usize
+}
+
+/// The initial row of pots and the rules for the next generation.
+pub struct Pots {
+    plants: VecDeque<Plant>,
+    combinations: Vec<([State; 5], State)>,
+}
+
+fn parse(input: &str) -> Pots {
+    let mut lines = input.lines();
+    let plants = lines
+        .next()
+        .unwrap()
+        .split(' ')
+        .nth(2)
+        .unwrap()
+        .chars()
+        .flat_map(State::try_from)
+        .enumerate()
+        .map(|(loc, state)| Plant {
+            state,
+            location: loc as i32,
+        })
+        .collect();
+
+    lines.next();
+
+    Pots {
+        plants,
+        combinations: parse_combinations(lines),
+    }
+}
+
+fn iterate(pots: &Pots, times: usize) -> usize {
+    let mut plants = pots.plants.clone();
+    let combinations = &pots.combinations;
+    let mut buffer = VecDeque::with_capacity(combinations.len());
+    let mut previous_amount = count_plants(&plants);
+    let mut previous_diff = 0;
+
+    for step in 0..times {
+        let first = plants.front().unwrap().location;
+
+        let mut current = [
+            Plant::new(first - 4, State::Dead),
+            Plant::new(first - 3, State::Dead),
+            Plant::new(first - 2, State::Dead),
+            Plant::new(first - 1, State::Dead),
+            plants.pop_front().unwrap(),
+        ];
+
+        let last = plants.back().unwrap().location;
+
+        plants.push_back(Plant::new(last + 1, State::Dead));
+        plants.push_back(Plant::new(last + 2, State::Dead));
+        plants.push_back(Plant::new(last + 3, State::Dead));
+
+        while let Some(plant) = plants.pop_front() {
+            current.rotate_left(1);
+            current[4] = plant;
+            let states = [
+                current[0].state,
+                current[1].state,
+                current[2].state,
+                current[3].state,
+                current[4].state,
+            ];
+
+            // inputs may only list the combinations that produce a plant
+            let state = combinations
+                .iter()
+                .find(|combo| combo.0 == states)
+                .map_or(State::Dead, |combo| combo.1);
+            buffer.push_back(Plant::new(current[2].location, state));
+        }
+
+        std::mem::swap(&mut plants, &mut buffer);
+
+        if times > 20 {
+            let amount = count_plants(&plants);
+            let diff = amount as i32 - previous_amount as i32;
+
+            if diff == previous_diff {
+                let remaining_generations = 50000000000usize - step - 1;
+                return amount + remaining_generations * diff as usize;
+            }
+
+            previous_diff = diff;
+            previous_amount = amount;
+        }
+    }
+
+    count_plants(&plants)
+}
+
+fn solve_one(pots: &Pots) -> usize {
+    iterate(pots, 20)
+}
+
+fn solve_two(pots: &Pots) -> usize {
+    iterate(pots, 500)
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 12;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Pots;
+
+    fn parse(input: &str) -> Result<Pots, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Pots) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Pots) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
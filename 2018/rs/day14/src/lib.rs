@@ -0,0 +1,127 @@
+fn parse(input: &str) -> String {
+    input.trim().to_string()
+}
+
+fn solve_one(input: &str) -> String {
+    let mut recipes = vec![3, 7];
+
+    let mut elf_one = 0usize;
+    let mut elf_two = 1usize;
+
+    let amount = input.parse().unwrap();
+
+    while recipes.len() < amount + 10 {
+        match (recipes.get(elf_one), recipes.get(elf_two)) {
+            (Some(&value_one), Some(&value_two)) => {
+                let mut sum = value_one + value_two;
+
+                match sum / 10 {
+                    0 => (),
+                    value => {
+                        recipes.push(value);
+                        sum %= 10;
+                    }
+                }
+
+                recipes.push(sum);
+                elf_one = (elf_one + 1 + value_one) % recipes.len();
+                elf_two = (elf_two + 1 + value_two) % recipes.len();
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    recipes[amount..(amount + 10)]
+        .iter()
+        .flat_map(|v| char::from_digit(*v as u32, 10))
+        .collect()
+}
+
+pub fn part_one(input: &str) -> String {
+    solve_one(&parse(input))
+}
+
+fn solve_two(input: &str) -> usize {
+    let mut recipes = vec![3, 7];
+
+    let mut elf_one = 0usize;
+    let mut elf_two = 1usize;
+
+    let target: &[usize] = &input
+        .chars()
+        .map(|ch| ch.to_digit(10).unwrap() as usize)
+        .collect::<Vec<_>>()[..];
+    let target_len = target.len();
+
+    loop {
+        match (recipes.get(elf_one), recipes.get(elf_two)) {
+            (Some(&value_one), Some(&value_two)) => {
+                let mut sum = value_one + value_two;
+
+                match sum / 10 {
+                    0 => (),
+                    value => {
+                        recipes.push(value);
+                        sum %= 10;
+                    }
+                }
+
+                recipes.push(sum);
+                elf_one = (elf_one + 1 + value_one) % recipes.len();
+                elf_two = (elf_two + 1 + value_two) % recipes.len();
+            }
+            _ => unreachable!(),
+        }
+
+        let len = recipes.len();
+        if len > target_len + 1 {
+            if &recipes[len - target_len..len] == target {
+                return len - target_len;
+            }
+
+            if &recipes[len - target_len - 1..len - 1] == target {
+                return len - target_len - 1;
+            }
+        }
+    }
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 14;
+
+    type PartOne = String;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> String {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &String) -> String {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &String) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,478 @@
+use std::{
+    cell::RefCell,
+    cmp,
+    collections::{BinaryHeap, HashSet, VecDeque},
+    iter,
+};
+
+thread_local! {
+    static QUEUE: RefCell<VecDeque<(Coord, Direction, usize)>> = const { RefCell::new(VecDeque::new()) };
+    static SEEN: RefCell<HashSet<Coord>> = RefCell::new(HashSet::new());
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Direction {
+    Up,
+    Left,
+    Right,
+    Down,
+}
+
+impl Direction {
+    fn iter() -> impl Iterator<Item = Self> {
+        iter::once(Self::Up)
+            .chain(iter::once(Self::Left))
+            .chain(iter::once(Self::Right))
+            .chain(iter::once(Self::Down))
+    }
+}
+
+#[derive(Debug)]
+struct MinHeap<T: Ord + PartialOrd + Eq + PartialEq>(BinaryHeap<Min<T>>);
+
+impl<T: Ord + PartialOrd + Eq + PartialEq> MinHeap<T> {
+    fn new() -> Self {
+        Self(BinaryHeap::new())
+    }
+
+    fn push(&mut self, value: T) {
+        self.0.push(Min(value))
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        match self.0.pop() {
+            Some(Min(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
+struct Coord(usize, usize);
+
+impl Coord {
+    fn neighbors(&self) -> impl Iterator<Item = Coord> {
+        let &Self(x, y) = self;
+        iter::once(Self(x, y - 1))
+            .chain(iter::once(Self(x - 1, y)))
+            .chain(iter::once(Self(x + 1, y)))
+            .chain(iter::once(Self(x, y + 1)))
+    }
+}
+
+impl cmp::PartialOrd for Coord {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl cmp::Ord for Coord {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        match self.1.cmp(&other.1) {
+            cmp::Ordering::Equal => self.0.cmp(&other.0),
+            cmp => cmp,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct Min<T: PartialOrd + Ord + Eq + PartialEq>(T);
+
+impl<T: Ord> cmp::PartialOrd for Min<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> cmp::Ord for Min<T> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+/// A square of the cave, holding a unit and its hit points.
+#[derive(Debug, Copy, Clone, Default)]
+pub enum Tile {
+    #[default]
+    Empty,
+    Wall,
+    Elf(usize),
+    Goblin(usize),
+}
+
+impl Tile {
+    fn is_creature(&self) -> bool {
+        matches!(self, Self::Goblin(_) | Self::Elf(_))
+    }
+
+    fn is_empty(&self) -> bool {
+        matches!(self, Self::Empty)
+    }
+
+    fn from_char(ch: char) -> Self {
+        match ch {
+            '.' => Self::Empty,
+            '#' => Self::Wall,
+            'E' => Self::Elf(200),
+            'G' => Self::Goblin(200),
+            _ => unreachable!(),
+        }
+    }
+
+    fn is_enemy(&self, other: &Self) -> bool {
+        matches!(
+            (self, other),
+            (Self::Goblin(_), Self::Elf(_)) | (Self::Elf(_), Self::Goblin(_))
+        )
+    }
+
+    fn hp(&self) -> usize {
+        match self {
+            Self::Goblin(hp) | Self::Elf(hp) => *hp,
+            _ => unreachable!(),
+        }
+    }
+
+    fn damage(&mut self, amount: usize) {
+        match self {
+            Self::Goblin(hp) | Self::Elf(hp) => *hp = hp.saturating_sub(amount),
+            _ => unreachable!(),
+        }
+    }
+
+    fn is_dead(&self) -> bool {
+        matches!(self, Self::Goblin(0) | Self::Elf(0))
+    }
+}
+
+struct Cave {
+    layout: Vec<Vec<Tile>>,
+    heap: MinHeap<Coord>,
+    locations: Vec<Coord>,
+    ticks: usize,
+    elf_power: usize,
+}
+
+impl Cave {
+    fn new(layout: &[Vec<Tile>]) -> Self {
+        let layout = layout.to_vec();
+
+        Self {
+            locations: layout
+                .iter()
+                .enumerate()
+                .flat_map(|(y, row)| {
+                    row.iter().enumerate().filter_map(move |(x, tile)| {
+                        if tile.is_creature() {
+                            Some(Coord(x, y))
+                        } else {
+                            None
+                        }
+                    })
+                })
+                .collect(),
+            layout,
+            heap: MinHeap::new(),
+            ticks: 0,
+            elf_power: 3,
+        }
+    }
+
+    fn get(&self, Coord(x, y): Coord) -> &Tile {
+        if let Some(row) = self.layout.get(y) {
+            if let Some(tile) = row.get(x) {
+                return tile;
+            }
+        }
+
+        unreachable!()
+    }
+
+    fn get_mut(&mut self, Coord(x, y): Coord) -> &mut Tile {
+        if let Some(row) = self.layout.get_mut(y) {
+            if let Some(tile) = row.get_mut(x) {
+                return tile;
+            }
+        }
+
+        unreachable!()
+    }
+
+    fn find_path(&self, start: Coord, goal: Coord) -> Option<(Direction, usize)> {
+        QUEUE.with_borrow_mut(|queue| {
+            SEEN.with_borrow_mut(|seen| {
+                queue.clear();
+
+                for (neighbor, direction) in start.neighbors().zip(Direction::iter()) {
+                    if self.get(neighbor).is_empty() {
+                        queue.push_back((neighbor, direction, 1));
+                    }
+                }
+
+                seen.clear();
+                seen.insert(start);
+
+                while let Some((a, direction, distance)) = queue.pop_front() {
+                    if a == goal {
+                        return Some((direction, distance));
+                    }
+
+                    for neighbor in a.neighbors() {
+                        if seen.contains(&neighbor) {
+                            continue;
+                        }
+                        seen.insert(neighbor);
+                        if self.get(neighbor).is_empty() {
+                            queue.push_back((neighbor, direction, distance + 1));
+                        }
+                    }
+                }
+
+                None
+            })
+        })
+    }
+
+    fn insert_tile_at(&mut self, coord: Coord, tile: Tile) {
+        self.layout[coord.1][coord.0] = tile;
+        self.locations.push(coord);
+    }
+
+    fn remove_tile_at(&mut self, coord: &Coord) -> Tile {
+        self.locations.retain(|c| *c != *coord);
+        std::mem::take(&mut self.layout[coord.1][coord.0])
+    }
+
+    fn count_elves(&self) -> usize {
+        let mut count = 0;
+
+        for loc in &self.locations {
+            if matches!(self.get(*loc), Tile::Elf(_)) {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    fn only_goblins_or_elves(&self) -> bool {
+        let elf_count = self.count_elves();
+
+        elf_count == self.locations.len() || elf_count == 0
+    }
+
+    fn find_move(&self, coord: &Coord, tile: &Tile) -> Option<Direction> {
+        for neighbor in coord.neighbors() {
+            if self.get(neighbor).is_enemy(tile) {
+                return None;
+            }
+        }
+
+        let mut min_distance = usize::MAX;
+        let mut destination_opt = None;
+        let mut direction = None;
+
+        for dest in &self.locations {
+            if !self.get(*dest).is_enemy(tile) {
+                continue;
+            }
+            for neighbor in dest.neighbors() {
+                let neighbor_tile = self.get(neighbor);
+
+                if !neighbor_tile.is_empty() {
+                    continue;
+                }
+
+                let (dir, dist) = match self.find_path(*coord, neighbor) {
+                    Some(result) => result,
+                    None => continue,
+                };
+
+                match (dist.cmp(&min_distance), destination_opt) {
+                    (cmp::Ordering::Less, _) | (_, None) => {
+                        min_distance = dist;
+                        destination_opt = Some(neighbor);
+                        direction = Some(dir);
+                    }
+                    (cmp::Ordering::Equal, Some(destination)) => {
+                        if neighbor < destination {
+                            min_distance = dist;
+                            destination_opt = Some(neighbor);
+                            direction = Some(dir);
+                        }
+                    }
+                    (cmp::Ordering::Greater, _) => {}
+                }
+            }
+        }
+
+        direction
+    }
+
+    fn get_damage(&self, tile: &Tile) -> usize {
+        match tile {
+            Tile::Goblin(_) => 3,
+            Tile::Elf(_) => self.elf_power,
+            _ => unreachable!(),
+        }
+    }
+
+    fn tick(&mut self) -> bool {
+        for &Coord(x, y) in &self.locations {
+            self.heap.push(Coord(x, y))
+        }
+
+        while let Some(mut coord) = self.heap.pop() {
+            let tile = self.remove_tile_at(&coord);
+            if !tile.is_creature() {
+                continue;
+            }
+
+            if let Some(direction) = self.find_move(&coord, &tile) {
+                coord = match direction {
+                    Direction::Up => Coord(coord.0, coord.1 - 1),
+                    Direction::Right => Coord(coord.0 + 1, coord.1),
+                    Direction::Down => Coord(coord.0, coord.1 + 1),
+                    Direction::Left => Coord(coord.0 - 1, coord.1),
+                };
+            };
+
+            let mut target = None;
+            let mut lowest_hp = usize::MAX;
+            for neighbor in coord.neighbors() {
+                let neighbor_tile = self.get(neighbor);
+                if tile.is_enemy(neighbor_tile) {
+                    let neighbor_hp = neighbor_tile.hp();
+                    match (neighbor_hp.cmp(&lowest_hp), target) {
+                        (cmp::Ordering::Less, _) | (_, None) => {
+                            lowest_hp = neighbor_hp;
+                            target = Some(neighbor);
+                        }
+                        (cmp::Ordering::Equal, Some(other)) => {
+                            if neighbor < other {
+                                lowest_hp = neighbor_hp;
+                                target = Some(neighbor);
+                            }
+                        }
+                        (cmp::Ordering::Greater, _) => {}
+                    }
+                }
+            }
+
+            if let Some(target) = target {
+                let amount = self.get_damage(&tile);
+                let enemy = self.get_mut(target);
+                enemy.damage(amount);
+                if enemy.is_dead() {
+                    self.remove_tile_at(&target);
+
+                    if self.only_goblins_or_elves() {
+                        self.insert_tile_at(coord, tile);
+                        break;
+                    }
+                }
+            }
+
+            self.insert_tile_at(coord, tile);
+        }
+
+        if self.heap.len() == 0 {
+            self.ticks += 1;
+        }
+
+        self.heap.len() == 0 && !self.only_goblins_or_elves()
+    }
+}
+
+fn parse(input: &str) -> Vec<Vec<Tile>> {
+    input
+        .lines()
+        .map(|line| line.chars().map(Tile::from_char).collect())
+        .collect()
+}
+
+fn solve_one(layout: &[Vec<Tile>]) -> usize {
+    let mut cave = Cave::new(layout);
+
+    while cave.tick() {}
+
+    cave.ticks
+        * cave
+            .locations
+            .iter()
+            .fold(0, |acc, coord| acc + cave.get(*coord).hp())
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(layout: &[Vec<Tile>]) -> usize {
+    let mut cave = Cave::new(layout);
+
+    let mut elf_power = 4;
+
+    let starting_elf_count = cave.count_elves();
+
+    loop {
+        cave.elf_power = elf_power;
+        while cave.tick() {}
+
+        if cave.count_elves() == starting_elf_count {
+            break;
+        }
+
+        elf_power += 1;
+        cave = Cave::new(layout)
+    }
+
+    cave.ticks
+        * cave
+            .locations
+            .iter()
+            .fold(0, |acc, coord| acc + cave.get(*coord).hp())
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 15;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Vec<Tile>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<Tile>>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Vec<Tile>>) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Vec<Tile>>) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
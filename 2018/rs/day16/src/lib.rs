@@ -0,0 +1,327 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    convert,
+};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+enum Opcode {
+    Addr,
+    Addi,
+    Mulr,
+    Muli,
+    Banr,
+    Bani,
+    Borr,
+    Bori,
+    Setr,
+    Seti,
+    Gtir,
+    Gtri,
+    Gtrr,
+    Eqir,
+    Eqri,
+    Eqrr,
+}
+
+impl convert::TryFrom<usize> for Opcode {
+    type Error = ();
+
+    fn try_from(number: usize) -> Result<Self, Self::Error> {
+        match number {
+            0 => Ok(Self::Addr),
+            1 => Ok(Self::Addi),
+            2 => Ok(Self::Mulr),
+            3 => Ok(Self::Muli),
+            4 => Ok(Self::Banr),
+            5 => Ok(Self::Bani),
+            6 => Ok(Self::Borr),
+            7 => Ok(Self::Bori),
+            8 => Ok(Self::Setr),
+            9 => Ok(Self::Seti),
+            10 => Ok(Self::Gtir),
+            11 => Ok(Self::Gtri),
+            12 => Ok(Self::Gtrr),
+            13 => Ok(Self::Eqir),
+            14 => Ok(Self::Eqri),
+            15 => Ok(Self::Eqrr),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Opcode {
+    fn iter() -> impl Iterator<Item = Self> {
+        (0..).map_while(|u| u.try_into().ok())
+    }
+}
+
+struct Instruction(usize, usize, usize, usize);
+
+impl Instruction {
+    fn new(&[op, a, b, c]: &[usize; 4]) -> Self {
+        Self(op, a, b, c)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Cpu {
+    registers: [usize; 4],
+    opcode_mapping: [Opcode; 16],
+}
+
+impl Cpu {
+    fn new() -> Self {
+        let mut mapping = [Opcode::Addi; 16];
+
+        for (index, op) in Opcode::iter().enumerate() {
+            mapping[index] = op;
+        }
+
+        Self {
+            registers: [0; 4],
+            opcode_mapping: mapping,
+        }
+    }
+
+    fn evaluate(&mut self, instruction: &Instruction) {
+        macro_rules! register_a {
+            () => {
+                self.registers[instruction.1]
+            };
+        }
+
+        macro_rules! register_b {
+            () => {
+                self.registers[instruction.2]
+            };
+        }
+        macro_rules! value_a {
+            () => {
+                instruction.1
+            };
+        }
+
+        macro_rules! value_b {
+            () => {
+                instruction.2
+            };
+        }
+
+        let result = match self.opcode_mapping[instruction.0] {
+            Opcode::Addr => register_a!() + register_b!(),
+            Opcode::Addi => register_a!() + value_b!(),
+            Opcode::Mulr => register_a!() * register_b!(),
+            Opcode::Muli => register_a!() * value_b!(),
+            Opcode::Banr => register_a!() & register_b!(),
+            Opcode::Bani => register_a!() & value_b!(),
+            Opcode::Borr => register_a!() | register_b!(),
+            Opcode::Bori => register_a!() | value_b!(),
+            Opcode::Setr => register_a!(),
+            Opcode::Seti => value_a!(),
+            Opcode::Gtir => (value_a!() > register_b!()) as usize,
+            Opcode::Gtri => (register_a!() > value_b!()) as usize,
+            Opcode::Gtrr => (register_a!() > register_b!()) as usize,
+            Opcode::Eqir => (value_a!() == register_b!()) as usize,
+            Opcode::Eqri => (register_a!() == value_b!()) as usize,
+            Opcode::Eqrr => (register_a!() == register_b!()) as usize,
+        };
+
+        self.registers[instruction.3] = result;
+    }
+}
+
+macro_rules! before_after_array {
+    ($line:ident) => {{
+        let mut array = [0usize; 4];
+
+        for (index, num) in $line
+            .chars()
+            .filter(|c| c.is_digit(10))
+            .flat_map(|c| c.to_digit(10))
+            .enumerate()
+        {
+            array[index] = num as usize;
+        }
+
+        array
+    }};
+}
+
+macro_rules! usize_array {
+    ($line:ident) => {{
+        let mut array = [0; 4];
+
+        for (index, num) in $line
+            .split_ascii_whitespace()
+            .flat_map(|n| n.parse::<usize>())
+            .enumerate()
+        {
+            array[index] = num;
+        }
+
+        array
+    }};
+}
+
+fn behaves_like(before: [usize; 4], inputs: &[usize; 4], after: [usize; 4]) -> Vec<Opcode> {
+    Opcode::iter()
+        .enumerate()
+        .filter(|(index, _)| {
+            let mut cpu = Cpu::new();
+            cpu.registers = before;
+            cpu.evaluate(&Instruction(*index, inputs[1], inputs[2], inputs[3]));
+            cpu.registers == after
+        })
+        .map(|(_, opcode)| opcode)
+        .collect()
+}
+
+/// The recorded samples, as registers before, instruction and registers after, and the test
+/// program.
+pub struct Manual {
+    samples: Vec<([usize; 4], [usize; 4], [usize; 4])>,
+    program: Vec<[usize; 4]>,
+}
+
+fn parse(input: &str) -> Manual {
+    let mut lines = input.lines();
+    let mut samples = Vec::new();
+
+    while let Some(line) = lines.next() {
+        if line.is_empty() {
+            break;
+        }
+
+        let before = before_after_array!(line);
+
+        let next_line = lines.next().unwrap();
+        let inputs = usize_array!(next_line);
+
+        let next_line = lines.next().unwrap();
+        let expected = before_after_array!(next_line);
+
+        samples.push((before, inputs, expected));
+
+        lines.next();
+    }
+
+    lines.next();
+
+    Manual {
+        samples,
+        program: lines.map(|line| usize_array!(line)).collect(),
+    }
+}
+
+fn execute(manual: &Manual) -> (i32, usize) {
+    let mut ambiguous_samples = 0;
+
+    let mut set_map = HashMap::new();
+
+    for &(before, inputs, expected) in &manual.samples {
+        let matches = behaves_like(before, &inputs, expected);
+        for opcode in Opcode::iter() {
+            let entry = set_map.entry(opcode).or_insert_with(HashSet::new);
+            if matches.contains(&opcode) {
+                entry.insert(inputs[0]);
+            }
+        }
+
+        if matches.len() >= 3 {
+            ambiguous_samples += 1;
+        }
+    }
+
+    let mut queue: VecDeque<_> = set_map.into_iter().collect();
+
+    let mut opcode_map = [Opcode::Addi; 16];
+
+    while let Some((opcode, set)) = queue.pop_front() {
+        if set.len() > 1 {
+            queue.push_back((opcode, set));
+            continue;
+        }
+
+        let value = set.into_iter().next().unwrap();
+        opcode_map[value] = opcode;
+
+        for (_, set) in queue.iter_mut() {
+            set.remove(&value);
+        }
+    }
+
+    let mut cpu = Cpu::new();
+    cpu.opcode_mapping = opcode_map;
+    for digits in &manual.program {
+        cpu.evaluate(&Instruction::new(digits));
+    }
+
+    (ambiguous_samples, cpu.registers[0])
+}
+
+pub fn solve(input: &str) -> (i32, usize) {
+    execute(&parse(input))
+}
+
+fn solve_one(manual: &Manual) -> i32 {
+    execute(manual).0
+}
+
+fn solve_two(manual: &Manual) -> usize {
+    execute(manual).1
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 16;
+
+    type PartOne = i32;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Manual;
+
+    fn parse(input: &str) -> Result<Manual, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Manual) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Manual) -> usize {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_behaves_like() {
+        assert_eq!(
+            behaves_like([3, 2, 1, 1], &[9, 2, 1, 2], [3, 2, 2, 1]),
+            vec![Opcode::Addi, Opcode::Mulr, Opcode::Seti]
+        );
+    }
+}
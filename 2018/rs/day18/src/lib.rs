@@ -0,0 +1,225 @@
+use std::collections::HashSet;
+
+/// A single acre of the lumber collection area.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Tile {
+    Open,
+    Trees,
+    Lumberyard,
+}
+
+impl Tile {
+    fn from_char(ch: char) -> Self {
+        match ch {
+            '.' => Self::Open,
+            '|' => Self::Trees,
+            '#' => Self::Lumberyard,
+            _ => unreachable!(),
+        }
+    }
+
+    fn count_if(&self, tile: Tile) -> i32 {
+        if *self == tile {
+            1
+        } else {
+            0
+        }
+    }
+}
+
+fn inc(n: usize, max: usize) -> Option<usize> {
+    match n + 1 {
+        next if next == max => None,
+        next => Some(next),
+    }
+}
+
+fn dec(n: usize, _: usize) -> Option<usize> {
+    match n {
+        0 => None,
+        _ => Some(n - 1),
+    }
+}
+
+fn noop(n: usize, _: usize) -> Option<usize> {
+    Some(n)
+}
+
+type NeighborFn = fn(usize, usize) -> Option<usize>;
+const NEIGHBORS: [(NeighborFn, NeighborFn); 8] = [
+    (inc, inc),
+    (inc, noop),
+    (inc, dec),
+    (dec, inc),
+    (dec, noop),
+    (dec, dec),
+    (noop, inc),
+    (noop, dec),
+];
+
+fn find_counts(area: &[Vec<Tile>]) -> (i32, i32) {
+    area.iter().fold((0, 0), |running_total, row| {
+        row.iter().fold(running_total, |(t, l), tile| {
+            (
+                t + tile.count_if(Tile::Trees),
+                l + tile.count_if(Tile::Lumberyard),
+            )
+        })
+    })
+}
+
+fn detect_cycle(past_values: &[(usize, (i32, i32))]) -> (usize, usize) {
+    for i in 0..past_values.len() {
+        for j in (i + 1)..past_values.len() {
+            let (step_one, value_one) = past_values[i];
+            let (step_two, value_two) = past_values[j];
+
+            if value_one != value_two {
+                continue;
+            }
+
+            for k in 0..=10 {
+                let (_, value_one) = past_values[i + k];
+                let (_, value_two) = past_values[j + k];
+                if value_one != value_two {
+                    break;
+                }
+
+                if k == 10 {
+                    return (step_one, step_two - step_one);
+                }
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+fn parse(input: &str) -> Vec<Vec<Tile>> {
+    input
+        .lines()
+        .map(|line| line.chars().map(Tile::from_char).collect())
+        .collect()
+}
+
+fn solve(area: &[Vec<Tile>], target: usize) -> i32 {
+    let mut area = area.to_vec();
+    let max_x = area[0].len();
+    let max_y = area.len();
+
+    let mut buffer = area.clone();
+
+    let mut past_values = vec![];
+    let mut seen = HashSet::new();
+
+    for step in 0..target {
+        for (y, row) in area.iter().enumerate() {
+            for (x, tile) in row.iter().enumerate() {
+                let neighbors =
+                    NEIGHBORS
+                        .iter()
+                        .filter_map(|(a, b)| match (a(x, max_x), b(y, max_y)) {
+                            (Some(x), Some(y)) => Some(area[y][x]),
+                            _ => None,
+                        });
+
+                match tile {
+                    Tile::Open => {
+                        let total = neighbors.fold(0, |acc, tile| acc + tile.count_if(Tile::Trees));
+                        buffer[y][x] = if total >= 3 { Tile::Trees } else { *tile };
+                    }
+                    Tile::Trees => {
+                        let total =
+                            neighbors.fold(0, |acc, tile| acc + tile.count_if(Tile::Lumberyard));
+                        buffer[y][x] = if total >= 3 { Tile::Lumberyard } else { *tile };
+                    }
+                    Tile::Lumberyard => {
+                        buffer[y][x] = match neighbors.fold((0, 0), |(a, b), tile| {
+                            (
+                                a + tile.count_if(Tile::Lumberyard),
+                                b + tile.count_if(Tile::Trees),
+                            )
+                        }) {
+                            (0, _) | (_, 0) => Tile::Open,
+                            _ => *tile,
+                        }
+                    }
+                }
+            }
+        }
+
+        std::mem::swap(&mut area, &mut buffer);
+        let value = find_counts(&area);
+        past_values.push((step, value));
+        seen.insert(value);
+
+        if past_values.len() - seen.len() > 50 {
+            break;
+        }
+    }
+
+    if past_values.len() == target {
+        let (_, (a, b)) = past_values.pop().unwrap();
+        return a * b;
+    }
+
+    let (offset, cycle_length) = detect_cycle(&past_values);
+
+    let index = (target - offset - 1) % cycle_length;
+
+    let (_, (a, b)) = past_values[index + offset];
+
+    a * b
+}
+
+fn solve_one(area: &[Vec<Tile>]) -> i32 {
+    solve(area, 10)
+}
+
+fn solve_two(area: &[Vec<Tile>]) -> i32 {
+    solve(area, 1000000000)
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 18;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Vec<Tile>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<Tile>>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Vec<Tile>>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Vec<Tile>>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
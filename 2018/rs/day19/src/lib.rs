@@ -0,0 +1,313 @@
+use aoc_core::{
+    error::{Token, Tokens},
+    Error,
+};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+enum Opcode {
+    Addr,
+    Addi,
+    Mulr,
+    Muli,
+    Banr,
+    Bani,
+    Borr,
+    Bori,
+    Setr,
+    Seti,
+    Gtir,
+    Gtri,
+    Gtrr,
+    Eqir,
+    Eqri,
+    Eqrr,
+}
+
+impl std::str::FromStr for Opcode {
+    type Err = ();
+
+    fn from_str(string: &str) -> Result<Opcode, ()> {
+        match string {
+            "addr" => Ok(Self::Addr),
+            "addi" => Ok(Self::Addi),
+            "mulr" => Ok(Self::Mulr),
+            "muli" => Ok(Self::Muli),
+            "banr" => Ok(Self::Banr),
+            "bani" => Ok(Self::Bani),
+            "borr" => Ok(Self::Borr),
+            "bori" => Ok(Self::Bori),
+            "setr" => Ok(Self::Setr),
+            "seti" => Ok(Self::Seti),
+            "gtir" => Ok(Self::Gtir),
+            "gtri" => Ok(Self::Gtri),
+            "gtrr" => Ok(Self::Gtrr),
+            "eqir" => Ok(Self::Eqir),
+            "eqri" => Ok(Self::Eqri),
+            "eqrr" => Ok(Self::Eqrr),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Opcode {
+    // whether inputs A and B name registers rather than values
+    fn reads_registers(self) -> (bool, bool) {
+        match self {
+            Self::Addr | Self::Mulr | Self::Banr | Self::Borr | Self::Gtrr | Self::Eqrr => {
+                (true, true)
+            }
+            Self::Addi
+            | Self::Muli
+            | Self::Bani
+            | Self::Bori
+            | Self::Setr
+            | Self::Gtri
+            | Self::Eqri => (true, false),
+            Self::Gtir | Self::Eqir => (false, true),
+            Self::Seti => (false, false),
+        }
+    }
+}
+
+const REGISTERS: usize = 6;
+
+fn parse_register(token: Token) -> Result<usize, Error> {
+    match token.parse("register")? {
+        register if register < REGISTERS => Ok(register),
+        register => Err(token.error(format!("no register {}", register))),
+    }
+}
+
+/// A single device instruction.
+pub struct Instruction(Opcode, usize, usize, usize);
+
+impl std::str::FromStr for Instruction {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut tokens = Tokens::new(line);
+
+        let token = tokens.next("opcode")?;
+        let opcode: Opcode = token
+            .text
+            .parse()
+            .map_err(|_| token.error(format!("unknown opcode `{}`", token.text)))?;
+        let (a_register, b_register) = opcode.reads_registers();
+
+        let token = tokens.next("input A")?;
+        let a = if a_register {
+            parse_register(token)?
+        } else {
+            token.parse("input A")?
+        };
+        let token = tokens.next("input B")?;
+        let b = if b_register {
+            parse_register(token)?
+        } else {
+            token.parse("input B")?
+        };
+        let c = parse_register(tokens.next("output C")?)?;
+        tokens.end()?;
+
+        Ok(Self(opcode, a, b, c))
+    }
+}
+
+/// The device registers and the register bound to the instruction pointer.
+#[derive(Debug, Copy, Clone)]
+pub struct Cpu {
+    registers: [usize; REGISTERS],
+    ip_register: usize,
+    ip: usize,
+}
+
+impl Cpu {
+    fn new(ip_register: usize) -> Self {
+        Self {
+            ip_register,
+            ip: 0,
+            registers: [0; REGISTERS],
+        }
+    }
+
+    fn evaluate(&mut self, instruction: &Instruction) {
+        macro_rules! register_a {
+            () => {
+                self.registers[instruction.1]
+            };
+        }
+
+        macro_rules! register_b {
+            () => {
+                self.registers[instruction.2]
+            };
+        }
+        macro_rules! value_a {
+            () => {
+                instruction.1
+            };
+        }
+
+        macro_rules! value_b {
+            () => {
+                instruction.2
+            };
+        }
+
+        self.registers[self.ip_register] = self.ip;
+
+        let result = match instruction.0 {
+            Opcode::Addr => register_a!() + register_b!(),
+            Opcode::Addi => register_a!() + value_b!(),
+            Opcode::Mulr => register_a!() * register_b!(),
+            Opcode::Muli => register_a!() * value_b!(),
+            Opcode::Banr => register_a!() & register_b!(),
+            Opcode::Bani => register_a!() & value_b!(),
+            Opcode::Borr => register_a!() | register_b!(),
+            Opcode::Bori => register_a!() | value_b!(),
+            Opcode::Setr => register_a!(),
+            Opcode::Seti => value_a!(),
+            Opcode::Gtir => (value_a!() > register_b!()) as usize,
+            Opcode::Gtri => (register_a!() > value_b!()) as usize,
+            Opcode::Gtrr => (register_a!() > register_b!()) as usize,
+            Opcode::Eqir => (value_a!() == register_b!()) as usize,
+            Opcode::Eqri => (register_a!() == value_b!()) as usize,
+            Opcode::Eqrr => (register_a!() == register_b!()) as usize,
+        };
+
+        self.registers[instruction.3] = result;
+        self.ip = self.registers[self.ip_register];
+        self.ip += 1;
+    }
+}
+
+fn parse_ip_register(line: &str) -> Result<usize, Error> {
+    let mut tokens = Tokens::new(line);
+    let token = tokens.next("#ip")?;
+    if token.text != "#ip" {
+        return Err(token.error(format!("expected `#ip`, found `{}`", token.text)));
+    }
+    let register = parse_register(tokens.next("register")?)?;
+    tokens.end()?;
+    Ok(register)
+}
+
+fn parse(input: &str) -> Result<(Cpu, Vec<Instruction>), Error> {
+    let mut lines = input.lines();
+    let ip_register =
+        parse_ip_register(lines.next().unwrap_or_default()).map_err(|err| err.at_line(1))?;
+
+    let instructions = lines
+        .enumerate()
+        .map(|(i, line)| line.parse().map_err(|err: Error| err.at_line(i + 2)))
+        .collect::<Result<Vec<Instruction>, Error>>()?;
+
+    let cpu = Cpu::new(ip_register);
+
+    Ok((cpu, instructions))
+}
+
+fn solve_one((cpu, instructions): &(Cpu, Vec<Instruction>)) -> Result<usize, Error> {
+    let mut cpu = *cpu;
+
+    while let Some(instruction) = instructions.get(cpu.ip) {
+        cpu.evaluate(instruction);
+    }
+
+    Ok(cpu.registers[0])
+}
+
+pub fn part_one(input: &str) -> Result<usize, Error> {
+    solve_one(&parse(input)?)
+}
+
+fn solve_two((cpu, instructions): &(Cpu, Vec<Instruction>)) -> Result<usize, Error> {
+    let mut cpu = *cpu;
+
+    cpu.registers[0] = 1;
+
+    while cpu.ip != 1 {
+        let instruction = instructions
+            .get(cpu.ip)
+            .ok_or_else(|| Error::new("the program halted before setting up its target"))?;
+        cpu.evaluate(instruction);
+    }
+
+    let value = cpu.registers[2];
+
+    let mut sum = 0;
+
+    for i in 1..=(value / 2) {
+        if value % i == 0 {
+            sum += i;
+        }
+    }
+
+    Ok(sum + value)
+}
+
+pub fn part_two(input: &str) -> Result<usize, Error> {
+    solve_two(&parse(input)?)
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 19;
+
+    type PartOne = Result<usize, Error>;
+    type PartTwo = Result<usize, Error>;
+
+    fn part_one(input: &str) -> Result<usize, Error> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Result<usize, Error> {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = (Cpu, Vec<Instruction>);
+
+    fn parse(input: &str) -> Result<(Cpu, Vec<Instruction>), aoc_core::Error> {
+        parse(input)
+    }
+
+    fn solve_one(input: &(Cpu, Vec<Instruction>)) -> Result<usize, Error> {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &(Cpu, Vec<Instruction>)) -> Result<usize, Error> {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input| part_one(input).err().map(|err| err.to_string());
+        assert_eq!(
+            error("#ip 0\nseti 5 0 1\nadx 1 2 3\n"),
+            Some("line 3, column 1: unknown opcode `adx`".to_string())
+        );
+        assert_eq!(
+            error("#ip 0\naddr 1 7 3\n"),
+            Some("line 2, column 8: no register 7".to_string())
+        );
+        assert_eq!(
+            error("#ip 0\nseti 5 0\n"),
+            Some("line 2, column 9: missing output C".to_string())
+        );
+        assert_eq!(
+            error("ip 0\n"),
+            Some("line 1, column 1: expected `#ip`, found `ip`".to_string())
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use std::{collections::HashMap, iter::Peekable, str::Chars};
+
+/// The position of a room.
+pub type Node = (i32, i32);
+
+/// The doors leading out of every room.
+pub type Graph = HashMap<Node, Vec<Node>>;
+
+fn build_graph(chars: &mut Peekable<Chars>, current: Node, graph: &mut Graph) -> Node {
+    let mut current = current;
+
+    while let Some(ch) = chars.peek() {
+        let next = match ch {
+            'E' => (current.0 + 1, current.1),
+            'W' => (current.0 - 1, current.1),
+            'N' => (current.0, current.1 + 1),
+            'S' => (current.0, current.1 - 1),
+            '(' => {
+                loop {
+                    chars.next();
+                    build_graph(chars, current, graph);
+
+                    if !matches!(chars.peek(), Some('|')) {
+                        break;
+                    }
+                }
+
+                debug_assert!(matches!(chars.peek(), Some(')')));
+
+                chars.next();
+                continue;
+            }
+            '|' | ')' => return current,
+            '$' => return current,
+            _ => unreachable!(),
+        };
+
+        chars.next();
+
+        debug_assert!(((current.1 - next.1).abs() + (current.0 - next.0).abs()) == 1);
+
+        let entry = graph.entry(current).or_default();
+        entry.push(next);
+        let entry = graph.entry(next).or_default();
+        entry.push(current);
+        current = next;
+    }
+
+    current
+}
+
+fn walk_graph(graph: &Graph, distance: &mut HashMap<Node, i32>, current: Node, steps: i32) {
+    let entry = distance.entry(current).or_default();
+    if *entry <= steps {
+        return;
+    }
+    *entry = steps;
+
+    for neighbor in graph.get(&current).unwrap() {
+        walk_graph(graph, distance, *neighbor, steps + 1);
+    }
+}
+
+fn parse(input: &str) -> Graph {
+    let mut graph: Graph = HashMap::new();
+    let mut chars = input.chars().peekable();
+    chars.next();
+
+    build_graph(&mut chars, (0, 0), &mut graph);
+
+    debug_assert!(matches!(chars.next(), Some('$')));
+
+    graph
+}
+
+fn walk(graph: &Graph) -> (i32, usize) {
+    let mut distances: HashMap<_, _> = graph.keys().map(|&key| (key, i32::MAX)).collect();
+
+    walk_graph(graph, &mut distances, (0, 0), 0);
+
+    (
+        distances.values().cloned().max().unwrap(),
+        distances.into_values().filter(|&v| v >= 1000).count(),
+    )
+}
+
+pub fn solve(input: &str) -> (i32, usize) {
+    walk(&parse(input))
+}
+
+fn solve_one(graph: &Graph) -> i32 {
+    walk(graph).0
+}
+
+fn solve_two(graph: &Graph) -> usize {
+    walk(graph).1
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 20;
+
+    type PartOne = i32;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Graph;
+
+    fn parse(input: &str) -> Result<Graph, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Graph) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Graph) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!((3, 0), solve("^WNE$"));
+        assert_eq!((10, 0), solve("^ENWWW(NEEE|SSE(EE|N))$"));
+        assert_eq!((18, 0), solve("^ENNWSWW(NEWS|)SSSEEN(WNSE|)EE(SWEN|)NNN$"));
+        assert_eq!(
+            (23, 0),
+            solve("^ESSWWN(E|NNENN(EESS(WNSE|)SSS|WWWSSSSE(SW|NNNE)))$")
+        );
+        assert_eq!(
+            (31, 0),
+            solve("^WSSEESWWWNW(S|NENNEEEENN(ESSSSW(NWSW|SSEN)|WSWWN(E|WWS(E|SS))))$")
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use std::collections::HashSet;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+enum Opcode {
+    Addr,
+    Addi,
+    Mulr,
+    Muli,
+    Banr,
+    Bani,
+    Borr,
+    Bori,
+    Setr,
+    Seti,
+    Gtir,
+    Gtri,
+    Gtrr,
+    Eqir,
+    Eqri,
+    Eqrr,
+}
+
+impl std::str::FromStr for Opcode {
+    type Err = ();
+
+    fn from_str(string: &str) -> Result<Opcode, ()> {
+        match string {
+            "addr" => Ok(Self::Addr),
+            "addi" => Ok(Self::Addi),
+            "mulr" => Ok(Self::Mulr),
+            "muli" => Ok(Self::Muli),
+            "banr" => Ok(Self::Banr),
+            "bani" => Ok(Self::Bani),
+            "borr" => Ok(Self::Borr),
+            "bori" => Ok(Self::Bori),
+            "setr" => Ok(Self::Setr),
+            "seti" => Ok(Self::Seti),
+            "gtir" => Ok(Self::Gtir),
+            "gtri" => Ok(Self::Gtri),
+            "gtrr" => Ok(Self::Gtrr),
+            "eqir" => Ok(Self::Eqir),
+            "eqri" => Ok(Self::Eqri),
+            "eqrr" => Ok(Self::Eqrr),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A single device instruction.
+#[derive(Debug)]
+pub struct Instruction(Opcode, usize, usize, usize);
+
+impl std::str::FromStr for Instruction {
+    type Err = ();
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut parts = line.split_ascii_whitespace();
+        Ok(Self(
+            parts.next().unwrap().parse().unwrap(),
+            parts.next().unwrap().parse().unwrap(),
+            parts.next().unwrap().parse().unwrap(),
+            parts.next().unwrap().parse().unwrap(),
+        ))
+    }
+}
+
+/// The device registers and the register bound to the instruction pointer.
+#[derive(Debug, Copy, Clone)]
+pub struct Cpu {
+    registers: [usize; 6],
+    ip_register: usize,
+    ip: usize,
+}
+
+impl Cpu {
+    fn new(ip_register: usize) -> Self {
+        Self {
+            ip_register,
+            ip: 0,
+            registers: [0; 6],
+        }
+    }
+
+    fn evaluate(&mut self, instruction: &Instruction) {
+        macro_rules! register_a {
+            () => {
+                self.registers[instruction.1]
+            };
+        }
+
+        macro_rules! register_b {
+            () => {
+                self.registers[instruction.2]
+            };
+        }
+        macro_rules! value_a {
+            () => {
+                instruction.1
+            };
+        }
+
+        macro_rules! value_b {
+            () => {
+                instruction.2
+            };
+        }
+
+        self.registers[self.ip_register] = self.ip;
+
+        let result = match instruction.0 {
+            Opcode::Addr => register_a!() + register_b!(),
+            Opcode::Addi => register_a!() + value_b!(),
+            Opcode::Mulr => register_a!() * register_b!(),
+            Opcode::Muli => register_a!() * value_b!(),
+            Opcode::Banr => register_a!() & register_b!(),
+            Opcode::Bani => register_a!() & value_b!(),
+            Opcode::Borr => register_a!() | register_b!(),
+            Opcode::Bori => register_a!() | value_b!(),
+            Opcode::Setr => register_a!(),
+            Opcode::Seti => value_a!(),
+            Opcode::Gtir => (value_a!() > register_b!()) as usize,
+            Opcode::Gtri => (register_a!() > value_b!()) as usize,
+            Opcode::Gtrr => (register_a!() > register_b!()) as usize,
+            Opcode::Eqir => (value_a!() == register_b!()) as usize,
+            Opcode::Eqri => (register_a!() == value_b!()) as usize,
+            Opcode::Eqrr => (register_a!() == register_b!()) as usize,
+        };
+
+        self.registers[instruction.3] = result;
+        self.ip = self.registers[self.ip_register];
+        self.ip += 1;
+    }
+}
+
+fn parse(input: &str) -> (Cpu, Vec<Instruction>) {
+    let ip_register = input
+        .lines()
+        .next()
+        .unwrap()
+        .split_ascii_whitespace()
+        .nth_back(0)
+        .unwrap()
+        .parse()
+        .unwrap();
+
+    let instructions: Vec<_> = input
+        .lines()
+        .skip(1)
+        .flat_map(str::parse::<Instruction>)
+        .collect();
+
+    let cpu = Cpu::new(ip_register);
+
+    (cpu, instructions)
+}
+
+fn solve_one((cpu, instructions): &(Cpu, Vec<Instruction>)) -> usize {
+    let mut cpu = *cpu;
+
+    while let Some(instruction) = instructions.get(cpu.ip) {
+        cpu.evaluate(instruction);
+
+        if cpu.ip == 24 && cpu.registers[5] == 10 {
+            cpu.registers[5] = (cpu.registers[2] / 256) - 1;
+        }
+
+        if cpu.ip == 28 {
+            return cpu.registers[1];
+        }
+    }
+
+    unreachable!()
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two((cpu, instructions): &(Cpu, Vec<Instruction>)) -> usize {
+    let mut cpu = *cpu;
+
+    let mut previous = usize::MAX;
+    let mut seen = HashSet::new();
+
+    while let Some(instruction) = instructions.get(cpu.ip) {
+        cpu.evaluate(instruction);
+
+        if cpu.ip == 24 && cpu.registers[5] == 1 {
+            cpu.registers[5] = (cpu.registers[2] / 256) - 1;
+        }
+
+        if cpu.ip == 28 {
+            if !seen.insert(cpu.registers[1]) {
+                return previous;
+            }
+            previous = cpu.registers[1];
+        }
+    }
+
+    unreachable!()
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 21;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = (Cpu, Vec<Instruction>);
+
+    fn parse(input: &str) -> Result<(Cpu, Vec<Instruction>), aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &(Cpu, Vec<Instruction>)) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &(Cpu, Vec<Instruction>)) -> usize {
+        solve_two(input)
+    }
+}
@@ -0,0 +1,16 @@
+#[test]
+fn test_json_output() {
+    let runs = aoc_core::examples::stdout(
+        env!("CARGO_BIN_EXE_aoc2018-day23"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/examples"),
+        &["--format", "json"],
+    );
+
+    for line in runs.iter().flat_map(|stdout| stdout.lines()) {
+        assert!(
+            serde_json::from_str::<serde_json::Value>(line).is_ok(),
+            "not json: {}",
+            line
+        );
+    }
+}
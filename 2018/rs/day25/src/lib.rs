@@ -0,0 +1,97 @@
+/// A point in four dimensional spacetime.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Point(i32, i32, i32, i32);
+
+impl Point {
+    fn distance(&self, other: &Self) -> i32 {
+        (self.0 - other.0).abs()
+            + (self.1 - other.1).abs()
+            + (self.2 - other.2).abs()
+            + (self.3 - other.3).abs()
+    }
+
+    fn from_str(line: &str) -> Self {
+        let mut parts = line.trim().split(',');
+        Self(
+            parts.next().unwrap().parse().unwrap(),
+            parts.next().unwrap().parse().unwrap(),
+            parts.next().unwrap().parse().unwrap(),
+            parts.next().unwrap().parse().unwrap(),
+        )
+    }
+}
+
+fn parse(input: &str) -> Vec<Point> {
+    input.lines().map(Point::from_str).collect()
+}
+
+fn solve_one(points: &[Point]) -> usize {
+    let mut points = points.to_vec();
+
+    let mut count = 0;
+
+    let mut constellation = vec![];
+    while !points.is_empty() {
+        let mut j = 0;
+        constellation.clear();
+        constellation.push(points.pop().unwrap());
+
+        while j < constellation.len() {
+            let mut i = 0;
+            while i < points.len() {
+                if points[i].distance(&constellation[j]) <= 3 {
+                    let point = points.swap_remove(i);
+                    constellation.push(point);
+                } else {
+                    i += 1;
+                }
+            }
+            j += 1;
+        }
+
+        count += 1;
+    }
+
+    count
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 25;
+    const PARTS: u8 = 1;
+
+    type PartOne = usize;
+    type PartTwo = aoc_core::NoAnswer;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(_input: &str) -> aoc_core::NoAnswer {
+        aoc_core::NoAnswer
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Point>;
+
+    fn parse(input: &str) -> Result<Vec<Point>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Point>) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(_input: &Vec<Point>) -> aoc_core::NoAnswer {
+        aoc_core::NoAnswer
+    }
+}
+
+aoc_core::examples!(Solver);
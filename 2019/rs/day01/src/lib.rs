@@ -0,0 +1,65 @@
+fn parse(input: &str) -> Vec<i32> {
+    input.split('\n').flat_map(|s| s.parse::<i32>()).collect()
+}
+
+fn solve_one(masses: &[i32]) -> i32 {
+    masses.iter().map(|x| x / 3 - 2).sum()
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn calculate_cost(value: i32, total: i32) -> i32 {
+    let result = value / 3 - 2;
+
+    if result <= 0 {
+        total
+    } else {
+        calculate_cost(result, total + result)
+    }
+}
+
+fn solve_two(masses: &[i32]) -> i32 {
+    masses.iter().map(|x| calculate_cost(*x, 0)).sum()
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 1;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Vec<i32>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<i32>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<i32>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
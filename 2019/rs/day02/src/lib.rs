@@ -0,0 +1,120 @@
+fn run(opcodes: &mut [i32]) {
+    let mut pc = 0;
+
+    macro_rules! read_int {
+        () => {{
+            let code = opcodes[pc];
+            pc += 1;
+            code
+        }};
+    }
+
+    macro_rules! read_arg {
+        () => {
+            opcodes[read_int!() as usize]
+        };
+    }
+
+    loop {
+        match read_int!() {
+            1 => {
+                let a = read_arg!();
+                let b = read_arg!();
+                let dest = read_int!() as usize;
+
+                opcodes[dest] = a + b;
+            }
+            2 => {
+                let a = read_arg!();
+                let b = read_arg!();
+                let dest = read_int!() as usize;
+
+                opcodes[dest] = a * b;
+            }
+            99 => break,
+            instruction => unreachable!("received unexpected opcode {instruction} at {}", pc - 1),
+        }
+    }
+}
+
+fn parse(input: &str) -> Vec<i32> {
+    input
+        .trim_end()
+        .split(',')
+        .flat_map(|x| x.parse())
+        .collect()
+}
+
+fn solve_one(intcodes: &[i32]) -> i32 {
+    let mut input = intcodes.to_vec();
+    input[1] = 12;
+    input[2] = 2;
+
+    run(&mut input);
+
+    input[0]
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(input: &[i32]) -> i32 {
+    let mut buffer = input.to_vec();
+
+    for a in 0..=99 {
+        for b in 0..=99 {
+            for (index, dest) in buffer.iter_mut().enumerate() {
+                *dest = input[index];
+            }
+
+            buffer[1] = a;
+            buffer[2] = b;
+            run(&mut buffer);
+
+            if buffer[0] == 19690720 {
+                return 100 * a + b;
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 2;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Vec<i32>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<i32>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<i32>) -> i32 {
+        solve_two(input)
+    }
+}
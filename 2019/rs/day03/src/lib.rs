@@ -0,0 +1,132 @@
+use std::collections::{HashMap, HashSet};
+
+struct WireIterator<'a> {
+    steps: i32,
+    parts: std::str::Split<'a, char>,
+    x: i32,
+    y: i32,
+    direction: &'a str,
+    remaining_steps: i32,
+}
+
+impl<'a> WireIterator<'a> {
+    fn from(string: &'a str) -> Self {
+        Self {
+            parts: string.split(','),
+            steps: 0,
+            direction: "",
+            x: 0,
+            y: 0,
+            remaining_steps: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for WireIterator<'a> {
+    type Item = ((i32, i32), i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining_steps > 0 {
+            // do nothing
+        } else {
+            let part = self.parts.next()?;
+            self.direction = &part[0..1];
+            self.remaining_steps = part[1..].parse().unwrap();
+        }
+
+        self.steps += 1;
+
+        match self.direction {
+            "R" => self.x += 1,
+            "U" => self.y += 1,
+            "L" => self.x -= 1,
+            "D" => self.y -= 1,
+            _ => unreachable!(),
+        };
+
+        self.remaining_steps -= 1;
+        Some(((self.x, self.y), self.steps))
+    }
+}
+
+/// Every location a wire visits, along with the steps taken to reach it.
+pub type Wire = Vec<((i32, i32), i32)>;
+
+fn parse(input: &str) -> (Wire, Wire) {
+    let mut lines = input.lines();
+    let wire_a = WireIterator::from(lines.next().unwrap()).collect();
+    let wire_b = WireIterator::from(lines.next().unwrap()).collect();
+    (wire_a, wire_b)
+}
+
+fn solve_one((wire_a, wire_b): &(Wire, Wire)) -> i32 {
+    let wire_a = wire_a
+        .iter()
+        .map(|&(loc, _)| loc)
+        .collect::<HashSet<(i32, i32)>>();
+    let wire_b = wire_b.iter().map(|&(loc, _)| loc).collect();
+
+    wire_a
+        .intersection(&wire_b)
+        .map(|(x, y)| x.abs() + y.abs())
+        .min()
+        .unwrap()
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two((wire_a, wire_b): &(Wire, Wire)) -> i32 {
+    let mut fewest_steps = i32::MAX;
+
+    let map_a: HashMap<(i32, i32), i32> = wire_a.iter().copied().collect();
+
+    for &(loc, steps) in wire_b {
+        if let Some(steps_a) = map_a.get(&loc) {
+            fewest_steps = fewest_steps.min(steps + steps_a);
+        }
+    }
+
+    fewest_steps
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 3;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = (Wire, Wire);
+
+    fn parse(input: &str) -> Result<(Wire, Wire), aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &(Wire, Wire)) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &(Wire, Wire)) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
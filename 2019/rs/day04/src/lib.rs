@@ -0,0 +1,199 @@
+/// Walks the non-decreasing candidate passwords within a range.
+#[derive(Debug, Clone)]
+pub struct PasswordIterator<const N: usize> {
+    end: [i32; N],
+    current: [i32; N],
+    done: bool,
+}
+
+impl<const N: usize> PasswordIterator<N> {
+    fn from(string: &str) -> Self {
+        let mut split = string.trim().split('-');
+
+        fn num_to_array<const N: usize>(number: &str) -> [i32; N] {
+            let mut output = [0i32; N];
+
+            for (index, ch) in number.chars().take(N - 1).enumerate() {
+                output[index] = match ch {
+                    '0' => 0,
+                    '1' => 1,
+                    '2' => 2,
+                    '3' => 3,
+                    '4' => 4,
+                    '5' => 5,
+                    '6' => 6,
+                    '7' => 7,
+                    '8' => 8,
+                    '9' => 9,
+                    _ => unreachable!(),
+                }
+            }
+
+            output
+        }
+
+        let mut current = num_to_array(split.next().unwrap());
+        let mut end = num_to_array(split.next().unwrap());
+
+        fn normalize_password<const N: usize>(password: &mut [i32; N]) {
+            let mut initial = None;
+
+            for index in 0..N {
+                if let Some(value) = initial {
+                    password[index] = value;
+                } else if let Some(&next) = password.get(index + 1) {
+                    if next < password[index] {
+                        initial = Some(password[index])
+                    }
+                }
+            }
+        }
+
+        normalize_password(&mut current);
+        current[N - 1] -= 1;
+        normalize_password(&mut end);
+
+        Self {
+            end,
+            current,
+            done: false,
+        }
+    }
+
+    fn increment(&mut self, index: usize) -> i32 {
+        let value = &mut self.current[index];
+        *value += 1;
+        if *value < 10 {
+            return *value;
+        }
+
+        if index == 0 {
+            unreachable!()
+        }
+
+        let new_value = self.increment(index - 1);
+        self.current[index] = new_value;
+        new_value
+    }
+}
+
+impl<const N: usize> Iterator for PasswordIterator<N> {
+    type Item = [i32; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        self.increment(N - 1);
+
+        if self.current == self.end {
+            self.done = true;
+            return None;
+        }
+
+        Some(self.current)
+    }
+}
+
+fn is_valid([one, two, three, four, five, six]: [i32; 6]) -> bool {
+    if one > two || two > three || three > four || four > five || five > six {
+        return false;
+    }
+
+    one == two || two == three || three == four || four == five || five == six
+}
+
+fn is_strictly_valid(digits: [i32; 6]) -> bool {
+    let mut it = digits.windows(2);
+
+    let mut counts = [0i32; 10];
+
+    counts[digits[0] as usize] += 1;
+
+    while let Some([left, right]) = it.next() {
+        if left > right {
+            return false;
+        }
+
+        counts[*right as usize] += 1;
+    }
+
+    counts.contains(&2)
+}
+
+fn parse(input: &str) -> PasswordIterator<6> {
+    PasswordIterator::from(input)
+}
+
+fn solve_one(passwords: &PasswordIterator<6>) -> i32 {
+    passwords.clone().filter(|digits| is_valid(*digits)).count() as i32
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(passwords: &PasswordIterator<6>) -> i32 {
+    passwords
+        .clone()
+        .filter(|digits| is_strictly_valid(*digits))
+        .count() as i32
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 4;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = PasswordIterator<6>;
+
+    fn parse(input: &str) -> Result<PasswordIterator<6>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &PasswordIterator<6>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &PasswordIterator<6>) -> i32 {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid() {
+        assert!(is_valid([1, 1, 1, 1, 1, 1]));
+        assert!(!is_valid([2, 2, 3, 4, 5, 0]));
+        assert!(!is_valid([1, 2, 3, 7, 8, 9]));
+    }
+
+    #[test]
+    fn test_is_strictly_valid() {
+        assert!(is_strictly_valid([1, 1, 2, 2, 3, 3]));
+        assert!(!is_strictly_valid([1, 2, 3, 4, 4, 4]));
+        assert!(is_strictly_valid([1, 1, 1, 1, 2, 2]));
+    }
+}
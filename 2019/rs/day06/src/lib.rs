@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+/// An object and the satellite orbiting it.
+pub type Orbit = (String, String);
+
+fn parse(input: &str) -> Vec<Orbit> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let mut split = line.split(')');
+            let object = split.next().unwrap();
+            let satellite = split.next().unwrap();
+            (object.to_string(), satellite.to_string())
+        })
+        .collect()
+}
+
+fn solve_one(orbits: &[Orbit]) -> i32 {
+    let mut graph = HashMap::<&str, Vec<&str>>::with_capacity(1500);
+
+    for (object, satellite) in orbits {
+        let entry = graph.entry(object).or_insert_with(|| Vec::with_capacity(2));
+        entry.push(satellite);
+    }
+
+    let mut stack = Vec::with_capacity(500);
+    stack.push(("COM", 0));
+
+    let mut count = 0;
+
+    while let Some((current, depth)) = stack.pop() {
+        count += depth;
+        if let Some(children) = graph.get(current) {
+            for child in children {
+                stack.push((child, depth + 1));
+            }
+        }
+    }
+
+    count
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(orbits: &[Orbit]) -> i32 {
+    let mut orbit_map = HashMap::<&str, &str>::with_capacity(2000);
+
+    for (object, satellite) in orbits {
+        orbit_map.insert(satellite, object);
+    }
+
+    let mut you_path = HashMap::<&str, i32>::new();
+
+    let mut current = orbit_map["YOU"];
+    let mut transfers = 0;
+
+    while current != "COM" {
+        you_path.insert(current, transfers);
+
+        current = orbit_map[current];
+        transfers += 1;
+    }
+
+    let mut current = orbit_map["SAN"];
+    let mut transfers = 0;
+
+    while !you_path.contains_key(current) {
+        transfers += 1;
+        current = orbit_map[current];
+    }
+
+    you_path[current] + transfers
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 6;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Orbit>;
+
+    fn parse(input: &str) -> Result<Vec<Orbit>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Orbit>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Orbit>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
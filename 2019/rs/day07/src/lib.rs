@@ -0,0 +1,121 @@
+use aoc_core::Error;
+use intcode::VM;
+use std::{sync::mpsc, thread};
+use utils::permute;
+
+fn solve_one(intcodes: &[i64]) -> Result<i64, Error> {
+    let mut vm = VM::from(intcodes.to_vec());
+    vm.compile();
+    let mut max_signal = 0;
+
+    for phase_settings in permute([0, 1, 2, 3, 4]) {
+        let mut output_signal = 0;
+
+        for setting in phase_settings {
+            vm.reset();
+            vm.write_input(setting);
+            vm.write_input(output_signal);
+            vm.run()?;
+            output_signal = vm
+                .read_output()
+                .ok_or_else(|| Error::new("no output signal"))?;
+        }
+
+        max_signal = max_signal.max(output_signal);
+    }
+
+    Ok(max_signal)
+}
+
+pub fn part_one(input: &str) -> Result<i64, Error> {
+    solve_one(&VM::parse_intcodes(input)?)
+}
+
+// runs an amp for each phase setting on its own thread, each feeding the
+// next and the last feeding back into the first, until they halt
+fn feedback(vm: &VM, phase_settings: &[i64]) -> Result<i64, Error> {
+    let (mut senders, receivers): (Vec<_>, Vec<_>) = phase_settings
+        .iter()
+        .map(|&setting| {
+            let (sender, receiver) = mpsc::channel();
+            sender.send(setting).unwrap();
+            (sender, receiver)
+        })
+        .unzip();
+    senders[0].send(0).unwrap();
+    // each amp gets the sender for the next one, so an amp whose input is
+    // gone stops waiting
+    senders.rotate_left(1);
+
+    let inputs = thread::scope(|scope| {
+        let amps: Vec<_> = receivers
+            .into_iter()
+            .zip(senders)
+            .map(|(input, mut output)| {
+                let mut amp = vm.clone();
+                scope.spawn(move || amp.run_io(&input, &mut output).map(|_| input))
+            })
+            .collect();
+        amps.into_iter()
+            .map(|amp| amp.join().unwrap())
+            .collect::<Result<Vec<_>, _>>()
+    })?;
+
+    // what the last amp sent after the first one halted
+    inputs[0]
+        .try_iter()
+        .last()
+        .ok_or_else(|| Error::new("no output signal"))
+}
+
+fn solve_two(intcodes: &[i64]) -> Result<i64, Error> {
+    let mut vm = VM::from(intcodes.to_vec());
+    vm.compile();
+    let mut max_signal = 0;
+
+    for phase_settings in permute([5, 6, 7, 8, 9]) {
+        max_signal = max_signal.max(feedback(&vm, &phase_settings)?);
+    }
+
+    Ok(max_signal)
+}
+
+pub fn part_two(input: &str) -> Result<i64, Error> {
+    solve_two(&VM::parse_intcodes(input)?)
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 7;
+
+    type PartOne = Result<i64, Error>;
+    type PartTwo = Result<i64, Error>;
+
+    fn part_one(input: &str) -> Result<i64, Error> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Result<i64, Error> {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, aoc_core::Error> {
+        VM::parse_intcodes(input)
+    }
+
+    fn solve_one(input: &Vec<i64>) -> Result<i64, Error> {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<i64>) -> Result<i64, Error> {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,151 @@
+const WIDTH: usize = 25;
+const HEIGHT: usize = 6;
+
+fn parse(input: &str) -> Vec<char> {
+    input.trim().chars().collect()
+}
+
+fn checksum<const WIDTH: usize, const HEIGHT: usize>(pixels: &[char]) -> i32 {
+    let mut layer_start = 0;
+    let mut output = 0;
+    let mut fewest_zeros = usize::MAX;
+
+    while layer_start < pixels.len() {
+        let lines = (0..HEIGHT).map(|line_number| {
+            let line_start = layer_start + line_number * WIDTH;
+            let line_end = line_start + WIDTH;
+            &pixels[line_start..line_end]
+        });
+
+        let mut zeros = 0;
+        let mut ones = 0;
+        let mut twos = 0;
+
+        for line in lines {
+            for &ch in line {
+                match ch {
+                    '0' => zeros += 1,
+                    '1' => ones += 1,
+                    '2' => twos += 1,
+                    _ => {}
+                }
+            }
+        }
+
+        if zeros < fewest_zeros {
+            output = ones * twos;
+            fewest_zeros = zeros;
+        }
+
+        layer_start += WIDTH * HEIGHT;
+    }
+
+    output
+}
+
+fn decode<const WIDTH: usize, const HEIGHT: usize>(pixels: &[char]) -> String {
+    let mut layer_start = 0;
+
+    let mut image = [['2'; WIDTH]; HEIGHT];
+
+    while layer_start < pixels.len() {
+        let lines = (0..HEIGHT).map(|line_number| {
+            let line_start = layer_start + line_number * WIDTH;
+            let line_end = line_start + WIDTH;
+            &pixels[line_start..line_end]
+        });
+
+        for (y, line) in lines.enumerate() {
+            for (x, &ch) in line.iter().enumerate() {
+                let current = &mut image[y][x];
+
+                if *current == '2' {
+                    *current = ch;
+                }
+            }
+        }
+
+        layer_start += WIDTH * HEIGHT;
+    }
+
+    let mut output = String::new();
+
+    for line in image {
+        for ch in line {
+            match ch {
+                '1' => output.push('#'),
+                '0' => output.push(' '),
+                _ => unreachable!(),
+            }
+        }
+
+        output.push('\n');
+    }
+
+    output
+}
+
+fn solve_one(pixels: &[char]) -> i32 {
+    checksum::<WIDTH, HEIGHT>(pixels)
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(pixels: &[char]) -> String {
+    decode::<WIDTH, HEIGHT>(pixels)
+}
+
+pub fn part_two(input: &str) -> String {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 8;
+
+    type PartOne = i32;
+    type PartTwo = String;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> String {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<char>;
+
+    fn parse(input: &str) -> Result<Vec<char>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<char>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<char>) -> String {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(checksum::<3, 2>(&parse("123456789012")), 1);
+    }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(decode::<2, 2>(&parse("0222112222120000")), " #\n# \n");
+    }
+}
@@ -0,0 +1,64 @@
+use aoc_core::Error;
+use intcode::VM;
+
+fn run_boost(intcodes: &[i64], mode: i64) -> Result<i64, Error> {
+    let mut vm = VM::from(intcodes.to_vec());
+
+    vm.write_input(mode);
+    vm.run()?;
+
+    vm.read_output()
+        .ok_or_else(|| Error::new("no BOOST keycode"))
+}
+
+fn solve_one(intcodes: &[i64]) -> Result<i64, Error> {
+    run_boost(intcodes, 1)
+}
+
+fn solve_two(intcodes: &[i64]) -> Result<i64, Error> {
+    run_boost(intcodes, 2)
+}
+
+pub fn part_one(input: &str) -> Result<i64, Error> {
+    solve_one(&VM::parse_intcodes(input)?)
+}
+
+pub fn part_two(input: &str) -> Result<i64, Error> {
+    solve_two(&VM::parse_intcodes(input)?)
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 9;
+
+    type PartOne = Result<i64, Error>;
+    type PartTwo = Result<i64, Error>;
+
+    fn part_one(input: &str) -> Result<i64, Error> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Result<i64, Error> {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, Error> {
+        VM::parse_intcodes(input)
+    }
+
+    fn solve_one(input: &Vec<i64>) -> Result<i64, Error> {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<i64>) -> Result<i64, Error> {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,189 @@
+use std::{
+    cmp,
+    collections::{HashMap, HashSet},
+};
+use utils::{fractions::Fraction, grid::Coordinate};
+
+fn parse(input: &str) -> Vec<Coordinate> {
+    let mut asteroids = vec![];
+
+    for (y, line) in input.trim().lines().enumerate() {
+        for (x, ch) in line.char_indices() {
+            if ch == '#' {
+                asteroids.push(Coordinate::new(x as i32, y as i32));
+            }
+        }
+    }
+
+    asteroids
+}
+
+fn find_station(asteroids: &[Coordinate]) -> (Coordinate, usize) {
+    let mut max = 0;
+    let mut slopes = HashSet::new();
+    let mut winner = Coordinate::new(0, 0);
+
+    for coord in asteroids {
+        slopes.clear();
+
+        for other in asteroids {
+            if other != coord {
+                slopes.insert((other - coord).slope());
+            }
+        }
+
+        let count = slopes.len();
+
+        if count > max {
+            max = count;
+            winner = *coord;
+        }
+    }
+
+    (winner, max)
+}
+
+fn solve_one(asteroids: &[Coordinate]) -> usize {
+    find_station(asteroids).1
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(asteroids: &[Coordinate]) -> i32 {
+    let station = find_station(asteroids).0;
+
+    let mut quadrant_maps = [
+        HashMap::new(),
+        HashMap::new(),
+        HashMap::new(),
+        HashMap::new(),
+    ];
+    let mut slopes = HashSet::new();
+
+    for &asteroid in asteroids {
+        if asteroid == station {
+            continue;
+        }
+
+        let mut coord = asteroid - station;
+        let quadrant: usize;
+
+        // (1,1)  |  (3,1)
+        // -----(2,2)-----
+        // (1,3)  |  (3,3)
+        //
+        // (-1,-1)  |  (1,-1)
+        // -------(0,0)------
+        // (-1,1)   |  (1,1)
+
+        match (coord.x.cmp(&0), coord.y.cmp(&0)) {
+            (cmp::Ordering::Equal | cmp::Ordering::Greater, cmp::Ordering::Less) => {
+                quadrant = 0;
+            }
+            (cmp::Ordering::Greater, cmp::Ordering::Equal | cmp::Ordering::Greater) => {
+                quadrant = 1;
+                coord = coord.rotate_left();
+            }
+            (cmp::Ordering::Less | cmp::Ordering::Equal, cmp::Ordering::Greater) => {
+                quadrant = 2;
+                coord = coord.rotate_left().rotate_left();
+            }
+            (cmp::Ordering::Less, cmp::Ordering::Less | cmp::Ordering::Equal) => {
+                quadrant = 3;
+                coord = coord.rotate_right();
+            }
+            (cmp::Ordering::Equal, cmp::Ordering::Equal) => unreachable!(),
+        }
+
+        slopes.insert(coord.slope());
+
+        quadrant_maps[quadrant]
+            .entry(coord.slope())
+            .or_insert_with(Vec::new)
+            .push(coord)
+    }
+
+    let mut slopes = slopes.into_iter().collect::<Vec<_>>();
+    slopes.sort_by(|a, b| {
+        if matches!(a, Fraction::PositiveUndefined) {
+            cmp::Ordering::Less
+        } else if matches!(b, Fraction::PositiveUndefined) {
+            cmp::Ordering::Greater
+        } else {
+            a.cmp(b)
+        }
+    });
+
+    for map in &mut quadrant_maps {
+        for (_, vec) in map.iter_mut() {
+            vec.sort_by(|a, b| {
+                b.manhattan_distance_to_origin()
+                    .cmp(&a.manhattan_distance_to_origin())
+            })
+        }
+    }
+
+    let mut destroyed = 0;
+
+    loop {
+        for (quadrant, map) in quadrant_maps.iter_mut().enumerate() {
+            for slope in &slopes {
+                if let Some(vec) = map.get_mut(slope) {
+                    if let Some(el) = vec.pop() {
+                        destroyed += 1;
+                        if destroyed == 200 {
+                            let mut result = el;
+                            for _ in 0..quadrant {
+                                result = result.rotate_right();
+                            }
+                            result += station;
+                            return result.x * 100 + result.y;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 10;
+
+    type PartOne = usize;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Coordinate>;
+
+    fn parse(input: &str) -> Result<Vec<Coordinate>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Coordinate>) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Coordinate>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
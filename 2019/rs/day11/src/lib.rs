@@ -0,0 +1,160 @@
+use aoc_core::Error;
+use intcode::{Status, VM};
+use std::collections::HashMap;
+use utils::grid::{Coordinate, Direction};
+
+#[derive(Clone, Copy)]
+enum Color {
+    Black,
+    White,
+}
+
+impl From<i64> for Color {
+    fn from(value: i64) -> Self {
+        match value {
+            0 => Self::Black,
+            1 => Self::White,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl From<Color> for i64 {
+    fn from(val: Color) -> Self {
+        match val {
+            Color::Black => 0,
+            Color::White => 1,
+        }
+    }
+}
+
+impl std::fmt::Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::White => '#',
+                Self::Black => ' ',
+            }
+        )
+    }
+}
+
+fn get_panels(
+    intcodes: &[i64],
+    starting_color: Color,
+) -> Result<HashMap<Coordinate, Color>, Error> {
+    let mut vm = VM::from(intcodes.to_vec());
+
+    let mut location = Coordinate::new(0, 0);
+    let mut direction = Direction::Up;
+    let mut panels = HashMap::new();
+    panels.insert(location, starting_color);
+
+    loop {
+        let panel = panels.entry(location).or_insert(Color::Black);
+
+        vm.write_input((*panel).into());
+
+        let status = vm.run()?;
+
+        match (vm.read_output(), vm.read_output()) {
+            (Some(color), Some(turn)) => {
+                *panel = Color::from(color);
+                match turn {
+                    0 => direction = direction.left(),
+                    1 => direction = direction.right(),
+                    _ => unreachable!(),
+                }
+                location = location.translate(&direction);
+            }
+            _ => unreachable!(),
+        }
+
+        if status == Status::Halted {
+            break;
+        }
+    }
+
+    Ok(panels)
+}
+
+fn solve_one(intcodes: &[i64]) -> Result<usize, Error> {
+    Ok(get_panels(intcodes, Color::Black)?.len())
+}
+
+pub fn part_one(input: &str) -> Result<usize, Error> {
+    solve_one(&VM::parse_intcodes(input)?)
+}
+
+fn solve_two(intcodes: &[i64]) -> Result<String, Error> {
+    let panels = get_panels(intcodes, Color::White)?;
+
+    let mut max_x = 0;
+    let mut max_y = 0;
+    let mut min_x = 0;
+    let mut min_y = 0;
+
+    for key in panels.keys() {
+        max_x = max_x.max(key.x);
+        max_y = max_y.max(key.y);
+        min_x = min_x.min(key.x);
+        min_y = min_y.min(key.y);
+    }
+
+    let mut output = String::new();
+
+    for y in (min_y..=max_y).rev() {
+        for x in min_x..=max_x {
+            output.push_str(
+                &panels
+                    .get(&Coordinate::new(x, y))
+                    .unwrap_or(&Color::White)
+                    .to_string(),
+            )
+        }
+
+        output.push('\n');
+    }
+
+    Ok(output)
+}
+
+pub fn part_two(input: &str) -> Result<String, Error> {
+    solve_two(&VM::parse_intcodes(input)?)
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 11;
+
+    type PartOne = Result<usize, Error>;
+    type PartTwo = Result<String, Error>;
+
+    fn part_one(input: &str) -> Result<usize, Error> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Result<String, Error> {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, aoc_core::Error> {
+        VM::parse_intcodes(input)
+    }
+
+    fn solve_one(input: &Vec<i64>) -> Result<usize, Error> {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<i64>) -> Result<String, Error> {
+        solve_two(input)
+    }
+}
@@ -0,0 +1,265 @@
+use std::cmp;
+use utils::{char_to_i32, tuples::Tuple};
+
+fn get_change(a: &i32, b: &i32) -> i32 {
+    match a.cmp(b) {
+        cmp::Ordering::Greater => -1,
+        cmp::Ordering::Equal => 0,
+        cmp::Ordering::Less => 1,
+    }
+}
+
+/// A moon's position and velocity.
+#[derive(Debug, Clone, Copy)]
+pub struct Moon {
+    position: Tuple,
+    velocity: Tuple,
+}
+
+impl Moon {
+    fn apply_gravity(&mut self, other: &Self) {
+        self.velocity.x += get_change(&self.position.x, &other.position.x);
+        self.velocity.y += get_change(&self.position.y, &other.position.y);
+        self.velocity.z += get_change(&self.position.z, &other.position.z);
+    }
+
+    fn step(&mut self) {
+        self.position += self.velocity;
+    }
+
+    fn energy(&self) -> i32 {
+        (self.position.x.abs() + self.position.y.abs() + self.position.z.abs())
+            * (self.velocity.x.abs() + self.velocity.y.abs() + self.velocity.z.abs())
+    }
+}
+
+fn parse(input: &str) -> [Moon; 4] {
+    let mut moons = [Moon {
+        position: Tuple::point(0, 0, 0),
+        velocity: Tuple::vector(0, 0, 0),
+    }; 4];
+
+    for (index, line) in input.trim().lines().enumerate() {
+        let mut chars = line.chars();
+        chars.next();
+        chars.next();
+        chars.next();
+
+        let mut nums = [0; 3];
+
+        for num in &mut nums {
+            let mut modifier = 1;
+            match chars.next() {
+                Some('-') => modifier = -1,
+                Some(value) => *num += char_to_i32(value),
+                _ => unreachable!(),
+            }
+
+            while let Some(ch) = chars.next() {
+                if ch == ',' {
+                    chars.next(); // ' '
+                    chars.next(); // 'y/z'
+                    chars.next(); // '='
+                    break;
+                } else if ch == '>' {
+                    break;
+                }
+
+                *num *= 10;
+                *num += char_to_i32(ch);
+            }
+
+            *num *= modifier;
+        }
+        let [x, y, z] = nums;
+
+        moons[index].position = Tuple::point(x, y, z);
+    }
+
+    moons
+}
+
+fn solve_one(moons: &[Moon; 4]) -> i32 {
+    let mut moons = *moons;
+
+    for _ in 0..1000 {
+        for i in 0..moons.len() {
+            let (left, right) = moons.split_at_mut(i + 1);
+            let moon = left.last_mut().unwrap();
+            for other in right {
+                moon.apply_gravity(other);
+                other.apply_gravity(moon);
+            }
+        }
+
+        for moon in &mut moons {
+            moon.step();
+        }
+    }
+
+    moons.into_iter().map(|m| m.energy()).sum()
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+struct CycleDetector(Vec<i32>);
+
+impl CycleDetector {
+    fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    fn add(&mut self, value: i32) {
+        self.0.push(value)
+    }
+
+    fn find_cycle(&mut self) -> Option<usize> {
+        let needle = self.0.first()?;
+        let values = &self.0;
+        let mut search_index = 0;
+
+        loop {
+            search_index += 1;
+            search_index += values.iter().skip(search_index).position(|x| x == needle)?;
+
+            let end = search_index * 2;
+
+            if end > values.len() {
+                return None;
+            }
+
+            if values[0..search_index] == values[search_index..end] {
+                return Some(search_index);
+            }
+        }
+    }
+}
+
+struct MoonCycleDetector {
+    x: CycleDetector,
+    y: CycleDetector,
+    z: CycleDetector,
+}
+
+impl MoonCycleDetector {
+    fn new() -> Self {
+        Self {
+            x: CycleDetector::new(),
+            y: CycleDetector::new(),
+            z: CycleDetector::new(),
+        }
+    }
+
+    fn add(&mut self, Tuple { x, y, z, .. }: &Tuple) {
+        self.x.add(*x);
+        self.y.add(*y);
+        self.z.add(*z);
+    }
+
+    fn find_cycle(&mut self) -> Option<usize> {
+        let x = self.x.find_cycle()?;
+        let y = self.y.find_cycle()?;
+        let z = self.z.find_cycle()?;
+
+        Some(lcm(lcm(x, y), z))
+    }
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    a / gcd(a, b) * b
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn find_cycle(detectors: &mut [MoonCycleDetector]) -> Option<usize> {
+    let mut result = 1;
+
+    for detector in detectors {
+        let cycle = detector.find_cycle()?;
+        result = lcm(result, cycle);
+    }
+
+    Some(result)
+}
+
+fn solve_two(moons: &[Moon; 4]) -> usize {
+    let mut moons = *moons;
+
+    let mut detectors = [
+        MoonCycleDetector::new(),
+        MoonCycleDetector::new(),
+        MoonCycleDetector::new(),
+        MoonCycleDetector::new(),
+    ];
+
+    loop {
+        for _ in 0..10000 {
+            for i in 0..moons.len() {
+                detectors[i].add(&moons[i].position);
+
+                let (left, right) = moons.split_at_mut(i + 1);
+                let moon = left.last_mut().unwrap();
+                for other in right {
+                    moon.apply_gravity(other);
+                    other.apply_gravity(moon);
+                }
+            }
+
+            for moon in &mut moons {
+                moon.step();
+            }
+        }
+
+        if let Some(cycle) = find_cycle(&mut detectors) {
+            return cycle;
+        }
+    }
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 12;
+
+    type PartOne = i32;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = [Moon; 4];
+
+    fn parse(input: &str) -> Result<[Moon; 4], aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &[Moon; 4]) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &[Moon; 4]) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
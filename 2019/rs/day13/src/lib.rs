@@ -0,0 +1,174 @@
+use aoc_core::Error;
+use intcode::{Status, VM};
+
+#[derive(Debug, Clone, Copy)]
+enum Tile {
+    Empty,
+    Wall,
+    Block,
+    Paddle,
+    Ball,
+}
+
+impl TryFrom<i64> for Tile {
+    type Error = Error;
+
+    fn try_from(value: i64) -> Result<Self, Error> {
+        match value {
+            0 => Ok(Self::Empty),
+            1 => Ok(Self::Wall),
+            2 => Ok(Self::Block),
+            3 => Ok(Self::Paddle),
+            4 => Ok(Self::Ball),
+            _ => Err(Error::new(format!("invalid tile id {}", value))),
+        }
+    }
+}
+
+type Screen = [[Tile; 40]; 25];
+
+fn draw(screen: &mut Screen, x: i64, y: i64, tile: Tile) -> Result<(), Error> {
+    let cell = usize::try_from(y)
+        .ok()
+        .and_then(|y| screen.get_mut(y))
+        .zip(usize::try_from(x).ok())
+        .and_then(|(row, x)| row.get_mut(x))
+        .ok_or_else(|| Error::new(format!("tile at {},{} is off the screen", x, y)))?;
+    *cell = tile;
+    Ok(())
+}
+
+fn solve_one(intcodes: &[i64]) -> Result<i32, Error> {
+    let mut vm = VM::from(intcodes.to_vec());
+
+    vm.run()?;
+
+    let mut buffer = [[Tile::Empty; 40]; 25];
+
+    loop {
+        match (vm.read_output(), vm.read_output(), vm.read_output()) {
+            (Some(x), Some(y), Some(value)) => draw(&mut buffer, x, y, value.try_into()?)?,
+            (None, None, None) => break,
+            _ => return Err(Error::new("the game stopped mid-tile")),
+        }
+    }
+
+    Ok(buffer
+        .into_iter()
+        .flat_map(|line| {
+            line.into_iter().filter_map(|tile| match tile {
+                Tile::Block => Some(1),
+                _ => None,
+            })
+        })
+        .sum())
+}
+
+pub fn part_one(input: &str) -> Result<i32, Error> {
+    solve_one(&VM::parse_intcodes(input)?)
+}
+
+fn solve_two(intcodes: &[i64]) -> Result<i32, Error> {
+    let mut vm = VM::from(intcodes.to_vec());
+    vm.reset_with(&[(0, 2)]);
+    let mut buffer = [[Tile::Empty; 40]; 25];
+
+    let mut status = vm.run()?;
+
+    let mut score = 0;
+    let mut ball_x = 0;
+    let mut paddle_x = 0;
+
+    loop {
+        while let (Some(x), Some(y), Some(value)) =
+            (vm.read_output(), vm.read_output(), vm.read_output())
+        {
+            if x == -1 && y == 0 {
+                score = value as i32;
+            } else {
+                let tile = value.try_into()?;
+                match tile {
+                    Tile::Ball => ball_x = x,
+                    Tile::Paddle => paddle_x = x,
+                    _ => {}
+                }
+                draw(&mut buffer, x, y, tile)?;
+            }
+        }
+
+        if status == Status::Halted {
+            break;
+        }
+
+        vm.write_input(match ball_x.cmp(&paddle_x) {
+            std::cmp::Ordering::Less => -1,
+            std::cmp::Ordering::Equal => 0,
+            std::cmp::Ordering::Greater => 1,
+        });
+
+        status = vm.run()?;
+    }
+
+    Ok(score)
+}
+
+pub fn part_two(input: &str) -> Result<i32, Error> {
+    solve_two(&VM::parse_intcodes(input)?)
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 13;
+
+    type PartOne = Result<i32, Error>;
+    type PartTwo = Result<i32, Error>;
+
+    fn part_one(input: &str) -> Result<i32, Error> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Result<i32, Error> {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, aoc_core::Error> {
+        VM::parse_intcodes(input)
+    }
+
+    fn solve_one(input: &Vec<i64>) -> Result<i32, Error> {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<i64>) -> Result<i32, Error> {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        // draws a single tile, then halts
+        assert_eq!(part_one("104,1,104,2,104,2,99"), Ok(1));
+        assert_eq!(
+            part_one("104,1,104,2,104,7,99"),
+            Err(Error::new("invalid tile id 7"))
+        );
+        assert_eq!(
+            part_one("104,40,104,0,104,1,99"),
+            Err(Error::new("tile at 40,0 is off the screen"))
+        );
+        assert_eq!(
+            part_one("104,1,104,2,99"),
+            Err(Error::new("the game stopped mid-tile"))
+        );
+    }
+}
@@ -0,0 +1,268 @@
+use aoc_core::{error::Tokens, Error};
+use std::collections::HashMap;
+
+const ORE: &str = "ORE";
+const FUEL: &str = "FUEL";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Quantity {
+    amount: u64,
+    chemical: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Reaction {
+    output: Quantity,
+    inputs: Vec<Quantity>,
+}
+
+// an amount and a chemical, and whether a comma says another one follows
+fn parse_quantity(tokens: &mut Tokens<'_>) -> Result<(Quantity, bool), Error> {
+    let amount = tokens.next("amount")?.parse("amount")?;
+    let chemical = tokens.next("chemical")?;
+    let (chemical, more) = match chemical.text.strip_suffix(',') {
+        Some(text) => (text, true),
+        None => (chemical.text, false),
+    };
+    let chemical = chemical.to_string();
+    Ok((Quantity { amount, chemical }, more))
+}
+
+fn parse_reaction(line: &str) -> Result<Reaction, Error> {
+    let mut tokens = Tokens::new(line);
+
+    let mut inputs = vec![];
+    loop {
+        let (input, more) = parse_quantity(&mut tokens)?;
+        inputs.push(input);
+        if !more {
+            break;
+        }
+    }
+
+    let arrow = tokens.next("`=>`")?;
+    if arrow.text != "=>" {
+        return Err(arrow.error(format!("expected `=>`, found `{}`", arrow.text)));
+    }
+    let (output, _) = parse_quantity(&mut tokens)?;
+    tokens.end()?;
+
+    if output.amount == 0 {
+        return Err(Error::new(format!("no {} is made", output.chemical)));
+    }
+    Ok(Reaction { output, inputs })
+}
+
+/// The reactions, each the only way to make its chemical.
+#[derive(Debug, Clone)]
+pub struct Reactions {
+    reactions: HashMap<String, Reaction>,
+    /// Every chemical made from ore, with each one before the ones it is
+    /// made from, so fuel comes first.
+    order: Vec<String>,
+}
+
+impl Reactions {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let mut reactions = HashMap::new();
+        for (i, line) in input.lines().enumerate() {
+            let reaction = parse_reaction(line).map_err(|err| err.at_line(i + 1))?;
+            let chemical = &reaction.output.chemical;
+            if reactions.contains_key(chemical) {
+                return Err(Error::new(format!("{} is made twice", chemical)).at_line(i + 1));
+            }
+            reactions.insert(chemical.clone(), reaction);
+        }
+
+        let mut graph = Self {
+            reactions,
+            order: vec![],
+        };
+        graph.order = graph.topological_order()?;
+        Ok(graph)
+    }
+
+    fn topological_order(&self) -> Result<Vec<String>, Error> {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum State {
+            Visiting,
+            Done,
+        }
+
+        // depth first from fuel, so a chemical is finished after everything
+        // it is made from
+        let mut states = HashMap::new();
+        let mut order = vec![];
+        let mut stack = vec![(FUEL, 0)];
+        while let Some((chemical, next)) = stack.pop() {
+            if next == 0 {
+                match states.get(chemical) {
+                    Some(State::Done) => continue,
+                    Some(State::Visiting) => {
+                        return Err(Error::new(format!("{} is made from itself", chemical)))
+                    }
+                    None => {}
+                }
+                states.insert(chemical, State::Visiting);
+            }
+
+            let reaction = self
+                .reactions
+                .get(chemical)
+                .ok_or_else(|| Error::new(format!("nothing makes {}", chemical)))?;
+            match reaction.inputs.get(next) {
+                Some(input) => {
+                    stack.push((chemical, next + 1));
+                    if input.chemical != ORE
+                        && states.get(input.chemical.as_str()) != Some(&State::Done)
+                    {
+                        stack.push((input.chemical.as_str(), 0));
+                    }
+                }
+                None => {
+                    states.insert(chemical, State::Done);
+                    order.push(chemical);
+                }
+            }
+        }
+
+        Ok(order.into_iter().rev().map(str::to_string).collect())
+    }
+
+    // reactions only make whole batches, and what is left over goes unused
+    fn ore_for(&self, fuel: u64) -> u64 {
+        let mut needed = HashMap::from([(FUEL, fuel)]);
+
+        // everything a chemical is needed for comes before it, so by the
+        // time it is reached all of it is known
+        for chemical in &self.order {
+            let need = needed.get(chemical.as_str()).copied().unwrap_or(0);
+            let reaction = &self.reactions[chemical];
+            let batches = need.div_ceil(reaction.output.amount);
+            for input in &reaction.inputs {
+                *needed.entry(input.chemical.as_str()).or_default() += batches * input.amount;
+            }
+        }
+
+        needed.get(ORE).copied().unwrap_or(0)
+    }
+}
+
+fn solve_one(reactions: &Reactions) -> Result<u64, Error> {
+    Ok(reactions.ore_for(1))
+}
+
+pub fn part_one(input: &str) -> Result<u64, Error> {
+    solve_one(&Reactions::parse(input)?)
+}
+
+fn solve_two(reactions: &Reactions) -> Result<u64, Error> {
+    const ORE_HELD: u64 = 1_000_000_000_000;
+
+    let per_fuel = reactions.ore_for(1);
+    if per_fuel == 0 {
+        return Err(Error::new("fuel takes no ore"));
+    }
+
+    // leftovers only make later fuel cheaper, so at least that much can be
+    // made, then the most that can be made is somewhere below a power of two
+    // times that which can't be
+    let mut low = ORE_HELD / per_fuel;
+    let mut high = low.max(1) * 2;
+    while reactions.ore_for(high) <= ORE_HELD {
+        low = high;
+        high *= 2;
+    }
+    while low + 1 < high {
+        let middle = (low + high) / 2;
+        if reactions.ore_for(middle) <= ORE_HELD {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    Ok(low)
+}
+
+pub fn part_two(input: &str) -> Result<u64, Error> {
+    solve_two(&Reactions::parse(input)?)
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 14;
+
+    type PartOne = Result<u64, Error>;
+    type PartTwo = Result<u64, Error>;
+
+    fn part_one(input: &str) -> Result<u64, Error> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Result<u64, Error> {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Reactions;
+
+    fn parse(input: &str) -> Result<Reactions, aoc_core::Error> {
+        Reactions::parse(input)
+    }
+
+    fn solve_one(input: &Reactions) -> Result<u64, Error> {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Reactions) -> Result<u64, Error> {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "10 ORE => 10 A
+1 ORE => 1 B
+7 A, 1 B => 1 C
+7 A, 1 C => 1 D
+7 A, 1 D => 1 E
+7 A, 1 E => 1 FUEL";
+
+    #[test]
+    fn test_reactions() {
+        let reactions = Reactions::parse(EXAMPLE).unwrap();
+        assert_eq!(reactions.order, vec!["FUEL", "E", "D", "C", "B", "A"]);
+        assert_eq!(reactions.ore_for(1), 31);
+        assert_eq!(reactions.ore_for(2), 62);
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |input: &str| Reactions::parse(input).unwrap_err().to_string();
+        assert_eq!(
+            error("1 ORE => 1 FUEL\n1 ORE -> 1 A"),
+            "line 2, column 7: expected `=>`, found `->`"
+        );
+        assert_eq!(
+            error("1 ORE, => 1 FUEL"),
+            "line 1, column 8: invalid amount `=>`"
+        );
+        assert_eq!(
+            error("1 ORE => 1 FUEL\n2 ORE => 1 FUEL"),
+            "line 2: FUEL is made twice"
+        );
+        assert_eq!(error("1 A => 1 FUEL"), "nothing makes A");
+        assert_eq!(
+            error("1 A => 1 FUEL\n1 B => 1 A\n1 A => 1 B"),
+            "A is made from itself"
+        );
+    }
+}
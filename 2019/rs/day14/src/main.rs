@@ -1,26 +1,5 @@
-use std::time::SystemTime;
+use aoc2019_day14::Solver;
 
-const DATA: &'static str = include_str!("../data.txt");
-
-fn part_one() -> i32 {
-    0
-}
-
-fn part_two() -> i32 {
-    0
-}
-
-fn time_it<F, T>(fun: F) -> T
-where
-    F: Fn() -> T,
-{
-    let start = SystemTime::now();
-    let result = fun();
-    println!("Time elapsed: {} µs", start.elapsed().unwrap().as_micros());
-    result
-}
-
-fn main() {
-    time_it(|| println!("part 1: {}", part_one()));
-    time_it(|| println!("part 2: {}", part_two()));
+fn main() -> std::io::Result<()> {
+    aoc_core::run_parsed::<Solver>()
 }
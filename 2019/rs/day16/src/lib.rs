@@ -0,0 +1,41 @@
+pub fn part_one(_input: &str) -> i32 {
+    0
+}
+
+pub fn part_two(_input: &str) -> i32 {
+    0
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 16;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = ();
+
+    fn parse(_input: &str) -> Result<(), aoc_core::Error> {
+        Ok(())
+    }
+
+    fn solve_one(_input: &()) -> i32 {
+        0
+    }
+
+    fn solve_two(_input: &()) -> i32 {
+        0
+    }
+}
@@ -0,0 +1,125 @@
+use aoc_core::Error;
+use intcode::VM;
+
+struct Drone {
+    vm: VM,
+}
+
+impl Drone {
+    fn new(intcodes: &[i64]) -> Self {
+        let mut vm = VM::from(intcodes.to_vec());
+        // the drone program is run from the start for every point
+        vm.compile();
+        Self { vm }
+    }
+
+    fn pulled(&mut self, x: i64, y: i64) -> Result<bool, Error> {
+        self.vm.reset();
+        self.vm.write_input(x);
+        self.vm.write_input(y);
+        self.vm.run()?;
+        match self.vm.read_output() {
+            Some(0) => Ok(false),
+            Some(1) => Ok(true),
+            Some(value) => Err(Error::new(format!("invalid drone report {}", value))),
+            None => Err(Error::new("the drone didn't report")),
+        }
+    }
+}
+
+fn solve_one(intcodes: &[i64]) -> Result<usize, Error> {
+    let mut drone = Drone::new(intcodes);
+    let mut count = 0;
+
+    for y in 0..50 {
+        for x in 0..50 {
+            if drone.pulled(x, y)? {
+                count += 1;
+            }
+        }
+    }
+
+    Ok(count)
+}
+
+pub fn part_one(input: &str) -> Result<usize, Error> {
+    solve_one(&VM::parse_intcodes(input)?)
+}
+
+// the answer is x * 10000 + y, so the square is closer than that
+const LIMIT: i64 = 10000;
+
+fn solve_two(intcodes: &[i64]) -> Result<i64, Error> {
+    let mut drone = Drone::new(intcodes);
+
+    // follows the left edge of the beam down, from where it is wide enough
+    // not to miss a row, checking whether the square with its bottom left
+    // corner there fits
+    let mut x = 0;
+    for y in 99..LIMIT {
+        while !drone.pulled(x, y)? {
+            x += 1;
+            if x == LIMIT {
+                return Err(Error::new(format!("no beam in row {}", y)));
+            }
+        }
+        if drone.pulled(x + 99, y - 99)? {
+            return Ok(x * LIMIT + y - 99);
+        }
+    }
+
+    Err(Error::new("the square doesn't fit in the beam"))
+}
+
+pub fn part_two(input: &str) -> Result<i64, Error> {
+    solve_two(&VM::parse_intcodes(input)?)
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 19;
+
+    type PartOne = Result<usize, Error>;
+    type PartTwo = Result<i64, Error>;
+
+    fn part_one(input: &str) -> Result<usize, Error> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Result<i64, Error> {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, aoc_core::Error> {
+        VM::parse_intcodes(input)
+    }
+
+    fn solve_one(input: &Vec<i64>) -> Result<usize, Error> {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<i64>) -> Result<i64, Error> {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_two() {
+        // reads the point, then reports it pulled or not
+        assert_eq!(part_two("3,0,3,0,104,1,99"), Ok(0));
+        assert_eq!(
+            part_two("3,0,3,0,104,0,99"),
+            Err(Error::new("no beam in row 99"))
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use aoc_core::Error;
+use intcode::{ascii::Ascii, VM};
+
+// jump if there is a hole in the next three tiles and ground to land on
+const WALK: &[&str] = &[
+    "NOT A J", "NOT B T", "OR T J", "NOT C T", "OR T J", "AND D J", "WALK",
+];
+
+// the same, but only if after landing it can step to E or jump again to H
+const RUN: &[&str] = &[
+    "NOT A J", "NOT B T", "OR T J", "NOT C T", "OR T J", "AND D J", "NOT E T", "NOT T T", "OR H T",
+    "AND T J", "RUN",
+];
+
+fn survey(intcodes: &[i64], script: &[&str]) -> Result<i64, Error> {
+    let mut droid = Ascii::from(VM::from(intcodes.to_vec()));
+    droid.read_to_stop()?;
+    for line in script {
+        droid.write_line(line);
+    }
+
+    // the droid only reports the hull damage if it makes it across
+    let view = droid.read_to_stop()?;
+    match droid.answers() {
+        [damage] => Ok(*damage),
+        _ => Err(Error::new(format!("the droid fell:\n{}", view))),
+    }
+}
+
+fn solve_one(intcodes: &[i64]) -> Result<i64, Error> {
+    survey(intcodes, WALK)
+}
+
+pub fn part_one(input: &str) -> Result<i64, Error> {
+    solve_one(&VM::parse_intcodes(input)?)
+}
+
+fn solve_two(intcodes: &[i64]) -> Result<i64, Error> {
+    survey(intcodes, RUN)
+}
+
+pub fn part_two(input: &str) -> Result<i64, Error> {
+    solve_two(&VM::parse_intcodes(input)?)
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 21;
+
+    type PartOne = Result<i64, Error>;
+    type PartTwo = Result<i64, Error>;
+
+    fn part_one(input: &str) -> Result<i64, Error> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Result<i64, Error> {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, aoc_core::Error> {
+        VM::parse_intcodes(input)
+    }
+
+    fn solve_one(input: &Vec<i64>) -> Result<i64, Error> {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<i64>) -> Result<i64, Error> {
+        solve_two(input)
+    }
+}
@@ -0,0 +1,90 @@
+use aoc_core::Error;
+use intcode::{
+    network::{Event, Network, Packet},
+    VM,
+};
+use std::ops::ControlFlow;
+
+const NAT: i64 = 255;
+
+fn boot(intcodes: &[i64]) -> Network {
+    Network::boot(intcodes, 50)
+}
+
+fn no_answer(result: ControlFlow<i64>) -> Result<i64, Error> {
+    match result {
+        ControlFlow::Break(y) => Ok(y),
+        ControlFlow::Continue(()) => Err(Error::new("the network went quiet")),
+    }
+}
+
+fn solve_one(intcodes: &[i64]) -> Result<i64, Error> {
+    let result = boot(intcodes).run(|_, event| match event {
+        Event::Packet(Packet { to: NAT, y, .. }) => ControlFlow::Break(y),
+        _ => ControlFlow::Continue(()),
+    })?;
+    no_answer(result)
+}
+
+pub fn part_one(input: &str) -> Result<i64, Error> {
+    solve_one(&VM::parse_intcodes(input)?)
+}
+
+fn solve_two(intcodes: &[i64]) -> Result<i64, Error> {
+    let mut held = None;
+    let mut last_y = None;
+
+    let result = boot(intcodes).run(|network, event| {
+        match (event, held) {
+            (Event::Packet(packet @ Packet { to: NAT, .. }), _) => held = Some(packet),
+            (Event::Idle, Some(packet)) => {
+                if last_y == Some(packet.y) {
+                    return ControlFlow::Break(packet.y);
+                }
+                last_y = Some(packet.y);
+                network.send(Packet { to: 0, ..packet });
+            }
+            _ => {}
+        }
+        ControlFlow::Continue(())
+    })?;
+    no_answer(result)
+}
+
+pub fn part_two(input: &str) -> Result<i64, Error> {
+    solve_two(&VM::parse_intcodes(input)?)
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 23;
+
+    type PartOne = Result<i64, Error>;
+    type PartTwo = Result<i64, Error>;
+
+    fn part_one(input: &str) -> Result<i64, Error> {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Result<i64, Error> {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, aoc_core::Error> {
+        VM::parse_intcodes(input)
+    }
+
+    fn solve_one(input: &Vec<i64>) -> Result<i64, Error> {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<i64>) -> Result<i64, Error> {
+        solve_two(input)
+    }
+}
@@ -0,0 +1,81 @@
+//! Compares compiled programs against the interpreter, on one long run and
+//! on many short runs from a reset like the brute force days do.
+//!
+//!     cargo bench -p intcode --bench compile
+
+use aoc_core::bench::{measure, Config};
+use intcode::{asm::assemble, VM};
+
+// the sum of the squares below its input
+const LOOP: &str = "
+        in n
+loop:   mul i, i, t
+        add total, t, total
+        add i, #1, i
+        lt i, n, c
+        jnz c, #loop
+        out total
+        hlt
+n:      data 0
+i:      data 0
+t:      data 0
+c:      data 0
+total:  data 0
+";
+
+// whether a point is inside a circle, like asking the drone on day 19
+const POINT: &str = "
+        in x
+        in y
+        mul x, x, x
+        mul y, y, y
+        add x, y, x
+        lt x, #2500, x
+        out x
+        hlt
+x:      data 0
+y:      data 0
+";
+
+fn bench(name: &str, vm: &VM, run: fn(&mut VM) -> i64) {
+    let mut compiled = vm.clone();
+    compiled.compile();
+    for (engine, vm) in [("interpreted", vm), ("compiled", &compiled)] {
+        let mut vm = vm.clone();
+        let stats = measure(&Config::default(), || run(&mut vm));
+        println!(
+            "{:<8}{:<13}{:>8} µs{:>8} µs",
+            name,
+            engine,
+            stats.median.as_micros(),
+            stats.p95.as_micros()
+        );
+    }
+}
+
+fn run_loop(vm: &mut VM) -> i64 {
+    vm.reset();
+    vm.write_input(100_000);
+    vm.run().unwrap();
+    vm.read_output().unwrap()
+}
+
+fn run_points(vm: &mut VM) -> i64 {
+    let mut inside = 0;
+    for x in 0..100 {
+        for y in 0..100 {
+            vm.reset();
+            vm.write_input(x);
+            vm.write_input(y);
+            vm.run().unwrap();
+            inside += vm.read_output().unwrap();
+        }
+    }
+    inside
+}
+
+fn main() {
+    println!("{:<21}{:>11}{:>11}", "program", "median", "p95");
+    bench("loop", &VM::from(assemble(LOOP).unwrap()), run_loop);
+    bench("points", &VM::from(assemble(POINT).unwrap()), run_points);
+}
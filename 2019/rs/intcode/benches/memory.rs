@@ -0,0 +1,78 @@
+//! Compares the memory backends on a program that fills and sums an array,
+//! against timings recorded for the VM before backends could be picked.
+//! `Dense` should run as fast as it.
+//!
+//!     cargo bench -p intcode --bench memory
+
+use aoc_core::bench::{measure, Config};
+use intcode::{asm::assemble, Dense, Memory, Paged, Sparse, VM};
+use std::time::Duration;
+
+const PROGRAM: &str = "
+        in n
+        arb #array
+fill:   add i, #0, @0
+        add i, #1, i
+        arb #1
+        lt i, n, t
+        jnz t, #fill
+sum:    arb #-1
+        add total, @0, total
+        add i, #-1, i
+        jnz i, #sum
+        out total
+        hlt
+n:      data 0
+i:      data 0
+t:      data 0
+total:  data 0
+array:  data 0
+";
+
+const LEN: i64 = 100_000;
+
+// median and p95 of the VM at ca24c59^, the last commit before memory
+// backends, running PROGRAM like `bench` does with the default config. They
+// were taken on the same machine as a run of this benchmark, so take both
+// again before comparing on another one.
+const BASELINE: (Duration, Duration) =
+    (Duration::from_micros(18_238), Duration::from_micros(28_403));
+
+fn row(name: &str, median: Duration, p95: Duration) {
+    let change = (median.as_secs_f64() / BASELINE.0.as_secs_f64() - 1.0) * 100.0;
+    println!(
+        "{:<8}{:>10} µs{:>10} µs{:>+9.1}%",
+        name,
+        median.as_micros(),
+        p95.as_micros(),
+        change
+    );
+}
+
+fn bench<M: Memory>(name: &str, program: &[i64]) {
+    let mut vm = VM::new(M::from(program));
+    let stats = measure(&Config::default(), || {
+        vm.reset();
+        vm.write_input(LEN);
+        vm.run().unwrap();
+        vm.read_output().unwrap()
+    });
+    row(name, stats.median, stats.p95);
+}
+
+fn main() {
+    let program = assemble(PROGRAM).unwrap();
+    let mut vm = VM::from(program.clone());
+    vm.write_input(LEN);
+    vm.run().unwrap();
+    assert_eq!(vm.read_output(), Some(LEN * (LEN - 1) / 2));
+
+    println!(
+        "{:<8}{:>13}{:>13}{:>10}",
+        "memory", "median", "p95", "change"
+    );
+    row("before", BASELINE.0, BASELINE.1);
+    bench::<Dense>("dense", &program);
+    bench::<Paged>("paged", &program);
+    bench::<Sparse>("sparse", &program);
+}
@@ -0,0 +1,148 @@
+use crate::{Dense, Memory, Status, VmError, VM};
+
+/// Talks to a program that reads and writes text, one character code per
+/// intcode, like the ones on days 17, 21 and 25. Anything the program writes
+/// that isn't ASCII is kept apart as an answer.
+#[derive(Debug, Clone)]
+pub struct Ascii<M = Dense> {
+    vm: VM<M>,
+    answers: Vec<i64>,
+    status: Option<Status>,
+}
+
+impl<M: Memory> From<VM<M>> for Ascii<M> {
+    fn from(vm: VM<M>) -> Self {
+        Self {
+            vm,
+            answers: vec![],
+            status: None,
+        }
+    }
+}
+
+impl<M: Memory> Ascii<M> {
+    pub fn vm(&self) -> &VM<M> {
+        &self.vm
+    }
+
+    pub fn vm_mut(&mut self) -> &mut VM<M> {
+        &mut self.vm
+    }
+
+    pub fn into_vm(self) -> VM<M> {
+        self.vm
+    }
+
+    /// Why the program last stopped, `Halted` or `NeedsInput`, or `None` if
+    /// it hasn't yet.
+    pub fn status(&self) -> Option<Status> {
+        self.status
+    }
+
+    /// The values outside ASCII written so far, oldest first.
+    pub fn answers(&self) -> &[i64] {
+        &self.answers
+    }
+
+    pub fn take_answers(&mut self) -> Vec<i64> {
+        std::mem::take(&mut self.answers)
+    }
+
+    /// Gives the program `line` followed by a newline.
+    pub fn write_line(&mut self, line: &str) {
+        for c in line.chars() {
+            self.vm.write_input(c as i64);
+        }
+        self.vm.write_input('\n' as i64);
+    }
+
+    // the next value written, or `None` if the program stopped
+    fn next(&mut self) -> Result<Option<i64>, VmError> {
+        if let Some(value) = self.vm.read_output() {
+            return Ok(Some(value));
+        }
+
+        match self.vm.run_until_output()? {
+            Status::Output(value) => Ok(Some(value)),
+            status => {
+                self.status = Some(status);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Runs the program until it finishes a line, returning it without the
+    /// newline. If it stops first, what it wrote of the line is returned, such
+    /// as a prompt, or `None` if it wrote nothing.
+    pub fn read_line(&mut self) -> Result<Option<String>, VmError> {
+        let mut line = String::new();
+        loop {
+            match self.next()? {
+                Some(10) => return Ok(Some(line)),
+                Some(value @ 0..=127) => line.push(value as u8 as char),
+                Some(answer) => self.answers.push(answer),
+                None if line.is_empty() => return Ok(None),
+                None => return Ok(Some(line)),
+            }
+        }
+    }
+
+    /// Runs the program until it halts or needs input, returning all the
+    /// text it wrote.
+    pub fn read_to_stop(&mut self) -> Result<String, VmError> {
+        let mut text = String::new();
+        loop {
+            match self.next()? {
+                Some(value @ 0..=127) => text.push(value as u8 as char),
+                Some(answer) => self.answers.push(answer),
+                None => return Ok(text),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm::assemble;
+
+    #[test]
+    fn test_ascii() {
+        // greets, then shouts back a line and answers 1000
+        let program = assemble(
+            "
+                out #104
+                out #105
+                out #10
+                out #62
+                out #32
+        loop:   in c
+                eq c, #10, t
+                jnz t, #done
+                add c, #-32, c
+                out c
+                jz #0, #loop
+        done:   out #10
+                out #1000
+                out #33
+                hlt
+        c:      data 0
+        t:      data 0
+            ",
+        )
+        .unwrap();
+        let mut ascii = Ascii::from(VM::from(program));
+        assert_eq!(ascii.status(), None);
+        assert_eq!(ascii.read_line(), Ok(Some("hi".to_string())));
+        assert_eq!(ascii.read_line(), Ok(Some("> ".to_string())));
+        assert_eq!(ascii.status(), Some(Status::NeedsInput));
+        assert_eq!(ascii.read_line(), Ok(None));
+
+        ascii.write_line("abc");
+        assert_eq!(ascii.read_line(), Ok(Some("ABC".to_string())));
+        assert_eq!(ascii.read_to_stop(), Ok("!".to_string()));
+        assert_eq!(ascii.status(), Some(Status::Halted));
+        assert_eq!(ascii.take_answers(), vec![1000]);
+        assert!(ascii.answers().is_empty());
+    }
+}
@@ -0,0 +1,254 @@
+use crate::{Mode, Op};
+use aoc_core::Error;
+use std::collections::HashMap;
+
+// an intcode, or a label to be replaced by its address once every label is known
+enum Word<'a> {
+    Int(i64),
+    Label {
+        name: &'a str,
+        line: usize,
+        column: usize,
+    },
+}
+
+fn column(line: &str, part: &str) -> usize {
+    part.as_ptr() as usize - line.as_ptr() as usize + 1
+}
+
+fn is_label(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+pub(crate) fn mode_digit(mode: Mode) -> i64 {
+    match mode {
+        Mode::Position => 0,
+        Mode::Immediate => 1,
+        Mode::Relative => 2,
+    }
+}
+
+struct Assembler<'a> {
+    words: Vec<Word<'a>>,
+    labels: HashMap<&'a str, usize>,
+    // the 1-based number of the line being assembled
+    line: usize,
+}
+
+impl<'a> Assembler<'a> {
+    fn operand(&self, line: &'a str, text: &'a str) -> Result<(Mode, Word<'a>), Error> {
+        let (mode, value) = match text.as_bytes().first() {
+            Some(b'#') => (Mode::Immediate, &text[1..]),
+            Some(b'@') => (Mode::Relative, &text[1..]),
+            _ => (Mode::Position, text),
+        };
+
+        let word = if let Ok(int) = value.parse() {
+            Word::Int(int)
+        } else if is_label(value) {
+            Word::Label {
+                name: value,
+                line: self.line,
+                column: column(line, value),
+            }
+        } else {
+            let message = format!("invalid operand `{}`", text);
+            return Err(Error::new(message).at_column(column(line, text)));
+        };
+        Ok((mode, word))
+    }
+
+    fn parse_line(&mut self, line: &'a str) -> Result<(), Error> {
+        let mut code = line.split(';').next().unwrap();
+        if let Some((label, rest)) = code.split_once(':') {
+            let label = label.trim();
+            let error = |message: String| Error::new(message).at_column(column(line, label));
+            if !is_label(label) {
+                return Err(error(format!("invalid label `{}`", label)));
+            }
+            if self.labels.insert(label, self.words.len()).is_some() {
+                return Err(error(format!("duplicate label `{}`", label)));
+            }
+            code = rest;
+        }
+
+        let code = code.trim();
+        if code.is_empty() {
+            return Ok(());
+        }
+        let (mnemonic, rest) = code.split_once(char::is_whitespace).unwrap_or((code, ""));
+        let operands = match rest.trim() {
+            "" => vec![],
+            rest => rest
+                .split(',')
+                .map(|text| match text.trim() {
+                    "" => Err(Error::new("missing operand").at_column(column(line, text))),
+                    text => self.operand(line, text),
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+        };
+        let error = |message: String| Error::new(message).at_column(column(line, mnemonic));
+
+        if mnemonic == "data" {
+            for (i, (mode, word)) in operands.into_iter().enumerate() {
+                if mode != Mode::Position {
+                    let text = rest.split(',').nth(i).unwrap().trim();
+                    let message = format!("data can't have a mode: `{}`", text);
+                    return Err(Error::new(message).at_column(column(line, text)));
+                }
+                self.words.push(word);
+            }
+            return Ok(());
+        }
+
+        let Some(op) = Op::from_mnemonic(mnemonic) else {
+            return Err(error(format!("unknown mnemonic `{}`", mnemonic)));
+        };
+        if operands.len() != op.params() {
+            let message = format!("`{}` takes {} operands", mnemonic, op.params());
+            return Err(error(message));
+        }
+        if let Some(i) = op.output_param() {
+            if operands[i].0 == Mode::Immediate {
+                let text = rest.split(',').nth(i).unwrap().trim();
+                let message = format!("can't write to an immediate operand `{}`", text);
+                return Err(Error::new(message).at_column(column(line, text)));
+            }
+        }
+
+        let opcode = operands
+            .iter()
+            .zip([100, 1000, 10000])
+            .map(|((mode, _), digit)| mode_digit(*mode) * digit)
+            .sum::<i64>()
+            + op.opcode();
+        self.words.push(Word::Int(opcode));
+        self.words
+            .extend(operands.into_iter().map(|(_, word)| word));
+        Ok(())
+    }
+}
+
+/// Assembles a program written one instruction per line, in the syntax
+/// `disasm::disassemble` lists programs in:
+///
+/// ```text
+/// ; prints its input counting down to 1
+///         in n
+/// loop:   out n
+///         add n, #-1, n
+///         jnz n, #loop
+///         hlt
+/// n:      data 0
+/// ```
+///
+/// Parameters are in position mode unless written as `#1` for immediate or
+/// `@1` for relative mode, and a label stands for the address it is on.
+/// `data` places its values in the program as they are.
+pub fn assemble(source: &str) -> Result<Vec<i64>, Error> {
+    let mut assembler = Assembler {
+        words: vec![],
+        labels: HashMap::new(),
+        line: 0,
+    };
+
+    for line in source.lines() {
+        assembler.line += 1;
+        assembler
+            .parse_line(line)
+            .map_err(|err| err.at_line(assembler.line))?;
+    }
+
+    assembler
+        .words
+        .iter()
+        .map(|word| match *word {
+            Word::Int(int) => Ok(int),
+            Word::Label { name, line, column } => match assembler.labels.get(name) {
+                Some(&address) => Ok(address as i64),
+                None => Err(Error::new(format!("undefined label `{}`", name))
+                    .at_line(line)
+                    .at_column(column)),
+            },
+        })
+        .collect()
+}
+
+// shared by the tests of the modules that run programs
+#[cfg(test)]
+pub(crate) const COUNTDOWN: &str = "\
+; prints its input counting down to 1
+        in n
+loop:   out n
+        add n, #-1, n
+        jnz n, #loop
+        hlt
+n:      data 0
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{disasm::disassemble, Status, VM};
+
+    #[test]
+    fn test_assemble() {
+        let intcodes = assemble(COUNTDOWN).unwrap();
+        assert_eq!(
+            intcodes,
+            vec![3, 12, 4, 12, 1001, 12, -1, 12, 1005, 12, 2, 99, 0]
+        );
+
+        let mut vm = VM::from(intcodes);
+        vm.write_input(3);
+        assert_eq!(vm.run(), Ok(Status::Halted));
+        assert_eq!(
+            [vm.read_output(), vm.read_output(), vm.read_output()],
+            [Some(3), Some(2), Some(1)]
+        );
+
+        assert_eq!(
+            assemble("arb #3\nout @-1\nend: data end, -7"),
+            Ok(vec![109, 3, 204, -1, 4, -7])
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let intcodes = vec![1105, 1, 4, 99, 1101, 2, 3, 9, 99, 0, 1, 2];
+        assert_eq!(assemble(&disassemble(&intcodes)), Ok(intcodes));
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |source: &str| assemble(source).unwrap_err().to_string();
+        assert_eq!(
+            error("hlt\n  mov 1, 2"),
+            "line 2, column 3: unknown mnemonic `mov`"
+        );
+        assert_eq!(
+            error("add 1, 2"),
+            "line 1, column 1: `add` takes 3 operands"
+        );
+        assert_eq!(
+            error("in x\nhlt\nout #nope"),
+            "line 1, column 4: undefined label `x`"
+        );
+        assert_eq!(
+            error("a: hlt\n a: hlt"),
+            "line 2, column 2: duplicate label `a`"
+        );
+        assert_eq!(error("2a: hlt"), "line 1, column 1: invalid label `2a`");
+        assert_eq!(error("out #"), "line 1, column 5: invalid operand `#`");
+        assert_eq!(error("out 1,"), "line 1, column 7: missing operand");
+        assert_eq!(
+            error("add 1, 2, #3"),
+            "line 1, column 11: can't write to an immediate operand `#3`"
+        );
+        assert_eq!(
+            error("data 1, @2"),
+            "line 1, column 9: data can't have a mode: `@2`"
+        );
+    }
+}
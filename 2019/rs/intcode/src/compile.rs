@@ -0,0 +1,136 @@
+use crate::{disasm::Instruction, Mode, Op};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Param {
+    Position(usize),
+    Immediate(i64),
+    Relative(i64),
+}
+
+/// An instruction with its modes and parameters already worked out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Decoded {
+    pub op: Op,
+    pub params: [Param; 3],
+    pub size: usize,
+}
+
+impl Decoded {
+    // `None` for instructions the VM would fail on, which are left to it
+    fn new(instruction: &Instruction) -> Option<Self> {
+        let mut params = [Param::Immediate(0); 3];
+        for (param, &(mode, value)) in params.iter_mut().zip(&instruction.params) {
+            *param = match mode {
+                Mode::Position => Param::Position(usize::try_from(value).ok()?),
+                Mode::Immediate => Param::Immediate(value),
+                Mode::Relative => Param::Relative(value),
+            };
+        }
+
+        Some(Self {
+            op: instruction.op,
+            params,
+            size: instruction.size(),
+        })
+    }
+}
+
+/// A program decoded ahead of time, so a VM running it doesn't have to pick
+/// apart every instruction it runs. See `VM::compile`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Code {
+    // the instruction starting at each address, where there is one
+    decoded: Vec<Option<Decoded>>,
+}
+
+impl Code {
+    /// Decodes an instruction at every address of `program`, as the program
+    /// can jump anywhere.
+    pub fn new(program: &[i64]) -> Self {
+        let decoded = (0..program.len())
+            .map(|address| Instruction::decode(program, address).and_then(|i| Decoded::new(&i)))
+            .collect();
+        Self { decoded }
+    }
+
+    pub fn len(&self) -> usize {
+        self.decoded.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.decoded.is_empty()
+    }
+
+    pub(crate) fn get(&self, address: usize) -> Option<&Decoded> {
+        self.decoded.get(address)?.as_ref()
+    }
+}
+
+/// The addresses of compiled code a VM has written to since it was reset,
+/// whose instructions have to be decoded again as they run.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Dirty {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl Dirty {
+    pub fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    pub fn mark(&mut self, address: usize) {
+        if address < self.len {
+            self.words[address / 64] |= 1 << (address % 64);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Whether any of the `size` addresses from `start` were written to.
+    pub fn any(&self, start: usize, size: usize) -> bool {
+        let end = (start + size).min(self.len);
+        if start >= end {
+            return false;
+        }
+
+        // an instruction is at most 4 long, so it spans one or two words
+        let (first, last) = (start / 64, (end - 1) / 64);
+        let mask = |from: usize, to: usize| (u64::MAX >> (63 - (to - from))) << from;
+        if first == last {
+            self.words[first] & mask(start % 64, (end - 1) % 64) != 0
+        } else {
+            self.words[first] & mask(start % 64, 63) != 0
+                || self.words[last] & mask(0, (end - 1) % 64) != 0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dirty() {
+        let mut dirty = Dirty::new(130);
+        dirty.mark(63);
+        dirty.mark(200);
+        assert!(dirty.any(60, 4));
+        assert!(dirty.any(63, 1));
+        assert!(dirty.any(62, 3));
+        assert!(!dirty.any(59, 4));
+        assert!(!dirty.any(64, 4));
+        assert!(!dirty.any(128, 4));
+        assert!(!dirty.any(200, 1));
+
+        dirty.mark(129);
+        assert!(dirty.any(127, 4));
+        dirty.clear();
+        assert!(!dirty.any(60, 4));
+    }
+}
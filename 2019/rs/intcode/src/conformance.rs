@@ -0,0 +1,316 @@
+//! The example programs from the 2019 puzzles, and every opcode in every
+//! mode, each run both interpreted and compiled.
+
+use crate::{asm::mode_digit, Dense, ErrorKind, Memory, Mode, Op, Sparse, Status, VmError, VM};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::ops::ControlFlow;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Run {
+    status: Result<Status, VmError>,
+    output: Vec<i64>,
+    memory: Vec<i64>,
+}
+
+fn run_on(mut vm: VM, input: &[i64]) -> Run {
+    for &value in input {
+        vm.write_input(value);
+    }
+    let status = vm.run();
+    let output = std::iter::from_fn(|| vm.read_output()).collect();
+    Run {
+        status,
+        output,
+        memory: vm.memory().to_vec(),
+    }
+}
+
+// runs `program` with `input`, checking compiling it changes nothing
+fn run(program: &[i64], input: &[i64]) -> Run {
+    let vm = VM::from(program.to_vec());
+    let mut compiled = vm.clone();
+    compiled.compile();
+
+    let run = run_on(vm, input);
+    assert_eq!(run_on(compiled, input), run, "{:?}", program);
+    run
+}
+
+fn output(program: &[i64], input: &[i64]) -> Vec<i64> {
+    let run = run(program, input);
+    assert_eq!(run.status, Ok(Status::Halted), "{:?}", program);
+    run.output
+}
+
+fn instruction(op: Op, modes: &[Mode]) -> i64 {
+    modes
+        .iter()
+        .zip([100, 1000, 10000])
+        .map(|(&mode, digit)| mode_digit(mode) * digit)
+        .sum::<i64>()
+        + op.opcode()
+}
+
+const MODES: [Mode; 3] = [Mode::Position, Mode::Immediate, Mode::Relative];
+
+// every mode for the first two parameters
+fn pairs() -> impl Iterator<Item = (Mode, Mode)> {
+    MODES
+        .into_iter()
+        .flat_map(|one| MODES.into_iter().map(move |two| (one, two)))
+}
+
+#[test]
+fn test_day_2() {
+    let cases: [(&[i64], &[i64]); 5] = [
+        (&[1, 0, 0, 0, 99], &[2, 0, 0, 0, 99]),
+        (&[2, 3, 0, 3, 99], &[2, 3, 0, 6, 99]),
+        (&[2, 4, 4, 5, 99, 0], &[2, 4, 4, 5, 99, 9801]),
+        (
+            &[1, 1, 1, 4, 99, 5, 6, 0, 99],
+            &[30, 1, 1, 4, 2, 5, 6, 0, 99],
+        ),
+        (
+            &[1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50],
+            &[3500, 9, 10, 70, 2, 3, 11, 0, 99, 30, 40, 50],
+        ),
+    ];
+    for (program, memory) in cases {
+        let run = run(program, &[]);
+        assert_eq!(run.status, Ok(Status::Halted));
+        assert_eq!(run.memory, memory);
+    }
+}
+
+#[test]
+fn test_day_5() {
+    assert_eq!(run(&[1002, 4, 3, 4, 33], &[]).memory[4], 99);
+    assert_eq!(run(&[1101, 100, -1, 4, 0], &[]).memory[4], 99);
+    assert_eq!(output(&[3, 0, 4, 0, 99], &[-7]), vec![-7]);
+
+    // each compares its input with 8, or 0 for the jumps
+    type Expected = fn(i64) -> bool;
+    let comparisons: [(&[i64], Expected); 6] = [
+        (&[3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8], |input| input == 8),
+        (&[3, 9, 7, 9, 10, 9, 4, 9, 99, -1, 8], |input| input < 8),
+        (&[3, 3, 1108, -1, 8, 3, 4, 3, 99], |input| input == 8),
+        (&[3, 3, 1107, -1, 8, 3, 4, 3, 99], |input| input < 8),
+        (
+            &[3, 12, 6, 12, 15, 1, 13, 14, 13, 4, 13, 99, -1, 0, 1, 9],
+            |input| input != 0,
+        ),
+        (
+            &[3, 3, 1105, -1, 9, 1101, 0, 0, 12, 4, 12, 99, 1],
+            |input| input != 0,
+        ),
+    ];
+    for (program, expected) in comparisons {
+        for input in [-8, 0, 7, 8, 9] {
+            assert_eq!(
+                output(program, &[input]),
+                vec![expected(input) as i64],
+                "{:?} with {}",
+                program,
+                input
+            );
+        }
+    }
+
+    let program = [
+        3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36, 98, 0, 0,
+        1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104, 999, 1105, 1, 46, 1101, 1000, 1, 20, 4, 20,
+        1105, 1, 46, 98, 99,
+    ];
+    for (input, expected) in [(7, 999), (8, 1000), (9, 1001)] {
+        assert_eq!(output(&program, &[input]), vec![expected]);
+    }
+}
+
+#[test]
+fn test_day_9() {
+    let quine = [
+        109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+    ];
+    assert_eq!(output(&quine, &[]), quine);
+
+    let product = output(&[1102, 34915192, 34915192, 7, 4, 7, 99, 0], &[]);
+    assert_eq!(product, vec![34915192 * 34915192]);
+    assert_eq!(product[0].to_string().len(), 16);
+    assert_eq!(
+        output(&[104, 1125899906842624, 99], &[]),
+        vec![1125899906842624]
+    );
+}
+
+// sets the relative base to 100, runs `code`, then halts, with `data` at 20
+fn program(code: &[i64], data: &[i64]) -> Vec<i64> {
+    let mut program = vec![0; 40];
+    program[..2].copy_from_slice(&[109, 100]);
+    program[2..2 + code.len()].copy_from_slice(code);
+    program[2 + code.len()] = 99;
+    program[20..20 + data.len()].copy_from_slice(data);
+    program
+}
+
+// the parameter that reads the value at `address`, or `value` in
+// immediate mode
+fn param(mode: Mode, address: i64, value: i64) -> i64 {
+    match mode {
+        Mode::Position => address,
+        Mode::Immediate => value,
+        Mode::Relative => address - 100,
+    }
+}
+
+#[test]
+fn test_every_mode() {
+    type Apply = fn(i64, i64) -> i64;
+    let arithmetic: [(Op, Apply); 4] = [
+        (Op::Add, |a, b| a + b),
+        (Op::Multiply, |a, b| a * b),
+        (Op::LessThan, |a, b| (a < b) as i64),
+        (Op::Equal, |a, b| (a == b) as i64),
+    ];
+    for (op, f) in arithmetic {
+        for (a, b) in [(6, 7), (7, 7), (-3, 2)] {
+            for (one, two) in pairs() {
+                for three in [Mode::Position, Mode::Relative] {
+                    let code = [
+                        instruction(op, &[one, two, three]),
+                        param(one, 20, a),
+                        param(two, 21, b),
+                        param(three, 30, 0),
+                    ];
+                    let run = run(&program(&code, &[a, b]), &[]);
+                    assert_eq!(run.status, Ok(Status::Halted), "{:?}", code);
+                    assert_eq!(run.memory[30], f(a, b), "{:?}", code);
+                }
+            }
+        }
+    }
+
+    // jumping to 8 outputs 1, falling through outputs 0
+    for (op, jumps) in [(Op::JumpIfNotZero, true), (Op::JumpIfZero, false)] {
+        for value in [0, 5] {
+            for (one, two) in pairs() {
+                let code = [
+                    instruction(op, &[one, two]),
+                    param(one, 20, value),
+                    param(two, 21, 8),
+                    104,
+                    0,
+                    99,
+                    104,
+                    1,
+                ];
+                let expected = (jumps == (value != 0)) as i64;
+                assert_eq!(
+                    output(&program(&code, &[value, 8]), &[]),
+                    vec![expected],
+                    "{:?}",
+                    code
+                );
+            }
+        }
+    }
+
+    for mode in [Mode::Position, Mode::Relative] {
+        let code = [instruction(Op::Input, &[mode]), param(mode, 30, 0), 4, 30];
+        assert_eq!(output(&program(&code, &[]), &[42]), vec![42]);
+    }
+    for mode in MODES {
+        let code = [instruction(Op::Output, &[mode]), param(mode, 20, 6)];
+        assert_eq!(output(&program(&code, &[6]), &[]), vec![6]);
+
+        // moves the relative base to 106, then outputs what is at 30
+        let code = [
+            instruction(Op::AdjustRelativeBase, &[mode]),
+            param(mode, 20, 6),
+            204,
+            -76,
+        ];
+        let mut program = program(&code, &[6]);
+        program[30] = 77;
+        assert_eq!(output(&program, &[]), vec![77]);
+    }
+}
+
+// a random program of arithmetic instructions, with values that are
+// sometimes out of range to find overflows and bad addresses
+fn random_program(rng: &mut StdRng) -> Vec<i64> {
+    let ops = [Op::Add, Op::Multiply, Op::LessThan, Op::Equal];
+    let mut program = vec![];
+    for _ in 0..rng.gen_range(1..20) {
+        let modes: Vec<Mode> = (0..3).map(|_| MODES[rng.gen_range(0..3)]).collect();
+        program.push(instruction(ops[rng.gen_range(0..4)], &modes));
+        for _ in 0..3 {
+            program.push(match rng.gen_range(0..10) {
+                0 => i64::MAX,
+                1 => i64::MIN,
+                2 => rng.gen(),
+                _ => rng.gen_range(-10..80),
+            });
+        }
+    }
+    program.push(99);
+    program
+}
+
+type Limited = (Result<ControlFlow<(), Status>, VmError>, usize, usize);
+
+// runs with a step limit, as programs can write jumps into themselves
+fn run_limited<M: Memory>(program: &[i64], compile: bool) -> Limited {
+    let mut vm = VM::new(M::from(program));
+    if compile {
+        vm.compile();
+    }
+    let mut steps = 0;
+    let status = vm.run_with(|_| {
+        steps += 1;
+        if steps > 1000 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+    (
+        status,
+        vm.pc(),
+        std::iter::from_fn(|| vm.read_output()).count(),
+    )
+}
+
+#[test]
+fn test_arithmetic_never_panics() {
+    let mut rng = StdRng::seed_from_u64(2019);
+    let (mut overflows, mut out_of_range) = (0, 0);
+    for _ in 0..2000 {
+        let program = random_program(&mut rng);
+
+        // sparse memory writes anywhere, dense memory stops far past the end
+        let sparse = run_limited::<Sparse>(&program, false);
+        assert_eq!(
+            run_limited::<Sparse>(&program, true),
+            sparse,
+            "{:?}",
+            program
+        );
+        let dense = run_limited::<Dense>(&program, false);
+        assert_eq!(run_limited::<Dense>(&program, true), dense, "{:?}", program);
+
+        for (status, ..) in [sparse, dense] {
+            match status {
+                Err(VmError {
+                    kind: ErrorKind::Overflow,
+                    ..
+                }) => overflows += 1,
+                Err(VmError {
+                    kind: ErrorKind::AddressOutOfRange(_),
+                    ..
+                }) => out_of_range += 1,
+                _ => {}
+            }
+        }
+    }
+    assert!(overflows > 0 && out_of_range > 0);
+}
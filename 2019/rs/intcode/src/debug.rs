@@ -0,0 +1,179 @@
+use crate::{disasm::Instruction, Memory, Mode, Status, VmError, VM};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    mem,
+    ops::ControlFlow,
+};
+
+/// Why the debugger paused the program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    /// About to run the instruction at this address.
+    Breakpoint(usize),
+    /// About to run the instruction at `pc`, which writes to `address`.
+    Write { pc: usize, address: usize },
+    /// The last instruction changed a watched address.
+    Watchpoint { address: usize, old: i64, new: i64 },
+}
+
+/// Breakpoints and watchpoints for running a VM from a debugger, built on
+/// `VM::run_with`.
+#[derive(Debug, Clone, Default)]
+pub struct Debugger {
+    /// Addresses of instructions to stop before.
+    pub breakpoints: BTreeSet<usize>,
+    /// Addresses to stop before an instruction writes to.
+    pub write_breakpoints: BTreeSet<usize>,
+    // the value each watched address had when it was last checked
+    watchpoints: BTreeMap<usize, i64>,
+    // where a breakpoint last stopped, so running again doesn't stop there straight away
+    paused_at: Option<usize>,
+}
+
+fn read<M: Memory>(vm: &VM<M>, address: usize) -> i64 {
+    vm.memory().get(address)
+}
+
+// the address the next instruction writes to, if it writes
+fn write_address<M: Memory>(vm: &VM<M>) -> Option<usize> {
+    let instruction = Instruction::decode_memory(vm.memory(), vm.pc())?;
+    let (mode, value) = instruction.params[instruction.op.output_param()?];
+    let address = match mode {
+        Mode::Relative => vm.relative_base().checked_add(value)?,
+        _ => value,
+    };
+    usize::try_from(address).ok()
+}
+
+impl Debugger {
+    /// Stops whenever the value at `address` changes.
+    pub fn watch<M: Memory>(&mut self, vm: &VM<M>, address: usize) {
+        self.watchpoints.insert(address, read(vm, address));
+    }
+
+    pub fn unwatch(&mut self, address: usize) -> bool {
+        self.watchpoints.remove(&address).is_some()
+    }
+
+    pub fn watchpoints(&self) -> impl Iterator<Item = usize> + '_ {
+        self.watchpoints.keys().copied()
+    }
+
+    fn check_watchpoints<M: Memory>(&mut self, vm: &VM<M>) -> ControlFlow<Stop> {
+        for (&address, value) in &mut self.watchpoints {
+            let new = read(vm, address);
+            if new != *value {
+                let old = mem::replace(value, new);
+                return ControlFlow::Break(Stop::Watchpoint { address, old, new });
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn check<M: Memory>(&mut self, vm: &VM<M>) -> ControlFlow<Stop> {
+        self.check_watchpoints(vm)?;
+
+        let pc = vm.pc();
+        let stop = match write_address(vm) {
+            _ if self.breakpoints.contains(&pc) => Stop::Breakpoint(pc),
+            Some(address) if self.write_breakpoints.contains(&address) => {
+                Stop::Write { pc, address }
+            }
+            _ => return ControlFlow::Continue(()),
+        };
+        self.paused_at = Some(pc);
+        ControlFlow::Break(stop)
+    }
+
+    /// Runs until the VM stops on its own or something it is watching for
+    /// happens. Running again after a breakpoint carries on past it.
+    pub fn run<M: Memory>(&mut self, vm: &mut VM<M>) -> Result<ControlFlow<Stop, Status>, VmError> {
+        let mut paused_at = self.paused_at.take();
+        vm.run_with(|vm| {
+            if paused_at.take() == Some(vm.pc()) {
+                self.check_watchpoints(vm)
+            } else {
+                self.check(vm)
+            }
+        })
+    }
+
+    /// Runs a single instruction, stopping if it changed a watched address.
+    pub fn step<M: Memory>(
+        &mut self,
+        vm: &mut VM<M>,
+    ) -> Result<ControlFlow<Stop, Option<Status>>, VmError> {
+        self.paused_at = None;
+        let status = vm.step()?;
+        Ok(match self.check_watchpoints(vm) {
+            ControlFlow::Break(stop) => ControlFlow::Break(stop),
+            ControlFlow::Continue(()) => ControlFlow::Continue(status),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm::{assemble, COUNTDOWN};
+
+    #[test]
+    fn test_debugger() {
+        let program = assemble(COUNTDOWN).unwrap();
+        let mut vm = VM::from(program);
+        vm.write_input(2);
+
+        let mut debugger = Debugger::default();
+        debugger.breakpoints.insert(2);
+        debugger.watch(&vm, 12);
+
+        let mut stops = vec![];
+        while let ControlFlow::Break(stop) = debugger.run(&mut vm).unwrap() {
+            stops.push(stop);
+        }
+        assert_eq!(
+            stops,
+            vec![
+                Stop::Watchpoint {
+                    address: 12,
+                    old: 0,
+                    new: 2
+                },
+                Stop::Breakpoint(2),
+                Stop::Watchpoint {
+                    address: 12,
+                    old: 2,
+                    new: 1
+                },
+                Stop::Breakpoint(2),
+                Stop::Watchpoint {
+                    address: 12,
+                    old: 1,
+                    new: 0
+                },
+            ]
+        );
+
+        assert_eq!([vm.read_output(), vm.read_output()], [Some(2), Some(1)]);
+
+        let mut vm = VM::from(vec![1101, 1, 2, 11, 109, 3, 21101, 3, 4, 8, 99, 0]);
+        let mut debugger = Debugger::default();
+        debugger.write_breakpoints.insert(11);
+        assert_eq!(
+            debugger.run(&mut vm),
+            Ok(ControlFlow::Break(Stop::Write { pc: 0, address: 11 }))
+        );
+        assert_eq!(debugger.step(&mut vm), Ok(ControlFlow::Continue(None)));
+        assert_eq!(debugger.step(&mut vm), Ok(ControlFlow::Continue(None)));
+        assert_eq!(vm.relative_base(), 3);
+        assert_eq!(
+            debugger.run(&mut vm),
+            Ok(ControlFlow::Break(Stop::Write { pc: 6, address: 11 }))
+        );
+        assert_eq!(
+            debugger.run(&mut vm),
+            Ok(ControlFlow::Continue(Status::Halted))
+        );
+        assert_eq!(vm.memory()[11], 7);
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{Memory, Mode, Op};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Write},
+};
+
+/// A decoded instruction and its parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    pub op: Op,
+    pub params: Vec<(Mode, i64)>,
+}
+
+impl Instruction {
+    /// Decodes the instruction at `address`, or `None` if the VM would fail
+    /// to execute it.
+    pub fn decode(intcodes: &[i64], address: usize) -> Option<Self> {
+        Self::decode_with(|address| intcodes.get(address).copied(), address)
+    }
+
+    /// Like `decode`, but with parameters past the end of memory reading as 0
+    /// like they do in the VM.
+    pub fn decode_memory(memory: &impl Memory, address: usize) -> Option<Self> {
+        Self::decode_with(|address| Some(memory.get(address)), address)
+    }
+
+    fn decode_with(read: impl Fn(usize) -> Option<i64>, address: usize) -> Option<Self> {
+        let int = read(address)?;
+        let op = Op::try_from(int % 100).ok()?;
+
+        // like the VM, reject bad modes even for parameters the op doesn't have
+        let mut params = Vec::with_capacity(op.params());
+        for (i, digit) in [100, 1000, 10000].into_iter().enumerate() {
+            let mode = Mode::try_from(int / digit % 10).ok()?;
+            if i < op.params() {
+                params.push((mode, read(address + 1 + i)?));
+            }
+        }
+        if let Some(i) = op.output_param() {
+            if params[i].0 == Mode::Immediate {
+                return None;
+            }
+        }
+
+        Some(Self { op, params })
+    }
+
+    /// The number of intcodes the instruction takes up.
+    pub fn size(&self) -> usize {
+        1 + self.params.len()
+    }
+
+    /// Where a jump goes, when it is known without running the program.
+    pub fn jump_target(&self) -> Option<usize> {
+        match (self.op, self.params.get(1)) {
+            (Op::JumpIfNotZero | Op::JumpIfZero, Some(&(Mode::Immediate, target))) => {
+                usize::try_from(target).ok()
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the next instruction can run after this one.
+    pub fn falls_through(&self) -> bool {
+        match (self.op, self.params.first()) {
+            (Op::Halt, _) => false,
+            (Op::JumpIfNotZero, Some(&(Mode::Immediate, value))) => value == 0,
+            (Op::JumpIfZero, Some(&(Mode::Immediate, value))) => value != 0,
+            _ => true,
+        }
+    }
+
+    fn write(&self, f: &mut impl Write, labels: &BTreeSet<usize>) -> fmt::Result {
+        write!(f, "{}", self.op.mnemonic())?;
+        for (i, &(mode, value)) in self.params.iter().enumerate() {
+            f.write_str(if i == 0 { " " } else { ", " })?;
+            match mode {
+                Mode::Position => write!(f, "{}", value)?,
+                Mode::Immediate => match self.jump_target() {
+                    Some(target) if i == 1 && labels.contains(&target) => {
+                        write!(f, "#{}", label(target))?
+                    }
+                    _ => write!(f, "#{}", value)?,
+                },
+                Mode::Relative => write!(f, "@{}", value)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, &BTreeSet::new())
+    }
+}
+
+fn label(address: usize) -> String {
+    format!("L{}", address)
+}
+
+fn join(intcodes: &[i64], separator: &str) -> String {
+    intcodes
+        .iter()
+        .map(i64::to_string)
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+// the instructions reachable from address 0, following jumps whose target is immediate
+fn trace(intcodes: &[i64]) -> BTreeMap<usize, Instruction> {
+    let mut code = BTreeMap::new();
+    let mut covered = vec![false; intcodes.len()];
+    let mut pending = vec![0];
+
+    while let Some(mut address) = pending.pop() {
+        while let Some(instruction) = Instruction::decode(intcodes, address) {
+            let end = address + instruction.size();
+            if covered[address..end].contains(&true) {
+                break;
+            }
+            covered[address..end].fill(true);
+
+            pending.extend(instruction.jump_target());
+            let falls_through = instruction.falls_through();
+            code.insert(address, instruction);
+            if !falls_through {
+                break;
+            }
+            address = end;
+        }
+    }
+
+    code
+}
+
+/// Lists a program one instruction per line, with modes written as `#1` for
+/// immediate and `@1` for relative parameters, and the address and intcodes
+/// of each line in a trailing comment. Jump targets get a label.
+///
+/// Only code reachable from address 0 is decoded. Jumps through memory can't
+/// be followed without running the program, so anything only they reach is
+/// listed as `data`, as is everything the VM couldn't execute.
+pub fn disassemble(intcodes: &[i64]) -> String {
+    let code = trace(intcodes);
+    let labels: BTreeSet<usize> = code
+        .values()
+        .filter_map(Instruction::jump_target)
+        .filter(|target| code.contains_key(target))
+        .collect();
+
+    let mut listing = String::new();
+    let mut address = 0;
+    while address < intcodes.len() {
+        let mut text = String::new();
+        let (size, comment) = match code.get(&address) {
+            Some(instruction) => {
+                instruction.write(&mut text, &labels).unwrap();
+                let raw = &intcodes[address..address + instruction.size()];
+                (
+                    instruction.size(),
+                    format!("{}: {}", address, join(raw, ",")),
+                )
+            }
+            None => {
+                let size = (address..intcodes.len())
+                    .take_while(|next| !code.contains_key(next))
+                    .take(8)
+                    .count();
+                let data = &intcodes[address..address + size];
+                text = format!("data {}", join(data, ", "));
+                (size, address.to_string())
+            }
+        };
+
+        let label = if labels.contains(&address) {
+            format!("{}:", label(address))
+        } else {
+            String::new()
+        };
+        writeln!(listing, "{:<8}{:<32}; {}", label, text, comment).unwrap();
+        address += size;
+    }
+
+    listing
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        let decode = |intcodes: &[i64]| Instruction::decode(intcodes, 0).map(|i| i.to_string());
+        assert_eq!(decode(&[1001, 9, 10, 9]), Some("add 9, #10, 9".to_string()));
+        assert_eq!(decode(&[204, -3]), Some("out @-3".to_string()));
+        assert_eq!(decode(&[99]), Some("hlt".to_string()));
+        assert_eq!(decode(&[1, 0, 0]), None);
+        assert_eq!(decode(&[11101, 1, 1, 0]), None);
+        assert_eq!(decode(&[399]), None);
+        assert_eq!(decode(&[42]), None);
+    }
+
+    #[test]
+    fn test_disassemble() {
+        let listing = disassemble(&[1105, 1, 4, 99, 1101, 2, 3, 9, 99, 0, 1, 2]);
+        let lines: Vec<_> = listing.lines().map(str::trim_end).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "        jnz #1, #L4                     ; 0: 1105,1,4",
+                "        data 99                         ; 3",
+                "L4:     add #2, #3, 9                   ; 4: 1101,2,3,9",
+                "        hlt                             ; 8: 99",
+                "        data 0, 1, 2                    ; 9",
+            ]
+        );
+    }
+}
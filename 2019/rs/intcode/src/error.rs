@@ -0,0 +1,49 @@
+use std::fmt;
+
+/// Why a program stopped, and the address of the instruction that failed.
+/// `pc` is left pointing at that instruction, so a caller can patch memory
+/// and run again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VmError {
+    pub pc: usize,
+    pub kind: ErrorKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidOpcode(i64),
+    InvalidMode(i64),
+    NegativeAddress(i64),
+    /// A write past the highest address the memory can hold.
+    AddressOutOfRange(usize),
+    WriteToImmediate,
+    /// A result too big for an intcode.
+    Overflow,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidOpcode(opcode) => write!(f, "invalid opcode {}", opcode),
+            Self::InvalidMode(mode) => write!(f, "invalid parameter mode {}", mode),
+            Self::NegativeAddress(address) => write!(f, "negative address {}", address),
+            Self::AddressOutOfRange(address) => write!(f, "address {} out of range", address),
+            Self::WriteToImmediate => write!(f, "write to an immediate mode parameter"),
+            Self::Overflow => write!(f, "arithmetic overflow"),
+        }
+    }
+}
+
+impl fmt::Display for VmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "pc {}: {}", self.pc, self.kind)
+    }
+}
+
+impl std::error::Error for VmError {}
+
+impl From<VmError> for aoc_core::Error {
+    fn from(err: VmError) -> Self {
+        Self::new(err.to_string())
+    }
+}
@@ -1,24 +1,50 @@
-use std::collections::VecDeque;
-
-#[derive(Clone, Copy, Debug)]
-enum Mode {
+use aoc_core::Error;
+use compile::{Decoded, Dirty, Param};
+use std::{collections::VecDeque, ops::ControlFlow, sync::Arc};
+
+pub mod ascii;
+pub mod asm;
+mod compile;
+#[cfg(test)]
+mod conformance;
+pub mod debug;
+pub mod disasm;
+mod error;
+mod memory;
+pub mod network;
+mod sink;
+mod snapshot;
+pub mod trace;
+
+pub use compile::Code;
+pub use error::{ErrorKind, VmError};
+pub use memory::{Dense, Memory, Paged, Sparse};
+pub use sink::Sink;
+pub use snapshot::{Change, Snapshot};
+use trace::Trace;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
     Position,
     Immediate,
     Relative,
 }
 
-impl From<i64> for Mode {
-    fn from(value: i64) -> Self {
+impl TryFrom<i64> for Mode {
+    type Error = ErrorKind;
+
+    fn try_from(value: i64) -> Result<Self, ErrorKind> {
         match value {
-            0 => Self::Position,
-            1 => Self::Immediate,
-            2 => Self::Relative,
-            _ => unreachable!(),
+            0 => Ok(Self::Position),
+            1 => Ok(Self::Immediate),
+            2 => Ok(Self::Relative),
+            _ => Err(ErrorKind::InvalidMode(value)),
         }
     }
 }
 
-enum Op {
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Op {
     Add,
     Multiply,
     Input,
@@ -31,27 +57,110 @@ enum Op {
     Halt,
 }
 
-impl From<i64> for Op {
-    fn from(value: i64) -> Self {
+impl TryFrom<i64> for Op {
+    type Error = ErrorKind;
+
+    fn try_from(value: i64) -> Result<Self, ErrorKind> {
         match value {
-            1 => Self::Add,
-            2 => Self::Multiply,
-            3 => Self::Input,
-            4 => Self::Output,
-            5 => Self::JumpIfNotZero,
-            6 => Self::JumpIfZero,
-            7 => Self::LessThan,
-            8 => Self::Equal,
-            9 => Self::AdjustRelativeBase,
-            99 => Self::Halt,
-            _ => unreachable!("unexpected op: {value}"),
+            1 => Ok(Self::Add),
+            2 => Ok(Self::Multiply),
+            3 => Ok(Self::Input),
+            4 => Ok(Self::Output),
+            5 => Ok(Self::JumpIfNotZero),
+            6 => Ok(Self::JumpIfZero),
+            7 => Ok(Self::LessThan),
+            8 => Ok(Self::Equal),
+            9 => Ok(Self::AdjustRelativeBase),
+            99 => Ok(Self::Halt),
+            _ => Err(ErrorKind::InvalidOpcode(value)),
         }
     }
 }
 
+impl Op {
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Self::Add => "add",
+            Self::Multiply => "mul",
+            Self::Input => "in",
+            Self::Output => "out",
+            Self::JumpIfNotZero => "jnz",
+            Self::JumpIfZero => "jz",
+            Self::LessThan => "lt",
+            Self::Equal => "eq",
+            Self::AdjustRelativeBase => "arb",
+            Self::Halt => "hlt",
+        }
+    }
+
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Self> {
+        [
+            Self::Add,
+            Self::Multiply,
+            Self::Input,
+            Self::Output,
+            Self::JumpIfNotZero,
+            Self::JumpIfZero,
+            Self::LessThan,
+            Self::Equal,
+            Self::AdjustRelativeBase,
+            Self::Halt,
+        ]
+        .into_iter()
+        .find(|op| op.mnemonic() == mnemonic)
+    }
+
+    pub fn opcode(self) -> i64 {
+        match self {
+            Self::Add => 1,
+            Self::Multiply => 2,
+            Self::Input => 3,
+            Self::Output => 4,
+            Self::JumpIfNotZero => 5,
+            Self::JumpIfZero => 6,
+            Self::LessThan => 7,
+            Self::Equal => 8,
+            Self::AdjustRelativeBase => 9,
+            Self::Halt => 99,
+        }
+    }
+
+    /// How many parameters follow the opcode.
+    pub fn params(self) -> usize {
+        match self {
+            Self::Add | Self::Multiply | Self::LessThan | Self::Equal => 3,
+            Self::JumpIfNotZero | Self::JumpIfZero => 2,
+            Self::Input | Self::Output | Self::AdjustRelativeBase => 1,
+            Self::Halt => 0,
+        }
+    }
+
+    /// The parameter the result is written to, if there is one.
+    pub fn output_param(self) -> Option<usize> {
+        match self {
+            Self::Add | Self::Multiply | Self::LessThan | Self::Equal => Some(2),
+            Self::Input => Some(0),
+            _ => None,
+        }
+    }
+}
+
+/// Why the VM stopped running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Halted,
+    /// Stopped at an input instruction with nothing to read. Running again
+    /// after `write_input` picks up where it left off.
+    NeedsInput,
+    /// Only returned by `run_until_output`, with the value written.
+    Output(i64),
+}
+
 #[derive(Debug, Clone)]
-pub struct VM {
-    intcodes: Vec<i64>,
+pub struct VM<M = Dense> {
+    intcodes: M,
+    // the program as loaded, for `reset`
+    image: M,
     pc: usize,
     input: VecDeque<i64>,
     output: VecDeque<i64>,
@@ -60,14 +169,66 @@ pub struct VM {
     mode_two: Mode,
     mode_three: Mode,
 
-    pub halted: bool,
+    halted: bool,
     relative_base: i64,
+
+    trace: Option<Box<Trace>>,
+
+    // the program decoded ahead of time, shared by clones
+    code: Option<Arc<Code>>,
+    dirty: Dirty,
+}
+
+fn checked(value: Option<i64>) -> Result<i64, ErrorKind> {
+    value.ok_or(ErrorKind::Overflow)
 }
 
 impl From<Vec<i64>> for VM {
     fn from(intcodes: Vec<i64>) -> Self {
+        Self::new(Dense::from(intcodes))
+    }
+}
+
+impl TryFrom<&str> for VM {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Error> {
+        Self::parse_intcodes(input).map(Self::from)
+    }
+}
+
+impl From<&Vec<i64>> for VM {
+    fn from(intcodes: &Vec<i64>) -> Self {
+        Self::from(intcodes.clone())
+    }
+}
+
+impl VM {
+    pub fn parse_intcodes(input: &str) -> Result<Vec<i64>, Error> {
+        let mut column = 1;
+        input
+            .trim_end()
+            .split(',')
+            .map(|int| {
+                let start = column + int.len() - int.trim_start().len();
+                column += int.len() + 1;
+                int.trim().parse().map_err(|_| {
+                    Error::new(format!("invalid intcode `{}`", int.trim()))
+                        .at_line(1)
+                        .at_column(start)
+                })
+            })
+            .collect()
+    }
+}
+
+impl<M: Memory> VM<M> {
+    /// A VM running the program in `memory`. `VM::from` makes one with
+    /// `Dense` memory.
+    pub fn new(memory: M) -> Self {
         Self {
-            intcodes,
+            image: memory.clone(),
+            intcodes: memory,
             pc: 0,
             input: Default::default(),
             output: Default::default(),
@@ -76,167 +237,690 @@ impl From<Vec<i64>> for VM {
             mode_three: Mode::Position,
             halted: false,
             relative_base: 0,
+            trace: None,
+            code: None,
+            dirty: Dirty::default(),
         }
     }
-}
 
-impl From<&str> for VM {
-    fn from(input: &str) -> Self {
-        Self::from(Self::parse_intcodes(input))
+    pub fn pc(&self) -> usize {
+        self.pc
     }
-}
 
-impl From<&Vec<i64>> for VM {
-    fn from(intcodes: &Vec<i64>) -> Self {
-        Self::from(intcodes.clone())
+    pub fn relative_base(&self) -> i64 {
+        self.relative_base
     }
-}
 
-impl VM {
-    pub fn parse_intcodes(input: &str) -> Vec<i64> {
-        input
-            .trim()
-            .split(',')
-            .flat_map(str::parse)
-            .collect::<Vec<i64>>()
+    pub fn memory(&self) -> &M {
+        &self.intcodes
+    }
+
+    /// The state of the VM. It holds a clone of the memory, which is cheap
+    /// with `Paged` or `Sparse` memory as they share pages with the VM.
+    pub fn snapshot(&self) -> Snapshot<M> {
+        Snapshot {
+            memory: self.intcodes.clone(),
+            pc: self.pc,
+            relative_base: self.relative_base,
+            input: self.input.iter().copied().collect(),
+            output: self.output.iter().copied().collect(),
+            halted: self.halted,
+        }
+    }
+
+    /// A VM running `program`, which resets go back to, carrying on from
+    /// `snapshot`.
+    pub fn from_snapshot(program: M, snapshot: &Snapshot<M>) -> Self {
+        let mut vm = Self::new(program);
+        vm.restore(snapshot);
+        vm
     }
 
-    pub fn reset(&mut self, intcodes: &Vec<i64>) {
-        self.intcodes.copy_from_slice(intcodes);
+    /// Puts the VM back in the state of `snapshot`. A trace carries on.
+    pub fn restore(&mut self, snapshot: &Snapshot<M>) {
+        self.intcodes = snapshot.memory.clone();
+        self.pc = snapshot.pc;
+        self.relative_base = snapshot.relative_base;
+        self.input = snapshot.input.iter().copied().collect();
+        self.output = snapshot.output.iter().copied().collect();
+        self.halted = snapshot.halted;
+        self.mark_changes();
+    }
+
+    /// Puts the VM back in the state it was loaded in, reusing its memory.
+    pub fn reset(&mut self) {
+        self.intcodes.copy_from(&self.image);
         self.pc = 0;
+        self.relative_base = 0;
         self.input.clear();
         self.output.clear();
         self.halted = false;
+        self.dirty.clear();
+    }
+
+    /// Resets the VM, then writes each value to its address, like putting
+    /// quarters in the arcade cabinet on day 13 with `&[(0, 2)]`.
+    pub fn reset_with(&mut self, patches: &[(usize, i64)]) {
+        self.reset();
+        for &(address, value) in patches {
+            self.set(address, value);
+        }
+    }
+
+    /// Loads another program, which later resets go back to. It is compiled
+    /// if the last one was.
+    pub fn reload(&mut self, intcodes: &[i64]) {
+        self.image = M::from(intcodes);
+        self.reset();
+        if self.code.is_some() {
+            self.compile();
+        }
+    }
+
+    /// Decodes the loaded program ahead of time, which makes running it
+    /// faster, most of all when it is run over and over with `reset` or on
+    /// clones of the VM, which share the decoded program. Instructions the
+    /// program overwrites are decoded as they run, and tracing decodes
+    /// every instruction as it runs too.
+    pub fn compile(&mut self) {
+        let program: Vec<i64> = (0..self.image.len()).map(|i| self.image.get(i)).collect();
+        self.code = Some(Arc::new(Code::new(&program)));
+        self.mark_changes();
+    }
+
+    // marks where memory no longer holds the program that was compiled
+    fn mark_changes(&mut self) {
+        let Some(code) = &self.code else {
+            return;
+        };
+
+        self.dirty = Dirty::new(code.len());
+        for address in 0..code.len() {
+            if self.intcodes.get(address) != self.image.get(address) {
+                self.dirty.mark(address);
+            }
+        }
+    }
+
+    fn set(&mut self, address: usize, value: i64) {
+        self.intcodes.set(address, value);
+        if self.code.is_some() {
+            self.dirty.mark(address);
+        }
     }
 
     fn read_int(&mut self) -> i64 {
-        let code = self.intcodes[self.pc];
+        let code = self.read_memory(self.pc);
         self.pc += 1;
         code
     }
 
-    fn write_memory(&mut self, mode: Mode, value: i64) {
-        let at = self.read_int();
-        let index = match mode {
-            Mode::Immediate => unreachable!("cannot write memory in immediate mode"),
-            Mode::Relative => at + self.relative_base,
-            Mode::Position => at,
-        } as usize;
+    fn address(value: i64) -> Result<usize, ErrorKind> {
+        usize::try_from(value).map_err(|_| ErrorKind::NegativeAddress(value))
+    }
 
-        if self.intcodes.len() <= index {
-            self.intcodes.resize(index + 1, 0);
-        }
+    fn relative_address(&self, offset: i64) -> Result<usize, ErrorKind> {
+        Self::address(checked(self.relative_base.checked_add(offset))?)
+    }
 
-        self.intcodes[index] = value;
+    fn writable(address: usize) -> Result<usize, ErrorKind> {
+        if address > M::MAX_ADDRESS {
+            return Err(ErrorKind::AddressOutOfRange(address));
+        }
+        Ok(address)
     }
 
-    fn read_memory(&mut self, index: usize) -> i64 {
-        if self.intcodes.len() <= index {
-            self.intcodes.resize(index + 1, 0);
+    fn write_memory(&mut self, mode: Mode, value: i64) -> Result<(), ErrorKind> {
+        let at = self.read_int();
+        let index = match mode {
+            Mode::Immediate => return Err(ErrorKind::WriteToImmediate),
+            Mode::Relative => self.relative_address(at)?,
+            Mode::Position => Self::address(at)?,
+        };
+        let index = Self::writable(index)?;
+
+        self.set(index, value);
+        if let Some(trace) = &mut self.trace {
+            trace.write(index, value);
         }
+        Ok(())
+    }
 
-        self.intcodes[index]
+    fn read_memory(&self, index: usize) -> i64 {
+        self.intcodes.get(index)
     }
 
-    fn read_param(&mut self, mode: Mode) -> i64 {
+    fn read_param(&mut self, mode: Mode) -> Result<i64, ErrorKind> {
         let value = self.read_int();
 
-        match mode {
-            Mode::Position => self.read_memory(value as usize),
+        let value = match mode {
+            Mode::Position => self.read_memory(Self::address(value)?),
             Mode::Immediate => value,
-            Mode::Relative => self.read_memory((self.relative_base + value) as usize),
+            Mode::Relative => self.read_memory(self.relative_address(value)?),
+        };
+        if let Some(trace) = &mut self.trace {
+            trace.operand(value);
         }
+        Ok(value)
     }
 
-    fn read_op(&mut self) -> Op {
+    fn read_op(&mut self) -> Result<Op, ErrorKind> {
         let int = self.read_int();
 
-        self.mode_one = Mode::from(int / 100 % 10);
-        self.mode_two = Mode::from(int / 1000 % 10);
-        self.mode_three = Mode::from(int / 10000 % 10);
+        self.mode_one = Mode::try_from(int / 100 % 10)?;
+        self.mode_two = Mode::try_from(int / 1000 % 10)?;
+        self.mode_three = Mode::try_from(int / 10000 % 10)?;
 
-        Op::from(int % 100)
+        let op = Op::try_from(int % 100)?;
+        if let Some(trace) = &mut self.trace {
+            trace.begin(self.pc - 1, op);
+        }
+        Ok(op)
     }
 
     fn read_input(&mut self) -> Option<i64> {
-        self.input.pop_back()
+        self.input.pop_front()
+    }
+
+    // puts back input read by an instruction that failed, so running it
+    // again reads the same value
+    fn unread_input(&mut self, value: i64) {
+        self.input.push_front(value)
     }
 
     fn write_output(&mut self, value: i64) {
-        self.output.push_front(value)
+        self.output.push_back(value)
     }
 
     pub fn write_input(&mut self, value: i64) {
-        self.input.push_front(value)
+        self.input.push_back(value)
     }
 
     pub fn read_output(&mut self) -> Option<i64> {
-        self.output.pop_back()
+        self.output.pop_front()
+    }
+
+    /// Runs until the program halts or needs input it has not been given,
+    /// queueing everything it writes for `read_output`.
+    pub fn run(&mut self) -> Result<Status, VmError> {
+        self.execute(false)
+    }
+
+    /// Like `run`, but also stops after each output, returning the value
+    /// instead of queueing it.
+    pub fn run_until_output(&mut self) -> Result<Status, VmError> {
+        self.execute(true)
     }
 
-    pub fn run(&mut self) {
+    /// Like `run`, but once the values from `write_input` run out, reads
+    /// from `input`, which can be anything from a `Vec` to a channel's
+    /// `Receiver` or `iter::from_fn` with a closure. Everything it writes
+    /// goes to `output` instead of being queued. Returns `NeedsInput` when
+    /// `input` runs out, so reading from a channel blocks until a value is
+    /// sent or every sender is dropped.
+    pub fn run_io(
+        &mut self,
+        input: impl IntoIterator<Item = i64>,
+        output: &mut impl Sink,
+    ) -> Result<Status, VmError> {
+        let mut input = input.into_iter();
+        loop {
+            match self.execute(true)? {
+                Status::Output(value) => output.write(value),
+                Status::NeedsInput => match input.next() {
+                    Some(value) => self.write_input(value),
+                    None => return Ok(Status::NeedsInput),
+                },
+                Status::Halted => return Ok(Status::Halted),
+            }
+        }
+    }
+
+    /// Like `run`, but calls `on_step` before each instruction. If it breaks,
+    /// the VM stops without running the instruction and the value it broke
+    /// with is returned.
+    pub fn run_with<B>(
+        &mut self,
+        mut on_step: impl FnMut(&Self) -> ControlFlow<B>,
+    ) -> Result<ControlFlow<B, Status>, VmError> {
         if self.halted {
-            return;
+            return Ok(ControlFlow::Continue(Status::Halted));
         }
 
         loop {
-            match self.read_op() {
-                Op::Add => {
-                    let a = self.read_param(self.mode_one);
-                    let b = self.read_param(self.mode_two);
-                    self.write_memory(self.mode_three, a + b);
-                }
-                Op::Multiply => {
-                    let a = self.read_param(self.mode_one);
-                    let b = self.read_param(self.mode_two);
-                    self.write_memory(self.mode_three, a * b);
-                }
-                Op::Input => match self.read_input() {
-                    Some(value) => self.write_memory(self.mode_one, value),
-                    _ => {
-                        self.pc -= 1;
-                        return;
-                    }
-                },
-                Op::Output => {
-                    let param = self.read_param(self.mode_one);
+            if let ControlFlow::Break(value) = on_step(self) {
+                return Ok(ControlFlow::Break(value));
+            }
+            if let Some(status) = self.tick(false)? {
+                return Ok(ControlFlow::Continue(status));
+            }
+        }
+    }
 
-                    self.write_output(param);
-                }
-                Op::JumpIfNotZero => {
-                    let a = self.read_param(self.mode_one);
-                    let b = self.read_param(self.mode_two);
+    /// Runs a single instruction, queueing its output. Returns the status
+    /// if the VM can't go on.
+    pub fn step(&mut self) -> Result<Option<Status>, VmError> {
+        if self.halted {
+            return Ok(Some(Status::Halted));
+        }
+        self.tick(false)
+    }
+
+    fn execute(&mut self, until_output: bool) -> Result<Status, VmError> {
+        if self.halted {
+            return Ok(Status::Halted);
+        }
+
+        loop {
+            if let Some(status) = self.tick(until_output)? {
+                return Ok(status);
+            }
+        }
+    }
 
-                    if a != 0 {
-                        self.pc = b as usize;
+    /// Starts recording what the program runs. The counters are always kept;
+    /// every instruction is only kept when `events` is set, as that takes a
+    /// lot of memory for long running programs.
+    pub fn start_trace(&mut self, events: bool) {
+        self.trace = Some(Box::new(Trace::new(events)));
+    }
+
+    pub fn trace(&self) -> Option<&Trace> {
+        self.trace.as_deref()
+    }
+
+    /// Stops recording, returning what was recorded.
+    pub fn take_trace(&mut self) -> Option<Trace> {
+        self.trace.take().map(|trace| *trace)
+    }
+
+    fn tick(&mut self, until_output: bool) -> Result<Option<Status>, VmError> {
+        let pc = self.pc;
+        let result = match self.decoded() {
+            Some(instruction) => self.execute_decoded(instruction),
+            None => self.execute_instruction(),
+        };
+        if let Some(trace) = &mut self.trace {
+            match result {
+                Ok(Some(Status::NeedsInput)) | Err(_) => trace.cancel(),
+                Ok(_) => trace.end(self.intcodes.len()),
+            }
+        }
+
+        match result {
+            Ok(Some(Status::Output(value))) if !until_output => {
+                self.write_output(value);
+                Ok(None)
+            }
+            Ok(status) => Ok(status),
+            Err(kind) => {
+                self.pc = pc;
+                Err(VmError { pc, kind })
+            }
+        }
+    }
+
+    // the compiled instruction at pc, unless it has to be decoded as it runs
+    fn decoded(&self) -> Option<Decoded> {
+        if self.trace.is_some() {
+            return None;
+        }
+        let instruction = self.code.as_ref()?.get(self.pc)?;
+        if self.dirty.any(self.pc, instruction.size) {
+            return None;
+        }
+        Some(*instruction)
+    }
+
+    fn operand(&self, param: Param) -> Result<i64, ErrorKind> {
+        Ok(match param {
+            Param::Position(address) => self.read_memory(address),
+            Param::Immediate(value) => value,
+            Param::Relative(offset) => self.read_memory(self.relative_address(offset)?),
+        })
+    }
+
+    fn target(&self, param: Param) -> Result<usize, ErrorKind> {
+        let address = match param {
+            Param::Position(address) => address,
+            Param::Immediate(_) => return Err(ErrorKind::WriteToImmediate),
+            Param::Relative(offset) => self.relative_address(offset)?,
+        };
+        Self::writable(address)
+    }
+
+    // `execute_instruction` for a compiled instruction
+    fn execute_decoded(&mut self, instruction: Decoded) -> Result<Option<Status>, ErrorKind> {
+        let [a, b, c] = instruction.params;
+        let pc = self.pc;
+        self.pc += instruction.size;
+
+        match instruction.op {
+            Op::Add => {
+                let value = checked(self.operand(a)?.checked_add(self.operand(b)?))?;
+                self.set(self.target(c)?, value);
+            }
+            Op::Multiply => {
+                let value = checked(self.operand(a)?.checked_mul(self.operand(b)?))?;
+                self.set(self.target(c)?, value);
+            }
+            Op::Input => match self.read_input() {
+                Some(value) => match self.target(a) {
+                    Ok(address) => self.set(address, value),
+                    Err(kind) => {
+                        self.unread_input(value);
+                        return Err(kind);
                     }
+                },
+                None => {
+                    self.pc = pc;
+                    return Ok(Some(Status::NeedsInput));
+                }
+            },
+            Op::Output => return Ok(Some(Status::Output(self.operand(a)?))),
+            Op::JumpIfNotZero => {
+                let (value, target) = (self.operand(a)?, self.operand(b)?);
+                if value != 0 {
+                    self.pc = Self::address(target)?;
                 }
-                Op::JumpIfZero => {
-                    let a = self.read_param(self.mode_one);
-                    let b = self.read_param(self.mode_two);
+            }
+            Op::JumpIfZero => {
+                let (value, target) = (self.operand(a)?, self.operand(b)?);
+                if value == 0 {
+                    self.pc = Self::address(target)?;
+                }
+            }
+            Op::LessThan => {
+                let value = self.operand(a)? < self.operand(b)?;
+                self.set(self.target(c)?, value as i64);
+            }
+            Op::Equal => {
+                let value = self.operand(a)? == self.operand(b)?;
+                self.set(self.target(c)?, value as i64);
+            }
+            Op::AdjustRelativeBase => {
+                self.relative_base = checked(self.relative_base.checked_add(self.operand(a)?))?;
+            }
+            Op::Halt => {
+                self.halted = true;
+                return Ok(Some(Status::Halted));
+            }
+        }
+
+        Ok(None)
+    }
 
-                    if a == 0 {
-                        self.pc = b as usize;
+    // executes one instruction, returning a status if it should stop the VM
+    fn execute_instruction(&mut self) -> Result<Option<Status>, ErrorKind> {
+        match self.read_op()? {
+            Op::Add => {
+                let a = self.read_param(self.mode_one)?;
+                let b = self.read_param(self.mode_two)?;
+                self.write_memory(self.mode_three, checked(a.checked_add(b))?)?;
+            }
+            Op::Multiply => {
+                let a = self.read_param(self.mode_one)?;
+                let b = self.read_param(self.mode_two)?;
+                self.write_memory(self.mode_three, checked(a.checked_mul(b))?)?;
+            }
+            Op::Input => match self.read_input() {
+                Some(value) => {
+                    if let Err(kind) = self.write_memory(self.mode_one, value) {
+                        self.unread_input(value);
+                        return Err(kind);
                     }
                 }
-                Op::LessThan => {
-                    let a = self.read_param(self.mode_one);
-                    let b = self.read_param(self.mode_two);
-                    self.write_memory(self.mode_three, if a < b { 1 } else { 0 })
-                }
-                Op::Equal => {
-                    let a = self.read_param(self.mode_one);
-                    let b = self.read_param(self.mode_two);
-                    self.write_memory(self.mode_three, if a == b { 1 } else { 0 })
+                _ => {
+                    self.pc -= 1;
+                    return Ok(Some(Status::NeedsInput));
                 }
-                Op::AdjustRelativeBase => {
-                    self.relative_base += self.read_param(self.mode_one);
+            },
+            Op::Output => {
+                let param = self.read_param(self.mode_one)?;
+
+                return Ok(Some(Status::Output(param)));
+            }
+            Op::JumpIfNotZero => {
+                let a = self.read_param(self.mode_one)?;
+                let b = self.read_param(self.mode_two)?;
+
+                if a != 0 {
+                    self.pc = Self::address(b)?;
                 }
-                Op::Halt => {
-                    self.halted = true;
-                    return;
+            }
+            Op::JumpIfZero => {
+                let a = self.read_param(self.mode_one)?;
+                let b = self.read_param(self.mode_two)?;
+
+                if a == 0 {
+                    self.pc = Self::address(b)?;
                 }
             }
+            Op::LessThan => {
+                let a = self.read_param(self.mode_one)?;
+                let b = self.read_param(self.mode_two)?;
+                self.write_memory(self.mode_three, if a < b { 1 } else { 0 })?;
+            }
+            Op::Equal => {
+                let a = self.read_param(self.mode_one)?;
+                let b = self.read_param(self.mode_two)?;
+                self.write_memory(self.mode_three, if a == b { 1 } else { 0 })?;
+            }
+            Op::AdjustRelativeBase => {
+                let offset = self.read_param(self.mode_one)?;
+                self.relative_base = checked(self.relative_base.checked_add(offset))?;
+            }
+            Op::Halt => {
+                self.halted = true;
+                return Ok(Some(Status::Halted));
+            }
         }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{sync::mpsc, thread};
+
+    // echoes its input twice, then halts
+    const ECHO_TWICE: &[i64] = &[3, 9, 4, 9, 3, 9, 4, 9, 99, 0];
+
+    fn run(intcodes: Vec<i64>) -> Result<Status, VmError> {
+        VM::from(intcodes).run()
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |pc, kind| Err(VmError { pc, kind });
+        assert_eq!(
+            run(vec![1, 0, 0, 0, 42]),
+            error(4, ErrorKind::InvalidOpcode(42))
+        );
+        assert_eq!(run(vec![301, 0, 0, 0]), error(0, ErrorKind::InvalidMode(3)));
+        assert_eq!(
+            run(vec![1101, 1, 1, 0, 4, -1]),
+            error(4, ErrorKind::NegativeAddress(-1))
+        );
+        assert_eq!(
+            run(vec![11101, 1, 1, 0]),
+            error(0, ErrorKind::WriteToImmediate)
+        );
+        assert_eq!(
+            run(vec![1101, 1, 1, 9_000_000_000_000_000_000, 99]),
+            error(0, ErrorKind::AddressOutOfRange(9_000_000_000_000_000_000))
+        );
+        assert_eq!(
+            VM::new(Sparse::from(
+                &[1101, 1, 1, 9_000_000_000_000_000_000, 99][..]
+            ))
+            .run(),
+            Ok(Status::Halted)
+        );
+        assert_eq!(run(vec![1, 0, 0, 0, 99]), Ok(Status::Halted));
+    }
+
+    #[test]
+    fn test_resume_after_error() {
+        let mut vm = VM::from(vec![104, 7, 42, 99]);
+        assert_eq!(vm.run().unwrap_err().to_string(), "pc 2: invalid opcode 42");
+        assert_eq!(vm.read_output(), Some(7));
+
+        vm.intcodes.set(2, 99);
+        assert_eq!(vm.run(), Ok(Status::Halted));
+    }
+
+    #[test]
+    fn test_retry_input() {
+        // reads into a negative address, then outputs what it read
+        let program = vec![203, -1, 4, 5, 99, 0];
+        for compiled in [false, true] {
+            let mut vm = VM::from(program.clone());
+            if compiled {
+                vm.compile();
+            }
+            vm.write_input(7);
+            assert_eq!(
+                vm.run(),
+                Err(VmError {
+                    pc: 0,
+                    kind: ErrorKind::NegativeAddress(-1)
+                })
+            );
+
+            vm.set(1, 5);
+            assert_eq!(vm.run(), Ok(Status::Halted));
+            assert_eq!(vm.read_output(), Some(7));
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        // grows memory and moves the relative base before echoing its input
+        let program = vec![1101, 1, 2, 1000, 109, 7, 203, 0, 204, 0, 99];
+        let mut vm = VM::from(program.clone());
+        vm.write_input(5);
+        assert_eq!(vm.run(), Ok(Status::Halted));
+        assert_eq!(vm.read_output(), Some(5));
+        assert_eq!(vm.memory().len(), 1001);
+
+        vm.write_input(9);
+        vm.reset();
+        assert_eq!(vm.memory().to_vec(), program);
+        assert_eq!((vm.pc(), vm.relative_base()), (0, 0));
+        assert_eq!(vm.run(), Ok(Status::NeedsInput));
+        vm.write_input(6);
+        assert_eq!(vm.run(), Ok(Status::Halted));
+        assert_eq!(vm.read_output(), Some(6));
+
+        vm.reset_with(&[(1, 40)]);
+        vm.write_input(8);
+        assert_eq!(vm.run(), Ok(Status::Halted));
+        assert_eq!(vm.read_output(), Some(8));
+        assert_eq!(vm.memory()[1000], 42);
+        vm.reset();
+        assert_eq!((vm.memory()[1], vm.memory()[1000]), (1, 0));
+
+        vm.reload(&[104, 3, 99]);
+        assert_eq!(vm.run(), Ok(Status::Halted));
+        assert_eq!(vm.read_output(), Some(3));
+        vm.reset();
+        assert_eq!(vm.memory().to_vec(), vec![104, 3, 99]);
+    }
+
+    #[test]
+    fn test_compile() {
+        let programs = [
+            vec![1, 0, 0, 0, 42],
+            vec![1101, 1, 1, 0, 4, -1],
+            vec![109, -5, 204, 0, 99],
+            vec![1105, 1, -2],
+            vec![1101, 1, 2, 1000, 109, 7, 203, 0, 204, 0, 99],
+            // overwrites the halt at 7 with an output
+            vec![1101, 104, 0, 7, 1106, 0, 7, 99, 42, 99],
+        ];
+        for program in programs {
+            let mut vm = VM::from(program.clone());
+            vm.write_input(5);
+            let mut compiled = vm.clone();
+            compiled.compile();
+            assert_eq!(compiled.run(), vm.run());
+            assert_eq!(compiled.pc(), vm.pc());
+            assert_eq!(compiled.memory(), vm.memory());
+            assert_eq!(compiled.read_output(), vm.read_output());
+        }
+
+        // day 2 patches parameters, which have to be read again
+        let mut vm = VM::from(vec![1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50]);
+        vm.compile();
+        assert_eq!(vm.run(), Ok(Status::Halted));
+        assert_eq!(vm.memory()[0], 3500);
+        vm.reset_with(&[(1, 11)]);
+        assert_eq!(vm.run(), Ok(Status::Halted));
+        assert_eq!(vm.memory()[0], 4500);
+        vm.reset();
+        assert_eq!(vm.run(), Ok(Status::Halted));
+        assert_eq!(vm.memory()[0], 3500);
+    }
+
+    #[test]
+    fn test_status() {
+        let program = ECHO_TWICE.to_vec();
+        let mut vm = VM::from(program.clone());
+        assert_eq!(vm.run(), Ok(Status::NeedsInput));
+        vm.write_input(5);
+        assert_eq!(vm.run(), Ok(Status::NeedsInput));
+        assert_eq!(vm.read_output(), Some(5));
+        vm.write_input(6);
+        assert_eq!(vm.run(), Ok(Status::Halted));
+        assert_eq!(vm.read_output(), Some(6));
+        assert_eq!(vm.run(), Ok(Status::Halted));
+
+        let mut vm = VM::from(program);
+        vm.write_input(5);
+        vm.write_input(6);
+        assert_eq!(vm.run_until_output(), Ok(Status::Output(5)));
+        assert_eq!(vm.run_until_output(), Ok(Status::Output(6)));
+        assert_eq!(vm.read_output(), None);
+        assert_eq!(vm.run_until_output(), Ok(Status::Halted));
+    }
+
+    #[test]
+    fn test_run_io() {
+        let program = ECHO_TWICE.to_vec();
+        let mut vm = VM::from(program.clone());
+        let mut output = vec![];
+        assert_eq!(vm.run_io([5], &mut output), Ok(Status::NeedsInput));
+        vm.write_input(6);
+        vm.write_input(7);
+        assert_eq!(vm.run_io([], &mut output), Ok(Status::Halted));
+        assert_eq!(output, vec![5, 6]);
+
+        let mut vm = VM::from(program.clone());
+        vm.write_input(1);
+        vm.write_input(2);
+        assert_eq!(vm.run(), Ok(Status::Halted));
+        assert_eq!((vm.read_output(), vm.read_output()), (Some(1), Some(2)));
+
+        // a pipeline of three echoes, each on its own thread
+        let (first, mut input) = mpsc::channel();
+        let vms = vec![VM::from(program); 3];
+        let output = thread::scope(|scope| {
+            for mut vm in vms {
+                let (mut sender, receiver) = mpsc::channel();
+                let input = std::mem::replace(&mut input, receiver);
+                scope.spawn(move || vm.run_io(&input, &mut sender));
+            }
+            first.send(3).unwrap();
+            first.send(4).unwrap();
+            let mut output = 0;
+            for value in &input {
+                output = output * 10 + value;
+            }
+            output
+        });
+        assert_eq!(output, 34);
     }
 }
@@ -0,0 +1,131 @@
+mod repl;
+
+use intcode::{ascii::Ascii, asm, disasm, trace::Trace, Status, VM};
+use std::{
+    env, fs,
+    io::{self, BufRead, Read, Write},
+    process,
+};
+
+const USAGE: &str = "usage:
+    intcode disasm [path]    list the program in path, or on stdin
+    intcode asm [path]       assemble the source in path, or on stdin
+    intcode debug <path>     step through the program in path
+    intcode play <path>      run the text program in path, typing its input
+    intcode trace <path> [input]...
+                             run the program, listing every instruction it runs
+    intcode profile <path> [input]...
+                             run the program, counting the instructions it runs";
+
+fn read(path: Option<&str>) -> Result<String, String> {
+    match path {
+        Some(path) => fs::read_to_string(path).map_err(|err| format!("{}: {}", path, err)),
+        None => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|err| err.to_string())?;
+            Ok(input)
+        }
+    }
+}
+
+fn read_intcodes(path: Option<&str>) -> Result<Vec<i64>, String> {
+    VM::parse_intcodes(&read(path)?).map_err(|err| err.to_string())
+}
+
+fn disasm(path: Option<&str>) -> Result<(), String> {
+    print!("{}", disasm::disassemble(&read_intcodes(path)?));
+    Ok(())
+}
+
+fn asm(path: Option<&str>) -> Result<(), String> {
+    let intcodes = asm::assemble(&read(path)?).map_err(|err| err.to_string())?;
+    let intcodes: Vec<String> = intcodes.iter().map(i64::to_string).collect();
+    println!("{}", intcodes.join(","));
+    Ok(())
+}
+
+// runs the program in `path` with a trace, giving it `input`
+fn traced(path: &str, input: &[&str], events: bool) -> Result<Trace, String> {
+    let mut vm = VM::from(read_intcodes(Some(path))?);
+    for value in input {
+        let value = value
+            .parse()
+            .map_err(|_| format!("invalid input `{}`", value))?;
+        vm.write_input(value);
+    }
+
+    vm.start_trace(events);
+    let status = vm.run().map_err(|err| err.to_string())?;
+    if status == Status::NeedsInput {
+        eprintln!("the program is waiting for more input");
+    }
+    Ok(vm.take_trace().unwrap())
+}
+
+fn trace(path: &str, input: &[&str]) -> Result<(), String> {
+    let mut stdout = io::stdout().lock();
+    for event in traced(path, input, true)?.events {
+        writeln!(stdout, "{}", event).map_err(|err| err.to_string())?;
+    }
+    Ok(())
+}
+
+fn profile(path: &str, input: &[&str]) -> Result<(), String> {
+    let profile = traced(path, input, false)?.profile;
+    println!("instructions: {}", profile.instructions);
+    println!("memory high-water mark: {}", profile.high_water);
+    println!("hottest instructions:");
+    for (pc, hits) in profile.hottest(10) {
+        println!("{:>8}: {} runs", pc, hits);
+    }
+    Ok(())
+}
+
+fn play(path: &str) -> Result<(), String> {
+    let mut ascii = Ascii::from(VM::from(read_intcodes(Some(path))?));
+    let mut stdin = io::stdin().lock();
+    loop {
+        let text = ascii.read_to_stop().map_err(|err| err.to_string())?;
+        print!("{}", text);
+        for answer in ascii.take_answers() {
+            println!("answer: {}", answer);
+        }
+        if ascii.status() == Some(Status::Halted) {
+            return Ok(());
+        }
+
+        io::stdout().flush().map_err(|err| err.to_string())?;
+        let mut line = String::new();
+        if stdin.read_line(&mut line).map_err(|err| err.to_string())? == 0 {
+            return Ok(());
+        }
+        ascii.write_line(line.trim_end_matches(['\r', '\n']));
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let result = match args.as_slice() {
+        ["disasm"] => disasm(None),
+        ["disasm", path] => disasm(Some(path)),
+        ["asm"] => asm(None),
+        ["asm", path] => asm(Some(path)),
+        ["debug", path] => read_intcodes(Some(path)).and_then(repl::run),
+        ["play", path] => play(path),
+        ["trace", path, input @ ..] => trace(path, input),
+        ["profile", path, input @ ..] => profile(path, input),
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,481 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Index,
+    sync::Arc,
+};
+
+const PAGE_SIZE: usize = 512;
+
+type Page = [i64; PAGE_SIZE];
+
+static ZERO: Page = [0; PAGE_SIZE];
+
+/// Where a VM keeps its memory, picked when it is made with `VM::new`.
+/// Addresses never written to read as 0.
+pub trait Memory: Clone + fmt::Debug + for<'a> From<&'a [i64]> {
+    /// The highest address a program can write to, so writing far past its
+    /// end is an error instead of allocating everything up to there.
+    const MAX_ADDRESS: usize = usize::MAX;
+
+    fn get(&self, address: usize) -> i64;
+
+    fn set(&mut self, address: usize, value: i64);
+
+    /// One past the highest address written to or loaded.
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The values stored, in runs by the address each starts at, in order.
+    /// Addresses outside every run read as 0.
+    fn runs(&self) -> Vec<(usize, &[i64])>;
+
+    /// Makes `self` read the same as `source`, reusing what it has allocated
+    /// where it can.
+    fn copy_from(&mut self, source: &Self);
+
+    /// The addresses that read differently in `self` and `other`, with the
+    /// values they read in each.
+    fn diff(&self, other: &Self) -> Vec<(usize, i64, i64)> {
+        (0..self.len().max(other.len()))
+            .filter_map(|address| {
+                let (old, new) = (self.get(address), other.get(address));
+                (old != new).then_some((address, old, new))
+            })
+            .collect()
+    }
+}
+
+// copies `source` into `page`, unless it is shared and has to be replaced
+fn copy_page(page: &mut Arc<Page>, source: &Arc<Page>) {
+    match Arc::get_mut(page) {
+        Some(page) => page.copy_from_slice(&source[..]),
+        None => *page = Arc::clone(source),
+    }
+}
+
+// the run holding page `index`, without its trailing zeroes, unless it is
+// all zeroes
+fn page_run(index: usize, page: &Page) -> Option<(usize, &[i64])> {
+    let end = page.iter().rposition(|&value| value != 0)?;
+    Some((index * PAGE_SIZE, &page[..=end]))
+}
+
+fn diff_page(index: usize, old: &Page, new: &Page, changes: &mut Vec<(usize, i64, i64)>) {
+    // pages still shared aren't compared
+    if std::ptr::eq(old, new) {
+        return;
+    }
+    for (offset, (&old, &new)) in old.iter().zip(new).enumerate() {
+        if old != new {
+            changes.push((index * PAGE_SIZE + offset, old, new));
+        }
+    }
+}
+
+fn new_page(chunk: &[i64]) -> Arc<Page> {
+    let mut page = ZERO;
+    page[..chunk.len()].copy_from_slice(chunk);
+    Arc::new(page)
+}
+
+/// Memory for every address up to the highest one used, the default. It is a
+/// plain `Vec`, the fastest to run on, but cloning it copies all of it.
+///
+/// Two memories are equal when every address reads the same.
+#[derive(Clone, Default)]
+pub struct Dense(Vec<i64>);
+
+impl From<&[i64]> for Dense {
+    fn from(intcodes: &[i64]) -> Self {
+        Self(intcodes.to_vec())
+    }
+}
+
+impl From<Vec<i64>> for Dense {
+    fn from(intcodes: Vec<i64>) -> Self {
+        Self(intcodes)
+    }
+}
+
+impl Memory for Dense {
+    // 128 MiB of intcodes
+    const MAX_ADDRESS: usize = (1 << 24) - 1;
+
+    #[inline]
+    fn get(&self, address: usize) -> i64 {
+        self.0.get(address).copied().unwrap_or(0)
+    }
+
+    #[inline]
+    fn set(&mut self, address: usize, value: i64) {
+        if self.0.len() <= address {
+            self.0.resize(address + 1, 0);
+        }
+        self.0[address] = value;
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn runs(&self) -> Vec<(usize, &[i64])> {
+        vec![(0, &self.0)]
+    }
+
+    fn copy_from(&mut self, source: &Self) {
+        self.0.clone_from(&source.0);
+    }
+}
+
+impl Dense {
+    pub fn iter(&self) -> impl Iterator<Item = i64> + '_ {
+        self.0.iter().copied()
+    }
+
+    pub fn to_vec(&self) -> Vec<i64> {
+        self.0.clone()
+    }
+
+    // trailing zeroes are left out, as they read the same as no memory
+    fn trimmed(&self) -> &[i64] {
+        let end = self.0.iter().rposition(|&value| value != 0);
+        &self.0[..end.map_or(0, |end| end + 1)]
+    }
+}
+
+impl Index<usize> for Dense {
+    type Output = i64;
+
+    fn index(&self, address: usize) -> &i64 {
+        self.0.get(address).unwrap_or(&0)
+    }
+}
+
+impl PartialEq for Dense {
+    fn eq(&self, other: &Self) -> bool {
+        self.trimmed() == other.trimmed()
+    }
+}
+
+impl Eq for Dense {}
+
+impl Hash for Dense {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.trimmed().hash(state);
+    }
+}
+
+impl fmt::Debug for Dense {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Memory split into pages that clones share until one of them writes to a
+/// page, so forking a VM only copies the pages the fork changes. Running on
+/// it is a little slower than on `Dense`, so it is for programs that are
+/// forked or snapshotted a lot, picked with `VM::new(Paged::from(..))`.
+///
+/// Two memories are equal when every address reads the same.
+#[derive(Clone, Default)]
+pub struct Paged {
+    pages: Vec<Arc<Page>>,
+    len: usize,
+}
+
+impl From<&[i64]> for Paged {
+    fn from(intcodes: &[i64]) -> Self {
+        let pages = intcodes.chunks(PAGE_SIZE).map(new_page).collect();
+
+        Self {
+            pages,
+            len: intcodes.len(),
+        }
+    }
+}
+
+impl From<Vec<i64>> for Paged {
+    fn from(intcodes: Vec<i64>) -> Self {
+        Self::from(intcodes.as_slice())
+    }
+}
+
+impl Memory for Paged {
+    // 128 MiB of page pointers
+    const MAX_ADDRESS: usize = (1 << 33) - 1;
+
+    #[inline]
+    fn get(&self, address: usize) -> i64 {
+        match self.pages.get(address / PAGE_SIZE) {
+            Some(page) => page[address % PAGE_SIZE],
+            None => 0,
+        }
+    }
+
+    #[inline]
+    fn set(&mut self, address: usize, value: i64) {
+        let index = address / PAGE_SIZE;
+        if self.pages.len() <= index {
+            // the new pages share one page of zeroes until they are written to
+            self.pages.resize(index + 1, Arc::new(ZERO));
+        }
+
+        Arc::make_mut(&mut self.pages[index])[address % PAGE_SIZE] = value;
+        self.len = self.len.max(address + 1);
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn copy_from(&mut self, source: &Self) {
+        self.pages.truncate(source.pages.len());
+        for (index, page) in source.pages.iter().enumerate() {
+            match self.pages.get_mut(index) {
+                Some(own) => copy_page(own, page),
+                None => self.pages.push(Arc::clone(page)),
+            }
+        }
+        self.len = source.len;
+    }
+
+    fn runs(&self) -> Vec<(usize, &[i64])> {
+        self.pages
+            .iter()
+            .enumerate()
+            .filter_map(|(index, page)| page_run(index, page))
+            .collect()
+    }
+
+    fn diff(&self, other: &Self) -> Vec<(usize, i64, i64)> {
+        let mut changes = vec![];
+        for index in 0..self.pages.len().max(other.pages.len()) {
+            diff_page(index, self.page(index), other.page(index), &mut changes);
+        }
+        changes
+    }
+}
+
+impl Paged {
+    fn page(&self, index: usize) -> &Page {
+        self.pages.get(index).map_or(&ZERO, |page| page.as_ref())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = i64> + '_ {
+        self.pages
+            .iter()
+            .flat_map(|page| page.iter().copied())
+            .take(self.len)
+    }
+
+    pub fn to_vec(&self) -> Vec<i64> {
+        self.iter().collect()
+    }
+}
+
+impl Index<usize> for Paged {
+    type Output = i64;
+
+    fn index(&self, address: usize) -> &i64 {
+        &self.page(address / PAGE_SIZE)[address % PAGE_SIZE]
+    }
+}
+
+impl PartialEq for Paged {
+    fn eq(&self, other: &Self) -> bool {
+        (0..self.pages.len().max(other.pages.len())).all(|index| {
+            let (a, b) = (self.page(index), other.page(index));
+            std::ptr::eq(a, b) || a == b
+        })
+    }
+}
+
+impl Eq for Paged {}
+
+impl Hash for Paged {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // trailing zeroes are left out, as they read the same as no memory
+        let end = (0..self.len)
+            .rev()
+            .find(|&address| self.get(address) != 0)
+            .map_or(0, |address| address + 1);
+        state.write_usize(end);
+        for value in self.iter().take(end) {
+            value.hash(state);
+        }
+    }
+}
+
+impl fmt::Debug for Paged {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Memory that only has the pages a program uses, for programs writing to
+/// addresses far past their end. It is slower than `Dense`, as every access
+/// looks its page up, but clones share pages the same way as `Paged`.
+#[derive(Debug, Clone, Default)]
+pub struct Sparse {
+    pages: HashMap<usize, Arc<Page>>,
+    len: usize,
+}
+
+impl From<&[i64]> for Sparse {
+    fn from(intcodes: &[i64]) -> Self {
+        let pages = intcodes
+            .chunks(PAGE_SIZE)
+            .map(new_page)
+            .enumerate()
+            .collect();
+
+        Self {
+            pages,
+            len: intcodes.len(),
+        }
+    }
+}
+
+impl Memory for Sparse {
+    #[inline]
+    fn get(&self, address: usize) -> i64 {
+        self.pages
+            .get(&(address / PAGE_SIZE))
+            .map_or(0, |page| page[address % PAGE_SIZE])
+    }
+
+    #[inline]
+    fn set(&mut self, address: usize, value: i64) {
+        let page = self
+            .pages
+            .entry(address / PAGE_SIZE)
+            .or_insert_with(|| Arc::new(ZERO));
+        Arc::make_mut(page)[address % PAGE_SIZE] = value;
+        self.len = self.len.max(address + 1);
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn copy_from(&mut self, source: &Self) {
+        self.pages
+            .retain(|index, _| source.pages.contains_key(index));
+        for (&index, page) in &source.pages {
+            match self.pages.get_mut(&index) {
+                Some(own) => copy_page(own, page),
+                None => {
+                    self.pages.insert(index, Arc::clone(page));
+                }
+            }
+        }
+        self.len = source.len;
+    }
+
+    fn runs(&self) -> Vec<(usize, &[i64])> {
+        let mut runs: Vec<_> = self
+            .pages
+            .iter()
+            .filter_map(|(&index, page)| page_run(index, page))
+            .collect();
+        runs.sort_unstable_by_key(|&(start, _)| start);
+        runs
+    }
+
+    fn diff(&self, other: &Self) -> Vec<(usize, i64, i64)> {
+        let mut indices: Vec<usize> = self
+            .pages
+            .keys()
+            .chain(other.pages.keys())
+            .copied()
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+
+        let mut changes = vec![];
+        for index in indices {
+            diff_page(index, self.page(index), other.page(index), &mut changes);
+        }
+        changes
+    }
+}
+
+impl Sparse {
+    fn page(&self, index: usize) -> &Page {
+        self.pages.get(&index).map_or(&ZERO, |page| page.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dense() {
+        let mut memory = Dense::from(vec![1, 2, 3]);
+        memory.set(10, 4);
+        assert_eq!(memory.len(), 11);
+        assert_eq!((memory[1], memory[5], memory.get(10_000)), (2, 0, 0));
+        assert_eq!(
+            memory.diff(&Dense::from(vec![1, 5, 3])),
+            vec![(1, 2, 5), (10, 4, 0)]
+        );
+
+        let mut copy = Dense::default();
+        copy.copy_from(&memory);
+        assert_eq!(copy, memory);
+        assert_eq!(Dense::from(vec![1, 0]), Dense::from(vec![1]));
+        assert_ne!(Dense::from(vec![1, 0]), Dense::from(vec![1, 0, 1]));
+    }
+
+    #[test]
+    fn test_copy_on_write() {
+        let mut memory = Paged::from(vec![1, 2, 3]);
+        memory.set(PAGE_SIZE * 2, 4);
+        assert_eq!(memory.len(), PAGE_SIZE * 2 + 1);
+        assert_eq!(
+            (memory[1], memory[PAGE_SIZE], memory.get(10_000)),
+            (2, 0, 0)
+        );
+
+        let mut fork = memory.clone();
+        fork.set(1, 5);
+        assert_eq!((memory[1], fork[1]), (2, 5));
+        assert!(Arc::ptr_eq(&memory.pages[2], &fork.pages[2]));
+        assert!(!Arc::ptr_eq(&memory.pages[0], &fork.pages[0]));
+        assert_eq!(memory.diff(&fork), vec![(1, 2, 5)]);
+
+        let page = Arc::as_ptr(&fork.pages[0]);
+        fork.copy_from(&memory);
+        assert_eq!(memory, fork);
+        assert_eq!(Arc::as_ptr(&fork.pages[0]), page);
+        assert_eq!(Paged::from(vec![1, 0]), Paged::from(vec![1]));
+        assert_ne!(Paged::from(vec![1, 0]), Paged::from(vec![1, 0, 1]));
+    }
+
+    #[test]
+    fn test_sparse() {
+        let mut memory = Sparse::from(&[1, 2, 3][..]);
+        memory.set(1_000_000_000, 4);
+        assert_eq!(memory.len(), 1_000_000_001);
+        assert_eq!(memory.pages.len(), 2);
+        assert_eq!((memory.get(1), memory.get(999_999_999)), (2, 0));
+
+        let mut fork = memory.clone();
+        fork.set(1_000_000_000, 5);
+        fork.set(PAGE_SIZE, 6);
+        assert_eq!((memory.get(1_000_000_000), fork.get(1_000_000_000)), (4, 5));
+        assert!(Arc::ptr_eq(&memory.pages[&0], &fork.pages[&0]));
+
+        let page = Arc::as_ptr(&fork.pages[&(1_000_000_000 / PAGE_SIZE)]);
+        fork.copy_from(&memory);
+        assert_eq!(fork.pages.len(), 2);
+        assert_eq!((fork.get(1_000_000_000), fork.get(PAGE_SIZE)), (4, 0));
+        assert_eq!(Arc::as_ptr(&fork.pages[&(1_000_000_000 / PAGE_SIZE)]), page);
+    }
+}
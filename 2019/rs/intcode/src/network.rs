@@ -0,0 +1,283 @@
+use crate::{Dense, Memory, Status, VmError, VM};
+use std::{collections::VecDeque, ops::ControlFlow};
+
+/// A message between VMs, which a VM sends by writing `to`, `x` and `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Packet {
+    pub to: i64,
+    pub x: i64,
+    pub y: i64,
+}
+
+/// What `Network::run` tells its monitor about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    /// A packet sent to an address with no VM, like the NAT's 255 on day 23.
+    Packet(Packet),
+    /// Every VM has halted or keeps reading from an empty queue without
+    /// sending anything.
+    Idle,
+}
+
+/// How long each VM runs before the next one gets a turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Policy {
+    /// At most this many instructions, and always at least one.
+    RoundRobin(usize),
+    /// Until it is waiting on an empty queue or halts.
+    #[default]
+    UntilIdle,
+}
+
+#[derive(Debug, Clone)]
+struct Node<M> {
+    vm: VM<M>,
+    // values delivered but not read yet
+    queue: VecDeque<i64>,
+    // what the VM has written of the packet it is sending
+    sending: Vec<i64>,
+    // how many times it has read `empty_input` since it last sent or was
+    // sent anything
+    empty_reads: usize,
+    halted: bool,
+}
+
+impl<M: Memory> Node<M> {
+    fn new(vm: VM<M>) -> Self {
+        Self {
+            vm,
+            queue: VecDeque::new(),
+            sending: vec![],
+            empty_reads: 0,
+            halted: false,
+        }
+    }
+
+    fn is_idle(&self) -> bool {
+        self.halted || (self.empty_reads > 1 && self.queue.is_empty())
+    }
+
+    // runs the VM for a turn, returning the packets it sent
+    fn turn(&mut self, policy: Policy, empty_input: i64) -> Result<Vec<Packet>, VmError> {
+        let mut budget = match policy {
+            // a VM given no instructions would never run, so nothing halts
+            Policy::RoundRobin(instructions) => instructions.max(1),
+            Policy::UntilIdle => usize::MAX,
+        };
+        let mut packets = vec![];
+        let mut polled = false;
+
+        while budget > 0 && !self.halted {
+            let status = match policy {
+                Policy::RoundRobin(_) => {
+                    budget -= 1;
+                    self.vm.step()?
+                }
+                Policy::UntilIdle => Some(self.vm.run()?),
+            };
+
+            while let Some(value) = self.vm.read_output() {
+                self.sending.push(value);
+                if let [to, x, y] = self.sending[..] {
+                    packets.push(Packet { to, x, y });
+                    self.sending.clear();
+                    self.empty_reads = 0;
+                }
+            }
+
+            match status {
+                Some(Status::NeedsInput) if self.queue.is_empty() => {
+                    // reading from an empty queue gives `empty_input` once a
+                    // turn, after that the VM waits for its next turn
+                    if polled {
+                        break;
+                    }
+                    polled = true;
+                    self.empty_reads += 1;
+                    self.vm.write_input(empty_input);
+                }
+                Some(Status::NeedsInput) => {
+                    self.empty_reads = 0;
+                    for value in self.queue.drain(..) {
+                        self.vm.write_input(value);
+                    }
+                }
+                Some(Status::Halted) => self.halted = true,
+                Some(Status::Output(_)) | None => {}
+            }
+        }
+
+        Ok(packets)
+    }
+}
+
+/// VMs that send each other packets, addressed by their index, like the
+/// computers on day 23.
+#[derive(Debug, Clone)]
+pub struct Network<M = Dense> {
+    nodes: Vec<Node<M>>,
+    pub policy: Policy,
+    /// What a VM reads when nothing has been sent to it.
+    pub empty_input: i64,
+}
+
+impl<M: Memory> Network<M> {
+    pub fn new(vms: impl IntoIterator<Item = VM<M>>) -> Self {
+        Self {
+            nodes: vms.into_iter().map(Node::new).collect(),
+            policy: Policy::default(),
+            empty_input: -1,
+        }
+    }
+
+    /// `count` VMs running `program`, each given its address as its first
+    /// input.
+    pub fn boot(program: &[i64], count: usize) -> Self {
+        let memory = M::from(program);
+        Self::new((0..count).map(|address| {
+            let mut vm = VM::new(memory.clone());
+            vm.write_input(address as i64);
+            vm
+        }))
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn vm(&self, address: usize) -> &VM<M> {
+        &self.nodes[address].vm
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.nodes.iter().all(Node::is_idle)
+    }
+
+    /// Queues `packet` for the VM it is addressed to, returning false if
+    /// there is no such VM.
+    pub fn send(&mut self, packet: Packet) -> bool {
+        let node = usize::try_from(packet.to)
+            .ok()
+            .and_then(|address| self.nodes.get_mut(address));
+        match node {
+            Some(node) => {
+                node.queue.extend([packet.x, packet.y]);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Gives each VM a turn in order of address until `monitor` breaks,
+    /// delivering packets as they are sent. Packets to addresses with no VM
+    /// go to `monitor`, as does the network going idle, when it can send
+    /// packets to wake it up. Returns `Continue` if it stays idle.
+    pub fn run<B>(
+        &mut self,
+        mut monitor: impl FnMut(&mut Self, Event) -> ControlFlow<B>,
+    ) -> Result<ControlFlow<B>, VmError> {
+        loop {
+            for address in 0..self.nodes.len() {
+                let packets = self.nodes[address].turn(self.policy, self.empty_input)?;
+                for packet in packets {
+                    if self.send(packet) {
+                        continue;
+                    }
+                    if let ControlFlow::Break(value) = monitor(self, Event::Packet(packet)) {
+                        return Ok(ControlFlow::Break(value));
+                    }
+                }
+            }
+
+            if self.is_idle() {
+                if let ControlFlow::Break(value) = monitor(self, Event::Idle) {
+                    return Ok(ControlFlow::Break(value));
+                }
+                if self.is_idle() {
+                    return Ok(ControlFlow::Continue(()));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm::assemble;
+
+    // each VM sends what it is sent on to the next address, adding 1 to y
+    const RELAY: &str = "
+            in address
+            add address, #1, next
+    loop:   in x
+            eq x, #-1, t
+            jnz t, #loop
+            in y
+            add y, #1, y
+            out next
+            out x
+            out y
+            jz #0, #loop
+    address: data 0
+    next:   data 0
+    x:      data 0
+    y:      data 0
+    t:      data 0
+    ";
+
+    #[test]
+    fn test_network() {
+        let program = assemble(RELAY).unwrap();
+        for policy in [
+            Policy::UntilIdle,
+            Policy::RoundRobin(3),
+            Policy::RoundRobin(0),
+        ] {
+            let mut network: Network = Network::boot(&program, 3);
+            network.policy = policy;
+            network.send(Packet { to: 0, x: 7, y: 0 });
+
+            let mut events = vec![];
+            let result = network.run(|_, event| {
+                events.push(event);
+                ControlFlow::<()>::Continue(())
+            });
+            assert_eq!(result, Ok(ControlFlow::Continue(())));
+            assert_eq!(
+                events,
+                vec![Event::Packet(Packet { to: 3, x: 7, y: 3 }), Event::Idle]
+            );
+        }
+    }
+
+    #[test]
+    fn test_monitor() {
+        let program = assemble(RELAY).unwrap();
+        let mut network: Network = Network::boot(&program, 2);
+
+        // like day 23's NAT, wakes the network with the last packet it caught
+        let mut last = None;
+        let mut woken = 0;
+        let result = network.run(|network, event| {
+            match event {
+                Event::Packet(packet) => last = Some(packet),
+                Event::Idle if woken == 3 => return ControlFlow::Break(last),
+                Event::Idle => {
+                    let y = last.map_or(0, |packet| packet.y);
+                    network.send(Packet { to: 0, x: woken, y });
+                    woken += 1;
+                }
+            }
+            ControlFlow::Continue(())
+        });
+        assert_eq!(
+            result,
+            Ok(ControlFlow::Break(Some(Packet { to: 2, x: 2, y: 6 })))
+        );
+    }
+}
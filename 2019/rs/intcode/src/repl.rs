@@ -0,0 +1,221 @@
+use intcode::{
+    debug::{Debugger, Stop},
+    disasm::Instruction,
+    Memory, Status, VmError, VM,
+};
+use std::{
+    io::{self, BufRead, Write},
+    ops::ControlFlow,
+    str::FromStr,
+};
+
+const HELP: &str = "commands:
+    s, step [n]               run n instructions (default 1)
+    c, continue               run until something below stops it, or it halts or needs input
+    b, break <addr>           stop before running the instruction at addr
+    bw, break-write <addr>    stop before an instruction writes to addr
+    w, watch <addr>           stop after the value at addr changes
+    d, delete <addr>          remove the breakpoints and watchpoint at addr
+    i, input <value>...       queue input for the program
+    r, regs                   show pc, the relative base and the next instruction
+    m, mem <addr> [n]         dump n intcodes from addr (default 16)
+    l, list [n]               list n instructions from pc (default 5)
+    h, help
+    q, quit";
+
+fn parse<T: FromStr>(word: Option<&&str>, name: &str, default: Option<T>) -> Result<T, String> {
+    match (word, default) {
+        (Some(word), _) => word
+            .parse()
+            .map_err(|_| format!("invalid {} `{}`", name, word)),
+        (None, Some(default)) => Ok(default),
+        (None, None) => Err(format!("missing {}", name)),
+    }
+}
+
+struct Session {
+    vm: VM,
+    debugger: Debugger,
+}
+
+impl Session {
+    fn regs(&self) {
+        let next = match Instruction::decode_memory(self.vm.memory(), self.vm.pc()) {
+            Some(instruction) => instruction.to_string(),
+            None => "not an instruction".to_string(),
+        };
+        println!(
+            "pc {}, relative base {}: {}",
+            self.vm.pc(),
+            self.vm.relative_base(),
+            next
+        );
+    }
+
+    fn report(&mut self, result: Result<ControlFlow<Stop, Option<Status>>, VmError>) {
+        while let Some(value) = self.vm.read_output() {
+            println!("output {}", value);
+        }
+
+        match result {
+            Ok(ControlFlow::Break(Stop::Breakpoint(pc))) => println!("breakpoint at {}", pc),
+            Ok(ControlFlow::Break(Stop::Write { address, .. })) => {
+                println!("about to write to {}", address)
+            }
+            Ok(ControlFlow::Break(Stop::Watchpoint { address, old, new })) => {
+                println!("{} changed from {} to {}", address, old, new)
+            }
+            Ok(ControlFlow::Continue(Some(Status::Halted))) => println!("halted"),
+            Ok(ControlFlow::Continue(Some(Status::NeedsInput))) => println!("waiting for input"),
+            Ok(ControlFlow::Continue(_)) => {}
+            Err(err) => println!("error: {}", err),
+        }
+        self.regs();
+    }
+
+    fn step(&mut self, count: usize) {
+        let mut result = Ok(ControlFlow::Continue(None));
+        for _ in 0..count {
+            result = self.debugger.step(&mut self.vm);
+            if !matches!(result, Ok(ControlFlow::Continue(None))) {
+                break;
+            }
+        }
+        self.report(result);
+    }
+
+    fn dump(&self, address: usize, count: usize) -> Result<(), String> {
+        let end = address.checked_add(count).ok_or_else(|| {
+            format!(
+                "{} intcodes from {} is past the last address",
+                count, address
+            )
+        })?;
+        let memory = self.vm.memory();
+        for row in (address..end).step_by(8) {
+            let values: Vec<String> = (row..row.saturating_add(8).min(end))
+                .map(|i| format!("{:>8}", memory.get(i)))
+                .collect();
+            println!("{:>6}:{}", row, values.join(""));
+        }
+        Ok(())
+    }
+
+    fn list(&self, count: usize) {
+        let mut address = self.vm.pc();
+        for _ in 0..count {
+            match Instruction::decode_memory(self.vm.memory(), address) {
+                Some(instruction) => {
+                    println!("{:>6}  {}", address, instruction);
+                    address += instruction.size();
+                }
+                None => {
+                    let value = self.vm.memory().get(address);
+                    println!("{:>6}  data {}", address, value);
+                    address += 1;
+                }
+            }
+        }
+    }
+
+    // runs one command, returning whether to keep going
+    fn command(&mut self, words: &[&str]) -> Result<bool, String> {
+        let Some((&command, args)) = words.split_first() else {
+            return Ok(true);
+        };
+        let address = || parse::<usize>(args.first(), "address", None);
+
+        match command {
+            "s" | "step" => self.step(parse(args.first(), "count", Some(1))?),
+            "c" | "continue" => {
+                let result = self.debugger.run(&mut self.vm);
+                self.report(result.map(|flow| match flow {
+                    ControlFlow::Break(stop) => ControlFlow::Break(stop),
+                    ControlFlow::Continue(status) => ControlFlow::Continue(Some(status)),
+                }));
+            }
+            "b" | "break" => {
+                self.debugger.breakpoints.insert(address()?);
+            }
+            "bw" | "break-write" => {
+                self.debugger.write_breakpoints.insert(address()?);
+            }
+            "w" | "watch" => self.debugger.watch(&self.vm, address()?),
+            "d" | "delete" => {
+                let address = address()?;
+                let removed = [
+                    self.debugger.breakpoints.remove(&address),
+                    self.debugger.write_breakpoints.remove(&address),
+                    self.debugger.unwatch(address),
+                ];
+                if !removed.contains(&true) {
+                    return Err(format!("nothing is set at {}", address));
+                }
+            }
+            "i" | "input" => {
+                for word in args {
+                    self.vm.write_input(parse(Some(word), "input", None)?);
+                }
+            }
+            "r" | "regs" => self.regs(),
+            "m" | "mem" => self.dump(address()?, parse(args.get(1), "count", Some(16))?)?,
+            "l" | "list" => self.list(parse(args.first(), "count", Some(5))?),
+            "h" | "help" => println!("{}", HELP),
+            "q" | "quit" => return Ok(false),
+            _ => return Err(format!("unknown command `{}`, try `help`", command)),
+        }
+        Ok(true)
+    }
+}
+
+/// Debugs a program interactively, reading commands from stdin.
+pub fn run(intcodes: Vec<i64>) -> Result<(), String> {
+    let mut session = Session {
+        vm: VM::from(intcodes),
+        debugger: Debugger::default(),
+    };
+    session.regs();
+
+    let mut stdin = io::stdin().lock();
+    loop {
+        print!("(intcode) ");
+        io::stdout().flush().map_err(|err| err.to_string())?;
+
+        let mut line = String::new();
+        if stdin.read_line(&mut line).map_err(|err| err.to_string())? == 0 {
+            println!();
+            return Ok(());
+        }
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match session.command(&words) {
+            Ok(true) => {}
+            Ok(false) => return Ok(()),
+            Err(message) => println!("{}", message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dump() {
+        let mut session = Session {
+            vm: VM::from(vec![1, 2, 3]),
+            debugger: Debugger::default(),
+        };
+        assert_eq!(session.command(&["m", "0", "3"]), Ok(true));
+        assert_eq!(
+            session.command(&["m", &usize::MAX.to_string(), "2"]),
+            Err(format!(
+                "2 intcodes from {} is past the last address",
+                usize::MAX
+            ))
+        );
+        assert_eq!(
+            session.command(&["m", &(usize::MAX - 1).to_string(), "1"]),
+            Ok(true)
+        );
+    }
+}
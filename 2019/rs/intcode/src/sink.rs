@@ -0,0 +1,37 @@
+use std::{collections::VecDeque, sync::mpsc};
+
+/// Somewhere `VM::run_io` writes output to.
+pub trait Sink {
+    fn write(&mut self, value: i64);
+}
+
+impl<F: FnMut(i64)> Sink for F {
+    fn write(&mut self, value: i64) {
+        self(value)
+    }
+}
+
+impl Sink for Vec<i64> {
+    fn write(&mut self, value: i64) {
+        self.push(value)
+    }
+}
+
+impl Sink for VecDeque<i64> {
+    fn write(&mut self, value: i64) {
+        self.push_back(value)
+    }
+}
+
+// output nobody is left to read is dropped, as when the VM it fed has halted
+impl Sink for mpsc::Sender<i64> {
+    fn write(&mut self, value: i64) {
+        let _ = self.send(value);
+    }
+}
+
+impl Sink for mpsc::SyncSender<i64> {
+    fn write(&mut self, value: i64) {
+        let _ = self.send(value);
+    }
+}
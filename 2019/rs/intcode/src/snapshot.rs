@@ -0,0 +1,324 @@
+use crate::{Dense, Memory};
+use aoc_core::{
+    error::{Token, Tokens},
+    Error,
+};
+use std::{fmt, str::FromStr};
+
+/// Everything about a VM that decides what its program does next, see
+/// `VM::snapshot`. Snapshots are equal and hash the same when the VMs
+/// would carry on the same way, so they can go in a visited set.
+///
+/// A snapshot is saved by formatting it and loaded again with `parse`, and
+/// a VM carries on from it with `VM::from_snapshot` or `VM::restore`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Snapshot<M = Dense> {
+    pub memory: M,
+    pub pc: usize,
+    pub relative_base: i64,
+    /// Input not read yet, in the order the program reads it.
+    pub input: Vec<i64>,
+    /// Output not read yet, oldest first.
+    pub output: Vec<i64>,
+    pub halted: bool,
+}
+
+/// A difference between two snapshots, from `Snapshot::diff`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    Pc(usize, usize),
+    RelativeBase(i64, i64),
+    Input(Vec<i64>, Vec<i64>),
+    Output(Vec<i64>, Vec<i64>),
+    Halted(bool, bool),
+    Memory { address: usize, old: i64, new: i64 },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Pc(old, new) => write!(f, "pc: {} -> {}", old, new),
+            Change::RelativeBase(old, new) => write!(f, "relative base: {} -> {}", old, new),
+            Change::Input(old, new) => write!(f, "input: {:?} -> {:?}", old, new),
+            Change::Output(old, new) => write!(f, "output: {:?} -> {:?}", old, new),
+            Change::Halted(old, new) => write!(f, "halted: {} -> {}", old, new),
+            Change::Memory { address, old, new } => write!(f, "[{}]: {} -> {}", address, old, new),
+        }
+    }
+}
+
+impl<M: Memory> Snapshot<M> {
+    /// What changed from `self` to `other`.
+    pub fn diff(&self, other: &Self) -> Vec<Change> {
+        let mut changes = vec![];
+        if self.pc != other.pc {
+            changes.push(Change::Pc(self.pc, other.pc));
+        }
+        if self.relative_base != other.relative_base {
+            changes.push(Change::RelativeBase(
+                self.relative_base,
+                other.relative_base,
+            ));
+        }
+        if self.input != other.input {
+            changes.push(Change::Input(self.input.clone(), other.input.clone()));
+        }
+        if self.output != other.output {
+            changes.push(Change::Output(self.output.clone(), other.output.clone()));
+        }
+        if self.halted != other.halted {
+            changes.push(Change::Halted(self.halted, other.halted));
+        }
+        changes.extend(
+            self.memory
+                .diff(&other.memory)
+                .into_iter()
+                .map(|(address, old, new)| Change::Memory { address, old, new }),
+        );
+        changes
+    }
+}
+
+fn write_list(
+    f: &mut fmt::Formatter<'_>,
+    key: &str,
+    values: impl Iterator<Item = i64>,
+) -> fmt::Result {
+    write!(f, "{}", key)?;
+    for (i, value) in values.enumerate() {
+        write!(f, "{}{}", if i == 0 { " " } else { "," }, value)?;
+    }
+    writeln!(f)
+}
+
+impl<M: Memory> fmt::Display for Snapshot<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "pc {}", self.pc)?;
+        writeln!(f, "relative_base {}", self.relative_base)?;
+        writeln!(f, "halted {}", self.halted)?;
+        write_list(f, "input", self.input.iter().copied())?;
+        write_list(f, "output", self.output.iter().copied())?;
+        // runs that don't follow on from the one before go on their own line
+        write!(f, "memory")?;
+        let (mut end, mut separator) = (0, " ");
+        for (start, values) in self.memory.runs() {
+            if start != end {
+                write!(f, "\nmemory@{}", start)?;
+                separator = " ";
+            }
+            for value in values {
+                write!(f, "{}{}", separator, value)?;
+                separator = ",";
+            }
+            end = start + values.len();
+        }
+        writeln!(f)
+    }
+}
+
+// a comma separated list, which is empty when the token is missing
+fn parse_list(token: Option<Token>) -> Result<Vec<i64>, Error> {
+    let Some(token) = token else {
+        return Ok(vec![]);
+    };
+
+    let mut column = token.column;
+    token
+        .text
+        .split(',')
+        .map(|text| {
+            let int = Token { text, column };
+            column += text.len() + 1;
+            int.parse("intcode")
+        })
+        .collect()
+}
+
+fn set<T>(field: &mut Option<T>, key: Token, value: T) -> Result<(), Error> {
+    match field.replace(value) {
+        Some(_) => Err(key.error(format!("`{}` is given twice", key.text))),
+        None => Ok(()),
+    }
+}
+
+impl<M: Memory> FromStr for Snapshot<M> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let (mut pc, mut relative_base, mut halted) = (None, None, None);
+        let (mut input, mut output, mut memory) = (None, None, None);
+        // runs of memory past the first, from `memory@<address>` lines
+        let mut runs = vec![];
+
+        for (i, line) in s.lines().enumerate() {
+            let mut tokens = Tokens::new(line);
+            let Ok(key) = tokens.next("") else {
+                continue;
+            };
+            let mut parse_field = || match key.text {
+                "pc" => set(&mut pc, key, tokens.next("pc")?.parse("pc")?),
+                "relative_base" => set(
+                    &mut relative_base,
+                    key,
+                    tokens.next("relative base")?.parse("relative base")?,
+                ),
+                "halted" => set(&mut halted, key, tokens.next("halted")?.parse("halted")?),
+                "input" => set(&mut input, key, parse_list(tokens.next("").ok())?),
+                "output" => set(&mut output, key, parse_list(tokens.next("").ok())?),
+                "memory" => set(&mut memory, key, parse_list(tokens.next("").ok())?),
+                text => match text.strip_prefix("memory@") {
+                    Some(address) => {
+                        let address: usize = Token {
+                            text: address,
+                            column: key.column + "memory@".len(),
+                        }
+                        .parse("address")?;
+                        let values = parse_list(tokens.next("").ok())?;
+                        let end = address.saturating_add(values.len());
+                        if end > M::MAX_ADDRESS.saturating_add(1) {
+                            let message = format!("`{}` runs past the highest address", text);
+                            return Err(key.error(message));
+                        }
+                        runs.push((address, values));
+                        Ok(())
+                    }
+                    None => Err(key.error(format!("unknown field `{}`", text))),
+                },
+            };
+            parse_field()
+                .and_then(|_| tokens.end())
+                .map_err(|err| err.at_line(i + 1))?;
+        }
+
+        let missing = |field: &str| Error::new(format!("missing `{}`", field));
+        let mut memory = M::from(&memory.ok_or_else(|| missing("memory"))?);
+        for (address, values) in runs {
+            for (offset, value) in values.into_iter().enumerate() {
+                memory.set(address + offset, value);
+            }
+        }
+        Ok(Snapshot {
+            memory,
+            pc: pc.ok_or_else(|| missing("pc"))?,
+            relative_base: relative_base.ok_or_else(|| missing("relative_base"))?,
+            input: input.ok_or_else(|| missing("input"))?,
+            output: output.ok_or_else(|| missing("output"))?,
+            halted: halted.ok_or_else(|| missing("halted"))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        asm::{assemble, COUNTDOWN},
+        Paged, Sparse, Status, VM,
+    };
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_snapshot() {
+        let program = assemble(COUNTDOWN).unwrap();
+        let mut vm = VM::from(program.clone());
+        vm.write_input(2);
+        vm.write_input(7);
+        vm.run_until_output().unwrap();
+        let saved = vm.snapshot();
+
+        let text = saved.to_string();
+        assert_eq!(
+            text,
+            "pc 4\nrelative_base 0\nhalted false\ninput 7\noutput\nmemory 3,12,4,12,1001,12,-1,12,1005,12,2,99,2\n"
+        );
+        assert_eq!(text.parse::<Snapshot>(), Ok(saved.clone()));
+
+        let mut fork = VM::from_snapshot(Dense::from(program.clone()), &saved);
+        assert_eq!(fork.run(), Ok(Status::Halted));
+        assert_eq!(fork.read_output(), Some(1));
+        assert_eq!(
+            saved.diff(&fork.snapshot()),
+            vec![
+                Change::Pc(4, 12),
+                Change::Halted(false, true),
+                Change::Memory {
+                    address: 12,
+                    old: 2,
+                    new: 0
+                },
+            ]
+        );
+
+        vm.run().unwrap();
+        vm.read_output();
+        let visited: HashSet<Snapshot> = [saved, vm.snapshot(), fork.snapshot()].into();
+        assert_eq!(visited.len(), 2);
+
+        fork.reset();
+        assert_eq!(fork.snapshot(), VM::from(program).snapshot());
+    }
+
+    #[test]
+    fn test_paged_snapshot() {
+        let mut vm = VM::new(Paged::from(assemble(COUNTDOWN).unwrap()));
+        vm.write_input(2);
+        vm.run_until_output().unwrap();
+        let saved = vm.snapshot();
+        assert_eq!(
+            saved.to_string().parse::<Snapshot<Paged>>(),
+            Ok(saved.clone())
+        );
+
+        let mut fork = VM::from_snapshot(Paged::from(assemble(COUNTDOWN).unwrap()), &saved);
+        assert_eq!(fork.run(), Ok(Status::Halted));
+        assert_eq!(fork.read_output(), Some(1));
+        assert_eq!(saved.diff(&fork.snapshot()).len(), 3);
+    }
+
+    #[test]
+    fn test_sparse_snapshot() {
+        let mut vm = VM::new(Sparse::from(&[1101, 5, 6, 1_000_000, 99][..]));
+        let start = vm.snapshot();
+        vm.run().unwrap();
+        let saved = vm.snapshot();
+
+        let text = saved.to_string();
+        assert!(text.ends_with(&format!(
+            "\nmemory 1101,5,6,1000000,99\nmemory@999936 {}11\n",
+            "0,".repeat(64)
+        )));
+        let parsed = text.parse::<Snapshot<Sparse>>().unwrap();
+        assert_eq!(parsed.to_string(), text);
+        assert_eq!(
+            start.diff(&parsed),
+            vec![
+                Change::Pc(0, 5),
+                Change::Halted(false, true),
+                Change::Memory {
+                    address: 1_000_000,
+                    old: 0,
+                    new: 11
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |text: &str| text.parse::<Snapshot>().unwrap_err().to_string();
+        assert_eq!(
+            error("pc 1\nmemory 1,x"),
+            "line 2, column 10: invalid intcode `x`"
+        );
+        assert_eq!(error("pc 1\npc 2"), "line 2, column 1: `pc` is given twice");
+        assert_eq!(error("sp 1"), "line 1, column 1: unknown field `sp`");
+        assert_eq!(error("halted no"), "line 1, column 8: invalid halted `no`");
+        assert_eq!(error("pc 1 2"), "line 1, column 6: unexpected `2`");
+        assert_eq!(error("pc 1"), "missing `memory`");
+        assert_eq!(error("memory@x 1"), "line 1, column 8: invalid address `x`");
+        assert_eq!(
+            error("memory@16777215 1,2"),
+            "line 1, column 1: `memory@16777215` runs past the highest address"
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use crate::Op;
+use std::{collections::HashMap, fmt};
+
+/// One instruction the VM ran.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub pc: usize,
+    pub op: Op,
+    /// The parameters it read, after resolving their modes.
+    pub operands: Vec<i64>,
+    /// The address it wrote to and the value written.
+    pub write: Option<(usize, i64)>,
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.pc, self.op.mnemonic())?;
+        for (i, operand) in self.operands.iter().enumerate() {
+            write!(f, "{}{}", if i == 0 { " " } else { ", " }, operand)?;
+        }
+        if let Some((address, value)) = self.write {
+            write!(f, " -> [{}] = {}", address, value)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Profile {
+    /// How many instructions ran.
+    pub instructions: u64,
+    /// How many times the instruction at each address ran.
+    pub hits: HashMap<usize, u64>,
+    /// The most intcodes the program's memory held.
+    pub high_water: usize,
+}
+
+impl Profile {
+    /// The `count` addresses whose instructions ran the most, busiest first.
+    pub fn hottest(&self, count: usize) -> Vec<(usize, u64)> {
+        let mut hits: Vec<(usize, u64)> = self.hits.iter().map(|(&pc, &n)| (pc, n)).collect();
+        hits.sort_unstable_by_key(|&(pc, n)| (std::cmp::Reverse(n), pc));
+        hits.truncate(count);
+        hits
+    }
+}
+
+/// What a VM ran since `VM::start_trace`.
+#[derive(Debug, Clone, Default)]
+pub struct Trace {
+    /// Every instruction, if the trace keeps them.
+    pub events: Vec<Event>,
+    pub profile: Profile,
+    keep_events: bool,
+    // the instruction being run
+    current: Option<Event>,
+}
+
+impl Trace {
+    pub(crate) fn new(keep_events: bool) -> Self {
+        Self {
+            keep_events,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn begin(&mut self, pc: usize, op: Op) {
+        self.current = Some(Event {
+            pc,
+            op,
+            operands: vec![],
+            write: None,
+        });
+    }
+
+    pub(crate) fn operand(&mut self, value: i64) {
+        if let Some(event) = &mut self.current {
+            event.operands.push(value);
+        }
+    }
+
+    pub(crate) fn write(&mut self, address: usize, value: i64) {
+        if let Some(event) = &mut self.current {
+            event.write = Some((address, value));
+        }
+    }
+
+    // the instruction finished, leaving memory `memory` intcodes long
+    pub(crate) fn end(&mut self, memory: usize) {
+        let Some(event) = self.current.take() else {
+            return;
+        };
+
+        self.profile.instructions += 1;
+        *self.profile.hits.entry(event.pc).or_default() += 1;
+        self.profile.high_water = self.profile.high_water.max(memory);
+        if self.keep_events {
+            self.events.push(event);
+        }
+    }
+
+    // the instruction didn't run, because it failed or is waiting for input
+    pub(crate) fn cancel(&mut self) {
+        self.current = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        asm::{assemble, COUNTDOWN},
+        VM,
+    };
+
+    #[test]
+    fn test_trace() {
+        let program = assemble(COUNTDOWN).unwrap();
+        let mut vm = VM::from(program);
+        vm.start_trace(true);
+        vm.run().unwrap();
+        vm.write_input(2);
+        vm.run().unwrap();
+
+        let trace = vm.take_trace().unwrap();
+        let events: Vec<String> = trace.events.iter().map(|event| event.to_string()).collect();
+        assert_eq!(
+            events,
+            vec![
+                "0: in -> [12] = 2",
+                "2: out 2",
+                "4: add 2, -1 -> [12] = 1",
+                "8: jnz 1, 2",
+                "2: out 1",
+                "4: add 1, -1 -> [12] = 0",
+                "8: jnz 0, 2",
+                "11: hlt",
+            ]
+        );
+        assert_eq!(trace.profile.instructions, 8);
+        assert_eq!(trace.profile.hottest(2), vec![(2, 2), (4, 2)]);
+        assert_eq!(trace.profile.high_water, 13);
+        assert!(vm.trace().is_none());
+
+        let mut vm = VM::from(vec![1101, 1, 1, 10, 99]);
+        vm.start_trace(false);
+        vm.run().unwrap();
+        let trace = vm.trace().unwrap();
+        assert!(trace.events.is_empty());
+        assert_eq!(trace.profile.instructions, 2);
+        assert_eq!(trace.profile.high_water, 11);
+    }
+}
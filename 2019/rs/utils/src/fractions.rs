@@ -18,9 +18,9 @@ pub enum Fraction {
     PositiveUndefined,
 }
 
-impl Into<f64> for &Fraction {
-    fn into(self) -> f64 {
-        match self {
+impl From<&Fraction> for f64 {
+    fn from(val: &Fraction) -> Self {
+        match val {
             &Fraction::Defined(num, denom) => num as f64 / denom as f64,
             Fraction::PositiveUndefined => f64::INFINITY,
             Fraction::NegativeUndefined => f64::NEG_INFINITY,
@@ -28,12 +28,12 @@ impl Into<f64> for &Fraction {
     }
 }
 
-impl Into<f64> for Fraction {
-    fn into(self) -> f64 {
-        match self {
-            Self::Defined(num, denom) => num as f64 / denom as f64,
-            Self::PositiveUndefined => f64::INFINITY,
-            Self::NegativeUndefined => f64::NEG_INFINITY,
+impl From<Fraction> for f64 {
+    fn from(val: Fraction) -> Self {
+        match val {
+            Fraction::Defined(num, denom) => num as f64 / denom as f64,
+            Fraction::PositiveUndefined => f64::INFINITY,
+            Fraction::NegativeUndefined => f64::NEG_INFINITY,
         }
     }
 }
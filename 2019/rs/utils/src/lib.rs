@@ -22,7 +22,7 @@ impl<const N: usize, T: Copy> Iterator for PermutationIterator<N, T> {
 
         while self.pointer < N {
             if self.stack[self.pointer] < self.pointer {
-                if self.pointer % 2 == 0 {
+                if self.pointer.is_multiple_of(2) {
                     self.state.swap(0, self.pointer);
                 } else {
                     self.state.swap(self.stack[self.pointer], self.pointer);
@@ -0,0 +1,108 @@
+use regex::Regex;
+
+fn password_regex() -> Regex {
+    Regex::new(r"(?P<min>\d+)-(?P<max>\d+) (?P<letter>\w): (?P<password>\w+)").unwrap()
+}
+
+/// A password and the policy it was set under.
+pub struct Entry {
+    min: usize,
+    max: usize,
+    letter: char,
+    password: String,
+}
+
+fn parse(input: &str) -> Vec<Entry> {
+    password_regex()
+        .captures_iter(input)
+        .map(|caps| Entry {
+            min: caps["min"].parse().unwrap(),
+            max: caps["max"].parse().unwrap(),
+            letter: caps["letter"].chars().next().unwrap(),
+            password: caps["password"].to_string(),
+        })
+        .collect()
+}
+
+fn solve_one(entries: &[Entry]) -> i32 {
+    let mut total = 0;
+
+    for &Entry {
+        min,
+        max,
+        letter,
+        ref password,
+    } in entries
+    {
+        let count = password
+            .chars()
+            .fold(0, |acc, ch| acc + (ch == letter) as usize);
+
+        total += (count <= max && count >= min) as i32;
+    }
+
+    total
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(entries: &[Entry]) -> i32 {
+    let mut total = 0;
+
+    for &Entry {
+        min,
+        max,
+        letter,
+        ref password,
+    } in entries
+    {
+        let is_min_letter = password.chars().nth(min - 1).unwrap() == letter;
+        let is_max_letter = password.chars().nth(max - 1).unwrap() == letter;
+
+        total += (is_min_letter ^ is_max_letter) as i32
+    }
+
+    total
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 2;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Entry>;
+
+    fn parse(input: &str) -> Result<Vec<Entry>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Entry>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Entry>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
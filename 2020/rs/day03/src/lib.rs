@@ -0,0 +1,86 @@
+const RUN: usize = 3;
+const SLOPES: [(usize, usize); 5] = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+
+fn parse(input: &str) -> Vec<Vec<u8>> {
+    input
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|c| match c {
+                    '#' => 1,
+                    _ => 0,
+                })
+                .collect::<Vec<u8>>()
+        })
+        .collect()
+}
+
+fn solve_one(values: &[Vec<u8>]) -> i32 {
+    let mut total_trees: i32 = 0;
+    let mut x: usize = 0;
+    for vec in values {
+        total_trees += vec[x] as i32;
+        x = (x + RUN) % vec.len();
+    }
+
+    total_trees
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(values: &[Vec<u8>]) -> i64 {
+    let mut product: i64 = 1;
+    for (run, rise) in SLOPES.iter() {
+        let mut total_trees: i32 = 0;
+        let mut x: usize = 0;
+        for vec in values.iter().step_by(*rise) {
+            total_trees += vec[x] as i32;
+            x = (x + run) % vec.len();
+        }
+        product *= total_trees as i64;
+    }
+
+    product
+}
+
+pub fn part_two(input: &str) -> i64 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 3;
+
+    type PartOne = i32;
+    type PartTwo = i64;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i64 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Vec<u8>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<u8>>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Vec<u8>>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Vec<u8>>) -> i64 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
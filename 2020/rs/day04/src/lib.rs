@@ -0,0 +1,151 @@
+fn is_in_range(value: &str, lower: i32, upper: i32) -> bool {
+    match value.trim_end_matches(char::is_alphabetic).parse() {
+        Ok(int_val) => lower <= int_val && int_val <= upper,
+        _ => false,
+    }
+}
+
+fn validate_hgt(value: &str) -> bool {
+    if value.ends_with("in") {
+        is_in_range(value, 59, 76)
+    } else if value.ends_with("cm") {
+        is_in_range(value, 150, 193)
+    } else {
+        false
+    }
+}
+
+fn validate_hcl(value: &str) -> bool {
+    let without_hash = value.trim_start_matches("#");
+
+    if without_hash == value {
+        return false;
+    }
+
+    i32::from_str_radix(without_hash, 16).is_ok()
+}
+
+fn validate_pid(value: &str) -> bool {
+    value.len() == 9 && value.bytes().all(|c: u8| c.is_ascii_digit())
+}
+
+/// The key and value of every field in a passport.
+pub type Passport = Vec<(String, String)>;
+
+fn parse(input: &str) -> Vec<Passport> {
+    input
+        .split("\n\n")
+        .map(|passport| {
+            passport
+                .split_ascii_whitespace()
+                .map(|s| {
+                    let (key, value) = s.split_once(':').unwrap();
+                    (key.to_string(), value.to_string())
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn solve_one(passports: &[Passport]) -> i32 {
+    let mut valid_passports = 0;
+
+    for elements in passports {
+        let mut fields_found = 0;
+        for (key, _) in elements {
+            fields_found += match key.as_str() {
+                "byr" => 1,
+                "iyr" => 1,
+                "eyr" => 1,
+                "hgt" => 1,
+                "hcl" => 1,
+                "ecl" => 1,
+                "pid" => 1,
+                _ => 0,
+            }
+        }
+
+        valid_passports += (fields_found == 7) as i32;
+    }
+
+    valid_passports
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(passports: &[Passport]) -> i32 {
+    let mut valid_passports = 0;
+
+    for elements in passports {
+        let mut fields_found = 0;
+        for (key, value) in elements {
+            let value = value.as_str();
+            fields_found += match key.as_str() {
+                // byr (Birth Year) - four digits; at least 1920 and at most 2002.
+                "byr" => is_in_range(value, 1920, 2002),
+                // iyr (Issue Year) - four digits; at least 2010 and at most 2020.
+                "iyr" => is_in_range(value, 2010, 2020),
+                // eyr (Expiration Year) - four digits; at least 2020 and at most 2030.
+                "eyr" => is_in_range(value, 2020, 2030),
+                // hgt (Height) - a number followed by either cm or in:
+                // If cm, the number must be at least 150 and at most 193.
+                // If in, the number must be at least 59 and at most 76.
+                "hgt" => validate_hgt(value),
+                // hcl (Hair Color) - a # followed by exactly six characters 0-9 or a-f.
+                "hcl" => validate_hcl(value),
+                // ecl (Eye Color) - exactly one of: amb blu brn gry grn hzl oth.
+                "ecl" => ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"].contains(&value),
+                // pid (Passport ID) - a nine-digit number, including leading zeroes.
+                "pid" => validate_pid(value),
+                "cid" => false,
+                _ => unreachable!(),
+            } as i32;
+        }
+
+        valid_passports += (fields_found == 7) as i32;
+    }
+
+    valid_passports
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 4;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Passport>;
+
+    fn parse(input: &str) -> Result<Vec<Passport>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Passport>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Passport>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,5 @@
+use aoc2020_day04::Solver;
+
+fn main() -> std::io::Result<()> {
+    aoc_core::run_parsed::<Solver>()
+}
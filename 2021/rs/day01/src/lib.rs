@@ -0,0 +1,68 @@
+fn parse(input: &str) -> Vec<i32> {
+    input.lines().map(|l| l.parse::<i32>().unwrap()).collect()
+}
+
+fn solve_one(measurements: &[i32]) -> i32 {
+    measurements.windows(2).fold(0, |acc, slice| {
+        acc + if slice[0] < slice[1] { 1 } else { 0 }
+    })
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(measurements: &[i32]) -> i32 {
+    let mut previous = 0;
+    let mut increases = -1;
+
+    for window in measurements.windows(3) {
+        let sum = window.iter().sum();
+        if sum > previous {
+            increases += 1;
+        }
+        previous = sum;
+    }
+
+    increases
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 1;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Vec<i32>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<i32>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<i32>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
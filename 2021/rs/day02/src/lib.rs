@@ -0,0 +1,104 @@
+/// A direction to move the submarine in, and how far.
+pub enum Command {
+    Forward(i32),
+    Up(i32),
+    Down(i32),
+}
+
+fn parse(input: &str) -> Vec<Command> {
+    input
+        .lines()
+        .map(|line| {
+            let mut parts = line.split_ascii_whitespace();
+
+            let direction = parts.next();
+
+            let amount: i32 = parts.next().unwrap().parse().unwrap();
+
+            match direction {
+                Some("forward") => Command::Forward(amount),
+                Some("up") => Command::Up(amount),
+                Some("down") => Command::Down(amount),
+                _ => unreachable!(),
+            }
+        })
+        .collect()
+}
+
+fn solve_one(commands: &[Command]) -> i32 {
+    let mut horizontal = 0;
+    let mut depth = 0;
+
+    for command in commands {
+        match *command {
+            Command::Forward(amount) => horizontal += amount,
+            Command::Up(amount) => depth -= amount,
+            Command::Down(amount) => depth += amount,
+        }
+    }
+
+    depth * horizontal
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(commands: &[Command]) -> i32 {
+    let mut horizontal = 0;
+    let mut depth = 0;
+    let mut aim = 0;
+
+    for command in commands {
+        match *command {
+            Command::Forward(amount) => {
+                horizontal += amount;
+                depth += aim * amount;
+            }
+            Command::Up(amount) => aim -= amount,
+            Command::Down(amount) => aim += amount,
+        }
+    }
+
+    depth * horizontal
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 2;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Vec<Command>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Command>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Command>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
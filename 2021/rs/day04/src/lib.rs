@@ -0,0 +1,212 @@
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone)]
+enum State {
+    Marked,
+    Unmarked,
+}
+
+#[derive(Debug, Clone)]
+struct Square {
+    value: i32,
+    state: State,
+}
+
+impl Square {
+    fn mark(&mut self) {
+        self.state = State::Marked;
+    }
+
+    fn is_marked(&self) -> bool {
+        matches!(self.state, State::Marked)
+    }
+
+    fn set_value(&mut self, value: i32) {
+        self.value = value
+    }
+
+    fn has_value(&self, value: i32) -> bool {
+        self.value == value
+    }
+}
+
+impl Default for Square {
+    fn default() -> Self {
+        Self {
+            value: 0,
+            state: State::Unmarked,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct Board([[Square; 5]; 5]);
+
+impl Board {
+    fn is_winner(&self) -> bool {
+        self.0.iter().any(|row| row.iter().all(|sq| sq.is_marked()))
+            || (0..5).any(|col| self.0.iter().all(|row| row[col].is_marked()))
+    }
+
+    fn mark(&mut self, value: i32) -> bool {
+        for row in &mut self.0 {
+            for sq in row {
+                if sq.has_value(value) {
+                    sq.mark();
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    fn sum_unmarked(&self) -> i32 {
+        self.0.iter().fold(0, |row_acc, row| {
+            row_acc
+                + row.iter().fold(
+                    0,
+                    |acc, sq| if sq.is_marked() { acc } else { acc + sq.value },
+                )
+        })
+    }
+}
+
+fn parse_boards(mut lines: std::str::Lines) -> Vec<Board> {
+    let mut boards = Vec::new();
+
+    while let Some(_) = lines.next() {
+        let mut board: Board = Default::default();
+
+        for row in 0..5 {
+            for (col, num) in lines
+                .next()
+                .unwrap()
+                .split_ascii_whitespace()
+                .map(|num| num.parse::<i32>().unwrap())
+                .enumerate()
+            {
+                board.0[row][col].set_value(num);
+            }
+        }
+
+        boards.push(board)
+    }
+
+    boards
+}
+
+/// Bingo boards and the numbers still to be drawn.
+#[derive(Clone)]
+pub struct Simulator {
+    numbers: Vec<i32>,
+    boards: Vec<Board>,
+    should_purge: bool,
+    winners: VecDeque<i32>,
+}
+
+impl Simulator {
+    fn new(numbers: Vec<i32>, boards: Vec<Board>) -> Self {
+        Self {
+            numbers,
+            boards,
+            should_purge: false,
+            winners: VecDeque::new(),
+        }
+    }
+}
+
+impl Iterator for Simulator {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.winners.is_empty() {
+            return self.winners.pop_front();
+        }
+
+        while let Some(num) = self.numbers.pop() {
+            if self.should_purge {
+                self.boards.retain(|b| !b.is_winner());
+                self.should_purge = false
+            }
+            for board in self.boards.iter_mut() {
+                if board.mark(num) && board.is_winner() {
+                    self.should_purge = true;
+                    self.winners.push_back(board.sum_unmarked() * num)
+                }
+            }
+
+            if !self.winners.is_empty() {
+                return self.winners.pop_front();
+            }
+        }
+
+        None
+    }
+}
+
+fn parse(input: &str) -> Simulator {
+    let mut lines = input.lines();
+    let numbers: Vec<_> = lines
+        .next()
+        .unwrap()
+        .split(',')
+        .map(|l| l.parse::<i32>().unwrap())
+        .rev()
+        .collect();
+    let boards = parse_boards(lines);
+
+    Simulator::new(numbers, boards)
+}
+
+fn solve_one(simulator: &Simulator) -> i32 {
+    simulator.clone().next().unwrap()
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(simulator: &Simulator) -> i32 {
+    simulator.clone().last().unwrap()
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 4;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Simulator;
+
+    fn parse(input: &str) -> Result<Simulator, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Simulator) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Simulator) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,184 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl FromStr for Point {
+    type Err = ();
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut parts = string.split(',');
+
+        if let (Some(x), Some(y)) = (parts.next(), parts.next()) {
+            if let (Ok(x), Ok(y)) = (x.parse(), y.parse()) {
+                return Ok(Self { x, y });
+            }
+        }
+
+        Err(())
+    }
+}
+
+struct LineIter {
+    current: Point,
+    end: Point,
+    x_diff: i32,
+    y_diff: i32,
+    done: bool,
+}
+
+impl Iterator for LineIter {
+    type Item = (i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        self.done = self.current == self.end;
+        let out = (self.current.x, self.current.y);
+
+        self.current.x += self.x_diff;
+        self.current.y += self.y_diff;
+
+        Some(out)
+    }
+}
+
+fn get_diff(ordering: Ordering) -> i32 {
+    match ordering {
+        Ordering::Greater => -1,
+        Ordering::Equal => 0,
+        Ordering::Less => 1,
+    }
+}
+
+/// A line of hydrothermal vents.
+pub struct Line {
+    start: Point,
+    end: Point,
+}
+
+impl Line {
+    fn points(&self) -> LineIter {
+        LineIter {
+            current: self.start,
+            x_diff: get_diff(self.start.x.cmp(&self.end.x)),
+            y_diff: get_diff(self.start.y.cmp(&self.end.y)),
+            end: self.end,
+            done: false,
+        }
+    }
+}
+
+impl FromStr for Line {
+    type Err = ();
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut parts = string.split(" -> ");
+
+        if let (Some(start), Some(end)) = (parts.next(), parts.next()) {
+            let start = start.parse()?;
+            let end = end.parse()?;
+            return Ok(Self { start, end });
+        }
+
+        Err(())
+    }
+}
+
+fn parse(input: &str) -> Vec<Line> {
+    input.lines().map(|l| l.parse::<Line>().unwrap()).collect()
+}
+
+fn overlap(lines: &[Line]) -> (i32, i32) {
+    let mut grid = HashMap::<(i32, i32), (i32, i32)>::new();
+
+    let mut part_one = 0;
+    let mut part_two = 0;
+
+    for line in lines {
+        let is_diagonal = line.start.x != line.end.x && line.start.y != line.end.y;
+        for p in line.points() {
+            let entry = grid.entry(p).or_default();
+            let mut current = *entry;
+            current.1 += 1;
+
+            if !is_diagonal {
+                current.0 += 1;
+                if current.0 == 2 {
+                    part_one += 1;
+                }
+            }
+
+            if current.1 == 2 {
+                part_two += 1;
+            }
+
+            *entry = current;
+        }
+    }
+
+    (part_one, part_two)
+}
+
+pub fn solve(input: &str) -> (i32, i32) {
+    overlap(&parse(input))
+}
+
+fn solve_one(lines: &[Line]) -> i32 {
+    overlap(lines).0
+}
+
+fn solve_two(lines: &[Line]) -> i32 {
+    overlap(lines).1
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 5;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Vec<Line>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Line>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Line>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
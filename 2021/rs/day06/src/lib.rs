@@ -0,0 +1,93 @@
+#[inline(always)]
+fn iterate(queue: &mut [usize; 9], times: i32) {
+    for _ in 0..times {
+        queue.rotate_left(1);
+        let eights = queue[8];
+        queue[6] += eights;
+    }
+}
+
+fn parse(input: &str) -> Vec<usize> {
+    input
+        .trim()
+        .split(',')
+        .map(|n| n.parse().unwrap())
+        .collect()
+}
+
+fn spawn(fish: &[usize]) -> (usize, usize) {
+    let mut queue = [0usize; 9];
+
+    for &num in fish {
+        if let Some(slot) = queue.get_mut(num) {
+            *slot += 1;
+            continue;
+        }
+
+        unreachable!()
+    }
+
+    iterate(&mut queue, 80);
+
+    let part_one = queue.iter().sum();
+
+    iterate(&mut queue, 256 - 80);
+
+    (part_one, queue.iter().sum::<usize>())
+}
+
+pub fn simulate(input: &str) -> (usize, usize) {
+    spawn(&parse(input))
+}
+
+fn solve_one(fish: &[usize]) -> usize {
+    spawn(fish).0
+}
+
+fn solve_two(fish: &[usize]) -> usize {
+    spawn(fish).1
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 6;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Vec<usize>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<usize>) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<usize>) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,113 @@
+fn simple_fuel_consumption(crabs: &[i32], position: i32) -> i32 {
+    crabs
+        .iter()
+        .fold(0, |acc, crab| acc + (position - crab).abs())
+}
+
+fn summation_fuel_consumption(crabs: &[i32], position: i32) -> i32 {
+    crabs.iter().fold(0, |acc, crab| {
+        let distance = (position - crab).abs();
+        acc + (distance * (distance + 1)) / 2
+    })
+}
+
+fn binary_search(
+    crabs: &[i32],
+    mut left: i32,
+    mut right: i32,
+    fuel_fn: fn(&[i32], i32) -> i32,
+) -> i32 {
+    let mut lowest_amount = i32::MAX;
+
+    while left < right {
+        let mid = (left + right) / 2;
+
+        let current_result = fuel_fn(crabs, mid);
+        let right_result = fuel_fn(crabs, mid + 1);
+
+        lowest_amount = lowest_amount.min(current_result);
+        lowest_amount = lowest_amount.min(current_result);
+
+        if right_result >= current_result {
+            right = mid;
+        } else {
+            left = mid + 1;
+        }
+    }
+
+    lowest_amount.min(fuel_fn(crabs, left))
+}
+
+fn parse(input: &str) -> Vec<i32> {
+    input
+        .trim()
+        .split(',')
+        .map(|string| string.parse().unwrap())
+        .collect()
+}
+
+fn align(crabs: &[i32]) -> (i32, i32) {
+    let left = *crabs.iter().min().unwrap();
+    let right = *crabs.iter().max().unwrap();
+
+    (
+        binary_search(crabs, left, right, simple_fuel_consumption),
+        binary_search(crabs, left, right, summation_fuel_consumption),
+    )
+}
+
+pub fn solve(input: &str) -> (i32, i32) {
+    align(&parse(input))
+}
+
+fn solve_one(crabs: &[i32]) -> i32 {
+    align(crabs).0
+}
+
+fn solve_two(crabs: &[i32]) -> i32 {
+    align(crabs).1
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 7;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Vec<i32>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<i32>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<i32>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
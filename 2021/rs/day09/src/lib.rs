@@ -0,0 +1,152 @@
+use std::collections::{HashSet, VecDeque};
+
+/// The height of every location on the map.
+pub type Grid = Vec<Vec<i32>>;
+
+fn char_to_i32(ch: char) -> i32 {
+    match ch {
+        '1' => 1,
+        '2' => 2,
+        '3' => 3,
+        '4' => 4,
+        '5' => 5,
+        '6' => 6,
+        '7' => 7,
+        '8' => 8,
+        '9' => 9,
+        '0' => 0,
+        _ => unreachable!(),
+    }
+}
+
+fn inc(n: usize) -> Option<usize> {
+    Some(n + 1)
+}
+
+fn dec(n: usize) -> Option<usize> {
+    if n == 0 {
+        None
+    } else {
+        Some(n - 1)
+    }
+}
+
+fn noop(n: usize) -> Option<usize> {
+    Some(n)
+}
+
+type NeighborFn = fn(usize) -> Option<usize>;
+
+const NEIGHBORS: [(NeighborFn, NeighborFn); 4] =
+    [(inc, noop), (dec, noop), (noop, inc), (noop, dec)];
+
+fn parse(input: &str) -> Grid {
+    input
+        .lines()
+        .map(|l| l.chars().map(char_to_i32).collect())
+        .collect()
+}
+
+fn explore(grid: &Grid) -> (i32, i32) {
+    let mut queue = VecDeque::new();
+    let mut seen = HashSet::new();
+    let mut sizes = Vec::new();
+    let mut risk_levels_sum = 0;
+
+    for (y, row) in grid.iter().enumerate() {
+        for (x, &value) in row.iter().enumerate() {
+            if value == 9 || seen.contains(&(x, y)) {
+                continue;
+            }
+
+            seen.insert((x, y));
+            queue.push_back((x, y));
+            let mut size = 0;
+
+            let mut lowest = value;
+
+            while let Some(tuple) = queue.pop_front() {
+                size += 1;
+
+                for (x_fn, y_fn) in NEIGHBORS {
+                    if let (Some(x), Some(y)) = (x_fn(tuple.0), y_fn(tuple.1)) {
+                        if let Some(row) = grid.get(y) {
+                            if let Some(&value) = row.get(x) {
+                                if value != 9 && seen.insert((x, y)) {
+                                    lowest = lowest.min(value);
+                                    queue.push_back((x, y))
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            sizes.push(size);
+            risk_levels_sum += lowest + 1;
+        }
+    }
+
+    sizes.sort();
+
+    (
+        risk_levels_sum,
+        (0..3).fold(1, |acc, _| acc * sizes.pop().unwrap()),
+    )
+}
+
+pub fn solve(input: &str) -> (i32, i32) {
+    explore(&parse(input))
+}
+
+fn solve_one(grid: &Grid) -> i32 {
+    explore(grid).0
+}
+
+fn solve_two(grid: &Grid) -> i32 {
+    explore(grid).1
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 9;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Grid, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Grid) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Grid) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
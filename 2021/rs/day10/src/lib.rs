@@ -0,0 +1,124 @@
+fn find_illegal_char(line: &str, stack: &mut Vec<char>) -> Option<char> {
+    for ch in line.chars() {
+        match ch {
+            '(' | '<' | '[' | '{' => stack.push(ch),
+            ')' | '>' | ']' | '}' => match stack.pop() {
+                Some(opener) => match (opener, ch) {
+                    ('(', ')') => (),
+                    ('{', '}') => (),
+                    ('[', ']') => (),
+                    ('<', '>') => (),
+                    _ => return Some(ch),
+                },
+                None => return Some(ch),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    None
+}
+
+fn score_stack(stack: &mut Vec<char>) -> u64 {
+    let mut score = 0;
+
+    while let Some(ch) = stack.pop() {
+        score *= 5;
+        score += match ch {
+            '(' => 1,
+            '[' => 2,
+            '{' => 3,
+            '<' => 4,
+            _ => unreachable!(),
+        }
+    }
+
+    score
+}
+
+fn parse(input: &str) -> Vec<String> {
+    input.lines().map(str::to_string).collect()
+}
+
+fn check(lines: &[String]) -> (i32, u64) {
+    let mut stack = Vec::new();
+
+    let mut syntax_error_score = 0;
+    let mut autocomplete_scores = Vec::new();
+    for line in lines {
+        if let Some(ch) = find_illegal_char(line, &mut stack) {
+            syntax_error_score += match ch {
+                ')' => 3,
+                ']' => 57,
+                '}' => 1197,
+                '>' => 25137,
+                _ => unreachable!(),
+            };
+            stack.clear()
+        } else {
+            autocomplete_scores.push(score_stack(&mut stack));
+        }
+    }
+
+    autocomplete_scores.sort();
+    (
+        syntax_error_score,
+        autocomplete_scores[autocomplete_scores.len() / 2],
+    )
+}
+
+pub fn solve(input: &str) -> (i32, u64) {
+    check(&parse(input))
+}
+
+fn solve_one(lines: &[String]) -> i32 {
+    check(lines).0
+}
+
+fn solve_two(lines: &[String]) -> u64 {
+    check(lines).1
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+pub fn part_two(input: &str) -> u64 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 10;
+
+    type PartOne = i32;
+    type PartTwo = u64;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> u64 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<String>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<String>) -> u64 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
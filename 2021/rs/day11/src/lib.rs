@@ -0,0 +1,176 @@
+const GRID_DIMENSION: usize = 10;
+
+/// The energy level of every octopus.
+pub type Grid = [[Octopus; GRID_DIMENSION]; GRID_DIMENSION];
+
+/// An octopus, either charging up or flashing.
+#[derive(Clone, Copy)]
+pub enum Octopus {
+    Normal(u32),
+    Flashing,
+}
+
+fn noop(n: usize) -> Option<usize> {
+    Some(n)
+}
+
+fn inc(n: usize) -> Option<usize> {
+    match n + 1 {
+        GRID_DIMENSION => None,
+        _ => Some(n + 1),
+    }
+}
+
+fn dec(n: usize) -> Option<usize> {
+    match n {
+        0 => None,
+        _ => Some(n - 1),
+    }
+}
+
+type NeighborFn = fn(usize) -> Option<usize>;
+
+const NEIGHBORS: [(NeighborFn, NeighborFn); 8] = [
+    (inc, noop),
+    (inc, inc),
+    (inc, dec),
+    (noop, inc),
+    (noop, dec),
+    (dec, inc),
+    (dec, noop),
+    (dec, dec),
+];
+
+fn propagate_flashes(grid: &mut Grid, x: usize, y: usize, increment: bool) {
+    unsafe {
+        let row = grid.get_unchecked_mut(y);
+        let octopus = row.get_unchecked_mut(x);
+        match octopus {
+            Octopus::Normal(9) if increment => *octopus = Octopus::Flashing,
+            Octopus::Normal(v @ 0..=8) if increment => {
+                *v += 1;
+                return;
+            }
+            Octopus::Normal(0..=9) | Octopus::Flashing => return,
+            _ => *octopus = Octopus::Flashing,
+        }
+    }
+
+    for (x_fn, y_fn) in NEIGHBORS {
+        if let (Some(x), Some(y)) = (x_fn(x), y_fn(y)) {
+            propagate_flashes(grid, x, y, true)
+        }
+    }
+}
+
+fn parse(input: &str) -> Grid {
+    let mut grid: Grid = [[Octopus::Flashing; GRID_DIMENSION]; GRID_DIMENSION];
+
+    for (y, line) in input.lines().enumerate() {
+        for (x, ch) in line.char_indices() {
+            grid[y][x] = Octopus::Normal(ch.to_digit(10).unwrap())
+        }
+    }
+
+    grid
+}
+
+fn flash(grid: &Grid) -> (i32, i32) {
+    let mut grid = *grid;
+
+    let mut flashing = 0;
+
+    for step in 1.. {
+        for row in grid.iter_mut() {
+            for octopus in row.iter_mut() {
+                match octopus {
+                    Octopus::Normal(value) => *value += 1,
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        for y in 0..GRID_DIMENSION {
+            for x in 0..GRID_DIMENSION {
+                propagate_flashes(&mut grid, x, y, false);
+            }
+        }
+
+        let mut all_flashing = true;
+        for row in grid.iter_mut() {
+            for octopus in row.iter_mut() {
+                match octopus {
+                    Octopus::Flashing => {
+                        if step <= 100 {
+                            flashing += 1;
+                        }
+                        *octopus = Octopus::Normal(0)
+                    }
+                    _ => all_flashing = false,
+                }
+            }
+        }
+
+        if all_flashing {
+            return (flashing, step);
+        }
+    }
+
+    unreachable!()
+}
+
+pub fn solve(input: &str) -> (i32, i32) {
+    flash(&parse(input))
+}
+
+fn solve_one(grid: &Grid) -> i32 {
+    flash(grid).0
+}
+
+fn solve_two(grid: &Grid) -> i32 {
+    flash(grid).1
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 11;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Grid, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Grid) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Grid) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,162 @@
+use std::collections::{HashMap, HashSet};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct Cave<'a>(&'a str);
+
+impl Cave<'_> {
+    fn is_small(&self) -> bool {
+        !matches!(self.0.chars().next(), Some('A'..='Z'))
+    }
+}
+
+struct CaveNetwork<'a> {
+    edges: HashMap<Cave<'a>, Vec<Cave<'a>>>,
+}
+
+impl<'a> CaveNetwork<'a> {
+    fn new() -> Self {
+        Self {
+            edges: HashMap::new(),
+        }
+    }
+
+    fn insert(&mut self, a: Cave<'a>, b: Cave<'a>) {
+        for (from, to) in [(a, b), (b, a)] {
+            if from.0 == "end" || to.0 == "start" {
+                continue;
+            }
+
+            let entry_from = self.edges.entry(from).or_default();
+            entry_from.push(to);
+        }
+    }
+
+    fn count_paths_to_end(
+        &self,
+        current: &Cave<'a>,
+        visited: &mut HashSet<Cave<'a>>,
+        can_double_visit: bool,
+    ) -> i32 {
+        let mut paths_found = 0;
+
+        for cave in self.edges.get(current).unwrap() {
+            if cave.0 == "end" {
+                paths_found += 1;
+                continue;
+            }
+
+            let is_small = cave.is_small();
+            let mut remove_visited = true;
+            let mut can_double_visit = can_double_visit;
+            if is_small {
+                if visited.contains(cave) {
+                    if !can_double_visit {
+                        continue;
+                    }
+
+                    remove_visited = false;
+                    can_double_visit = false;
+                }
+
+                visited.insert(*cave);
+            }
+            paths_found += self.count_paths_to_end(cave, visited, can_double_visit);
+            if is_small && remove_visited {
+                visited.remove(cave);
+            }
+        }
+
+        paths_found
+    }
+
+    fn count_all_routes(&mut self, can_double_visit: bool) -> i32 {
+        let start = Cave("start");
+
+        let mut visited = HashSet::new();
+        self.count_paths_to_end(&start, &mut visited, can_double_visit)
+    }
+}
+
+/// Two caves joined by a passage.
+pub type Connection = (String, String);
+
+fn parse(input: &str) -> Vec<Connection> {
+    input
+        .lines()
+        .map(|line| {
+            let mut split = line.split('-');
+            let a = split.next().unwrap().to_string();
+            let b = split.next().unwrap().to_string();
+            (a, b)
+        })
+        .collect()
+}
+
+fn count_routes(connections: &[Connection]) -> (i32, i32) {
+    let mut network = CaveNetwork::new();
+
+    for (a, b) in connections {
+        network.insert(Cave(a), Cave(b));
+    }
+
+    (
+        network.count_all_routes(false),
+        network.count_all_routes(true),
+    )
+}
+
+pub fn solve(input: &str) -> (i32, i32) {
+    count_routes(&parse(input))
+}
+
+fn solve_one(connections: &[Connection]) -> i32 {
+    count_routes(connections).0
+}
+
+fn solve_two(connections: &[Connection]) -> i32 {
+    count_routes(connections).1
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 12;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Connection>;
+
+    fn parse(input: &str) -> Result<Vec<Connection>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Connection>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Connection>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
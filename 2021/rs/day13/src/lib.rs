@@ -0,0 +1,151 @@
+use std::collections::HashSet;
+
+/// A fold along an axis at a line.
+pub type Fold = (char, usize);
+
+/// The dots on the paper and the folds to make.
+pub type Instructions = (HashSet<(usize, usize)>, Vec<Fold>);
+
+fn parse(input: &str) -> Instructions {
+    let mut coords: HashSet<(usize, usize)> = HashSet::new();
+    let mut folds: Vec<(char, usize)> = vec![];
+
+    let mut lines = input.lines();
+    for line in lines.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+
+        let mut split = line.split(',');
+        let x = split.next().unwrap().parse::<usize>().unwrap();
+        let y = split.next().unwrap().parse::<usize>().unwrap();
+
+        coords.insert((x, y));
+    }
+
+    for line in lines {
+        let rule = line.split_ascii_whitespace().nth(2).unwrap();
+        let mut parts = rule.split('=');
+        let axis = parts.next().unwrap().chars().next().unwrap();
+        let location = parts.next().unwrap().parse().unwrap();
+        folds.push((axis, location));
+    }
+
+    (coords, folds)
+}
+
+fn render_code(coords: HashSet<(usize, usize)>) -> String {
+    let mut buffer: Vec<Vec<char>> = vec![];
+
+    for (x, y) in coords {
+        while buffer.len() <= y {
+            buffer.push(vec![]);
+        }
+
+        let row = &mut buffer[y];
+
+        while row.len() <= x {
+            row.push(' ');
+        }
+
+        row[x] = '#';
+    }
+
+    let mut output = String::new();
+
+    for row in buffer {
+        for ch in row {
+            output.push(ch);
+        }
+
+        output.push('\n');
+    }
+
+    output
+}
+
+fn solve((coords, folds): &Instructions) -> (usize, HashSet<(usize, usize)>) {
+    let mut coords = coords.clone();
+
+    let mut buffer_set = HashSet::new();
+    let mut after_first = 0;
+
+    for (fold, &(axis, location)) in folds.iter().enumerate() {
+        let offset = 2 * location;
+
+        for &(mut coord) in &coords {
+            let value = match axis {
+                'x' => &mut coord.0,
+                'y' => &mut coord.1,
+                _ => unreachable!(),
+            };
+
+            if *value > location {
+                *value = offset - *value;
+            }
+
+            buffer_set.insert(coord);
+        }
+
+        std::mem::swap(&mut buffer_set, &mut coords);
+        buffer_set.clear();
+
+        if fold == 0 {
+            after_first = coords.len();
+        }
+    }
+
+    (after_first, coords)
+}
+
+fn solve_one(instructions: &Instructions) -> usize {
+    solve(instructions).0
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(instructions: &Instructions) -> String {
+    render_code(solve(instructions).1)
+}
+
+pub fn part_two(input: &str) -> String {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 13;
+
+    type PartOne = usize;
+    type PartTwo = String;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> String {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Instructions;
+
+    fn parse(input: &str) -> Result<Instructions, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Instructions) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Instructions) -> String {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+
+type RuleMap = HashMap<char, Vec<(char, char)>>;
+
+fn parse_rules(lines: std::str::Lines) -> RuleMap {
+    let mut rules: RuleMap = HashMap::new();
+
+    for line in lines {
+        let mut chars = line.chars();
+        let first = chars.next().unwrap();
+        let second = chars.next().unwrap();
+        let output = chars.nth(4).unwrap();
+
+        let outputs = rules.entry(first).or_default();
+        outputs.push((second, output));
+    }
+
+    rules
+}
+
+fn find_outputs((a, b): (char, char), rules: &RuleMap) -> ((char, char), (char, char)) {
+    if let Some(outputs) = rules.get(&a) {
+        for &(second, output) in outputs {
+            if second == b {
+                return ((a, output), (output, b));
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+/// The polymer template and the pair insertion rules.
+pub struct Polymer {
+    template: String,
+    rules: RuleMap,
+}
+
+fn parse(input: &str) -> Polymer {
+    let mut lines = input.lines();
+
+    let template = lines.next().unwrap().to_string();
+
+    lines.next();
+
+    let rules = parse_rules(lines);
+
+    Polymer { template, rules }
+}
+
+fn solve(polymer: &Polymer, iterations: i32) -> u64 {
+    let Polymer {
+        template: polymer_template,
+        rules,
+    } = polymer;
+
+    // maps inputs to outputs, e.g. CH -> (CB, BH)
+    let mut input_output_map = HashMap::new();
+    // counts yields, e.g. CH -> { C: 1, B: 1 }
+    // only count the first element of the polymer to prevent double counting
+    let mut one_round_totals = HashMap::new();
+
+    // for each possible pair of elements
+    for (a, outputs) in rules {
+        for (b, _) in outputs {
+            let pair = (*a, *b);
+            // find its two outputs
+            let (one, two) = find_outputs(pair, rules);
+
+            let mut output_totals = HashMap::new();
+
+            // track what is produced
+            input_output_map.insert(pair, (one, two));
+
+            // and how much is produced
+            *output_totals.entry(one.0).or_insert(0) += 1;
+            *output_totals.entry(two.0).or_insert(0) += 1;
+
+            one_round_totals.insert(pair, output_totals);
+        }
+    }
+
+    let mut pair_counts = HashMap::new();
+
+    // parse the polymer template into pairs of elements
+    for chars in polymer_template
+        .chars()
+        .zip(polymer_template.chars().skip(1))
+    {
+        // and count how often each pair appears
+        *pair_counts.entry(chars).or_insert(0) += 1;
+    }
+
+    let mut temporary_pair_counts = HashMap::new();
+
+    // as many times as necessary
+    for _ in 0..(iterations - 1) {
+        // for each pair of elements currently present
+        for (pair, count) in &pair_counts {
+            // find its outputs
+            if let Some((output_one, output_two)) = input_output_map.get(pair) {
+                // and track how much is produced
+                *temporary_pair_counts.entry(*output_one).or_insert(0) += count;
+                *temporary_pair_counts.entry(*output_two).or_insert(0) += count;
+            }
+        }
+
+        // swap the memory for the next iteration
+        std::mem::swap(&mut temporary_pair_counts, &mut pair_counts);
+        temporary_pair_counts.clear();
+    }
+    // after calculating how much of each pair of elements is produced
+
+    let mut grand_total_map = HashMap::new();
+    // for each pair produced
+    for (pair, count) in pair_counts {
+        // find out what elements are produced by that pair
+        for (ch, amount) in one_round_totals.get(&pair).unwrap() {
+            // and calculate how much is produced
+            *grand_total_map.entry(*ch).or_insert(0) += count * amount;
+        }
+    }
+
+    // only the first elements of pairs were counted, which means the last
+    // element in the polymer template was never counted
+    *grand_total_map
+        .entry(polymer_template.chars().last().unwrap())
+        .or_default() += 1;
+
+    let mut min = u64::MAX;
+    let mut max = u64::MIN;
+
+    for (_, count) in grand_total_map {
+        min = count.min(min);
+        max = count.max(max);
+    }
+
+    max - min
+}
+
+fn solve_one(polymer: &Polymer) -> u64 {
+    solve(polymer, 10)
+}
+
+pub fn part_one(input: &str) -> u64 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(polymer: &Polymer) -> u64 {
+    solve(polymer, 40)
+}
+
+pub fn part_two(input: &str) -> u64 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 14;
+
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn part_one(input: &str) -> u64 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> u64 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Polymer;
+
+    fn parse(input: &str) -> Result<Polymer, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Polymer) -> u64 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Polymer) -> u64 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,148 @@
+use std::cmp::Ordering;
+
+struct Probe {
+    x_velocity: i32,
+    x_position: i32,
+    y_velocity: i32,
+    y_position: i32,
+    x_target: (i32, i32),
+    y_target: (i32, i32),
+    max_height: i32,
+}
+
+impl Probe {
+    fn new(x_velocity: i32, y_velocity: i32, x_target: (i32, i32), y_target: (i32, i32)) -> Self {
+        Probe {
+            x_velocity,
+            y_velocity,
+            x_target,
+            y_target,
+            x_position: 0,
+            y_position: 0,
+            max_height: 0,
+        }
+    }
+
+    fn step(&mut self) {
+        self.x_position += self.x_velocity;
+        self.y_position += self.y_velocity;
+        self.max_height = self.max_height.max(self.y_position);
+
+        match self.x_velocity.cmp(&0) {
+            Ordering::Equal => (),
+            Ordering::Greater => self.x_velocity -= 1,
+            Ordering::Less => self.x_velocity += 1,
+        }
+
+        self.y_velocity -= 1;
+    }
+
+    fn hits_target(&mut self) -> bool {
+        while self.x_position <= self.x_target.1 && self.y_position >= self.y_target.0 {
+            if self.x_position >= self.x_target.0 && self.y_position <= self.y_target.1 {
+                return true;
+            }
+
+            self.step();
+        }
+
+        false
+    }
+}
+
+/// The x and y ranges of the target area.
+pub type Target = ((i32, i32), (i32, i32));
+
+fn parse(input: &str) -> Target {
+    let mut parts = input.split_ascii_whitespace();
+
+    let x = parts.nth(2).unwrap();
+    let mut x = x[2..x.len() - 1].split("..");
+    let mut y = parts.next().unwrap()[2..].split("..");
+
+    (
+        (
+            x.next().unwrap().parse().unwrap(),
+            x.next().unwrap().parse().unwrap(),
+        ),
+        (
+            y.next().unwrap().parse().unwrap(),
+            y.next().unwrap().parse().unwrap(),
+        ),
+    )
+}
+
+fn launch(target: &Target) -> (i32, i32) {
+    let ((x1, x2), (y1, y2)) = *target;
+
+    let mut max_height = i32::MIN;
+    let mut hits = 0;
+
+    for y_velocity in y1..(y1.abs() + y1.abs() / 2) {
+        for x_velocity in 0..(x2 + x2 / 2) {
+            let mut probe = Probe::new(x_velocity, y_velocity, (x1, x2), (y1, y2));
+            if probe.hits_target() {
+                max_height = max_height.max(probe.max_height);
+                hits += 1;
+            }
+        }
+    }
+
+    (max_height, hits)
+}
+
+pub fn solve(input: &str) -> (i32, i32) {
+    launch(&parse(input))
+}
+
+fn solve_one(target: &Target) -> i32 {
+    launch(target).0
+}
+
+fn solve_two(target: &Target) -> i32 {
+    launch(target).1
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 17;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Target;
+
+    fn parse(input: &str) -> Result<Target, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Target) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Target) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+fn char_to_usize(ch: char) -> usize {
+    match ch {
+        '.' => 0,
+        _ => 1,
+    }
+}
+
+/// The image enhancement algorithm and the picture it is applied to.
+#[derive(Clone)]
+pub struct Picture {
+    data: HashMap<(i32, i32), usize>,
+    max_x: i32,
+    max_y: i32,
+    min_x: i32,
+    min_y: i32,
+    default: usize,
+    alg: Vec<usize>,
+}
+
+impl Picture {
+    fn from_str(string: &str) -> Self {
+        let mut lines = string.lines();
+        let alg = lines.next().unwrap().chars().map(char_to_usize).collect();
+        lines.next();
+
+        let data: HashMap<(i32, i32), _> = lines
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars()
+                    .map(char_to_usize)
+                    .enumerate()
+                    .map(move |(x, p)| ((x as i32, y as i32), p))
+            })
+            .collect();
+
+        let mut max_x = 0;
+        let mut max_y = 0;
+
+        for i in 0.. {
+            if data.contains_key(&(i, i)) {
+                max_x = i;
+                max_y = i;
+            } else {
+                break;
+            }
+        }
+
+        Self {
+            data,
+            max_x,
+            max_y,
+            min_x: 0,
+            min_y: 0,
+            default: 0,
+            alg,
+        }
+    }
+
+    fn get_pixel(&self, x: i32, y: i32) -> usize {
+        *self.data.get(&(x, y)).unwrap_or(&self.default)
+    }
+
+    fn grow(&mut self) {
+        let mut new_data = HashMap::new();
+        for y in (self.min_y - 1)..=self.max_y + 1 {
+            for x in (self.min_x - 1)..=self.max_x + 1 {
+                let mut index = 0;
+
+                for dy in (y - 1)..=(y + 1) {
+                    for dx in (x - 1)..=(x + 1) {
+                        index = (index << 1) | self.get_pixel(dx, dy);
+                    }
+                }
+
+                new_data.insert(
+                    (x, y),
+                    match self.alg.get(index) {
+                        Some(pixel) => *pixel,
+                        _ => unreachable!(),
+                    },
+                );
+            }
+        }
+
+        // the pixels past the edge are all the same, so they all turn into
+        // the first or last pixel of the algorithm
+        self.default = self.alg[if self.default == 0 { 0 } else { 511 }];
+        self.min_x -= 1;
+        self.min_y -= 1;
+        self.max_x += 1;
+        self.max_y += 1;
+        self.data = new_data;
+    }
+}
+
+fn parse(input: &str) -> Picture {
+    Picture::from_str(input)
+}
+
+fn grow(picture: &Picture, rounds: i32) -> usize {
+    let mut picture = picture.clone();
+
+    for _ in 0..rounds {
+        picture.grow();
+    }
+
+    picture.data.values().sum()
+}
+
+fn solve_one(picture: &Picture) -> usize {
+    grow(picture, 2)
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(picture: &Picture) -> usize {
+    grow(picture, 50)
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 20;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Picture;
+
+    fn parse(input: &str) -> Result<Picture, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Picture) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Picture) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,212 @@
+/// A cuboid, along with the parts of it later steps overlap.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cube {
+    x1: i128,
+    x2: i128,
+    y1: i128,
+    y2: i128,
+    z1: i128,
+    z2: i128,
+    intersections: Vec<Cube>,
+}
+
+impl Cube {
+    fn volume(&self) -> i128 {
+        (self.x2 - self.x1 + 1) * (self.y2 - self.y1 + 1) * (self.z2 - self.z1 + 1)
+            - self.intersections.iter().fold(0, |acc, c| acc + c.volume())
+    }
+
+    fn intersect(&mut self, other: &Self) {
+        let x1 = self.x1.max(other.x1);
+        let x2 = self.x2.min(other.x2);
+        if x1 > x2 {
+            return;
+        }
+        let y1 = self.y1.max(other.y1);
+        let y2 = self.y2.min(other.y2);
+        if y1 > y2 {
+            return;
+        }
+        let z1 = self.z1.max(other.z1);
+        let z2 = self.z2.min(other.z2);
+        if z1 > z2 {
+            return;
+        }
+
+        let new = Cube {
+            x1,
+            x2,
+            y1,
+            y2,
+            z1,
+            z2,
+            intersections: vec![],
+        };
+
+        for intersection in &mut self.intersections {
+            intersection.intersect(&new)
+        }
+
+        self.intersections.push(new);
+    }
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 22;
+
+    type PartOne = i128;
+    type PartTwo = i128;
+
+    fn part_one(input: &str) -> i128 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i128 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Step>;
+
+    fn parse(input: &str) -> Result<Vec<Step>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Step>) -> i128 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Step>) -> i128 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
+
+fn parse_range(range: &str) -> (i128, i128) {
+    let mut parts = range[2..].split("..");
+
+    (
+        parts.next().unwrap().parse().unwrap(),
+        parts.next().unwrap().parse().unwrap(),
+    )
+}
+
+fn parse_cube(line: &str) -> (&str, Cube) {
+    let mut parts = line.split_ascii_whitespace();
+
+    let state = parts.next().unwrap();
+
+    let mut ranges = parts.next().unwrap().split(',');
+    let (x1, x2) = parse_range(ranges.next().unwrap());
+    let (y1, y2) = parse_range(ranges.next().unwrap());
+    let (z1, z2) = parse_range(ranges.next().unwrap());
+
+    (
+        state,
+        Cube {
+            x1,
+            x2,
+            y1,
+            y2,
+            z1,
+            z2,
+            intersections: vec![],
+        },
+    )
+}
+
+/// Whether a step turns cubes on, and the cuboid it covers.
+pub type Step = (bool, Cube);
+
+fn parse(input: &str) -> Vec<Step> {
+    input
+        .lines()
+        .map(|line| {
+            let (state, cube) = parse_cube(line);
+            (state == "on", cube)
+        })
+        .collect()
+}
+
+fn solve(steps: &[Step], limits: Option<(i128, i128)>) -> i128 {
+    let mut stack = Vec::<Cube>::new();
+
+    for (on, cube) in steps {
+        if let Some((lower_limit, upper_limit)) = limits {
+            if cube.x1 < lower_limit
+                || cube.x2 > upper_limit
+                || cube.y1 < lower_limit
+                || cube.y2 > upper_limit
+                || cube.z1 < lower_limit
+                || cube.z2 > upper_limit
+            {
+                continue;
+            }
+        }
+
+        for other in &mut stack {
+            other.intersect(cube);
+        }
+
+        if *on {
+            stack.push(cube.clone())
+        }
+    }
+
+    stack.into_iter().fold(0, |acc, c| acc + c.volume())
+}
+
+fn solve_one(steps: &[Step]) -> i128 {
+    solve(steps, Some((-50, 50)))
+}
+
+pub fn part_one(input: &str) -> i128 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(steps: &[Step]) -> i128 {
+    solve(steps, None)
+}
+
+pub fn part_two(input: &str) -> i128 {
+    solve_two(&parse(input))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_split() {
+        let (_, mut a) = parse_cube("on x=10..12,y=10..12,z=10..12");
+        let (_, mut b) = parse_cube("on x=11..13,y=11..13,z=11..13");
+
+        println!("a: {}", a.volume());
+        println!("b: {}", b.volume());
+
+        a.intersect(&b);
+
+        println!("a after: {}", a.volume());
+        println!("b after: {}", b.volume());
+        println!("a + b: {}", a.volume() + b.volume());
+
+        let (_, mut c) = parse_cube("off x=9..11,y=9..11,z=9..11");
+        a.intersect(&c);
+        b.intersect(&c);
+
+        println!("a + b again: {}", a.volume() + b.volume());
+
+        let (_, d) = parse_cube("on x=10..10,y=10..10,z=10..10");
+
+        a.intersect(&d);
+        b.intersect(&d);
+        c.intersect(&d);
+
+        assert_eq!(39, a.volume() + b.volume() + d.volume());
+    }
+}
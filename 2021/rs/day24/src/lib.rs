@@ -0,0 +1,157 @@
+/// The constants one digit's block of the MONAD program uses.
+#[derive(Default, Debug)]
+pub struct Step {
+    id: usize,
+    pop: bool,
+    x_mod: i32,
+    w_mod: i32,
+}
+
+fn parse(input: &str) -> [(Step, Step); 7] {
+    let mut steps: [Step; 14] = Default::default();
+
+    let mut lines = input.lines();
+
+    for (step, slot) in steps.iter_mut().enumerate() {
+        let mut next_step = Step {
+            id: step + 1,
+            ..Default::default()
+        };
+        let mut first_one_seen = false;
+
+        for line in lines.by_ref() {
+            let mut parts = line.split_ascii_whitespace();
+
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some("div"), Some("z"), Some("1")) => next_step.pop = false,
+                (Some("div"), Some("z"), Some("26")) => next_step.pop = true,
+                (Some("add"), Some("x"), Some(amount)) if amount != "z" => {
+                    next_step.x_mod = amount.parse().unwrap();
+                }
+                (Some("add"), Some("y"), Some("1")) if !first_one_seen => first_one_seen = true,
+                (Some("add"), Some("y"), Some(amount)) if amount != "w" && amount != "25" => {
+                    next_step.w_mod = amount.parse().unwrap();
+                    break;
+                }
+                _ => (),
+            }
+        }
+
+        *slot = next_step;
+    }
+
+    let mut pairs: [(Step, Step); 7] = Default::default();
+
+    for step in steps {
+        if step.pop {
+            for pair in pairs.iter_mut().rev() {
+                if let (Step { id: 1..=14, .. }, pop @ Step { id: 0, .. }) = pair {
+                    *pop = step;
+                    break;
+                }
+            }
+        } else {
+            for pair in pairs.iter_mut() {
+                if let (push @ Step { id: 0, .. }, _) = pair {
+                    *push = step;
+                    break;
+                }
+            }
+        }
+    }
+
+    pairs
+}
+
+enum Kind {
+    Min,
+    Max,
+}
+
+fn solve(pairs: &[(Step, Step); 7], kind: Kind) -> i64 {
+    let mut model_number = [0; 14];
+
+    for (push_step, pop_step) in pairs {
+        let x_mod = pop_step.x_mod;
+        let w_mod = push_step.w_mod;
+
+        let mut push_value = match kind {
+            Kind::Max => 9,
+            Kind::Min => 1,
+        };
+        let mut pop_value;
+
+        loop {
+            pop_value = push_value + x_mod + w_mod;
+            if (1..=9).contains(&pop_value) {
+                break;
+            }
+            push_value += match kind {
+                Kind::Max => -1,
+                Kind::Min => 1,
+            };
+        }
+
+        model_number[push_step.id - 1] = push_value;
+        model_number[pop_step.id - 1] = pop_value;
+    }
+
+    let mut result = 0i64;
+
+    for value in model_number {
+        result *= 10;
+        result += value as i64;
+    }
+
+    result
+}
+
+fn solve_one(pairs: &[(Step, Step); 7]) -> i64 {
+    solve(pairs, Kind::Max)
+}
+
+pub fn part_one(input: &str) -> i64 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(pairs: &[(Step, Step); 7]) -> i64 {
+    solve(pairs, Kind::Min)
+}
+
+pub fn part_two(input: &str) -> i64 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 24;
+
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn part_one(input: &str) -> i64 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i64 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = [(Step, Step); 7];
+
+    fn parse(input: &str) -> Result<[(Step, Step); 7], aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &[(Step, Step); 7]) -> i64 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &[(Step, Step); 7]) -> i64 {
+        solve_two(input)
+    }
+}
@@ -0,0 +1,73 @@
+fn parse(input: &str) -> Vec<Vec<i32>> {
+    let mut elves = vec![vec![]];
+
+    for line in input.lines() {
+        match line.parse::<i32>() {
+            Ok(value) => elves.last_mut().unwrap().push(value),
+            _ => elves.push(vec![]),
+        }
+    }
+
+    elves
+}
+
+fn totals(elves: &[Vec<i32>]) -> Vec<i32> {
+    elves.iter().map(|elf| elf.iter().sum()).collect()
+}
+
+fn solve_one(elves: &[Vec<i32>]) -> i32 {
+    totals(elves).into_iter().max().unwrap()
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(elves: &[Vec<i32>]) -> i32 {
+    let mut totals = totals(elves);
+
+    totals.sort();
+    totals.reverse();
+
+    totals[0..3].iter().sum()
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i32>>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Vec<i32>>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Vec<i32>>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,127 @@
+fn parse(input: &str) -> Vec<(char, char)> {
+    input
+        .lines()
+        .map(|line| {
+            let mut parts = line.split_ascii_whitespace();
+            let opponent = parts.next().unwrap().chars().next().unwrap();
+            let me = parts.next().unwrap().chars().next().unwrap();
+            (opponent, me)
+        })
+        .collect()
+}
+
+fn solve_one(rounds: &[(char, char)]) -> i32 {
+    let mut score = 0;
+
+    for &(opponent, me) in rounds {
+        match me {
+            'X' => {
+                score += 1 + match opponent {
+                    'A' => 3,
+                    'B' => 0,
+                    'C' => 6,
+                    _ => unreachable!(),
+                }
+            }
+            'Y' => {
+                score += 2 + match opponent {
+                    'A' => 6,
+                    'B' => 3,
+                    'C' => 0,
+                    _ => unreachable!(),
+                }
+            }
+            'Z' => {
+                score += 3 + match opponent {
+                    'A' => 0,
+                    'B' => 6,
+                    'C' => 3,
+                    _ => unreachable!(),
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    score
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(rounds: &[(char, char)]) -> i32 {
+    let mut score = 0;
+
+    for &(opponent, me) in rounds {
+        match me {
+            'X' => {
+                score += match opponent {
+                    'A' => 3,
+                    'B' => 1,
+                    'C' => 2,
+                    _ => unreachable!(),
+                }
+            }
+            'Y' => {
+                score += 3 + match opponent {
+                    'A' => 1,
+                    'B' => 2,
+                    'C' => 3,
+                    _ => unreachable!(),
+                }
+            }
+            'Z' => {
+                score += 6 + match opponent {
+                    'A' => 2,
+                    'B' => 3,
+                    'C' => 1,
+                    _ => unreachable!(),
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    score
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<(char, char)>;
+
+    fn parse(input: &str) -> Result<Vec<(char, char)>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<(char, char)>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<(char, char)>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
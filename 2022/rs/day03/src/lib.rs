@@ -0,0 +1,108 @@
+use std::collections::HashSet;
+
+fn find_common_char(line: &str) -> char {
+    let middle = line.len() / 2;
+
+    let first_pocket = line[0..middle].chars().collect::<HashSet<_>>();
+
+    for ch in line[middle..].chars() {
+        if first_pocket.contains(&ch) {
+            return ch;
+        }
+    }
+
+    unreachable!();
+}
+
+fn get_priority(ch: char) -> i32 {
+    match ch {
+        'a'..='z' => 1 + ch as i32 - 'a' as i32,
+        'A'..='Z' => 27 + ch as i32 - 'A' as i32,
+        _ => unreachable!(),
+    }
+}
+
+fn parse(input: &str) -> Vec<String> {
+    input.lines().map(str::to_string).collect()
+}
+
+fn solve_one(lines: &[String]) -> i32 {
+    let mut result = 0;
+
+    for line in lines {
+        let ch = find_common_char(line);
+        result += get_priority(ch);
+    }
+
+    result
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(lines: &[String]) -> i32 {
+    let mut result = 0;
+
+    let mut lines = lines.iter();
+
+    while let Some(first) = lines.next() {
+        let second = lines.next().unwrap();
+        let third = lines.next().unwrap();
+
+        let first_set = first.chars().collect::<HashSet<_>>();
+        let second_set = second.chars().collect::<HashSet<_>>();
+        let third_set = third.chars().collect::<HashSet<_>>();
+
+        let intersection = first_set
+            .intersection(&second_set)
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        let ch = intersection.intersection(&third_set).next().unwrap();
+
+        result += get_priority(*ch);
+    }
+
+    result
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<String>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<String>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
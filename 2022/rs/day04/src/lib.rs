@@ -0,0 +1,102 @@
+fn parse_range(range: &str) -> (i32, i32) {
+    let mut parts = range.split('-');
+
+    let first = parts.next().unwrap().parse().unwrap();
+    let second = parts.next().unwrap().parse().unwrap();
+
+    (first, second)
+}
+
+/// The sections two elves are assigned.
+pub type Pair = ((i32, i32), (i32, i32));
+
+fn parse(input: &str) -> Vec<Pair> {
+    input
+        .lines()
+        .map(|line| {
+            let mut parts = line.split(',');
+
+            let first = parse_range(parts.next().unwrap());
+            let second = parse_range(parts.next().unwrap());
+
+            (first, second)
+        })
+        .collect()
+}
+
+fn solve_one(pairs: &[Pair]) -> i32 {
+    let mut result = 0;
+
+    for &(first, second) in pairs {
+        if (first.0 <= second.0 && first.1 >= second.1)
+            || (second.0 <= first.0 && second.1 >= first.1)
+        {
+            result += 1;
+        }
+    }
+
+    result
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(pairs: &[Pair]) -> i32 {
+    let mut result = 0;
+
+    for &(first, second) in pairs {
+        let first_range = first.0..=first.1;
+        let second_range = second.0..=second.1;
+
+        if first_range.contains(&second.0)
+            || first_range.contains(&second.1)
+            || second_range.contains(&first.0)
+            || second_range.contains(&first.1)
+        {
+            result += 1;
+        }
+    }
+
+    result
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Pair>;
+
+    fn parse(input: &str) -> Result<Vec<Pair>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Pair>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Pair>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,141 @@
+struct Move {
+    count: i32,
+    src: usize,
+    dest: usize,
+}
+
+/// The starting stacks of crates and the moves the crane makes.
+pub struct Procedure {
+    stacks: [Vec<char>; 9],
+    moves: Vec<Move>,
+}
+
+fn parse(input: &str) -> Procedure {
+    let mut stacks: [Vec<char>; 9] = [
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    ];
+
+    let mut lines = input.lines();
+
+    for line in lines.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+
+        for (index, ch) in line.char_indices() {
+            match ch {
+                'A'..='Z' => stacks[(index - 1) / 4].insert(0, ch),
+                _ => continue,
+            }
+        }
+    }
+
+    let moves = lines
+        .map(|line| {
+            let mut parts = line.split_ascii_whitespace();
+            parts.next();
+            let count: i32 = parts.next().unwrap().parse().unwrap();
+            parts.next();
+            let src: usize = parts.next().unwrap().parse().unwrap();
+            parts.next();
+            let dest: usize = parts.next().unwrap().parse().unwrap();
+
+            Move {
+                count,
+                src: src - 1,
+                dest: dest - 1,
+            }
+        })
+        .collect();
+
+    Procedure { stacks, moves }
+}
+
+fn get_result(stacks: [Vec<char>; 9]) -> String {
+    stacks.iter().filter_map(|stack| stack.last()).collect()
+}
+
+fn solve_one(procedure: &Procedure) -> String {
+    let mut stacks = procedure.stacks.clone();
+
+    for &Move { count, src, dest } in &procedure.moves {
+        for _ in 0..count {
+            let ch = stacks[src].pop().unwrap();
+            stacks[dest].push(ch);
+        }
+    }
+
+    get_result(stacks)
+}
+
+pub fn part_one(input: &str) -> String {
+    solve_one(&parse(input))
+}
+
+fn solve_two(procedure: &Procedure) -> String {
+    let mut stacks = procedure.stacks.clone();
+    let mut temp_stack = Vec::new();
+
+    for &Move { count, src, dest } in &procedure.moves {
+        let src = &mut stacks[src];
+        for _ in 0..count {
+            let ch = src.pop().unwrap();
+            temp_stack.push(ch);
+        }
+
+        let dest = &mut stacks[dest];
+        while let Some(ch) = temp_stack.pop() {
+            dest.push(ch);
+        }
+    }
+
+    get_result(stacks)
+}
+
+pub fn part_two(input: &str) -> String {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn part_one(input: &str) -> String {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> String {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Procedure;
+
+    fn parse(input: &str) -> Result<Procedure, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Procedure) -> String {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Procedure) -> String {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
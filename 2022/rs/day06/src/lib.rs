@@ -0,0 +1,81 @@
+fn all_unique_chars<const N: usize>(slice: &[char; N]) -> bool {
+    for i in 0..N {
+        for j in (i + 1)..N {
+            if slice[i] == slice[j] {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+fn parse(input: &str) -> Vec<char> {
+    input.chars().collect()
+}
+
+fn find_unique_sequence<const N: usize>(signal: &[char]) -> usize {
+    let mut buffer = ['\0'; N];
+
+    for (index, &ch) in signal.iter().enumerate() {
+        buffer[index % N] = ch;
+
+        if index >= N && all_unique_chars(&buffer) {
+            return index + 1;
+        }
+    }
+
+    unreachable!()
+}
+
+fn solve_one(signal: &[char]) -> usize {
+    find_unique_sequence::<4>(signal)
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(signal: &[char]) -> usize {
+    find_unique_sequence::<14>(signal)
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<char>;
+
+    fn parse(input: &str) -> Result<Vec<char>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<char>) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<char>) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,154 @@
+use std::str::Lines;
+
+/// A file or a directory, with its total size.
+#[derive(Debug)]
+#[allow(dead_code)]
+pub enum Dirent {
+    File(String, usize),
+    Dir(String, usize, Vec<Dirent>),
+}
+
+impl Dirent {
+    fn add_child(&mut self, child: Dirent) {
+        match self {
+            Dirent::File(..) => unreachable!(),
+            Dirent::Dir(_, size, children) => {
+                *size += child.size();
+                children.push(child)
+            }
+        }
+    }
+
+    fn size(&self) -> usize {
+        *match self {
+            Dirent::File(_, size) => size,
+            Dirent::Dir(_, size, _) => size,
+        }
+    }
+}
+
+fn build_tree(cd: &str, lines: &mut Lines) -> Option<Dirent> {
+    let name = cd.split_ascii_whitespace().last().unwrap();
+    if name == ".." {
+        return None;
+    }
+
+    lines.next();
+
+    let mut dirent = Dirent::Dir(name.to_string(), 0, vec![]);
+
+    while let Some(line) = lines.next() {
+        if line.starts_with("$") {
+            match build_tree(line, lines) {
+                Some(child) => dirent.add_child(child),
+                None => return Some(dirent),
+            }
+        } else if !line.starts_with("dir") {
+            let mut parts = line.split_ascii_whitespace();
+            let size: usize = parts.next().unwrap().parse().unwrap();
+            let name = parts.next().unwrap();
+            dirent.add_child(Dirent::File(name.to_string(), size));
+        }
+    }
+
+    Some(dirent)
+}
+
+fn parse(input: &str) -> Dirent {
+    let mut lines = input.lines();
+
+    let cd = lines.next().unwrap();
+
+    build_tree(cd, &mut lines).unwrap()
+}
+
+fn solve_one(root: &Dirent) -> usize {
+    let mut queue = vec![root];
+    let mut total = 0;
+
+    while let Some(dirent) = queue.pop() {
+        match dirent {
+            Dirent::Dir(_, size, children) => {
+                for child in children {
+                    queue.push(child)
+                }
+
+                if *size <= 100000 {
+                    total += size;
+                }
+            }
+            _ => continue,
+        }
+    }
+
+    total
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(root: &Dirent) -> usize {
+    let space_required = 70000000 - 30000000;
+    let target_increase = root.size() - space_required;
+
+    let mut queue = vec![root];
+    let mut result = usize::MAX;
+
+    while let Some(dirent) = queue.pop() {
+        match dirent {
+            Dirent::Dir(_, size, children) => {
+                for child in children {
+                    queue.push(child)
+                }
+
+                if *size > target_increase {
+                    result = result.min(*size)
+                }
+            }
+            _ => continue,
+        }
+    }
+
+    result
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 7;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Dirent;
+
+    fn parse(input: &str) -> Result<Dirent, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Dirent) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Dirent) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
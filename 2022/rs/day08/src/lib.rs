@@ -0,0 +1,159 @@
+#[derive(Debug, Clone, Copy)]
+struct Tree {
+    height: i32,
+    visible: bool,
+}
+
+fn height_to_i32(height: char) -> i32 {
+    match height {
+        '0' => 0,
+        '1' => 1,
+        '2' => 2,
+        '3' => 3,
+        '4' => 4,
+        '5' => 5,
+        '6' => 6,
+        '7' => 7,
+        '8' => 8,
+        '9' => 9,
+        _ => unreachable!(),
+    }
+}
+
+impl Tree {
+    fn new(height: i32) -> Self {
+        Tree {
+            height,
+            visible: false,
+        }
+    }
+}
+
+fn rotate<T: Copy + Clone>(matrix: &mut Vec<Vec<T>>) {
+    let clone = matrix.clone();
+
+    for i in 0..matrix.len() {
+        for j in 0..matrix.len() {
+            matrix[i][j] = clone[j][i];
+        }
+    }
+
+    for row in matrix {
+        row.reverse();
+    }
+}
+
+fn parse(input: &str) -> Vec<Vec<i32>> {
+    input
+        .lines()
+        .map(|l| l.chars().map(height_to_i32).collect())
+        .collect()
+}
+
+fn solve_one(heights: &[Vec<i32>]) -> i32 {
+    let mut forest: Vec<Vec<_>> = heights
+        .iter()
+        .map(|row| row.iter().copied().map(Tree::new).collect())
+        .collect();
+
+    for _ in 0..4 {
+        for row in &mut forest {
+            let mut current_height = -1;
+            for tree in row {
+                if tree.height > current_height {
+                    tree.visible = true;
+                    current_height = tree.height;
+                }
+            }
+        }
+        rotate(&mut forest);
+    }
+
+    forest
+        .into_iter()
+        .flat_map(|row| row.into_iter().map(|tree| if tree.visible { 1 } else { 0 }))
+        .sum()
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+const DIRS: [[i32; 2]; 4] = [[0, 1], [0, -1], [1, 0], [-1, 0]];
+
+fn solve_two(forest: &[Vec<i32>]) -> i32 {
+    let mut highest_score = 0;
+
+    for (i, row) in forest.iter().enumerate() {
+        for (j, tree) in row.iter().enumerate() {
+            let mut scores = [0; 4];
+
+            for (dir, [mod_x, mod_y]) in DIRS.iter().enumerate() {
+                let mut x = j;
+                let mut y = i;
+
+                let mut view_distance = 0;
+                loop {
+                    x = (x as i32 + mod_x) as usize;
+                    y = (y as i32 + mod_y) as usize;
+
+                    if let Some(neighbor) = forest.get(y).and_then(|row| row.get(x)) {
+                        view_distance += 1;
+                        if neighbor >= tree {
+                            break;
+                        }
+                    } else {
+                        break;
+                    }
+                }
+
+                scores[dir] = view_distance;
+            }
+
+            let [a, b, c, d] = scores;
+            highest_score = highest_score.max(a * b * c * d);
+        }
+    }
+
+    highest_score
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 8;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i32>>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Vec<i32>>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Vec<i32>>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,117 @@
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+struct Coord(i32, i32);
+
+impl Coord {
+    fn is_touching(&self, other: &Self) -> bool {
+        matches!(
+            ((self.0 - other.0).abs(), (self.1 - other.1).abs()),
+            (0, 0) | (1, 0) | (0, 1) | (1, 1)
+        )
+    }
+
+    fn diff(&self, other: &Self) -> Self {
+        Self(self.0 - other.0, self.1 - other.1)
+    }
+}
+
+/// A direction to move the head of the rope in, and how far.
+pub type Motion = (char, i32);
+
+fn parse(input: &str) -> Vec<Motion> {
+    input
+        .lines()
+        .map(|line| {
+            let mut parts = line.split_ascii_whitespace();
+            let dir = parts.next().unwrap().chars().next().unwrap();
+            (dir, parts.next().unwrap().parse().unwrap())
+        })
+        .collect()
+}
+
+fn move_rope<const N: usize>(motions: &[Motion]) -> usize {
+    let mut rope = [Coord(0, 0); N];
+
+    let mut positions = HashSet::<Coord>::new();
+
+    for &(dir, steps) in motions {
+        for _ in 0..steps {
+            positions.insert(rope[N - 1]);
+            let head = &mut rope[0];
+
+            match dir {
+                'U' => head.1 += 1,
+                'R' => head.0 += 1,
+                'D' => head.1 -= 1,
+                'L' => head.0 -= 1,
+                _ => unreachable!(),
+            }
+
+            for i in 0..(N - 1) {
+                let j = i + 1;
+                if !rope[i].is_touching(&rope[j]) {
+                    let Coord(x, y) = rope[i].diff(&rope[j]);
+                    let segment = &mut rope[j];
+                    segment.0 += x.signum();
+                    segment.1 += y.signum();
+                }
+            }
+        }
+    }
+
+    positions.insert(rope[N - 1]);
+    positions.len()
+}
+
+fn solve_one(motions: &[Motion]) -> usize {
+    move_rope::<2>(motions)
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(motions: &[Motion]) -> usize {
+    move_rope::<10>(motions)
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 9;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Motion>;
+
+    fn parse(input: &str) -> Result<Vec<Motion>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Motion>) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Motion>) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
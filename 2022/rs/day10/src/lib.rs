@@ -0,0 +1,144 @@
+/// An instruction for the CPU.
+#[derive(Clone, Copy)]
+pub enum Op {
+    Noop,
+    Addx(i32),
+}
+
+impl From<&str> for Op {
+    fn from(line: &str) -> Self {
+        let mut parts = line.split_ascii_whitespace();
+        match parts.next().unwrap() {
+            "noop" => Op::Noop,
+            "addx" => Op::Addx(parts.next().unwrap().parse().unwrap()),
+            _ => unreachable!(),
+        }
+    }
+}
+
+struct ProgramIter<'a> {
+    ops: std::slice::Iter<'a, Op>,
+    x: i32,
+    cycle: i32,
+    op: Option<Op>,
+}
+
+impl<'a> ProgramIter<'a> {
+    fn new(ops: &'a [Op]) -> Self {
+        Self {
+            ops: ops.iter(),
+            x: 1,
+            cycle: 0,
+            op: None,
+        }
+    }
+}
+
+impl Iterator for ProgramIter<'_> {
+    type Item = (i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = match self.op {
+            None => match self.ops.next().copied() {
+                Some(Op::Noop) => self.x,
+                op @ Some(Op::Addx(..)) => {
+                    self.op = op;
+                    self.x
+                }
+                None => return None,
+            },
+            Some(Op::Addx(v)) => {
+                self.op = None;
+                let x = self.x;
+                self.x += v;
+                x
+            }
+            _ => unreachable!(),
+        };
+
+        let cycle = self.cycle;
+        self.cycle += 1;
+        Some((cycle, x))
+    }
+}
+
+fn parse(input: &str) -> Vec<Op> {
+    input.lines().map(Op::from).collect()
+}
+
+fn solve_one(ops: &[Op]) -> i32 {
+    let mut result = 0;
+
+    for (cycle, x) in ProgramIter::new(ops) {
+        let nth_cycle = cycle + 1;
+        if [20, 60, 100, 140, 180, 220].contains(&nth_cycle) {
+            result += nth_cycle * x
+        }
+    }
+
+    result
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(ops: &[Op]) -> String {
+    let mut output = String::new();
+
+    for (cycle, x) in ProgramIter::new(ops) {
+        let x_position = cycle % 40;
+        if x_position == 0 && cycle != 0 {
+            output.push('\n');
+        }
+
+        if [x - 1, x, x + 1].contains(&x_position) {
+            output.push('#');
+        } else {
+            output.push('.');
+        }
+    }
+    output.push('\n');
+
+    output
+}
+
+pub fn part_two(input: &str) -> String {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 10;
+
+    type PartOne = i32;
+    type PartTwo = String;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> String {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Op>;
+
+    fn parse(input: &str) -> Result<Vec<Op>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Op>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Op>) -> String {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
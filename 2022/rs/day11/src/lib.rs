@@ -0,0 +1,236 @@
+#[derive(Debug, Clone)]
+enum Operand {
+    Old,
+    Literal(u64),
+}
+
+impl Operand {
+    fn or(self, other: Self) -> Self {
+        match self {
+            Self::Old => other,
+            _ => self,
+        }
+    }
+}
+
+impl From<&str> for Operand {
+    fn from(value: &str) -> Self {
+        match value {
+            "old" => Self::Old,
+            _ => Self::Literal(value.parse().unwrap()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Op {
+    Add(Operand),
+    Mul(Operand),
+}
+
+impl Op {
+    fn from(operator: &str, operand1: Operand, operand2: Operand) -> Self {
+        match operator {
+            "+" => Self::Add(operand1.or(operand2)),
+            "*" => Self::Mul(operand1.or(operand2)),
+            _ => unreachable!(),
+        }
+    }
+}
+
+fn parse_list_items(line: &str) -> Vec<u64> {
+    let nums = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+
+    line.split_ascii_whitespace()
+        .flat_map(|item| {
+            if !item.starts_with(nums) {
+                return None;
+            }
+
+            let end = item.len() - if item.ends_with(',') { 1 } else { 0 };
+
+            item[0..end].parse().ok()
+        })
+        .collect()
+}
+
+fn parse_operation(line: &str) -> Op {
+    let mut parts = line.split_ascii_whitespace();
+    parts.next();
+    parts.next();
+    parts.next();
+    let operand1 = Operand::from(parts.next().unwrap());
+    let operator = parts.next().unwrap();
+    let operand2 = Operand::from(parts.next().unwrap());
+    Op::from(operator, operand1, operand2)
+}
+
+/// A monkey, the items it holds and how it decides where to throw them.
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<u64>,
+    operation: Op,
+    test: u64,
+    yes: usize,
+    no: usize,
+    monkey_business: u64,
+}
+
+impl Monkey {
+    fn inspect(&mut self, item: &u64) -> u64 {
+        self.monkey_business += 1;
+        match self.operation {
+            Op::Add(Operand::Literal(value)) => item + value,
+            Op::Add(Operand::Old) => item + item,
+            Op::Mul(Operand::Literal(value)) => item * value,
+            Op::Mul(Operand::Old) => item * item,
+        }
+    }
+
+    fn test(&self, item: &u64) -> usize {
+        if item.is_multiple_of(self.test) {
+            self.yes
+        } else {
+            self.no
+        }
+    }
+}
+
+fn parse(input: &str) -> Vec<Monkey> {
+    let mut lines = input.lines();
+
+    let mut monkeys = Vec::new();
+
+    while let Some(_) = lines.next() {
+        let items = lines.next().unwrap().trim();
+        let operation = lines.next().unwrap().trim();
+
+        let test = lines.next().unwrap().trim();
+        let yes = lines.next().unwrap().trim();
+        let no = lines.next().unwrap().trim();
+        lines.next();
+
+        monkeys.push(Monkey {
+            items: parse_list_items(items),
+            operation: parse_operation(operation),
+            test: test
+                .split_ascii_whitespace()
+                .last()
+                .unwrap()
+                .parse()
+                .unwrap(),
+            yes: yes
+                .split_ascii_whitespace()
+                .last()
+                .unwrap()
+                .parse()
+                .unwrap(),
+            no: no.split_ascii_whitespace().last().unwrap().parse().unwrap(),
+            monkey_business: 0,
+        })
+    }
+
+    monkeys
+}
+
+fn find_monkey_business(mut monkeys: Vec<Monkey>, modulo: Option<u64>, rounds: u64) -> u64 {
+    let mut buffer = Vec::new();
+
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            std::mem::swap(&mut monkeys[i].items, &mut buffer);
+            for item in &buffer {
+                let mut new_value = monkeys[i].inspect(item);
+                if let Some(modulo) = modulo {
+                    new_value %= modulo
+                } else {
+                    new_value /= 3
+                };
+                let index = monkeys[i].test(&new_value);
+                monkeys[index].items.push(new_value);
+            }
+            buffer.clear();
+        }
+    }
+
+    let mut highest = 0;
+    let mut second = 0;
+
+    for monkey in monkeys {
+        if monkey.monkey_business > highest {
+            second = highest;
+            highest = monkey.monkey_business;
+        } else if monkey.monkey_business > second {
+            second = monkey.monkey_business;
+        }
+    }
+
+    highest * second
+}
+
+fn solve_one(monkeys: &[Monkey]) -> u64 {
+    find_monkey_business(monkeys.to_vec(), None, 20)
+}
+
+pub fn part_one(input: &str) -> u64 {
+    solve_one(&parse(input))
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let t = b;
+        b = a % b;
+        a = t;
+    }
+    a
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    (a * b) / gcd(a, b)
+}
+
+fn solve_two(monkeys: &[Monkey]) -> u64 {
+    let modulo = monkeys.iter().map(|m| m.test).fold(1, lcm);
+
+    find_monkey_business(monkeys.to_vec(), Some(modulo), 10000)
+}
+
+pub fn part_two(input: &str) -> u64 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 11;
+
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn part_one(input: &str) -> u64 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> u64 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Vec<Monkey>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Monkey>) -> u64 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Monkey>) -> u64 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,124 @@
+use std::collections::VecDeque;
+
+struct Tile {
+    ch: char,
+    seen: bool,
+}
+
+impl Tile {
+    fn new(ch: char) -> Self {
+        Self { ch, seen: false }
+    }
+}
+
+fn parse(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|line| line.chars().collect()).collect()
+}
+
+fn walk_trail(map: &[Vec<char>], starting_char: char) -> i32 {
+    let mut queue = VecDeque::new();
+
+    let mut grid: Vec<Vec<_>> = map
+        .iter()
+        .enumerate()
+        .map(|(y, row)| {
+            let mut tiles = Vec::with_capacity(row.len());
+
+            for (x, &ch) in row.iter().enumerate() {
+                let mut tile = Tile::new(ch);
+
+                if ch == starting_char {
+                    queue.push_back(((x, y), 0, ch));
+                    tile.seen = true;
+                }
+
+                tiles.push(tile);
+            }
+
+            tiles
+        })
+        .collect();
+
+    while let Some(((x, y), count, current)) = queue.pop_front() {
+        for (new_x, new_y) in [
+            (x + 1, y),
+            (x.saturating_sub(1), y),
+            (x, y + 1),
+            (x, y.saturating_sub(1)),
+        ] {
+            if let Some(tile) = grid.get_mut(new_y).and_then(|row| row.get_mut(new_x)) {
+                if tile.seen {
+                    continue;
+                }
+
+                let height = match tile.ch {
+                    'E' => 'z',
+                    ch => ch,
+                };
+
+                if current == 'S' || (current as u32 + 1) >= height as u32 {
+                    if tile.ch == 'E' {
+                        return count + 1;
+                    }
+
+                    tile.seen = true;
+                    queue.push_back(((new_x, new_y), count + 1, tile.ch));
+                }
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+fn solve_one(map: &[Vec<char>]) -> i32 {
+    walk_trail(map, 'S')
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(map: &[Vec<char>]) -> i32 {
+    walk_trail(map, 'a')
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 12;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<char>>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Vec<char>>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Vec<char>>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
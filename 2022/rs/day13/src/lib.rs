@@ -0,0 +1,215 @@
+use std::{cmp::Ordering, fmt::Display, iter::Peekable, str::Chars};
+
+/// An integer or a list of packets.
+#[derive(Debug, Clone)]
+pub enum Packet {
+    Integer(i32),
+    List(Vec<Packet>),
+}
+
+impl Display for Packet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::List(list) => {
+                write!(f, "[")?;
+                let mut commas = list.len();
+
+                for packet in list {
+                    write!(f, "{packet}")?;
+                    commas -= 1;
+                    if commas != 0 {
+                        write!(f, ",")?;
+                    }
+                }
+
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+impl Eq for Packet {}
+
+impl PartialEq<Packet> for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Integer(l0), Self::Integer(r0)) => l0 == r0,
+            (Self::List(l0), Self::List(r0)) => {
+                l0.len() == r0.len() && l0.iter().zip(r0.iter()).all(|(l, r)| l == r)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Packet::Integer(a), Packet::Integer(b)) => a.cmp(b),
+            (int @ Packet::Integer(_), list @ Packet::List(_)) => {
+                Packet::List(vec![int.clone()]).cmp(list)
+            }
+            (list @ Packet::List(_), int @ Packet::Integer(_)) => {
+                list.cmp(&Packet::List(vec![int.clone()]))
+            }
+            (Packet::List(a), Packet::List(b)) => {
+                for i in 0..(a.len().max(b.len())) {
+                    match (a.get(i), b.get(i)) {
+                        (None, Some(_)) | (Some(_), None) => return a.len().cmp(&b.len()),
+                        (Some(a), Some(b)) => match a.cmp(b) {
+                            Ordering::Equal => continue,
+                            result => return result,
+                        },
+                        (None, None) => unreachable!(),
+                    }
+                }
+
+                Ordering::Equal
+            }
+        }
+    }
+}
+
+impl From<&str> for Packet {
+    fn from(line: &str) -> Self {
+        let chars = line.chars();
+
+        Self::from(&mut chars.into_iter().peekable())
+    }
+}
+
+impl From<&mut Peekable<Chars<'_>>> for Packet {
+    fn from(chars: &mut Peekable<Chars<'_>>) -> Self {
+        while let Some(ch) = chars.next() {
+            match ch {
+                '[' => {
+                    let mut list = Vec::new();
+                    while let Some(next) = chars.peek() {
+                        match next {
+                            ']' => {
+                                chars.next();
+                                return Self::List(list);
+                            }
+                            ',' => {
+                                chars.next();
+                                list.push(Self::from(chars.into_iter()))
+                            }
+                            '0'..='9' => {
+                                let mut acc = next.to_digit(10).unwrap();
+                                chars.next();
+
+                                while let Some(nexter @ '0'..='9') = chars.peek() {
+                                    acc *= 10;
+                                    acc += nexter.to_digit(10).unwrap();
+                                    chars.next();
+                                }
+                                list.push(Self::Integer(acc as i32));
+                            }
+                            '[' => list.push(Self::from(chars.into_iter())),
+                            _ => unreachable!(),
+                        }
+                    }
+                }
+                '0'..='9' => return Self::Integer(ch.to_digit(10).unwrap() as i32),
+                _ => unreachable!(),
+            }
+        }
+
+        unreachable!()
+    }
+}
+
+fn parse(input: &str) -> Vec<Packet> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(Packet::from)
+        .collect()
+}
+
+fn solve_one(packets: &[Packet]) -> i32 {
+    let mut result = 0;
+
+    for (index, pair) in packets.chunks(2).enumerate() {
+        if pair[0] < pair[1] {
+            result += index as i32 + 1;
+        }
+    }
+
+    result
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(packets: &[Packet]) -> i32 {
+    let mut packets = packets.to_vec();
+
+    packets.push(Packet::from("[[6]]"));
+    packets.push(Packet::from("[[2]]"));
+
+    packets.sort();
+
+    let mut result = 0;
+
+    for (index, packet) in packets.into_iter().enumerate() {
+        let string = packet.to_string();
+        if string == "[[2]]" || string == "[[6]]" {
+            if result == 0 {
+                result = index as i32 + 1
+            } else {
+                return result * (index as i32 + 1);
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 13;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Packet>;
+
+    fn parse(input: &str) -> Result<Vec<Packet>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Packet>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Packet>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
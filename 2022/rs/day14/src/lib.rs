@@ -0,0 +1,248 @@
+/// The rock formations in the cave, and the sand resting on them.
+#[derive(Clone)]
+pub struct Cave {
+    map: Vec<Vec<char>>,
+}
+
+impl Cave {
+    fn new() -> Self {
+        Self { map: Vec::new() }
+    }
+
+    fn from(data: &str) -> Self {
+        let mut cave = Cave::new();
+        let lines: Vec<Vec<_>> = data
+            .lines()
+            .map(|line| line.split(" -> ").map(Coord::from).collect())
+            .collect();
+
+        for mut line in lines {
+            let mut current = line.pop().unwrap();
+            while !line.is_empty() {
+                *cave.get_mut(&current) = '#';
+                let last = line.last().unwrap();
+                current.move_towards(last);
+                if current == *last {
+                    line.pop();
+                }
+            }
+            *cave.get_mut(&current) = '#';
+        }
+
+        cave
+    }
+
+    fn current_width(&self) -> usize {
+        self.map.first().map(|row| row.len()).unwrap_or(0)
+    }
+
+    fn add_rows(&mut self, y: usize) {
+        let width = self.current_width();
+
+        while self.map.len() <= y {
+            self.map.push(vec!['.'; width])
+        }
+    }
+
+    fn add_cols(&mut self, x: usize) {
+        let width = self.current_width();
+        if width > x {
+            return;
+        }
+
+        let additional_width = x - self.current_width() + 1;
+
+        if additional_width == 0 {
+            return;
+        }
+
+        for row in &mut self.map {
+            row.extend(['.'].iter().cycle().take(additional_width))
+        }
+    }
+
+    fn get(&self, &Coord(x, y): &Coord) -> Option<&char> {
+        self.map.get(y).and_then(|row| row.get(x))
+    }
+
+    fn get_mut(&mut self, &Coord(x, y): &Coord) -> &mut char {
+        self.add_rows(y);
+        self.add_cols(x);
+
+        if let Some(row) = self.map.get_mut(y) {
+            if let Some(ch) = row.get_mut(x) {
+                return ch;
+            }
+        }
+
+        unreachable!()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Coord(usize, usize);
+
+impl From<&str> for Coord {
+    fn from(pair: &str) -> Self {
+        let mut parts = pair.split(',');
+
+        Self(
+            parts.next().unwrap().parse().unwrap(),
+            parts.next().unwrap().parse().unwrap(),
+        )
+    }
+}
+
+impl Coord {
+    fn move_towards(&mut self, other: &Self) {
+        if self.0 == other.0 {
+            if self.1 > other.1 {
+                self.1 -= 1;
+            } else {
+                self.1 += 1;
+            }
+        } else {
+            if self.0 > other.0 {
+                self.0 -= 1;
+            } else {
+                self.0 += 1;
+            }
+        }
+    }
+
+    fn move_down(&mut self) {
+        self.1 += 1;
+    }
+
+    fn move_left(&mut self) {
+        self.0 -= 1;
+    }
+
+    fn move_right(&mut self) {
+        self.0 += 1;
+    }
+
+    fn below_to_left(&self) -> Self {
+        Self(self.0 - 1, self.1 + 1)
+    }
+
+    fn below_to_right(&self) -> Self {
+        Self(self.0 + 1, self.1 + 1)
+    }
+
+    fn below(&self) -> Self {
+        Self(self.0, self.1 + 1)
+    }
+}
+
+fn flow_sand(mut cave: Cave) -> i32 {
+    let mut result = 0;
+
+    loop {
+        let mut location = Coord(500, 0);
+
+        loop {
+            match cave.get(&location.below()) {
+                Some('.') => {
+                    location.move_down();
+                    continue;
+                }
+                Some(_) => {}
+                None => return result,
+            }
+
+            match cave.get(&location.below_to_left()) {
+                Some('.') => {
+                    location.move_down();
+                    location.move_left();
+                    continue;
+                }
+                Some(_) => {}
+                None => return result,
+            }
+
+            match cave.get(&location.below_to_right()) {
+                Some('.') => {
+                    location.move_down();
+                    location.move_right();
+                    continue;
+                }
+                Some(_) => {}
+                None => return result,
+            }
+
+            *cave.get_mut(&location) = 'o';
+            result += 1;
+
+            if location == Coord(500, 0) {
+                return result;
+            }
+
+            break;
+        }
+    }
+}
+
+fn parse(input: &str) -> Cave {
+    Cave::from(input)
+}
+
+fn solve_one(cave: &Cave) -> i32 {
+    flow_sand(cave.clone())
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(cave: &Cave) -> i32 {
+    let mut cave = cave.clone();
+    cave.add_rows(cave.map.len() + 1);
+    cave.add_cols(cave.current_width() * 2);
+
+    for ch in cave.map.last_mut().unwrap().iter_mut() {
+        *ch = '#'
+    }
+
+    flow_sand(cave)
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 14;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Cave;
+
+    fn parse(input: &str) -> Result<Cave, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Cave) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Cave) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
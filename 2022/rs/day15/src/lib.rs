@@ -0,0 +1,186 @@
+use std::vec;
+use utils::Coord;
+
+/// A sensor and how far away the beacon closest to it is.
+pub struct Sensor {
+    location: Coord,
+    distance: i64,
+}
+
+impl Sensor {
+    fn in_range(&self, coord: &Coord) -> bool {
+        self.location.distance_to(coord) <= self.distance
+    }
+}
+
+/// The sensors and the beacons they found.
+pub type Report = (Vec<Sensor>, Vec<Coord>);
+
+fn parse(input: &str) -> Report {
+    let mut sensors = vec![];
+    let mut beacons = vec![];
+
+    for mut parts in input.lines().map(|l| {
+        l.split_ascii_whitespace()
+            .filter(|part| part.starts_with("x") || part.starts_with("y"))
+    }) {
+        let sensor_x = parts.next().unwrap();
+        let sensor_y = parts.next().unwrap();
+        let beacon_x = parts.next().unwrap();
+        let beacon_y = parts.next().unwrap();
+
+        let location = Coord(
+            sensor_x[2..sensor_x.len() - 1].parse().unwrap(),
+            sensor_y[2..sensor_y.len() - 1].parse().unwrap(),
+        );
+        let beacon = Coord(
+            beacon_x[2..beacon_x.len() - 1].parse().unwrap(),
+            beacon_y[2..].parse().unwrap(),
+        );
+        sensors.push(Sensor {
+            distance: location.distance_to(&beacon),
+            location,
+        });
+        beacons.push(beacon);
+    }
+
+    (sensors, beacons)
+}
+
+fn positions_without_beacon((sensors, beacons): &Report, row: i64) -> i64 {
+    let mut min_x = 0;
+    let mut max_x = 0;
+
+    for sensor in sensors {
+        min_x = min_x.min(sensor.location.0 - sensor.distance);
+        max_x = max_x.max(sensor.location.0 + sensor.distance);
+    }
+
+    let mut result = max_x - min_x;
+
+    'outer: for x in min_x..=max_x {
+        let loc = Coord(x, row);
+
+        for sensor in sensors {
+            if beacons.contains(&loc) || sensor.location == loc || sensor.in_range(&loc) {
+                continue 'outer;
+            }
+        }
+
+        result -= 1;
+    }
+
+    result
+}
+
+fn tuning_frequency((sensors, _): &Report, max_range: i64) -> i64 {
+    for sensor in sensors {
+        let y_range = sensor.distance + 1;
+
+        for dy in -y_range..=y_range {
+            let x_location = y_range - dy.abs();
+
+            'x_loop: for dx in [-x_location, x_location] {
+                let location = sensor.location + Coord(dx, dy);
+                if location.0 < 0
+                    || location.1 < 0
+                    || location.0 > max_range
+                    || location.1 > max_range
+                {
+                    continue;
+                }
+
+                for other in sensors {
+                    if other.in_range(&location) {
+                        continue 'x_loop;
+                    }
+                }
+
+                return location.0 * 4000000 + location.1;
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+fn solve_one(report: &Report) -> i64 {
+    positions_without_beacon(report, 2000000)
+}
+
+pub fn part_one(input: &str) -> i64 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(report: &Report) -> i64 {
+    tuning_frequency(report, 4000000)
+}
+
+pub fn part_two(input: &str) -> i64 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 15;
+
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn part_one(input: &str) -> i64 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i64 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Report;
+
+    fn parse(input: &str) -> Result<Report, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Report) -> i64 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Report) -> i64 {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3
+";
+
+    #[test]
+    fn test_positions_without_beacon() {
+        assert_eq!(positions_without_beacon(&parse(EXAMPLE), 10), 26);
+    }
+
+    #[test]
+    fn test_tuning_frequency() {
+        assert_eq!(tuning_frequency(&parse(EXAMPLE), 20), 56000011);
+    }
+}
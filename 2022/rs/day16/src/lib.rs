@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+use utils::Graph;
+
+fn parse_line(line: &str) -> (&str, i32, Vec<&str>) {
+    let mut parts = line.split_ascii_whitespace();
+    parts.next();
+    let tunnel = parts.next().unwrap();
+    parts.next();
+    parts.next();
+    let rate = parts.next().unwrap().split('=').next_back().unwrap();
+    let rate = rate[0..rate.len() - 1].parse().unwrap();
+    let mut tunnels = vec![];
+    let mut rev = parts.rev();
+
+    tunnels.push(rev.next().unwrap());
+
+    loop {
+        let dest = rev.next().unwrap();
+
+        if !dest.ends_with(",") {
+            break;
+        }
+
+        tunnels.push(&dest[0..dest.len() - 1]);
+    }
+
+    (tunnel, rate, tunnels)
+}
+
+/// A valve, its flow rate and the valves its tunnels lead to.
+pub type Valve = (String, i32, Vec<String>);
+
+fn parse(input: &str) -> Vec<Valve> {
+    input
+        .lines()
+        .map(|line| {
+            let (tunnel, rate, tunnels) = parse_line(line);
+            let tunnels = tunnels.into_iter().map(str::to_string).collect();
+            (tunnel.to_string(), rate, tunnels)
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+struct State<'a> {
+    location: &'a str,
+    time_remaining: i32,
+    rate: i32,
+    visited: i32,
+    total: i32,
+}
+
+fn calculate_flows(scan: &[Valve], time: i32) -> HashMap<i32, i32> {
+    let mut graph = Graph::<&str>::new();
+    let mut rates_map = HashMap::new();
+
+    for (tunnel, rate, tunnels) in scan {
+        rates_map.insert(tunnel.as_str(), *rate);
+
+        for dest in tunnels {
+            graph.add_edge(tunnel.as_str(), dest.as_str());
+        }
+    }
+
+    let valves: Vec<_> = rates_map
+        .iter()
+        .filter_map(|(key, value)| if *value == 0 { None } else { Some(*key) })
+        .collect();
+
+    let state_map: HashMap<_, _> = valves
+        .iter()
+        .enumerate()
+        .map(|(index, name)| (*name, 1 << index))
+        .collect();
+
+    let mut queue = Vec::from([State {
+        location: "AA",
+        time_remaining: time,
+        rate: 0,
+        visited: 0,
+        total: 0,
+    }]);
+    let mut flows = HashMap::new();
+    let all_visited = state_map.values().fold(0, |a, b| a | b);
+
+    while let Some(State {
+        location,
+        time_remaining,
+        rate,
+        visited,
+        total,
+    }) = queue.pop()
+    {
+        if visited == all_visited {
+            let entry = flows.entry(visited).or_default();
+            let new_total = total + rate * time_remaining;
+            if *entry < new_total {
+                *entry = new_total;
+            }
+            continue;
+        }
+
+        for next in &valves {
+            if (visited & state_map[next]) != 0 {
+                continue;
+            }
+
+            let time_elapsed = graph.distance_between(location, next) + 1;
+            let entry = flows.entry(visited).or_default();
+            let new_total = total + rate * time_remaining;
+            if *entry < new_total {
+                *entry = new_total;
+            }
+
+            if time_elapsed >= time_remaining {
+                continue;
+            }
+
+            queue.push(State {
+                location: next,
+                time_remaining: time_remaining - time_elapsed,
+                rate: rate + rates_map[next],
+                visited: visited | state_map[next],
+                total: total + rate * time_elapsed,
+            })
+        }
+    }
+
+    flows
+}
+
+fn solve_one(scan: &[Valve]) -> i32 {
+    *calculate_flows(scan, 30).values().max().unwrap()
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(scan: &[Valve]) -> i32 {
+    let flows = calculate_flows(scan, 26);
+
+    let mut result = 0;
+
+    for (index, (a, amount_a)) in flows.iter().enumerate() {
+        for (b, amount_b) in flows.iter().skip(index + 1) {
+            if a & b == 0 {
+                result = result.max(amount_a + amount_b);
+            }
+        }
+    }
+
+    result
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 16;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Valve>;
+
+    fn parse(input: &str) -> Result<Vec<Valve>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Valve>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Valve>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
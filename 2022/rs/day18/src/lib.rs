@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+use utils::Coord3D;
+
+fn parse_line(line: &str) -> Coord3D {
+    let mut parts = line.split(',');
+
+    Coord3D(
+        parts.next().unwrap().parse().unwrap(),
+        parts.next().unwrap().parse().unwrap(),
+        parts.next().unwrap().parse().unwrap(),
+    )
+}
+
+fn parse(input: &str) -> Vec<Coord3D> {
+    input.lines().map(parse_line).collect()
+}
+
+fn solve_one(coords: &[Coord3D]) -> i32 {
+    let mut result = coords.len() as i32 * 6;
+
+    for (i, coord) in coords.iter().enumerate() {
+        for other in coords.iter().skip(i + 1) {
+            if coord.adjacent(other) {
+                result -= 2;
+            }
+        }
+    }
+
+    result
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(coords: &[Coord3D]) -> i32 {
+    let lava_droplets: HashSet<_> = coords.iter().copied().collect();
+
+    let start = Coord3D(0, 0, 0);
+
+    let mut stack = vec![start];
+    let mut visited = HashSet::from([start]);
+    let mut result = 0;
+
+    let mut x_max = 0;
+    let mut y_max = 0;
+    let mut z_max = 0;
+    let mut x_min = 0;
+    let mut y_min = 0;
+    let mut z_min = 0;
+
+    for coord in &lava_droplets {
+        x_max = x_max.max(coord.0 + 1);
+        y_max = y_max.max(coord.1 + 1);
+        z_max = z_max.max(coord.2 + 1);
+        x_min = x_min.min(coord.0 - 1);
+        y_min = y_min.min(coord.1 - 1);
+        z_min = z_min.min(coord.2 - 1);
+    }
+
+    while let Some(location) = stack.pop() {
+        for neighbor in [
+            [1, 0, 0],
+            [-1, 0, 0],
+            [0, 1, 0],
+            [0, -1, 0],
+            [0, 0, 1],
+            [0, 0, -1],
+        ]
+        .map(|t| location.translate(t))
+        {
+            if (-1..=x_max).contains(&neighbor.0)
+                && (-1..=y_max).contains(&neighbor.1)
+                && (-1..=z_max).contains(&neighbor.2)
+            {
+                if lava_droplets.contains(&neighbor) {
+                    result += 1;
+                } else if visited.insert(neighbor) {
+                    stack.push(neighbor)
+                }
+            }
+        }
+    }
+
+    result
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 18;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Coord3D>;
+
+    fn parse(input: &str) -> Result<Vec<Coord3D>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Coord3D>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Coord3D>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,99 @@
+#[derive(Debug)]
+struct Value {
+    number: i64,
+    index: usize,
+}
+
+impl Value {
+    fn from((index, number): (usize, i64)) -> Self {
+        Self { number, index }
+    }
+}
+
+fn parse(input: &str) -> Vec<i64> {
+    input.lines().flat_map(|n| n.parse()).collect()
+}
+
+fn decode(file: &[i64], key: i64, rounds: usize) -> i64 {
+    let mut numbers: Vec<_> = file
+        .iter()
+        .map(|n| n * key)
+        .enumerate()
+        .map(Value::from)
+        .collect();
+    let len = numbers.len();
+
+    for _ in 0..rounds {
+        for next_index in 0..numbers.len() {
+            let current_index = numbers
+                .iter()
+                .position(|value| value.index == next_index)
+                .unwrap();
+
+            let value = numbers.remove(current_index);
+            let new_index =
+                (current_index as i64 + value.number).rem_euclid(len as i64 - 1) as usize;
+
+            numbers.insert(new_index, value);
+        }
+    }
+
+    let index_of_zero = numbers.iter().position(|val| val.number == 0).unwrap();
+    let a = (index_of_zero + 1000) % len;
+    let b = (index_of_zero + 2000) % len;
+    let c = (index_of_zero + 3000) % len;
+
+    numbers[a].number + numbers[b].number + numbers[c].number
+}
+
+fn solve_one(file: &[i64]) -> i64 {
+    decode(file, 1, 1)
+}
+
+pub fn part_one(input: &str) -> i64 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(file: &[i64]) -> i64 {
+    decode(file, 811589153, 10)
+}
+
+pub fn part_two(input: &str) -> i64 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 20;
+
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn part_one(input: &str) -> i64 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i64 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<i64>) -> i64 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<i64>) -> i64 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
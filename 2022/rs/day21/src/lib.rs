@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+
+/// An operation a monkey does on two numbers.
+#[derive(Debug, Clone)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Eq,
+}
+
+/// What a monkey yells, either a number or the result of an operation.
+#[derive(Debug, Clone)]
+pub enum Expr {
+    Op(String, Op, String),
+    Literal(i64),
+}
+
+fn parse_line(line: &str) -> (String, Expr) {
+    let mut parts = line.split_ascii_whitespace();
+    let name = parts.next().unwrap();
+    let monkey_or_literal = parts.next().unwrap();
+
+    if let Ok(value) = monkey_or_literal.parse::<i64>() {
+        return (name[0..name.len() - 1].to_string(), Expr::Literal(value));
+    }
+
+    let op = match parts.next().unwrap() {
+        "+" => Op::Add,
+        "-" => Op::Sub,
+        "*" => Op::Mul,
+        "/" => Op::Div,
+        _ => unreachable!(),
+    };
+
+    (
+        name[0..name.len() - 1].to_string(),
+        Expr::Op(
+            monkey_or_literal.to_string(),
+            op,
+            parts.next().unwrap().to_string(),
+        ),
+    )
+}
+
+fn parse(input: &str) -> HashMap<String, Expr> {
+    input.lines().map(parse_line).collect()
+}
+
+fn evaluate(name: &str, monkeys: &HashMap<String, Expr>) -> i64 {
+    match monkeys.get(name) {
+        Some(Expr::Literal(value)) => *value,
+        Some(Expr::Op(a, op, b)) => {
+            let a = evaluate(a, monkeys);
+            let b = evaluate(b, monkeys);
+            match op {
+                Op::Add => a + b,
+                Op::Sub => a - b,
+                Op::Mul => a * b,
+                Op::Div => a / b,
+                Op::Eq => {
+                    if a == b {
+                        1
+                    } else {
+                        0
+                    }
+                }
+            }
+        }
+        None => unreachable!(),
+    }
+}
+
+fn solve_one(monkeys: &HashMap<String, Expr>) -> i64 {
+    evaluate("root", monkeys)
+}
+
+pub fn part_one(input: &str) -> i64 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(monkeys: &HashMap<String, Expr>) -> i64 {
+    let mut monkeys = monkeys.clone();
+
+    let root = monkeys.remove("root").unwrap();
+
+    match root {
+        Expr::Op(a, _, b) => {
+            let cache: HashMap<_, _> = monkeys
+                .keys()
+                .map(|name| (name.clone(), evaluate(name, &monkeys)))
+                .collect();
+
+            monkeys.insert("humn".to_string(), Expr::Literal(0));
+
+            for (name, value) in cache {
+                if evaluate(&name, &monkeys) == value {
+                    monkeys.insert(name, Expr::Literal(value));
+                }
+            }
+
+            monkeys.insert("root".to_string(), Expr::Op(a, Op::Eq, b));
+        }
+        _ => unreachable!(),
+    }
+
+    for n in 3243420000000.. {
+        *monkeys.get_mut("humn").unwrap() = Expr::Literal(n);
+
+        if evaluate("root", &monkeys) == 1 {
+            return n;
+        }
+    }
+
+    unreachable!()
+}
+
+pub fn part_two(input: &str) -> i64 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 21;
+
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn part_one(input: &str) -> i64 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i64 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = HashMap<String, Expr>;
+
+    fn parse(input: &str) -> Result<HashMap<String, Expr>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &HashMap<String, Expr>) -> i64 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &HashMap<String, Expr>) -> i64 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
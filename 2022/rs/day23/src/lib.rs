@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+use utils::{Chart, Coord, Direction};
+
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+/// A spot in the grove, empty or with an elf on it.
+pub enum Tile {
+    #[default]
+    Empty,
+    Elf,
+}
+
+impl From<char> for Tile {
+    fn from(value: char) -> Self {
+        match value {
+            '.' => Self::Empty,
+            '#' => Self::Elf,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl From<Tile> for char {
+    fn from(val: Tile) -> Self {
+        match val {
+            Tile::Empty => '.',
+            Tile::Elf => '#',
+        }
+    }
+}
+
+fn neighbors(direction: &Direction) -> [Coord; 3] {
+    match direction {
+        Direction::North => [Coord(-1, 1), Coord(0, 1), Coord(1, 1)],
+        Direction::East => [Coord(1, 1), Coord(1, 0), Coord(1, -1)],
+        Direction::South => [Coord(-1, -1), Coord(0, -1), Coord(1, -1)],
+        Direction::West => [Coord(-1, 1), Coord(-1, 0), Coord(-1, -1)],
+    }
+}
+
+fn parse(input: &str) -> Chart<Tile> {
+    let mut map = Chart::new();
+    let offset = input.lines().fold(0, |acc, _| acc + 1) / 2;
+
+    for (y, line) in input.lines().enumerate() {
+        for (x, ch) in line.char_indices() {
+            if ch == '#' {
+                map.overwrite(&Coord(x as i64, offset - y as i64), Tile::from(ch));
+            }
+        }
+    }
+
+    map
+}
+
+fn solve(map: &Chart<Tile>, max_round: Option<i32>) -> i32 {
+    let mut directions = [
+        Direction::North,
+        Direction::South,
+        Direction::West,
+        Direction::East,
+    ]
+    .iter()
+    .cycle();
+
+    let mut counts = HashMap::new();
+    let mut map = map.clone();
+
+    let mut stack = Vec::new();
+
+    for round in 1..=max_round.unwrap_or(i32::MAX) {
+        for elf in &map {
+            if matches!(elf.1, Tile::Empty) {
+                continue;
+            }
+
+            let mut mv = None;
+            let mut has_neighbor = false;
+
+            'outer: for dir in directions.clone().take(4) {
+                let new_location = elf.0.step(dir);
+                for neighbor in neighbors(dir).map(|n| n + *elf.0) {
+                    if matches!(map.get(&neighbor), Some(Tile::Elf)) {
+                        has_neighbor = true;
+                        continue 'outer;
+                    }
+                }
+
+                mv = mv.or(Some(new_location));
+            }
+
+            if !has_neighbor {
+                continue;
+            }
+
+            if let Some(mv) = mv {
+                let entry = counts.entry(mv).or_insert(0);
+                *entry += 1;
+                stack.push((*elf.0, mv));
+            }
+        }
+
+        if stack.is_empty() {
+            if max_round.is_none() {
+                return round;
+            }
+
+            break;
+        }
+
+        while let Some((current, new)) = stack.pop() {
+            if counts[&new] != 1 {
+                continue;
+            }
+
+            map.overwrite(&current, Tile::Empty);
+            map.overwrite(&new, Tile::Elf);
+        }
+
+        counts.clear();
+        directions.next();
+    }
+
+    map.iter_grid()
+        .fold(0, |acc, (_, tile)| acc + matches!(tile, Tile::Empty) as i32)
+}
+
+fn solve_one(map: &Chart<Tile>) -> i32 {
+    solve(map, Some(10))
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(map: &Chart<Tile>) -> i32 {
+    solve(map, None)
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 23;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Chart<Tile>;
+
+    fn parse(input: &str) -> Result<Chart<Tile>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Chart<Tile>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Chart<Tile>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
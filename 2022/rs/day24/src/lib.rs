@@ -0,0 +1,345 @@
+use std::collections::{BinaryHeap, HashSet};
+use utils::{lcm, Chart, Coord};
+
+#[derive(Debug)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn apply(&self, coord: &Coord) -> Coord {
+        match self {
+            Direction::Up => *coord + Coord(0, -1),
+            Direction::Down => *coord + Coord(0, 1),
+            Direction::Left => *coord + Coord(-1, 0),
+            Direction::Right => *coord + Coord(1, 0),
+        }
+    }
+
+    fn from_u8(value: u8) -> impl Iterator<Item = Self> {
+        [
+            if (value & 0b0001) == Self::Up.into() {
+                Some(Self::Up)
+            } else {
+                None
+            },
+            if (value & 0b0010) == Self::Down.into() {
+                Some(Self::Down)
+            } else {
+                None
+            },
+            if (value & 0b0100) == Self::Left.into() {
+                Some(Self::Left)
+            } else {
+                None
+            },
+            if (value & 0b1000) == Self::Right.into() {
+                Some(Self::Right)
+            } else {
+                None
+            },
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+impl From<Direction> for u8 {
+    fn from(val: Direction) -> Self {
+        match val {
+            Direction::Up => 0b0001,
+            Direction::Down => 0b0010,
+            Direction::Left => 0b0100,
+            Direction::Right => 0b1000,
+        }
+    }
+}
+
+impl From<u8> for Direction {
+    fn from(value: u8) -> Self {
+        match value {
+            0b0001 => Self::Up,
+            0b0010 => Self::Down,
+            0b0100 => Self::Left,
+            0b1000 => Self::Right,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl From<char> for Direction {
+    fn from(value: char) -> Self {
+        match value {
+            '^' => Self::Up,
+            'v' => Self::Down,
+            '<' => Self::Left,
+            '>' => Self::Right,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl From<Direction> for char {
+    fn from(val: Direction) -> Self {
+        match val {
+            Direction::Up => '^',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::Right => '>',
+        }
+    }
+}
+
+/// A spot in the valley, a wall or the blizzards passing through it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Tile {
+    #[default]
+    Empty,
+    Wall,
+    Blizzard(u8),
+}
+
+impl std::ops::AddAssign<Direction> for Tile {
+    // blizzards sharing a tile are stored as a bit set of their directions
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn add_assign(&mut self, dir: Direction) {
+        match self {
+            Tile::Empty => *self = Tile::Blizzard(dir.into()),
+            Tile::Blizzard(value) => *value |= Into::<u8>::into(dir),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl From<Tile> for char {
+    fn from(val: Tile) -> Self {
+        match val {
+            Tile::Empty => '.',
+            Tile::Wall => '#',
+            Tile::Blizzard(value) => match value.count_ones() {
+                4 => '4',
+                3 => '3',
+                2 => '2',
+                1 => Direction::from(value).into(),
+                _ => unreachable!("{value}"),
+            },
+        }
+    }
+}
+
+impl From<char> for Tile {
+    fn from(value: char) -> Self {
+        match value {
+            '.' => Self::Empty,
+            '#' => Self::Wall,
+            _ => Self::Blizzard(Direction::from(value).into()),
+        }
+    }
+}
+
+struct State {
+    position: Coord,
+    minutes: usize,
+    distance: i64,
+}
+
+impl Eq for State {}
+
+impl PartialEq for State {
+    fn eq(&self, other: &Self) -> bool {
+        self.distance == other.distance
+    }
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match other.distance.cmp(&self.distance) {
+            std::cmp::Ordering::Equal => other.minutes.cmp(&self.minutes),
+            ordering => ordering,
+        }
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn parse(input: &str) -> Vec<Chart<Tile>> {
+    let mut chart = Chart::new();
+
+    for (y, line) in input.lines().skip(1).enumerate() {
+        let line = &line[1..line.len() - 1];
+        for (x, ch) in line.char_indices() {
+            if ch == '#' {
+                break;
+            }
+            chart.overwrite(&Coord(x as i64, y as i64), Tile::from(ch));
+        }
+    }
+
+    let len = lcm(chart.bottom() + 1, chart.right() + 1) - 1;
+    let mut charts = Vec::with_capacity(len as usize);
+    charts.push(chart);
+    let mut stack = vec![];
+
+    for _ in 0..len {
+        let mut chart = charts.last().unwrap().clone();
+        for (coord, tile) in &mut chart {
+            if !matches!(*tile, Tile::Empty) {
+                stack.push((*coord, *tile));
+            }
+            *tile = Tile::Empty;
+        }
+
+        while let Some((coord, tile)) = stack.pop() {
+            match tile {
+                Tile::Empty => {}
+                Tile::Blizzard(value) => {
+                    for dir in Direction::from_u8(value) {
+                        let new_coord = dir.apply(&coord);
+                        let chart_tile = if let Some(chart_tile) = chart.get_mut(&new_coord) {
+                            chart_tile
+                        } else {
+                            let new_coord = match dir {
+                                Direction::Up => Coord(new_coord.0, chart.bottom()),
+                                Direction::Down => Coord(new_coord.0, chart.top()),
+                                Direction::Left => Coord(chart.right(), new_coord.1),
+                                Direction::Right => Coord(chart.left(), new_coord.1),
+                            };
+                            chart.get_mut(&new_coord).unwrap()
+                        };
+                        *chart_tile += dir;
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        charts.push(chart);
+    }
+
+    charts
+}
+
+fn do_trip(charts: &[Chart<Tile>], round_trip: bool) -> usize {
+    let mut heap = BinaryHeap::new();
+    let mut seen = HashSet::new();
+
+    let mut total = 0;
+    for (start, goal) in [
+        (
+            Coord(0, -1),
+            Coord(charts[0].right(), charts[0].bottom() + 1),
+        ),
+        (
+            Coord(charts[0].right(), charts[0].bottom() + 1),
+            Coord(0, -1),
+        ),
+        (
+            Coord(0, -1),
+            Coord(charts[0].right(), charts[0].bottom() + 1),
+        ),
+    ]
+    .into_iter()
+    .take(if round_trip { 3 } else { 1 })
+    {
+        seen.clear();
+        heap.clear();
+        heap.push(State {
+            position: start,
+            minutes: total,
+            distance: goal.distance_to(&Coord(0, 0)),
+        });
+        let mut min = usize::MAX;
+
+        while let Some(state) = heap.pop() {
+            for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1), (0, 0)] {
+                let new_position = state.position + Coord(dx, dy);
+                let minutes = state.minutes + 1;
+                if minutes + new_position.distance_to(&goal) as usize > min {
+                    continue;
+                }
+
+                if new_position == goal {
+                    min = min.min(minutes);
+                    continue;
+                }
+
+                let chart = &charts[minutes % charts.len()];
+
+                if !matches!(chart.get(&new_position), Some(Tile::Empty)) && new_position != start {
+                    continue;
+                }
+
+                if seen.insert((new_position, minutes)) {
+                    heap.push(State {
+                        minutes,
+                        position: new_position,
+                        distance: new_position.distance_to(&goal),
+                    });
+                }
+            }
+        }
+
+        total += min - total;
+    }
+
+    total
+}
+
+fn solve_one(charts: &[Chart<Tile>]) -> usize {
+    do_trip(charts, false)
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(charts: &[Chart<Tile>]) -> usize {
+    do_trip(charts, true)
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 24;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Chart<Tile>>;
+
+    fn parse(input: &str) -> Result<Vec<Chart<Tile>>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Chart<Tile>>) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Chart<Tile>>) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,147 @@
+use std::{fmt::Display, ops::Add};
+
+/// A number in balanced base five.
+#[derive(Debug, Clone)]
+pub struct Snafu {
+    digits: Vec<i64>,
+}
+
+impl Snafu {
+    fn from(digits: &str) -> Self {
+        Self {
+            digits: digits
+                .chars()
+                .map(|ch| match ch {
+                    '2' => 2,
+                    '1' => 1,
+                    '0' => 0,
+                    '-' => -1,
+                    '=' => -2,
+                    _ => unreachable!(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Add<Snafu> for Snafu {
+    type Output = Self;
+
+    fn add(self, other: Snafu) -> Self::Output {
+        let mut result = Snafu {
+            digits: Vec::with_capacity(self.digits.len().max(other.digits.len())),
+        };
+
+        let mut iter_a = self.digits.iter().rev();
+        let mut iter_b = other.digits.iter().rev();
+        let mut carry = 0;
+
+        let mut can_loop = true;
+        while can_loop {
+            let mut sum = match (iter_a.next(), iter_b.next()) {
+                (Some(a), Some(b)) => a + b,
+                (None, Some(value)) | (Some(value), None) => *value,
+                (None, None) => {
+                    can_loop = false;
+                    0
+                }
+            } + carry;
+
+            carry = if sum > 2 {
+                sum -= 5;
+                1
+            } else if sum < -2 {
+                sum += 5;
+                -1
+            } else {
+                0
+            };
+
+            if can_loop || sum != 0 {
+                result.digits.push(sum);
+            }
+        }
+
+        result.digits.reverse();
+
+        result
+    }
+}
+
+impl Display for Snafu {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.digits.is_empty() {
+            write!(f, "0")?;
+        } else {
+            for digit in &self.digits {
+                write!(
+                    f,
+                    "{}",
+                    match *digit {
+                        -2 => '=',
+                        -1 => '-',
+                        0 => '0',
+                        1 => '1',
+                        2 => '2',
+                        _ => unreachable!(),
+                    }
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn parse(input: &str) -> Vec<Snafu> {
+    input.lines().map(Snafu::from).collect()
+}
+
+fn solve_one(numbers: &[Snafu]) -> String {
+    numbers
+        .iter()
+        .cloned()
+        .fold(Snafu::from("0"), |acc, snafu| acc + snafu)
+        .to_string()
+}
+
+pub fn part_one(input: &str) -> String {
+    solve_one(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 25;
+    const PARTS: u8 = 1;
+
+    type PartOne = String;
+    type PartTwo = aoc_core::NoAnswer;
+
+    fn part_one(input: &str) -> String {
+        part_one(input)
+    }
+
+    fn part_two(_input: &str) -> aoc_core::NoAnswer {
+        aoc_core::NoAnswer
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Snafu>;
+
+    fn parse(input: &str) -> Result<Vec<Snafu>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Snafu>) -> String {
+        solve_one(input)
+    }
+
+    fn solve_two(_input: &Vec<Snafu>) -> aoc_core::NoAnswer {
+        aoc_core::NoAnswer
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -67,6 +67,15 @@ where
     distance_cache: HashMap<(T, T), i32>,
 }
 
+impl<T> Default for Graph<T>
+where
+    T: PartialEq + Eq + Hash + Debug + Copy,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> Graph<T>
 where
     T: PartialEq + Eq + Hash + Debug + Copy,
@@ -198,13 +207,11 @@ where
 
         for y in y_range {
             for x in self.min_x..=self.max_x {
-                write!(
-                    f,
-                    "{}",
-                    self.get(&Coord(x, y)).copied().unwrap_or_default().into() as char
-                )?;
+                write!(f, "{}", {
+                    self.get(&Coord(x, y)).copied().unwrap_or_default().into()
+                })?;
             }
-            write!(f, "\n")?;
+            writeln!(f)?;
         }
 
         Ok(())
@@ -237,6 +244,15 @@ where
     }
 }
 
+impl<T> Default for Chart<T>
+where
+    T: Default + Clone + Copy + PartialEq + Eq + Into<char>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> Chart<T>
 where
     T: Default + Clone + Copy + PartialEq + Eq + Into<char>,
@@ -0,0 +1,87 @@
+fn parse(input: &str) -> Vec<String> {
+    input.trim().lines().map(str::to_string).collect()
+}
+
+fn sum_lines<F: Fn((usize, char), &str) -> Option<u32>>(lines: &[String], fun: F) -> u32 {
+    let mut sum = 0;
+
+    for line in lines {
+        let mut it = line.char_indices().flat_map(|ch| fun(ch, line));
+
+        let first = it.next().unwrap();
+        let last = it.last().unwrap_or(first);
+        sum += first * 10 + last
+    }
+
+    sum
+}
+
+fn solve_one(lines: &[String]) -> u32 {
+    sum_lines(lines, |(_, ch), _| ch.to_digit(10))
+}
+
+pub fn part_one(input: &str) -> u32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(lines: &[String]) -> u32 {
+    sum_lines(lines, |(index, ch), line| {
+        if let Some(value) = ch.to_digit(10) {
+            return Some(value);
+        }
+
+        let line = &line[index..];
+        match ch {
+            'o' if line.starts_with("one") => Some(1),
+            't' if line.starts_with("two") => Some(2),
+            't' if line.starts_with("three") => Some(3),
+            'f' if line.starts_with("four") => Some(4),
+            'f' if line.starts_with("five") => Some(5),
+            's' if line.starts_with("six") => Some(6),
+            's' if line.starts_with("seven") => Some(7),
+            'e' if line.starts_with("eight") => Some(8),
+            'n' if line.starts_with("nine") => Some(9),
+            _ => None,
+        }
+    })
+}
+
+pub fn part_two(input: &str) -> u32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn part_one(input: &str) -> u32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> u32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<String>) -> u32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<String>) -> u32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
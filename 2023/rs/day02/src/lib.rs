@@ -0,0 +1,124 @@
+/// A game and every handful of cubes shown in it.
+pub struct Game {
+    id: i32,
+    cubes: Vec<(i32, String)>,
+}
+
+fn parse(input: &str) -> Vec<Game> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let mut it = line.split(": ");
+
+            let id = it
+                .next()
+                .unwrap()
+                .split(" ")
+                .last()
+                .unwrap()
+                .parse::<i32>()
+                .unwrap();
+
+            let mut cubes = vec![];
+            for rounds in it.next().unwrap().split("; ") {
+                for round in rounds.split(", ") {
+                    let mut it = round.split(" ");
+
+                    let count = it.next().unwrap().parse::<i32>().unwrap();
+                    let color = it.next().unwrap();
+
+                    cubes.push((count, color.to_string()));
+                }
+            }
+
+            Game { id, cubes }
+        })
+        .collect()
+}
+
+fn solve_one(games: &[Game]) -> i32 {
+    let mut sum = 0;
+
+    'game: for game in games {
+        for (count, color) in &game.cubes {
+            match color.as_str() {
+                "red" if *count > 12 => continue 'game,
+                "green" if *count > 13 => continue 'game,
+                "blue" if *count > 14 => continue 'game,
+                _ => {}
+            }
+        }
+
+        sum += game.id
+    }
+
+    sum
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(games: &[Game]) -> i32 {
+    let mut sum = 0;
+
+    for game in games {
+        let mut red = 0;
+        let mut green = 0;
+        let mut blue = 0;
+
+        for &(count, ref color) in &game.cubes {
+            match color.as_str() {
+                "red" => red = red.max(count),
+                "green" => green = green.max(count),
+                "blue" => blue = blue.max(count),
+                _ => unreachable!(),
+            }
+        }
+
+        sum += red * green * blue;
+    }
+
+    sum
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Vec<Game>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Game>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Game>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,191 @@
+use std::{collections::BTreeMap, iter};
+use utils::Point;
+
+/// Where a number is on the schematic.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Span {
+    start: Point,
+    end: Point,
+    circumference_points: Vec<Point>,
+}
+
+impl Span {
+    fn circumambulate<'a>(&'a mut self) -> impl Iterator<Item = Point> + 'a {
+        if self.circumference_points.is_empty() {
+            let start_x = self.start.x - 1;
+            let end_x = self.end.x + 1;
+
+            let start_y = self.start.y - 1;
+            let end_y = self.end.y + 1;
+
+            self.circumference_points = (start_x..=end_x)
+                .map(move |x| Point::from((x, start_y)))
+                .chain((start_x..=end_x).map(move |x| Point::from((x, end_y))))
+                .chain(iter::once(Point::from((start_x, self.start.y))))
+                .chain(iter::once(Point::from((end_x, self.start.y))))
+                .collect()
+        }
+
+        self.circumference_points.iter().copied()
+    }
+}
+
+fn parse_buffer(chars: &mut Vec<char>) -> i32 {
+    assert!(!chars.is_empty());
+    let mut init = chars[0].to_digit(10).unwrap() as i32;
+
+    if chars.len() == 1 {
+        chars.clear();
+        return init;
+    }
+
+    for ch in chars.iter().skip(1) {
+        init *= 10;
+        init += ch.to_digit(10).unwrap() as i32;
+    }
+
+    chars.clear();
+
+    init
+}
+
+/// The numbers on the schematic and the symbols around them.
+pub type Schematic = (BTreeMap<Span, i32>, BTreeMap<Point, char>);
+
+fn parse(input: &str) -> Schematic {
+    let mut spans = BTreeMap::<Span, i32>::new();
+    let mut symbols = BTreeMap::<Point, char>::new();
+
+    let mut span_start = None;
+    let mut buffer = Vec::new();
+    for (y, line) in input.trim().lines().enumerate() {
+        for (x, ch) in line.char_indices() {
+            if ch.is_ascii_digit() && span_start.is_none() {
+                span_start = Some(Point::from((x, y)));
+                buffer.push(ch);
+                continue;
+            }
+
+            if ch.is_ascii_digit() {
+                buffer.push(ch);
+                continue;
+            }
+
+            if let Some(start) = span_start.take() {
+                let span = Span {
+                    start,
+                    end: Point::from((x - 1, y)),
+                    circumference_points: Vec::new(),
+                };
+
+                spans.insert(span, parse_buffer(&mut buffer));
+            }
+
+            if ch != '.' {
+                symbols.insert(Point::from((x, y)), ch);
+            }
+        }
+
+        if let Some(start) = span_start.take() {
+            let span = Span {
+                start,
+                end: Point::from((line.len() - 1, y)),
+                circumference_points: Vec::new(),
+            };
+
+            spans.insert(span, parse_buffer(&mut buffer));
+        }
+    }
+
+    (spans, symbols)
+}
+
+fn solve_one((spans, symbols): &Schematic) -> i32 {
+    let mut sum = 0;
+
+    for (mut span, value) in spans.clone() {
+        let has_symbol = span
+            .circumambulate()
+            .any(|point| symbols.contains_key(&point));
+
+        if has_symbol {
+            sum += value;
+        }
+    }
+
+    sum
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two((spans, symbols): &Schematic) -> i32 {
+    let mut sum = 0;
+    let mut spans = spans.clone().into_iter().collect::<Vec<_>>();
+
+    'outer: for (&symbol, &ch) in symbols {
+        if ch != '*' {
+            continue;
+        }
+
+        let mut a = None;
+        let mut b = None;
+
+        for (span, value) in spans.iter_mut() {
+            if span.circumambulate().any(|point| point == symbol) {
+                if a.is_none() {
+                    a = Some(*value);
+                } else if b.is_none() {
+                    b = Some(*value);
+                } else {
+                    continue 'outer;
+                }
+            }
+        }
+
+        sum += a.unwrap_or_default() * b.unwrap_or_default()
+    }
+
+    sum
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Schematic;
+
+    fn parse(input: &str) -> Result<Schematic, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Schematic) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Schematic) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
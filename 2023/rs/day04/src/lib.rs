@@ -0,0 +1,148 @@
+use std::collections::{BTreeMap, VecDeque};
+
+/// A scratchcard and how many of its numbers win.
+#[derive(Debug)]
+pub struct Card {
+    id: usize,
+    winners: i32,
+}
+
+impl Card {
+    fn parse<const W: usize, const M: usize>(line: &str) -> Self {
+        let mut it = line.split_ascii_whitespace();
+
+        it.next(); // "Card"
+
+        let id: usize = it.next().unwrap().trim_end_matches(':').parse().unwrap();
+
+        let mut winning_numbers = [0; W];
+
+        for n in &mut winning_numbers {
+            *n = it.next().unwrap().parse().unwrap();
+        }
+
+        it.next(); // "|"
+
+        let mut winners = 0;
+
+        for _ in 0..M {
+            let num = it.next().unwrap().parse().unwrap();
+
+            if winning_numbers.contains(&num) {
+                winners += 1;
+            }
+        }
+
+        Self { id, winners }
+    }
+}
+
+impl Card {
+    fn score(&self) -> i32 {
+        match self.winners {
+            0 => 0,
+            score => 2i32.pow(score as u32 - 1),
+        }
+    }
+}
+
+fn parse<const W: usize, const M: usize>(input: &str) -> Vec<Card> {
+    input.trim().lines().map(Card::parse::<W, M>).collect()
+}
+
+fn points(cards: &[Card]) -> i32 {
+    cards.iter().map(Card::score).sum()
+}
+
+fn scratchcards(cards: &[Card]) -> i32 {
+    let card_map = cards
+        .iter()
+        .map(|card| (card.id, card))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut cards = card_map.values().copied().collect::<VecDeque<_>>();
+
+    let mut count = 0;
+
+    while let Some(card) = cards.pop_front() {
+        count += 1;
+        for offset in 1..=card.winners {
+            cards.push_back(card_map[&(card.id + offset as usize)])
+        }
+    }
+
+    count
+}
+
+fn solve_one(cards: &[Card]) -> i32 {
+    points(cards)
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse::<10, 25>(input))
+}
+
+fn solve_two(cards: &[Card]) -> i32 {
+    scratchcards(cards)
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse::<10, 25>(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Vec<Card>, aoc_core::Error> {
+        Ok(parse::<10, 25>(input))
+    }
+
+    fn solve_one(input: &Vec<Card>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Card>) -> i32 {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+    #[test]
+    fn test_points() {
+        assert_eq!(points(&parse::<5, 8>(EXAMPLE)), 13);
+    }
+
+    #[test]
+    fn test_scratchcards() {
+        assert_eq!(scratchcards(&parse::<5, 8>(EXAMPLE)), 30);
+    }
+}
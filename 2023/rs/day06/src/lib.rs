@@ -0,0 +1,99 @@
+/// A race and the distance to beat in it.
+#[derive(Debug)]
+pub struct Boat {
+    time: usize,
+    distance: usize,
+}
+
+impl Boat {
+    fn race(&self, time_held: usize) -> bool {
+        let time_remaining = self.time - time_held;
+        let velocity = time_held;
+        let distance = velocity * time_remaining;
+        distance > self.distance
+    }
+
+    fn race_all(&self) -> usize {
+        (0..self.time).fold(0, |acc, t| if self.race(t) { acc + 1 } else { acc })
+    }
+}
+
+fn parse(input: &str) -> Vec<Boat> {
+    let mut lines = input.trim().lines();
+
+    let times = lines.next().unwrap().split_ascii_whitespace().skip(1);
+    let dists = lines.next().unwrap().split_ascii_whitespace().skip(1);
+
+    times
+        .zip(dists)
+        .map(|(time, dist)| Boat {
+            time: time.parse().unwrap(),
+            distance: dist.parse().unwrap(),
+        })
+        .collect()
+}
+
+fn kern<F: Fn(&Boat) -> usize>(boats: &[Boat], field: F) -> usize {
+    boats
+        .iter()
+        .map(|boat| field(boat).to_string())
+        .collect::<String>()
+        .parse()
+        .unwrap()
+}
+
+fn solve_one(boats: &[Boat]) -> usize {
+    boats.iter().map(Boat::race_all).product()
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(boats: &[Boat]) -> usize {
+    Boat {
+        time: kern(boats, |boat| boat.time),
+        distance: kern(boats, |boat| boat.distance),
+    }
+    .race_all()
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Boat>;
+
+    fn parse(input: &str) -> Result<Vec<Boat>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Boat>) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Boat>) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
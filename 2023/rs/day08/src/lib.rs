@@ -0,0 +1,137 @@
+use std::collections::BTreeMap;
+use utils::math::traits::*;
+
+#[derive(Debug)]
+struct Node {
+    left: String,
+    right: String,
+}
+
+/// The left/right instructions and the nodes they walk through.
+pub struct Network {
+    directions: Vec<char>,
+    graph: BTreeMap<String, Node>,
+}
+
+fn parse(input: &str) -> Network {
+    let mut lines = input.trim().lines();
+
+    let directions = lines.next().unwrap().chars().collect();
+
+    let mut graph = BTreeMap::new();
+
+    for line in lines.skip(1) {
+        let name = line[0..3].to_string();
+        let left = line[7..10].to_string();
+        let right = line[12..15].to_string();
+
+        graph.insert(name, Node { left, right });
+    }
+
+    Network { directions, graph }
+}
+
+fn solve_one(Network { directions, graph }: &Network) -> i32 {
+    let mut current = graph.get("AAA").unwrap();
+
+    for (count, &direction) in directions.iter().cycle().enumerate() {
+        let next = match direction {
+            'L' => &current.left,
+            'R' => &current.right,
+            _ => unreachable!("Invalid direction"),
+        };
+
+        if next == "ZZZ" {
+            return count as i32 + 1;
+        }
+
+        current = graph.get(next).unwrap();
+    }
+
+    unreachable!()
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(Network { directions, graph }: &Network) -> usize {
+    let mut positions: Vec<_> = graph
+        .iter()
+        .filter_map(|(k, v)| if k.ends_with("A") { Some((v, 0)) } else { None })
+        .collect();
+
+    for (count, &direction) in directions.iter().cycle().enumerate() {
+        let mut all_cycles_found = true;
+
+        for (pos, cycle_length) in &mut positions {
+            if *cycle_length == 0 {
+                all_cycles_found = false;
+            } else {
+                continue;
+            }
+
+            let next = match direction {
+                'L' => &pos.left,
+                'R' => &pos.right,
+                _ => unreachable!("Invalid direction"),
+            };
+
+            if next.ends_with("Z") && *cycle_length == 0 {
+                *cycle_length = count + 1;
+            }
+
+            *pos = graph.get(next).unwrap();
+        }
+
+        if all_cycles_found {
+            break;
+        }
+    }
+
+    positions
+        .into_iter()
+        .map(|(_, cycle_length)| cycle_length)
+        .reduce(|a, b| a.lcm(b))
+        .unwrap()
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+
+    type PartOne = i32;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Network, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Network) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Network) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,85 @@
+fn parse(input: &str) -> Vec<Vec<i32>> {
+    input
+        .trim()
+        .lines()
+        .map(|l| l.split_ascii_whitespace().flat_map(|n| n.parse()).collect())
+        .collect()
+}
+
+fn get_differences(seq: &[i32]) -> Vec<i32> {
+    seq.windows(2).map(|window| window[1] - window[0]).collect()
+}
+
+fn build_sequences(start: Vec<i32>) -> Vec<Vec<i32>> {
+    let mut current = get_differences(&start);
+    let mut subsequences = vec![start];
+
+    loop {
+        let next = get_differences(&current);
+        subsequences.push(current);
+        if next.iter().all(|n| *n == 0) {
+            break;
+        }
+        current = next;
+    }
+
+    subsequences
+}
+
+fn calculate_total<F: Copy + Fn(i32, Vec<i32>) -> i32>(sequences: &[Vec<i32>], fun: F) -> i32 {
+    sequences.iter().fold(0, |total, seq| {
+        total + build_sequences(seq.clone()).into_iter().rev().fold(0, fun)
+    })
+}
+
+fn solve_one(sequences: &[Vec<i32>]) -> i32 {
+    calculate_total(sequences, |acc, seq| acc + seq.last().unwrap())
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(sequences: &[Vec<i32>]) -> i32 {
+    calculate_total(sequences, |acc, seq| seq.first().unwrap() - acc)
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i32>>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Vec<i32>>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Vec<i32>>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,333 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    fmt::{Display, Write},
+};
+use utils::Point;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    fn rev(&self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::South => Self::North,
+            Self::East => Self::West,
+            Self::West => Self::East,
+        }
+    }
+}
+
+/// A tile of the pipe maze.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Tile {
+    Clear,
+    Ground,
+    Vertical,
+    Horizontal,
+    NorthEastConn,
+    NorthWestConn,
+    SouthEastConn,
+    SouthWestConn,
+    Start,
+}
+
+impl From<char> for Tile {
+    fn from(value: char) -> Self {
+        match value {
+            '|' => Tile::Vertical,
+            '-' => Tile::Horizontal,
+            'L' => Tile::NorthEastConn,
+            'J' => Tile::NorthWestConn,
+            '7' => Tile::SouthWestConn,
+            'F' => Tile::SouthEastConn,
+            '.' => Tile::Ground,
+            'S' => Tile::Start,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl From<&Tile> for char {
+    fn from(value: &Tile) -> Self {
+        match value {
+            Tile::Clear => ' ',
+            Tile::Vertical => '│',
+            Tile::Horizontal => '─',
+            Tile::NorthEastConn => '└',
+            Tile::NorthWestConn => '┘',
+            Tile::SouthWestConn => '┐',
+            Tile::SouthEastConn => '┌',
+            Tile::Ground => '.',
+            Tile::Start => 'S',
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_char(self.into())
+    }
+}
+
+impl Tile {
+    fn is_accessible_from(&self, dir: Direction) -> bool {
+        use Direction::*;
+
+        matches!(
+            (self, dir),
+            (Self::Vertical, North | South)
+                | (Self::Horizontal, East | West)
+                | (Self::NorthEastConn, North | East)
+                | (Self::NorthWestConn, North | West)
+                | (Self::SouthEastConn, South | East)
+                | (Self::SouthWestConn, South | West)
+        )
+    }
+
+    fn can_move_to(&self, dir: Direction) -> bool {
+        use Direction::*;
+
+        matches!(
+            (self, dir),
+            (Self::Start, _)
+                | (Self::Vertical, North | South)
+                | (Self::Horizontal, East | West)
+                | (Self::NorthEastConn, North | East)
+                | (Self::NorthWestConn, North | West)
+                | (Self::SouthEastConn, South | East)
+                | (Self::SouthWestConn, South | West)
+        )
+    }
+
+    fn transit(&self, from: Direction) -> Direction {
+        use Direction::*;
+        match (self, from) {
+            (Self::Vertical, North | South) => from,
+            (Self::Horizontal, East | West) => from,
+            (Self::NorthEastConn, South) => East,
+            (Self::NorthEastConn, West) => North,
+            (Self::Start | Self::NorthWestConn, South) => West,
+            (Self::Start | Self::NorthWestConn, East) => North,
+            (Self::SouthEastConn, North) => East,
+            (Self::SouthEastConn, West) => South,
+            (Self::SouthWestConn, North) => West,
+            (Self::SouthWestConn, East) => South,
+            _ => unreachable!("failed to transit: {} {:?}", self, from),
+        }
+    }
+}
+
+/// The pipe maze by position.
+pub type Chart = BTreeMap<Point, Tile>;
+
+fn parse(input: &str) -> Chart {
+    let mut chart = BTreeMap::new();
+
+    for (y, line) in input.trim().lines().enumerate() {
+        for (x, ch) in line.char_indices() {
+            chart.insert(Point::from((x, y)), Tile::from(ch));
+        }
+    }
+
+    chart
+}
+
+fn measure(chart: &Chart) -> BTreeMap<Point, usize> {
+    let start = chart
+        .iter()
+        .find_map(|(point, tile)| (*tile == Tile::Start).then_some(*point))
+        .expect("failed to find start");
+    let mut distances = BTreeMap::from([(start, 0usize)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((p, step_count)) = queue.pop_front() {
+        let current = chart.get(&p).expect("failed to get current tile");
+
+        for (next, dir) in [
+            (p.translate_up(), Direction::South),
+            (p.translate_down(), Direction::North),
+            (p.translate_left(), Direction::East),
+            (p.translate_right(), Direction::West),
+        ] {
+            if !current.can_move_to(dir.rev()) {
+                continue;
+            }
+
+            if let Some(neighbor) = chart.get(&next) {
+                if !neighbor.is_accessible_from(dir) {
+                    continue;
+                }
+
+                let current_shortest = distances.get(&next).copied().unwrap_or(usize::MAX);
+
+                if current_shortest > step_count + 1 {
+                    distances.insert(next, step_count + 1);
+                    queue.push_back((next, step_count + 1))
+                }
+            }
+        }
+    }
+
+    distances
+}
+
+fn solve_one(chart: &Chart) -> usize {
+    let distances = measure(chart);
+
+    distances.values().max().copied().unwrap()
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+struct Surfer {
+    location: Point,
+    heading: Direction,
+}
+
+impl Surfer {
+    fn advance(&mut self, chart: &BTreeMap<Point, Tile>) -> Box<dyn Iterator<Item = Point>> {
+        // find next location in loop
+        self.location = match self.heading {
+            Direction::North => self.location.translate_up(),
+            Direction::East => self.location.translate_right(),
+            Direction::South => self.location.translate_down(),
+            Direction::West => self.location.translate_left(),
+        };
+
+        let tile = chart.get(&self.location).unwrap();
+        // determine our new heading based off the new tile shape
+        let next_heading = tile.transit(self.heading);
+
+        let first = self.get_point_to_right();
+
+        // if we don't turn, we only have visited one tile to the right
+        if next_heading == self.heading {
+            Box::from(std::iter::once(first))
+        } else {
+            self.heading = next_heading;
+
+            // otherwise we have to yield the tile before and after the turn
+            Box::from(std::iter::once(first).chain(std::iter::once(self.get_point_to_right())))
+        }
+    }
+
+    fn get_point_to_right(&self) -> Point {
+        match self.heading {
+            Direction::North => self.location.translate_right(),
+            Direction::East => self.location.translate_down(),
+            Direction::South => self.location.translate_left(),
+            Direction::West => self.location.translate_up(),
+        }
+    }
+}
+
+fn flood_fill(chart: &mut BTreeMap<Point, Tile>, p: Point) {
+    assert_eq!(chart.insert(p, Tile::Clear), Some(Tile::Ground));
+
+    let mut queue = VecDeque::from([p]);
+
+    while let Some(p) = queue.pop_front() {
+        for next in [
+            p.translate_down(),
+            p.translate_left(),
+            p.translate_right(),
+            p.translate_up(),
+        ] {
+            if matches!(chart.get(&next), Some(Tile::Ground)) {
+                queue.push_back(next);
+                chart.insert(next, Tile::Clear);
+            }
+        }
+    }
+}
+
+fn solve_two(chart: &Chart) -> usize {
+    let distances = measure(chart);
+    let mut chart = chart.clone();
+
+    // replace any tiles that aren't part of the loop with ground
+    for (k, v) in &mut chart {
+        if !distances.contains_key(k) {
+            *v = Tile::Ground;
+        }
+    }
+
+    let mut starting_point = Point { x: 0, y: 0 };
+
+    // find the loop by walking south
+    while !distances.contains_key(&starting_point) {
+        starting_point = starting_point.translate_down();
+    }
+
+    let mut surfer = Surfer {
+        location: starting_point,
+        heading: Direction::South,
+    };
+
+    // prime the surfer, drop the box it returns
+    drop(surfer.advance(&chart));
+
+    // surf the entire loop
+    while surfer.location != starting_point {
+        for p in surfer.advance(&chart) {
+            // if we find any ground tiles to the right of the surfer
+            if let Some(t) = chart.get(&p) {
+                if *t == Tile::Ground {
+                    // clear those ground tiles w/ flood-fill algorithm
+                    flood_fill(&mut chart, p);
+                }
+            }
+        }
+    }
+
+    // any ground remaining must have been within the loop
+    chart.values().filter(|t| **t == Tile::Ground).count()
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 10;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Chart;
+
+    fn parse(input: &str) -> Result<Chart, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Chart) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Chart) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
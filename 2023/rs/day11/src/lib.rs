@@ -0,0 +1,118 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+type Point = (i64, i64);
+
+fn manhattan_distance((a1, a2): &Point, (b1, b2): &Point) -> i64 {
+    (a1 - b1).abs() + (a2 - b2).abs()
+}
+
+fn parse(input: &str) -> Vec<Point> {
+    let mut galaxies = Vec::new();
+
+    for (y, line) in input.trim().lines().enumerate() {
+        for (x, ch) in line.char_indices() {
+            if ch == '#' {
+                galaxies.push((x as i64, y as i64))
+            }
+        }
+    }
+
+    galaxies
+}
+
+fn find_distances(galaxies: &[Point], times: i64) -> i64 {
+    let occupied_cols: BTreeSet<_> = galaxies.iter().map(|galaxy| galaxy.0).collect();
+    let occupied_rows: BTreeSet<_> = galaxies.iter().map(|galaxy| galaxy.1).collect();
+
+    let max_x = occupied_cols.last().copied().unwrap_or(0);
+    let max_y = occupied_rows.last().copied().unwrap_or(0);
+
+    let empty_cols = (0..=max_x).filter(|x| !occupied_cols.contains(x));
+    let empty_rows = (0..=max_y).filter(|y| !occupied_rows.contains(y));
+
+    let mut galaxies = galaxies.to_vec();
+
+    let mut x_expansions = BTreeMap::new();
+
+    for x in empty_cols {
+        for n in x..=max_x {
+            *x_expansions.entry(n).or_insert(0i64) += 1;
+        }
+    }
+
+    let mut y_expansions = BTreeMap::new();
+
+    for y in empty_rows {
+        for n in y..=max_y {
+            *y_expansions.entry(n).or_insert(0i64) += 1;
+        }
+    }
+
+    for galaxy in &mut galaxies {
+        let old = *galaxy;
+        galaxy.0 += x_expansions.get(&old.0).unwrap_or(&0) * (times - 1).max(1);
+        galaxy.1 += y_expansions.get(&old.1).unwrap_or(&0) * (times - 1).max(1);
+    }
+
+    let mut distances = 0;
+
+    for (i, a) in galaxies.iter().enumerate() {
+        for b in galaxies.iter().skip(i + 1) {
+            distances += manhattan_distance(a, b);
+        }
+    }
+
+    distances
+}
+
+fn solve_one(galaxies: &[Point]) -> i64 {
+    find_distances(galaxies, 1)
+}
+
+pub fn part_one(input: &str) -> i64 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(galaxies: &[Point]) -> i64 {
+    find_distances(galaxies, 1_000_000)
+}
+
+pub fn part_two(input: &str) -> i64 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn part_one(input: &str) -> i64 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i64 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Point>;
+
+    fn parse(input: &str) -> Result<Vec<Point>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Point>) -> i64 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Point>) -> i64 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
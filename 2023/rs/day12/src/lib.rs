@@ -0,0 +1,151 @@
+use std::collections::BTreeMap;
+
+const DAMAGED: char = '#';
+const SPRING: char = '.';
+
+fn place_spring(
+    cache: &mut BTreeMap<(usize, usize), usize>,
+    schema: &[char],
+    groups: &[usize],
+) -> usize {
+    let cache_key = (schema.len(), groups.len());
+    if let Some(count) = cache.get(&cache_key) {
+        return *count;
+    }
+
+    if groups.is_empty() {
+        let result = if schema.contains(&DAMAGED) { 0 } else { 1 };
+        cache.insert(cache_key, result);
+        return result;
+    }
+
+    let size = groups[0];
+    let mut count = 0;
+
+    for index in 0..schema.len() {
+        if schema[0..index].contains(&DAMAGED) {
+            break;
+        }
+
+        let schema = &schema[index..];
+
+        if schema.len() < size {
+            break;
+        }
+
+        let section = &schema[..size];
+        if section.contains(&SPRING) || schema.get(size) == Some(&DAMAGED) {
+            continue;
+        }
+
+        let skip = size + 1;
+
+        if skip > schema.len() {
+            if groups.len() == 1 {
+                count += 1;
+            }
+
+            break;
+        }
+
+        let next_schema = &schema[skip..];
+        count += place_spring(cache, next_schema, &groups[1..])
+    }
+
+    cache.insert(cache_key, count);
+
+    count
+}
+
+/// A row of springs and the sizes of its damaged groups.
+pub type Record = (Vec<char>, Vec<usize>);
+
+fn parse(input: &str) -> Vec<Record> {
+    input
+        .lines()
+        .map(|line| {
+            let mut parts = line.split_ascii_whitespace();
+
+            let schema = parts.next().unwrap().chars().collect();
+            let groups = parts
+                .next()
+                .unwrap()
+                .split(',')
+                .flat_map(|x| x.parse::<usize>())
+                .collect();
+
+            (schema, groups)
+        })
+        .collect()
+}
+
+fn solve_line((schema_chars, group_nums): &Record, unfold: bool) -> usize {
+    let mut schema = Vec::new();
+    let mut groups = Vec::new();
+
+    let iterations = if unfold { 5 } else { 1 };
+
+    for it in 0..iterations {
+        if it != 0 {
+            schema.push('?');
+        }
+
+        schema.extend(schema_chars);
+        groups.extend(group_nums);
+    }
+
+    let mut cache = BTreeMap::new();
+    place_spring(&mut cache, &schema, &groups)
+}
+
+fn solve_one(records: &[Record]) -> usize {
+    records.iter().map(|record| solve_line(record, false)).sum()
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(records: &[Record]) -> usize {
+    records.iter().map(|record| solve_line(record, true)).sum()
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 12;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Record>;
+
+    fn parse(input: &str) -> Result<Vec<Record>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Record>) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Record>) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,132 @@
+fn search_horizontal(lines: &[Vec<char>], skip: Option<usize>) -> Option<usize> {
+    for i in 1..lines.len() {
+        if Some(i) == skip {
+            continue;
+        }
+
+        let (a, b) = lines.split_at(i);
+
+        if a.iter().rev().zip(b).all(|(a, b)| a == b) {
+            return Some(i * 100);
+        }
+    }
+
+    None
+}
+
+fn search_vertical(lines: &[Vec<char>], skip: Option<usize>) -> Option<usize> {
+    let line_len = lines[0].len();
+
+    for i in 1..line_len {
+        if Some(i) == skip {
+            continue;
+        }
+
+        let all_lines_mirror = lines.iter().all(|line| {
+            let (a, b) = line.split_at(i);
+
+            a.iter().rev().zip(b.iter()).all(|(a, b)| a == b)
+        });
+
+        if all_lines_mirror {
+            return Some(i);
+        }
+    }
+
+    None
+}
+
+/// A pattern of ash and rocks, one row per line.
+pub type Pattern = Vec<Vec<char>>;
+
+fn parse(input: &str) -> Vec<Pattern> {
+    input
+        .trim()
+        .split("\n\n")
+        .map(|p| p.lines().map(|line| line.chars().collect()).collect())
+        .collect()
+}
+
+fn solve_one(patterns: &[Pattern]) -> usize {
+    patterns
+        .iter()
+        .map(|lines| {
+            search_horizontal(lines, None)
+                .or(search_vertical(lines, None))
+                .unwrap()
+        })
+        .sum()
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(patterns: &[Pattern]) -> usize {
+    patterns
+        .iter()
+        .map(|lines| {
+            let mut lines = lines.clone();
+
+            let expected_horizontal = search_horizontal(&lines, None).map(|v| v / 100);
+            let expected_vertical = search_vertical(&lines, None);
+
+            for y in 0..lines.len() {
+                for x in 0..lines[y].len() {
+                    let old = lines[y][x];
+                    lines[y][x] = if old == '.' { '#' } else { '.' };
+
+                    if let Some(result) = search_horizontal(&lines, expected_horizontal)
+                        .or(search_vertical(&lines, expected_vertical))
+                    {
+                        return result;
+                    }
+
+                    lines[y][x] = old
+                }
+            }
+
+            unreachable!()
+        })
+        .sum()
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 13;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Pattern>;
+
+    fn parse(input: &str) -> Result<Vec<Pattern>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Pattern>) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Pattern>) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
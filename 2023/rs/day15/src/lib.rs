@@ -0,0 +1,117 @@
+fn hash(string: &str) -> i32 {
+    let mut value = 0;
+
+    for ch in string.chars() {
+        value += ch as i32;
+        value *= 17;
+        value %= 256;
+    }
+
+    value
+}
+
+fn parse(input: &str) -> Vec<String> {
+    input.trim().split(",").map(str::to_string).collect()
+}
+
+fn solve_one(steps: &[String]) -> i32 {
+    steps.iter().map(|step| hash(step)).sum()
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+type Lens<'a> = (&'a str, i32);
+
+fn solve_two(steps: &[String]) -> i32 {
+    let mut boxes: Vec<Vec<Lens<'_>>> = (0..0xff + 1).map(|_| Vec::new()).collect();
+
+    macro_rules! find_lens {
+        ($box:expr, $label:expr) => {
+            $box.iter().enumerate().find_map(
+                |(index, (n, _))| {
+                    if *n == $label {
+                        Some(index)
+                    } else {
+                        None
+                    }
+                },
+            )
+        };
+    }
+
+    for op in steps {
+        if let Some(label) = op.strip_suffix('-') {
+            let b = hash(label) as usize;
+
+            if let Some(index) = find_lens!(&boxes[b], label) {
+                boxes[b].remove(index);
+            }
+        } else {
+            let label = &op[..op.len() - 2];
+            let b = hash(label) as usize;
+            let len = op[op.len() - 1..].parse().unwrap();
+
+            if let Some(index) = find_lens!(&boxes[b], label) {
+                boxes[b][index].1 = len;
+            } else {
+                boxes[b].push((label, len))
+            }
+        }
+    }
+
+    boxes
+        .into_iter()
+        .enumerate()
+        .flat_map(|(box_index, b)| {
+            let box_power = box_index as i32 + 1;
+
+            b.into_iter().enumerate().map(move |(index, (_, len))| {
+                let lens_power = index as i32 + 1;
+
+                box_power * lens_power * len
+            })
+        })
+        .sum()
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 15;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<String>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<String>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
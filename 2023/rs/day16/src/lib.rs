@@ -0,0 +1,158 @@
+use std::{
+    collections::{BTreeSet, VecDeque},
+    iter,
+};
+use utils::{Direction, Point, Translate};
+
+/// A tile of the contraption.
+pub enum Tile {
+    Mirror,     // '/'
+    Backmirror, // '\'
+    HSplitter,  // '-'
+    VSplitter,  // '|'
+    Empty,      // '.'
+}
+
+impl From<char> for Tile {
+    fn from(value: char) -> Self {
+        match value {
+            '/' => Self::Mirror,
+            '\\' => Self::Backmirror,
+            '-' => Self::HSplitter,
+            '|' => Self::VSplitter,
+            '.' => Self::Empty,
+            _ => unreachable!("{value}"),
+        }
+    }
+}
+
+impl Tile {
+    fn traverse(&self, from: Direction) -> impl Iterator<Item = Direction> {
+        match self {
+            Self::Empty => iter::once(Some(from.rev())).chain(iter::once(None)),
+            Self::HSplitter => match from {
+                Direction::East | Direction::West => {
+                    iter::once(Some(from.rev())).chain(iter::once(None))
+                }
+                Direction::North | Direction::South => {
+                    iter::once(Some(Direction::East)).chain(iter::once(Some(Direction::West)))
+                }
+            },
+            Self::VSplitter => match from {
+                Direction::North | Direction::South => {
+                    iter::once(Some(from.rev())).chain(iter::once(None))
+                }
+                Direction::East | Direction::West => {
+                    iter::once(Some(Direction::North)).chain(iter::once(Some(Direction::South)))
+                }
+            },
+            Self::Mirror => match from {
+                Direction::East => iter::once(Some(Direction::South)).chain(iter::once(None)),
+                Direction::South => iter::once(Some(Direction::East)).chain(iter::once(None)),
+                Direction::West => iter::once(Some(Direction::North)).chain(iter::once(None)),
+                Direction::North => iter::once(Some(Direction::West)).chain(iter::once(None)),
+            },
+            Self::Backmirror => match from {
+                Direction::East => iter::once(Some(Direction::North)).chain(iter::once(None)),
+                Direction::North => iter::once(Some(Direction::East)).chain(iter::once(None)),
+                Direction::West => iter::once(Some(Direction::South)).chain(iter::once(None)),
+                Direction::South => iter::once(Some(Direction::West)).chain(iter::once(None)),
+            },
+        }
+        .flatten()
+    }
+}
+
+fn count_points(cavern: &[Vec<Tile>], start: (Point, Direction)) -> usize {
+    let x_bounds = 0..cavern[0].len() as i32;
+    let y_bounds = 0..cavern.len() as i32;
+
+    let mut queue = VecDeque::from([start]);
+    let mut visited_states = BTreeSet::from([start]);
+    let mut visited_points = BTreeSet::from([start.0]);
+
+    while let Some((loc, direction)) = queue.pop_front() {
+        let next = loc.translate(direction);
+
+        if !x_bounds.contains(&next.x) || !y_bounds.contains(&next.y) {
+            continue;
+        }
+
+        for dir in cavern[next.y as usize][next.x as usize].traverse(direction.rev()) {
+            if visited_states.insert((next, dir)) {
+                visited_points.insert(next);
+                queue.push_back((next, dir));
+            }
+        }
+    }
+
+    visited_points.len()
+}
+
+fn parse(input: &str) -> Vec<Vec<Tile>> {
+    input
+        .trim()
+        .lines()
+        .map(|l| l.chars().map(Tile::from).collect())
+        .collect()
+}
+
+fn solve_one(cavern: &[Vec<Tile>]) -> usize {
+    count_points(cavern, (Point::from((0, 0)), Direction::East))
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(cavern: &[Vec<Tile>]) -> usize {
+    let max_x = cavern[0].len() - 1;
+    let max_y = cavern.len() - 1;
+
+    (0..=max_x)
+        .flat_map(|x| [(x, 0, Direction::South), (x, max_y, Direction::North)])
+        .chain((0..=max_y).flat_map(|y| [(0, y, Direction::East), (max_x, y, Direction::West)]))
+        .map(|(x, y, dir)| count_points(cavern, (Point::from((x, y)), dir)))
+        .max()
+        .unwrap()
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 16;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Vec<Tile>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<Tile>>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Vec<Tile>>) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Vec<Tile>>) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
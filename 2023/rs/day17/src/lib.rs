@@ -0,0 +1,159 @@
+use std::collections::{BinaryHeap, HashMap};
+use utils::{Direction, Point, Translate};
+
+fn parse(input: &str) -> Vec<Vec<usize>> {
+    input
+        .trim()
+        .lines()
+        .map(|l| l.split("").flat_map(|c| c.parse()).collect())
+        .collect()
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Crucible {
+    straights: usize,
+    dir: Direction,
+    pos: Point,
+    heat_loss: usize,
+}
+
+impl Ord for Crucible {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.heat_loss.cmp(&self.heat_loss)
+    }
+}
+
+impl PartialOrd for Crucible {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn push_crucible(tiles: &[Vec<usize>], min: usize, max: usize) -> usize {
+    let mut cache = HashMap::<(Point, Direction, usize), (bool, usize)>::new();
+
+    let mut heap = BinaryHeap::from([
+        Crucible {
+            pos: Point::from((0, 0)),
+            dir: Direction::East,
+            straights: 0,
+            heat_loss: 0,
+        },
+        Crucible {
+            pos: Point::from((0, 0)),
+            dir: Direction::South,
+            straights: 0,
+            heat_loss: 0,
+        },
+    ]);
+    while let Some(c) = heap.pop() {
+        cache
+            .entry((c.pos, c.dir, c.straights))
+            .or_insert((false, usize::MAX))
+            .0 = true;
+
+        for dir in [c.dir, c.dir.left(), c.dir.right()] {
+            let same_dir = c.dir == dir;
+
+            if (c.straights < min && !same_dir) || (c.straights > max - 1 && same_dir) {
+                continue;
+            }
+
+            let pos = c.pos.translate(dir);
+            let straights = 1 + if same_dir { c.straights } else { 0 };
+            let heat_loss = c.heat_loss
+                + if let Some(c) = tiles
+                    .get(pos.y as usize)
+                    .and_then(|row| row.get(pos.x as usize))
+                {
+                    c
+                } else {
+                    continue;
+                };
+
+            let (visited, min_heat_loss) = cache
+                .entry((pos, dir, straights))
+                .or_insert((false, usize::MAX));
+
+            if *visited || *min_heat_loss <= heat_loss {
+                continue;
+            }
+            *min_heat_loss = heat_loss;
+
+            heap.push(Crucible {
+                pos,
+                dir,
+                straights,
+                heat_loss,
+            })
+        }
+    }
+
+    let target = Point::from((tiles[0].len() - 1, tiles.len() - 1));
+
+    cache
+        .into_iter()
+        .filter_map(
+            |((pos, _, _), (_visited, cost))| {
+                if pos == target {
+                    Some(cost)
+                } else {
+                    None
+                }
+            },
+        )
+        .min()
+        .unwrap()
+}
+
+fn solve_one(tiles: &[Vec<usize>]) -> usize {
+    push_crucible(tiles, 0, 3)
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(tiles: &[Vec<usize>]) -> usize {
+    push_crucible(tiles, 4, 10)
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 17;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Vec<usize>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<usize>>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Vec<usize>>) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Vec<usize>>) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,134 @@
+use utils::{Direction, Point, Translate};
+
+/// A trench to dig from the current location.
+pub struct Instruction {
+    dir: Direction,
+    count: i64,
+}
+
+impl Translate<&Instruction> for Point {
+    fn translate(&self, change: &Instruction) -> Self {
+        let amount = change.count as i32;
+
+        match change.dir {
+            Direction::North => self.translate((0, -amount)),
+            Direction::South => self.translate((0, amount)),
+            Direction::West => self.translate((-amount, 0)),
+            Direction::East => self.translate((amount, 0)),
+        }
+    }
+}
+
+fn dig(instructions: &[Instruction]) -> i64 {
+    let mut location = Point::from((0, 0));
+
+    let mut area = 0;
+
+    for inst in instructions {
+        let next = location.translate(inst);
+
+        area += location.x as i64 * next.y as i64 - next.x as i64 * location.y as i64;
+        area += inst.count;
+
+        location = next;
+    }
+
+    area / 2 + 1
+}
+
+/// The dig plan as written and as decoded from its colour codes.
+pub type Plan = (Vec<Instruction>, Vec<Instruction>);
+
+fn parse(input: &str) -> Plan {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let mut parts = line.split_ascii_whitespace();
+            let dir = match parts.next().unwrap() {
+                "U" => Direction::North,
+                "R" => Direction::East,
+                "D" => Direction::South,
+                "L" => Direction::West,
+                _ => unreachable!(),
+            };
+            let count = parts.next().unwrap().parse().unwrap();
+
+            let code = parts.next().unwrap();
+
+            let mut long_count = 0;
+            for n in code.chars().skip(2).take(5) {
+                long_count *= 16;
+                long_count += n.to_digit(16).unwrap() as i64;
+            }
+
+            let long_dir = match &code[7..8] {
+                "0" => Direction::East,
+                "1" => Direction::South,
+                "2" => Direction::West,
+                "3" => Direction::North,
+                _ => unreachable!(),
+            };
+
+            (
+                Instruction { dir, count },
+                Instruction {
+                    dir: long_dir,
+                    count: long_count,
+                },
+            )
+        })
+        .unzip()
+}
+
+fn solve_one((instructions, _): &Plan) -> i64 {
+    dig(instructions)
+}
+
+pub fn part_one(input: &str) -> i64 {
+    solve_one(&parse(input))
+}
+
+fn solve_two((_, instructions): &Plan) -> i64 {
+    dig(instructions)
+}
+
+pub fn part_two(input: &str) -> i64 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 18;
+
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn part_one(input: &str) -> i64 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i64 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Plan;
+
+    fn parse(input: &str) -> Result<Plan, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Plan) -> i64 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Plan) -> i64 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
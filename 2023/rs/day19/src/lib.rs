@@ -0,0 +1,303 @@
+use std::collections::HashMap;
+
+fn parse_field(field: &str) -> i32 {
+    field.split("=").last().unwrap().parse().unwrap()
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Part<T: Clone + Copy> {
+    x: T,
+    m: T,
+    a: T,
+    s: T,
+}
+
+impl<T: Clone + Copy> Part<T> {
+    fn get(&self, field: &str) -> T {
+        match field {
+            "x" => self.x,
+            "a" => self.a,
+            "m" => self.m,
+            "s" => self.s,
+            _ => unreachable!(),
+        }
+    }
+
+    fn get_mut(&mut self, field: &str) -> &mut T {
+        match field {
+            "x" => &mut self.x,
+            "m" => &mut self.m,
+            "a" => &mut self.a,
+            "s" => &mut self.s,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Part<i32> {
+    fn rating(&self) -> i32 {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+impl Part<(usize, usize)> {
+    fn new() -> Self {
+        Self {
+            x: (1, 4000),
+            m: (1, 4000),
+            a: (1, 4000),
+            s: (1, 4000),
+        }
+    }
+
+    fn split_lt(&self, field: &str, amount: usize) -> (Self, Self) {
+        let mut left = *self;
+        let mut right = *self;
+        let upper = &mut left.get_mut(field).1;
+        let lower = &mut right.get_mut(field).0;
+        *upper = (*upper).min(amount - 1);
+        *lower = (*lower).max(amount);
+        (left, right)
+    }
+
+    fn split_gt(&self, field: &str, amount: usize) -> (Self, Self) {
+        let mut left = *self;
+        let mut right = *self;
+        let upper = &mut left.get_mut(field).1;
+        let lower = &mut right.get_mut(field).0;
+        *upper = (*upper).min(amount);
+        *lower = (*lower).max(amount + 1);
+        (right, left)
+    }
+
+    fn split(&self, (field, op, amount): &Condition) -> (Self, Self) {
+        match op.as_str() {
+            "<" => self.split_lt(field, *amount as usize),
+            ">" => self.split_gt(field, *amount as usize),
+            _ => unreachable!(),
+        }
+    }
+
+    fn possibilities(&self) -> usize {
+        macro_rules! len {
+            ($range:expr) => {
+                if $range.1 < $range.0 {
+                    0
+                } else {
+                    1 + $range.1 - $range.0
+                }
+            };
+        }
+
+        len!(self.x) * len!(self.m) * len!(self.a) * len!(self.s)
+    }
+}
+
+type Condition = (String, String, i32);
+
+#[derive(Debug)]
+struct Rule {
+    dest: String,
+    cond: Option<Condition>,
+}
+
+impl Rule {
+    fn test(&self, part: &Part<i32>) -> bool {
+        if let Some((field, op, amount)) = &self.cond {
+            let value = part.get(field);
+
+            match op.as_str() {
+                ">" => value > *amount,
+                "<" => value < *amount,
+                _ => unreachable!(),
+            }
+        } else {
+            true
+        }
+    }
+}
+
+impl From<&str> for Rule {
+    fn from(value: &str) -> Self {
+        let colon = value.find(':');
+        if colon.is_none() {
+            return Self {
+                dest: value.to_string(),
+                cond: None,
+            };
+        }
+
+        let colon = colon.unwrap();
+
+        let field = value[..1].to_string();
+        let op = value[1..2].to_string();
+        let amount: i32 = value[2..colon].parse().unwrap();
+        let dest = value[colon + 1..].to_string();
+
+        Self {
+            dest,
+            cond: Some((field, op, amount)),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Workflow {
+    name: String,
+    rules: Vec<Rule>,
+}
+
+impl Workflow {
+    fn find_dest(&self, part: &Part<i32>) -> &str {
+        for cond in &self.rules {
+            if cond.test(part) {
+                return &cond.dest;
+            }
+        }
+
+        unreachable!()
+    }
+}
+
+impl From<&str> for Workflow {
+    fn from(s: &str) -> Self {
+        let name_end = s.find('{').unwrap();
+        let name = s[..name_end].to_string();
+        let rules = s[name_end + 1..s.len() - 1]
+            .split(",")
+            .map(Rule::from)
+            .collect();
+
+        Workflow { name, rules }
+    }
+}
+
+/// The workflows and the parts to sort through them.
+pub struct System {
+    workflows: HashMap<String, Workflow>,
+    parts: Vec<Part<i32>>,
+}
+
+fn parse(input: &str) -> System {
+    let mut split = input.trim().split("\n\n");
+    let workflows = split
+        .next()
+        .unwrap()
+        .lines()
+        .map(Workflow::from)
+        .map(|w| (w.name.clone(), w))
+        .collect();
+
+    let parts = split
+        .next()
+        .unwrap()
+        .lines()
+        .map(|l| {
+            let mut split = l[1..l.len() - 1].split(",");
+
+            Part {
+                x: parse_field(split.next().unwrap()),
+                m: parse_field(split.next().unwrap()),
+                a: parse_field(split.next().unwrap()),
+                s: parse_field(split.next().unwrap()),
+            }
+        })
+        .collect();
+
+    System { workflows, parts }
+}
+
+fn solve_one(System { workflows, parts }: &System) -> i32 {
+    let mut total = 0;
+
+    for part in parts {
+        let mut workflow = "in";
+
+        while workflow != "A" && workflow != "R" {
+            workflow = workflows[workflow].find_dest(part);
+        }
+
+        if workflow == "A" {
+            total += part.rating();
+        }
+    }
+
+    total
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn walk(
+    workflows: &HashMap<String, Workflow>,
+    workflow: &str,
+    mut range: Part<(usize, usize)>,
+) -> usize {
+    let flow = &workflows[workflow];
+    let mut total = 0;
+
+    for rule in &flow.rules {
+        if let Some(cond) = &rule.cond {
+            let (l, r) = range.split(cond);
+            if rule.dest == "A" {
+                total += l.possibilities();
+            } else if rule.dest != "R" {
+                total += walk(workflows, &rule.dest, l);
+            }
+            range = r;
+        } else {
+            if rule.dest == "A" {
+                total += range.possibilities();
+            } else if rule.dest != "R" {
+                total += walk(workflows, &rule.dest, range)
+            }
+        }
+    }
+
+    total
+}
+
+fn solve_two(System { workflows, .. }: &System) -> usize {
+    walk(workflows, "in", Part::<(usize, usize)>::new())
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 19;
+
+    type PartOne = i32;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = System;
+
+    fn parse(input: &str) -> Result<System, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &System) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &System) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,371 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    ops::Neg,
+};
+use utils::math::traits::*;
+
+const BROADCASTER: &str = "BROADCASTER";
+
+type Id = String;
+
+/// Whether a flip-flop is on.
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    Off,
+    On,
+}
+
+impl Neg for State {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        match self {
+            Self::Off => Self::On,
+            Self::On => Self::Off,
+        }
+    }
+}
+
+/// Whether a pulse is high or low.
+#[derive(Debug, Clone, Copy)]
+pub enum PulseKind {
+    Low,
+    High,
+}
+
+impl PulseKind {
+    fn is_high(&self) -> bool {
+        matches!(self, PulseKind::High)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Pulse {
+    sender: Id,
+    receiver: Id,
+    kind: PulseKind,
+}
+
+/// A module and the modules it sends pulses to.
+#[derive(Debug, Clone)]
+pub enum Module {
+    Broadcaster {
+        outputs: Vec<Id>,
+    },
+    FlipFlop {
+        state: State,
+        outputs: Vec<Id>,
+    },
+    Conjunction {
+        inputs: HashMap<Id, PulseKind>,
+        outputs: Vec<Id>,
+    },
+}
+
+impl Module {
+    fn outputs(&self) -> &[Id] {
+        match self {
+            Self::Conjunction { outputs, .. }
+            | Self::FlipFlop { outputs, .. }
+            | Self::Broadcaster { outputs } => outputs,
+        }
+    }
+}
+
+fn parse_line(line: &str) -> (Id, Module) {
+    let mut parts = line.split(" -> ");
+
+    let name = parts.next().unwrap();
+
+    match &name[0..1] {
+        "b" => (
+            BROADCASTER.to_string(),
+            Module::Broadcaster {
+                outputs: parts
+                    .next()
+                    .unwrap()
+                    .split(", ")
+                    .map(str::to_string)
+                    .collect(),
+            },
+        ),
+        "%" => (
+            name[1..].to_string(),
+            Module::FlipFlop {
+                state: State::Off,
+                outputs: parts
+                    .next()
+                    .unwrap()
+                    .split(", ")
+                    .map(str::to_string)
+                    .collect(),
+            },
+        ),
+        "&" => (
+            name[1..].to_string(),
+            Module::Conjunction {
+                inputs: Default::default(),
+                outputs: parts
+                    .next()
+                    .unwrap()
+                    .split(", ")
+                    .map(str::to_string)
+                    .collect(),
+            },
+        ),
+        _ => unreachable!("{name}"),
+    }
+}
+
+/// Every module by name.
+pub type Modules = HashMap<Id, Module>;
+
+fn parse(input: &str) -> Modules {
+    let mut modules: HashMap<_, _> = input.trim().lines().map(parse_line).collect();
+
+    let mut outputs_to_inputs = Vec::new();
+
+    for (k, v) in &modules {
+        outputs_to_inputs.extend(v.outputs().iter().map(|o| (o.clone(), k.clone())))
+    }
+
+    for (output, input) in outputs_to_inputs {
+        if let Some(Module::Conjunction { inputs, .. }) = modules.get_mut(&output) {
+            inputs.insert(input, PulseKind::Low);
+        }
+    }
+
+    modules
+}
+
+fn solve_one(modules: &Modules) -> usize {
+    let mut modules = modules.clone();
+
+    let mut highs = 0;
+    let mut lows = 0;
+
+    for _ in 0..1000 {
+        let mut queue = VecDeque::from([Pulse {
+            sender: String::new(),
+            receiver: BROADCASTER.to_string(),
+            kind: PulseKind::Low,
+        }]);
+
+        while let Some(pulse) = queue.pop_front() {
+            match pulse.kind {
+                PulseKind::High => highs += 1,
+                PulseKind::Low => lows += 1,
+            }
+
+            let module = match modules.get_mut(&pulse.receiver) {
+                Some(module) => module,
+                None => continue,
+            };
+
+            match module {
+                Module::Broadcaster { outputs } => {
+                    for id in outputs {
+                        queue.push_back(Pulse {
+                            sender: BROADCASTER.to_string(),
+                            receiver: id.clone(),
+                            kind: pulse.kind,
+                        })
+                    }
+                }
+                Module::FlipFlop { state, outputs } => match pulse.kind {
+                    PulseKind::High => {}
+                    PulseKind::Low => {
+                        for output in outputs {
+                            match state {
+                                State::Off => queue.push_back(Pulse {
+                                    sender: pulse.receiver.clone(),
+                                    receiver: output.clone(),
+                                    kind: PulseKind::High,
+                                }),
+                                State::On => queue.push_back(Pulse {
+                                    sender: pulse.receiver.clone(),
+                                    receiver: output.clone(),
+                                    kind: PulseKind::Low,
+                                }),
+                            }
+                        }
+
+                        *state = -*state;
+                    }
+                },
+                Module::Conjunction { inputs, outputs } => {
+                    inputs.insert(pulse.sender.clone(), pulse.kind);
+
+                    let kind = if inputs.values().all(|kind| kind.is_high()) {
+                        PulseKind::Low
+                    } else {
+                        PulseKind::High
+                    };
+
+                    for output in outputs {
+                        queue.push_back(Pulse {
+                            sender: pulse.receiver.clone(),
+                            receiver: output.clone(),
+                            kind,
+                        })
+                    }
+                }
+            }
+        }
+    }
+
+    highs * lows
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(modules: &Modules) -> usize {
+    let mut modules = modules.clone();
+
+    let rx_input = modules
+        .iter()
+        .find_map(|(k, v)| {
+            if v.outputs().iter().any(|output| output == "rx") {
+                Some(k.clone())
+            } else {
+                None
+            }
+        })
+        .unwrap();
+
+    let rx_dependencies: Vec<_> = modules
+        .iter()
+        .filter_map(|(k, v)| {
+            if v.outputs().contains(&rx_input) {
+                Some(k.clone())
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    let mut cycle_lengths = HashMap::<Id, usize>::new();
+
+    for press in 1.. {
+        let mut queue = VecDeque::from([Pulse {
+            sender: String::new(),
+            receiver: BROADCASTER.to_string(),
+            kind: PulseKind::Low,
+        }]);
+
+        while let Some(pulse) = queue.pop_front() {
+            let module = match modules.get_mut(&pulse.receiver) {
+                Some(module) => module,
+                None => continue,
+            };
+
+            match module {
+                Module::Broadcaster { outputs } => {
+                    for id in outputs {
+                        queue.push_back(Pulse {
+                            sender: BROADCASTER.to_string(),
+                            receiver: id.clone(),
+                            kind: pulse.kind,
+                        })
+                    }
+                }
+                Module::FlipFlop { state, outputs } => match pulse.kind {
+                    PulseKind::High => {}
+                    PulseKind::Low => {
+                        for output in outputs {
+                            match state {
+                                State::Off => queue.push_back(Pulse {
+                                    sender: pulse.receiver.clone(),
+                                    receiver: output.clone(),
+                                    kind: PulseKind::High,
+                                }),
+                                State::On => queue.push_back(Pulse {
+                                    sender: pulse.receiver.clone(),
+                                    receiver: output.clone(),
+                                    kind: PulseKind::Low,
+                                }),
+                            }
+                        }
+
+                        *state = -*state;
+                    }
+                },
+                Module::Conjunction { inputs, outputs } => {
+                    inputs.insert(pulse.sender.clone(), pulse.kind);
+
+                    let kind = if inputs.values().all(|kind| kind.is_high()) {
+                        PulseKind::Low
+                    } else {
+                        if !cycle_lengths.contains_key(&pulse.receiver) {
+                            cycle_lengths.insert(pulse.receiver.clone(), press);
+                        }
+
+                        PulseKind::High
+                    };
+
+                    for output in outputs {
+                        queue.push_back(Pulse {
+                            sender: pulse.receiver.clone(),
+                            receiver: output.clone(),
+                            kind,
+                        })
+                    }
+                }
+            }
+        }
+
+        if rx_dependencies
+            .iter()
+            .all(|dep| cycle_lengths.contains_key(dep))
+        {
+            return rx_dependencies
+                .into_iter()
+                .map(|dep| cycle_lengths[&dep])
+                .fold(1, |acc, n| n.lcm(acc));
+        }
+    }
+
+    unreachable!()
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 20;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Modules;
+
+    fn parse(input: &str) -> Result<Modules, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Modules) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Modules) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
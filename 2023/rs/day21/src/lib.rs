@@ -0,0 +1,232 @@
+use std::{collections::HashSet, vec};
+use utils::{Chart, Direction, Point, Translate};
+
+/// A tile of the garden map.
+#[derive(Debug, Clone, Copy)]
+pub enum Tile {
+    Start,
+    Garden,
+    Rock,
+}
+
+impl From<char> for Tile {
+    fn from(value: char) -> Self {
+        match value {
+            '.' => Self::Garden,
+            '#' => Self::Rock,
+            'S' => Self::Start,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Tile {
+    fn is_passable(&self) -> bool {
+        !matches!(self, Self::Rock)
+    }
+
+    fn is_start(&self) -> bool {
+        matches!(self, Self::Start)
+    }
+}
+
+fn parse(input: &str) -> Chart<Tile> {
+    Chart::<Tile>::from(
+        input
+            .trim()
+            .lines()
+            .map(|l| l.chars().map(Tile::from).collect())
+            .collect::<Vec<_>>(),
+    )
+}
+
+fn reachable(plot: &Chart<Tile>, max_steps: usize) -> usize {
+    let start = plot
+        .iter()
+        .find_map(
+            |(point, tile)| {
+                if tile.is_start() {
+                    Some(point)
+                } else {
+                    None
+                }
+            },
+        )
+        .unwrap();
+
+    let mut current = HashSet::from([start]);
+    let mut buffer = HashSet::new();
+
+    for _ in 0..max_steps {
+        buffer.clear();
+
+        for p in current.iter() {
+            for dir in Direction::all() {
+                let new_p = p.translate(dir);
+                match plot.get(new_p) {
+                    Some(tile) if tile.is_passable() => {
+                        buffer.insert(new_p);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        std::mem::swap(&mut current, &mut buffer);
+    }
+
+    current.len()
+}
+
+fn solve_one(plot: &Chart<Tile>) -> usize {
+    reachable(plot, 64)
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(plot: &Chart<Tile>) -> usize {
+    let max_steps = 1000;
+
+    let start = plot
+        .iter()
+        .find_map(
+            |(point, tile)| {
+                if tile.is_start() {
+                    Some(point)
+                } else {
+                    None
+                }
+            },
+        )
+        .unwrap();
+
+    let width = plot.width() as i32;
+    let height = plot.height() as i32;
+
+    let mut current = HashSet::from([start]);
+    let mut buffer = HashSet::new();
+
+    let mut changes = vec![];
+
+    for it in 0..max_steps {
+        if (it - 65) % 131 == 0 {
+            changes.push(current.len() as i32);
+
+            if changes.len() == 3 {
+                break;
+            }
+        }
+
+        buffer.clear();
+
+        for p in current.iter() {
+            for dir in Direction::all() {
+                let next_loc = p.translate(dir);
+
+                let mut canonical_x = next_loc.x % width;
+                if canonical_x.is_negative() {
+                    canonical_x += width;
+                }
+                let mut canonical_y = next_loc.y % height;
+                if canonical_y.is_negative() {
+                    canonical_y += height;
+                }
+
+                let tile = plot.get(Point::from((canonical_x, canonical_y))).unwrap();
+
+                if tile.is_passable() {
+                    buffer.insert(next_loc);
+                }
+            }
+        }
+
+        std::mem::swap(&mut current, &mut buffer);
+    }
+
+    let mut first_changes = vec![];
+
+    for window in changes.windows(2) {
+        first_changes.push(window[1] - window[0]);
+    }
+
+    let mut second_changes = vec![];
+
+    for window in first_changes.windows(2) {
+        second_changes.push(window[1] - window[0]);
+    }
+
+    let target_len = (26501365 - 65) / 131 - 3;
+
+    let mut result = *changes.last().unwrap() as usize;
+    let mut change_by = *first_changes.last().unwrap() as usize;
+    let diff = *second_changes.last().unwrap() as usize;
+
+    for _ in 0..=target_len {
+        change_by += diff;
+        result += change_by;
+    }
+
+    result
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 21;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Chart<Tile>;
+
+    fn parse(input: &str) -> Result<Chart<Tile>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Chart<Tile>) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Chart<Tile>) -> usize {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reachable() {
+        let input = "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+";
+        assert_eq!(reachable(&parse(input), 6), 16);
+    }
+}
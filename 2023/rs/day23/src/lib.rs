@@ -0,0 +1,234 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use utils::{Chart, Direction, Point, Translate};
+
+/// A tile of the hiking trail map.
+#[derive(Debug, Clone, Copy)]
+pub enum Tile {
+    Path,
+    Forest,
+    SlopeUp,
+    SlopeDown,
+    SlopeLeft,
+    SlopeRight,
+}
+
+impl From<char> for Tile {
+    fn from(value: char) -> Self {
+        match value {
+            '#' => Self::Forest,
+            '.' => Self::Path,
+            '^' => Self::SlopeUp,
+            'v' => Self::SlopeDown,
+            '>' => Self::SlopeRight,
+            '<' => Self::SlopeLeft,
+            _ => unreachable!("unexpected char: '{value}'"),
+        }
+    }
+}
+
+impl From<Tile> for char {
+    fn from(value: Tile) -> Self {
+        match value {
+            Tile::Forest => '#',
+            Tile::Path => '.',
+            Tile::SlopeUp => '^',
+            Tile::SlopeDown => 'v',
+            Tile::SlopeRight => '>',
+            Tile::SlopeLeft => '<',
+        }
+    }
+}
+
+impl Tile {
+    fn is_passable(&self, direction_of_travel: Option<Direction>) -> bool {
+        match (self, direction_of_travel) {
+            (Self::Forest, _) => false,
+            (Self::Path, _) => true,
+            (Self::SlopeUp, Some(Direction::North) | None) => true,
+            (Self::SlopeUp, _) => false,
+            (Self::SlopeDown, Some(Direction::South) | None) => true,
+            (Self::SlopeDown, _) => false,
+            (Self::SlopeLeft, Some(Direction::West) | None) => true,
+            (Self::SlopeLeft, _) => false,
+            (Self::SlopeRight, Some(Direction::East) | None) => true,
+            (Self::SlopeRight, _) => false,
+        }
+    }
+}
+
+type Graph = HashMap<Point, Vec<(Point, usize)>>;
+
+fn parse(input: &str) -> Chart<Tile> {
+    Chart::from(
+        input
+            .lines()
+            .map(|line| line.chars().map(Tile::from).collect())
+            .collect::<Vec<Vec<_>>>(),
+    )
+}
+
+fn build_graph(chart: &Chart<Tile>, slopes: bool) -> (Graph, Point) {
+    let pos = Point::from((1, 0));
+    let mut visited = HashSet::from([pos]);
+    let mut nodes = vec![pos];
+    let mut queue = VecDeque::from([pos]);
+
+    while let Some(pos) = queue.pop_front() {
+        let mut count = 0;
+
+        for dir in Direction::all() {
+            let next_pos = pos.translate(dir);
+
+            if visited.contains(&next_pos) {
+                continue;
+            }
+
+            match chart.get(next_pos) {
+                Some(tile) if tile.is_passable(None) => {
+                    queue.push_back(next_pos);
+                    visited.insert(next_pos);
+                    count += 1;
+                }
+                _ => continue,
+            }
+        }
+
+        if count > 1 {
+            nodes.push(pos);
+        }
+    }
+
+    let y = chart.height() - 1;
+
+    let mut target = Point::from((0, 0));
+
+    for x in 0..chart.width() {
+        let pos = Point::from((x, y));
+        match chart.get(pos) {
+            Some(Tile::Path) => {
+                nodes.push(pos);
+                target = pos;
+                break;
+            }
+            Some(_) => continue,
+            None => unreachable!(),
+        }
+    }
+
+    let mut graph = HashMap::new();
+    let mut queue = VecDeque::new();
+    for node in &nodes {
+        visited.clear();
+        visited.insert(*node);
+        queue.push_back((*node, 0));
+
+        while let Some((pos, steps)) = queue.pop_front() {
+            if pos != *node && nodes.contains(&pos) {
+                let entry = graph.entry(*node).or_insert(Vec::new());
+                entry.push((pos, steps));
+
+                continue;
+            }
+
+            for next_dir in Direction::all() {
+                let next_pos = pos.translate(next_dir);
+                if visited.contains(&next_pos) {
+                    continue;
+                }
+                visited.insert(next_pos);
+
+                let check = slopes.then_some(next_dir);
+
+                match chart.get(next_pos) {
+                    Some(tile) if tile.is_passable(check) => {
+                        queue.push_back((pos.translate(next_dir), steps + 1))
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    (graph, target)
+}
+
+fn hike(chart: &Chart<Tile>, slopes: bool) -> usize {
+    let start = Point::from((1, 0));
+
+    let (graph, exit) = build_graph(chart, slopes);
+
+    let visited = HashSet::from([start]);
+    let mut queue = VecDeque::from([(start, 0, visited)]);
+
+    let mut max = 0;
+
+    while let Some((pos, steps, visited)) = queue.pop_front() {
+        if pos == exit {
+            max = max.max(steps);
+            continue;
+        }
+
+        for &(next, weight) in graph.get(&pos).unwrap() {
+            if visited.contains(&next) {
+                continue;
+            }
+            let mut visited = visited.clone();
+            visited.insert(next);
+            queue.push_back((next, steps + weight, visited));
+        }
+    }
+
+    max
+}
+
+fn solve_one(chart: &Chart<Tile>) -> usize {
+    hike(chart, true)
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(chart: &Chart<Tile>) -> usize {
+    hike(chart, false)
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 23;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Chart<Tile>;
+
+    fn parse(input: &str) -> Result<Chart<Tile>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Chart<Tile>) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Chart<Tile>) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,201 @@
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+/// Every component and the components wired to it.
+pub type Graph = HashMap<String, Vec<String>>;
+
+fn parse(input: &str) -> Graph {
+    let mut graph = Graph::new();
+
+    for line in input.lines() {
+        let from = &line[0..3];
+
+        for to in line.split(": ").last().unwrap().split_ascii_whitespace() {
+            let entry = graph.entry(from.to_string()).or_default();
+            entry.push(to.to_string());
+            let entry = graph.entry(to.to_string()).or_default();
+            entry.push(from.to_string());
+        }
+    }
+
+    graph
+}
+
+#[derive(Debug, Clone)]
+struct State<'a> {
+    target: &'a str,
+    curr: &'a str,
+    prev: &'a str,
+    seen: Vec<&'a str>,
+}
+
+impl<'a> State<'a> {
+    fn new(target: &'a str, next: &'a str) -> Self {
+        Self {
+            target,
+            curr: next,
+            prev: target,
+            seen: vec![next],
+        }
+    }
+
+    fn visit(mut self, next: &'a str) -> Self {
+        self.seen.push(next);
+
+        Self {
+            target: self.target,
+            curr: next,
+            prev: self.curr,
+            seen: self.seen,
+        }
+    }
+}
+
+impl PartialOrd for State<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for State<'_> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.seen.len().cmp(&self.seen.len())
+    }
+}
+
+impl PartialEq for State<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.seen.len() == other.seen.len()
+    }
+}
+
+impl Eq for State<'_> {}
+
+fn solve_one(graph: &Graph) -> i32 {
+    let mut graph: HashMap<&str, Vec<&str>> = graph
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.iter().map(String::as_str).collect()))
+        .collect();
+
+    let mut path_map = HashMap::new();
+    let mut heap = BinaryHeap::new();
+    let mut walked = HashSet::new();
+    for (&start, neighbors) in &graph {
+        for &neighbor in neighbors {
+            if !walked.insert((start, neighbor)) || !walked.insert((neighbor, start)) {
+                continue;
+            }
+
+            let mut shortest = None;
+            heap.clear();
+            heap.push(State::new(start, neighbor));
+
+            'outer: while let Some(state) = heap.pop() {
+                for &next in graph.get(state.curr).unwrap() {
+                    if next == state.prev || state.seen.contains(&next) {
+                        continue;
+                    }
+
+                    if next == state.target {
+                        shortest = Some(state);
+                        break 'outer;
+                    }
+                    heap.push(state.clone().visit(next));
+                }
+            }
+
+            let shortest = shortest.unwrap();
+            match path_map.get_mut(&start) {
+                None => {
+                    path_map.insert(start, shortest);
+                }
+                Some(s) if s.seen.len() < shortest.seen.len() => *s = shortest,
+                _ => {}
+            }
+        }
+    }
+
+    let mut longest_paths = path_map.into_values().collect::<Vec<_>>();
+    longest_paths.sort_by(|a, b| b.cmp(a));
+
+    let a = longest_paths.pop().unwrap();
+    let b = longest_paths.pop().unwrap();
+    let c = longest_paths.pop().unwrap();
+
+    let one = a.target;
+    let two = a.seen.first().copied().unwrap();
+
+    for State { target, seen, .. } in [a, b, c] {
+        let &first = seen.first().unwrap();
+
+        for (start, end) in [(target, first), (first, target)] {
+            graph.get_mut(start).unwrap().retain(|&n| n != end);
+        }
+    }
+
+    let mut result = 1;
+
+    for pos in [one, two] {
+        let mut stack = vec![pos];
+        let mut seen = HashSet::from([pos]);
+
+        while let Some(node) = stack.pop() {
+            for &neighbor in graph.get(node).unwrap() {
+                if seen.insert(neighbor) {
+                    stack.push(neighbor);
+                }
+            }
+        }
+
+        result *= seen.len() as i32;
+    }
+
+    result
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(_graph: &Graph) -> i32 {
+    0
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 25;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Graph;
+
+    fn parse(input: &str) -> Result<Graph, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Graph) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Graph) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
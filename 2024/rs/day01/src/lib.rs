@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+fn parse(input: &str) -> (Vec<i32>, Vec<i32>) {
+    let mut list1 = Vec::new();
+    let mut list2 = Vec::new();
+    for line in input.lines() {
+        let mut parts = line.split_whitespace();
+        list1.push(parts.next().unwrap().parse().unwrap());
+        list2.push(parts.next().unwrap().parse().unwrap());
+    }
+    (list1, list2)
+}
+
+fn solve_one((a, b): &(Vec<i32>, Vec<i32>)) -> i32 {
+    let mut a = a.clone();
+    let mut b = b.clone();
+
+    a.sort();
+    b.sort();
+
+    a.into_iter().zip(b).map(|(a, b)| (a - b).abs()).sum()
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two((a, b): &(Vec<i32>, Vec<i32>)) -> i32 {
+    let counts = b.iter().fold(HashMap::new(), |mut map, val| {
+        map.entry(val).and_modify(|count| *count += 1).or_insert(1);
+        map
+    });
+
+    a.iter()
+        .map(|val| counts.get(&val).unwrap_or(&0) * val)
+        .sum()
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = (Vec<i32>, Vec<i32>);
+
+    fn parse(input: &str) -> Result<(Vec<i32>, Vec<i32>), aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &(Vec<i32>, Vec<i32>)) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &(Vec<i32>, Vec<i32>)) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,110 @@
+use std::cmp::Ordering;
+
+fn is_safe(report: &Vec<i32>) -> bool {
+    let mut prev = None::<i32>;
+    let mut dir = None::<Ordering>;
+
+    for &int in report {
+        if let Some(prev) = prev {
+            if let Some(dir) = dir {
+                if dir != prev.cmp(&int) {
+                    return false;
+                }
+            } else {
+                dir = Some(prev.cmp(&int));
+            }
+
+            if !(1..=3).contains(&(prev - int).abs()) {
+                return false;
+            }
+        }
+
+        prev = Some(int);
+    }
+
+    true
+}
+
+fn parse(input: &str) -> Vec<Vec<i32>> {
+    input
+        .lines()
+        .map(|l| {
+            l.split_ascii_whitespace()
+                .map(|s| s.parse::<i32>().unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+fn solve_one(reports: &[Vec<i32>]) -> i32 {
+    reports
+        .iter()
+        .fold(0, |acc, l| acc + if is_safe(l) { 1 } else { 0 })
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn can_be_made_safe(report: Vec<i32>) -> bool {
+    if is_safe(&report) {
+        return true;
+    }
+
+    for i in 0..report.len() {
+        let mut new_report = report.clone();
+        new_report.remove(i);
+
+        if is_safe(&new_report) {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn solve_two(reports: &[Vec<i32>]) -> i32 {
+    reports.iter().fold(0, |acc, l| {
+        acc + if can_be_made_safe(l.clone()) { 1 } else { 0 }
+    })
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i32>>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Vec<i32>>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Vec<i32>>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
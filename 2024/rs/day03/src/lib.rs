@@ -0,0 +1,94 @@
+use regex::Regex;
+
+/// An instruction found in the corrupted memory.
+pub enum Instruction {
+    Mul(i32, i32),
+    Do,
+    Dont,
+}
+
+fn parse(input: &str) -> Vec<Instruction> {
+    let re = Regex::new(r"mul\((\d+),(\d+)\)|(do)(\(\))|(don't)(\(\))").unwrap();
+
+    re.captures_iter(input)
+        .map(|c| c.extract())
+        .map(|(_, [a, b])| match a {
+            "do" => Instruction::Do,
+            "don't" => Instruction::Dont,
+            _ => Instruction::Mul(a.parse().unwrap(), b.parse().unwrap()),
+        })
+        .collect()
+}
+
+fn solve_one(instructions: &[Instruction]) -> i32 {
+    instructions
+        .iter()
+        .map(|instruction| match instruction {
+            Instruction::Mul(a, b) => a * b,
+            _ => 0,
+        })
+        .sum()
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(instructions: &[Instruction]) -> i32 {
+    let mut active = true;
+    let mut sum = 0;
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Do => active = true,
+            Instruction::Dont => active = false,
+            Instruction::Mul(a, b) => {
+                if active {
+                    sum += a * b;
+                }
+            }
+        }
+    }
+
+    sum
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Instruction>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Instruction>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,133 @@
+fn parse(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|line| line.chars().collect()).collect()
+}
+
+fn get_nested<T: Copy + Default>(grid: &[Vec<T>], x: i32, y: i32) -> T {
+    if x < 0 || y < 0 {
+        return T::default();
+    }
+
+    grid.get(y as usize)
+        .and_then(|l| l.get(x as usize))
+        .copied()
+        .unwrap_or_default()
+}
+
+fn solve_one(grid: &[Vec<char>]) -> i32 {
+    let directions = [
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+        (0, -1),
+        (0, 1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+    ];
+
+    let search = ['M', 'A', 'S'];
+
+    let mut count = 0;
+
+    for (y, line) in grid.iter().enumerate() {
+        for (x, &ch) in line.iter().enumerate() {
+            if ch != 'X' {
+                continue;
+            }
+
+            'dir: for (dx, dy) in directions {
+                let mut x = x as i32 + dx;
+                let mut y = y as i32 + dy;
+                if x < 0 || y < 0 {
+                    continue;
+                }
+
+                for expected in search {
+                    let ch = get_nested(grid, x, y);
+                    if ch != expected {
+                        continue 'dir;
+                    }
+
+                    x += dx;
+                    y += dy;
+                }
+
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(grid: &[Vec<char>]) -> i32 {
+    let mut count = 0;
+
+    for (y, line) in grid.iter().enumerate() {
+        for (x, &ch) in line.iter().enumerate() {
+            if ch != 'A' {
+                continue;
+            }
+
+            let a = get_nested(grid, x as i32 - 1, y as i32 - 1);
+            let b = get_nested(grid, x as i32 + 1, y as i32 + 1);
+
+            let c = get_nested(grid, x as i32 - 1, y as i32 + 1);
+            let d = get_nested(grid, x as i32 + 1, y as i32 - 1);
+
+            match ((a, b), (c, d)) {
+                (('M', 'S'), ('S', 'M')) => count += 1,
+                (('S', 'M'), ('M', 'S')) => count += 1,
+                (('M', 'S'), ('M', 'S')) => count += 1,
+                (('S', 'M'), ('S', 'M')) => count += 1,
+                _ => {}
+            }
+        }
+    }
+
+    count
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<char>>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Vec<char>>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Vec<char>>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
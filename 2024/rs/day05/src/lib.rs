@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+
+/// The pages that must come before and after a page.
+#[derive(Debug)]
+pub struct Rule {
+    before: Vec<i32>,
+    after: Vec<i32>,
+}
+
+impl Rule {
+    fn new() -> Self {
+        Self {
+            before: Default::default(),
+            after: Default::default(),
+        }
+    }
+}
+
+/// The ordering rules by page.
+pub type RuleBook = HashMap<i32, Rule>;
+
+/// The pages of one update, in order.
+#[derive(Clone, Debug)]
+pub struct Manual(Vec<i32>);
+
+impl Manual {
+    fn new(pages: Vec<i32>) -> Self {
+        Self(pages)
+    }
+
+    fn is_valid(&self, rules: &RuleBook) -> bool {
+        for (i, n) in self.0.iter().enumerate() {
+            let rule = rules.get(n).unwrap();
+            if !self.0.iter().take(i).all(|m| rule.before.contains(m))
+                || !self.0.iter().skip(i + 1).all(|m| rule.after.contains(m))
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn middle_page(&self) -> i32 {
+        self.0[self.0.len() / 2]
+    }
+
+    fn fix(&mut self, rules: &RuleBook) {
+        let mut i = 0usize;
+
+        while i < self.0.len() {
+            let rule = rules.get(&self.0[i]).unwrap();
+
+            if let Some(bad) = self
+                .0
+                .iter()
+                .skip(i + 1)
+                .find_map(|m| rule.before.contains(m).then_some(*m))
+            {
+                let index = self.0.iter().position(|m| m == &bad).unwrap();
+                self.0.remove(index);
+                self.0.insert(i, bad);
+                i = 0;
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
+
+fn parse(input: &str) -> (RuleBook, Vec<Manual>) {
+    let mut rules = HashMap::new();
+
+    let mut lines = input.lines();
+
+    for line in lines.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+
+        let mut parts = line.split('|');
+
+        let first = parts.next().unwrap().parse().unwrap();
+        let second = parts.next().unwrap().parse().unwrap();
+
+        let page = rules.entry(first).or_insert(Rule::new());
+        page.after.push(second);
+        let page = rules.entry(second).or_insert(Rule::new());
+        page.before.push(first);
+    }
+
+    (
+        rules,
+        lines
+            .map(|l| Manual::new(l.split(',').map(|n| n.parse().unwrap()).collect()))
+            .collect(),
+    )
+}
+
+fn solve_one((rules, manuals): &(RuleBook, Vec<Manual>)) -> i32 {
+    manuals
+        .iter()
+        .filter(|m| m.is_valid(rules))
+        .map(|m| m.middle_page())
+        .sum()
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two((rules, manuals): &(RuleBook, Vec<Manual>)) -> i32 {
+    let mut manuals = manuals.clone();
+
+    manuals.retain(|m| !m.is_valid(rules));
+
+    for m in &mut manuals {
+        m.fix(rules);
+        assert!(m.is_valid(rules));
+    }
+
+    manuals.iter().map(|m| m.middle_page()).sum()
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = (RuleBook, Vec<Manual>);
+
+    fn parse(input: &str) -> Result<(RuleBook, Vec<Manual>), aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &(RuleBook, Vec<Manual>)) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &(RuleBook, Vec<Manual>)) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
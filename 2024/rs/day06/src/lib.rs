@@ -0,0 +1,187 @@
+use std::{collections::HashSet, fmt::Debug};
+use utils::{Coord, Direction};
+
+/// A grid of tiles.
+#[derive(Debug, Clone)]
+pub struct Map<T: Debug + Clone + Copy> {
+    tiles: Vec<Vec<T>>,
+}
+
+impl<T: Debug + Clone + Copy> Map<T> {
+    fn from(tiles: Vec<Vec<T>>) -> Self {
+        Self { tiles }
+    }
+
+    fn get(&self, coord: Coord) -> Option<T> {
+        if coord.x < 0 || coord.y < 0 {
+            return None;
+        }
+
+        self.tiles
+            .get(coord.y as usize)
+            .and_then(|row| row.get(coord.x as usize).copied())
+    }
+}
+
+/// A tile of the lab.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Guard(Direction),
+    Obstacle,
+    Open,
+}
+
+impl From<char> for Tile {
+    fn from(value: char) -> Self {
+        match value {
+            '#' => Self::Obstacle,
+            '.' => Self::Open,
+            '^' => Self::Guard(Direction::Up),
+            '>' => Self::Guard(Direction::Right),
+            'v' => Self::Guard(Direction::Down),
+            '<' => Self::Guard(Direction::Left),
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// The lab and where the guard starts and faces.
+pub type Lab = (Map<Tile>, Coord, Direction);
+
+fn parse(input: &str) -> Lab {
+    let mut start = Coord::new(0, 0);
+    let mut direction = Direction::Up;
+
+    let lines = input
+        .trim()
+        .lines()
+        .enumerate()
+        .map(|(y, line)| {
+            line.char_indices()
+                .map(|(x, ch)| match Tile::from(ch) {
+                    Tile::Guard(dir) => {
+                        start = Coord::new(x as i32, y as i32);
+                        direction = dir;
+
+                        Tile::Open
+                    }
+                    tile => tile,
+                })
+                .collect()
+        })
+        .collect();
+
+    (Map::from(lines), start, direction)
+}
+
+fn walk<F>(
+    map: &Map<Tile>,
+    mut current: Coord,
+    mut direction: Direction,
+    mut fun: F,
+) -> Result<(), ()>
+where
+    F: FnMut(Coord, Direction) -> bool,
+{
+    while let Some(tile) = map.get(current.translate(direction)) {
+        match tile {
+            Tile::Obstacle => {
+                direction = direction.turn_right();
+            }
+            Tile::Open => {
+                current = current.translate(direction);
+                if !fun(current, direction) {
+                    return Err(());
+                }
+            }
+            Tile::Guard(_) => unreachable!(),
+        }
+    }
+
+    Ok(())
+}
+
+fn solve_one(&(ref map, current, direction): &Lab) -> i32 {
+    let mut seen = HashSet::from([current]);
+
+    walk(map, current, direction, |coord, _| {
+        seen.insert(coord);
+        true
+    })
+    .unwrap();
+
+    seen.len() as i32
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(&(ref map, current, direction): &Lab) -> i32 {
+    let mut map = map.clone();
+    let mut seen = HashSet::from([(current, direction)]);
+    let mut result = 0;
+
+    for y in 0..map.tiles.len() {
+        for x in 0..map.tiles[0].len() {
+            if map.tiles[y][x] == Tile::Obstacle {
+                continue;
+            }
+
+            seen.clear();
+
+            map.tiles[y][x] = Tile::Obstacle;
+
+            match walk(&map, current, direction, |coord, dir| {
+                seen.insert((coord, dir))
+            }) {
+                Ok(_) => {}
+                Err(_) => result += 1,
+            }
+
+            map.tiles[y][x] = Tile::Open;
+        }
+    }
+
+    result
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Lab;
+
+    fn parse(input: &str) -> Result<Lab, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Lab) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Lab) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,116 @@
+fn concat(a: i64, b: i64) -> i64 {
+    let mut shift = 1;
+    let mut rem = b;
+    while rem > 0 {
+        shift *= 10;
+        rem /= 10;
+    }
+    a * shift + b
+}
+
+enum Op {
+    Add,
+    Mul,
+    Cat,
+}
+
+impl Op {
+    fn perform(&self, a: i64, b: i64) -> i64 {
+        match self {
+            Op::Add => a + b,
+            Op::Mul => a * b,
+            Op::Cat => concat(a, b),
+        }
+    }
+}
+
+fn solve(target: i64, values: &[i64], total: i64, ops: &[Op]) -> bool {
+    if values.is_empty() {
+        return target == total;
+    }
+
+    ops.iter()
+        .find(|op| solve(target, &values[1..], op.perform(total, values[0]), ops))
+        .is_some()
+}
+
+/// A test value and the numbers that should produce it.
+pub type Equation = (i64, Vec<i64>);
+
+fn parse(input: &str) -> Vec<Equation> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let mut split = line.split(": ");
+            let target = split.next().unwrap().parse().unwrap();
+
+            let values = split
+                .next()
+                .unwrap()
+                .split(' ')
+                .map(|s| s.parse().unwrap())
+                .collect();
+
+            (target, values)
+        })
+        .collect()
+}
+
+fn check_line(&(target, ref values): &Equation, ops: &[Op]) -> Option<i64> {
+    solve(target, values, 0, ops).then_some(target)
+}
+
+fn solve_one(equations: &[Equation]) -> i64 {
+    let ops = [Op::Add, Op::Mul];
+    equations.iter().flat_map(|e| check_line(e, &ops)).sum()
+}
+
+pub fn part_one(input: &str) -> i64 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(equations: &[Equation]) -> i64 {
+    let ops = [Op::Add, Op::Mul, Op::Cat];
+    equations.iter().flat_map(|e| check_line(e, &ops)).sum()
+}
+
+pub fn part_two(input: &str) -> i64 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 7;
+
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn part_one(input: &str) -> i64 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i64 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Equation>;
+
+    fn parse(input: &str) -> Result<Vec<Equation>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Equation>) -> i64 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Equation>) -> i64 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,132 @@
+use std::collections::{HashMap, HashSet};
+use utils::Coord;
+
+/// The antennae by frequency and the size of the map.
+pub type Antennae = (HashMap<char, Vec<Coord>>, i32);
+
+fn parse(input: &str) -> Antennae {
+    let mut max = 0;
+    let mut antennae = HashMap::<char, Vec<Coord>>::new();
+
+    for (y, line) in input.trim().lines().enumerate() {
+        max = y as i32;
+
+        for (x, ch) in line.char_indices() {
+            if ch == '.' {
+                continue;
+            }
+
+            antennae
+                .entry(ch)
+                .or_default()
+                .push(Coord::new(x as i32, y as i32))
+        }
+    }
+
+    (antennae, max + 1)
+}
+
+fn solve_one(&(ref antennae, max): &Antennae) -> i32 {
+    let mut spots = HashSet::new();
+
+    // println!("{antennae:?}");
+
+    for coords in antennae.values() {
+        for (i, &a) in coords.iter().enumerate() {
+            for &b in coords.iter().skip(i + 1) {
+                let diff = a - b;
+
+                for spot in [a + diff, b - diff] {
+                    if spot.x >= 0 && spot.x < max && spot.y >= 0 && spot.y < max {
+                        spots.insert(spot);
+                    }
+                }
+            }
+        }
+    }
+
+    // for y in 0..max {
+    //     for x in 0..max {
+    //         if spots.contains(&Coord::new(x, y)) {
+    //             print!("#");
+    //         } else {
+    //             print!(".");
+    //         }
+    //     }
+    //     println!()
+    // }
+
+    spots.len() as i32
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(&(ref antennae, max): &Antennae) -> i32 {
+    let mut spots = HashSet::new();
+
+    // println!("{antennae:?}");
+
+    for coords in antennae.values() {
+        for (i, &a) in coords.iter().enumerate() {
+            for &b in coords.iter().skip(i + 1) {
+                let diff = a - b;
+                spots.insert(a);
+                spots.insert(b);
+                for (mut spot, diff) in [(a, diff), (b, -diff)] {
+                    loop {
+                        spot += diff;
+                        if spot.x >= 0 && spot.x < max && spot.y >= 0 && spot.y < max {
+                            spots.insert(spot);
+                        } else {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    spots.len() as i32
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 8;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Antennae;
+
+    fn parse(input: &str) -> Result<Antennae, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Antennae) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Antennae) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,147 @@
+/// Each block of the disk and the file occupying it.
+pub type Disk = Vec<Option<i32>>;
+
+fn parse(input: &str) -> Disk {
+    let mut id = 0;
+    let mut is_file = true;
+
+    let mut disk = Vec::new();
+
+    for ch in input.trim().chars() {
+        let amount = ch.to_digit(10).unwrap();
+
+        let mut value = None;
+        if is_file {
+            value = Some(id);
+            id += 1;
+        }
+
+        is_file = !is_file;
+
+        for _ in 0..amount {
+            disk.push(value);
+        }
+    }
+
+    disk
+}
+
+fn calculate_checksum(disk: &Disk) -> u64 {
+    disk.iter()
+        .enumerate()
+        .map(|(index, v)| v.unwrap_or(0) as u64 * index as u64)
+        .sum()
+}
+
+fn solve_one(disk: &Disk) -> u64 {
+    let mut disk = disk.clone();
+
+    let mut head = 0;
+    let mut tail = disk.len() - 1;
+
+    loop {
+        while disk[head].is_some() {
+            head += 1;
+        }
+
+        while disk[tail].is_none() {
+            tail -= 1;
+        }
+
+        if head >= tail {
+            break;
+        }
+
+        disk.swap(head, tail);
+    }
+
+    calculate_checksum(&disk)
+}
+
+pub fn part_one(input: &str) -> u64 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(disk: &Disk) -> u64 {
+    let mut disk = disk.clone();
+
+    let mut end = disk.len() - 1;
+
+    while end > 1 {
+        while disk[end].is_none() {
+            end -= 1;
+        }
+
+        let mut start = end;
+
+        while disk[start] == disk[end] {
+            start -= 1;
+            if start == 0 {
+                break;
+            }
+        }
+
+        start += 1;
+
+        let len = end - start;
+
+        let mut search_start = 1;
+        let mut search_end = search_start + len;
+
+        while search_end < start {
+            if disk[search_start..=search_end].iter().all(Option::is_none) {
+                for i in 0..=len {
+                    disk.swap(start + i, search_start + i);
+                }
+                break;
+            }
+
+            search_start += 1;
+            search_end += 1;
+        }
+
+        end = start - 1;
+    }
+
+    calculate_checksum(&disk)
+}
+
+pub fn part_two(input: &str) -> u64 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 9;
+
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn part_one(input: &str) -> u64 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> u64 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Disk;
+
+    fn parse(input: &str) -> Result<Disk, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Disk) -> u64 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Disk) -> u64 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,144 @@
+use std::collections::HashSet;
+use utils::{Coord, Direction, Map};
+
+/// The height map and its trailheads.
+pub type Topography = (Map<i32>, Vec<Coord>);
+
+fn parse(input: &str) -> Topography {
+    let mut zeros = Vec::new();
+
+    let map = Map::from(
+        input
+            .trim()
+            .lines()
+            .enumerate()
+            .map(|(y, line)| {
+                line.char_indices()
+                    .map(|(x, c)| {
+                        let value = c.to_digit(10).unwrap() as i32;
+                        if value == 0 {
+                            zeros.push(Coord::new(x as i32, y as i32));
+                        }
+                        value
+                    })
+                    .collect()
+            })
+            .collect::<Vec<_>>(),
+    );
+
+    (map, zeros)
+}
+
+fn walk(map: &Map<i32>, loc: Coord, tracker: &mut Tracker) {
+    let current = map.get(loc).copied().unwrap();
+
+    for dir in Direction::all() {
+        let next_loc = loc.translate(dir);
+
+        match map.get(next_loc).copied() {
+            None => {}
+            Some(n) if current + 1 != n => {}
+            Some(9) => {
+                tracker.add(next_loc);
+            }
+            Some(_) => {
+                walk(map, next_loc, tracker);
+            }
+        }
+    }
+}
+
+enum Tracker {
+    Vec(Vec<Coord>),
+    Set(HashSet<Coord>),
+}
+
+impl Tracker {
+    fn add(&mut self, value: Coord) {
+        match self {
+            Self::Vec(v) => {
+                v.push(value);
+            }
+            Self::Set(s) => {
+                s.insert(value);
+            }
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Self::Vec(v) => v.len(),
+            Self::Set(s) => s.len(),
+        }
+    }
+
+    fn clear(&mut self) {
+        match self {
+            Self::Vec(v) => v.clear(),
+            Self::Set(s) => s.clear(),
+        }
+    }
+}
+
+fn solve((map, zeros): &Topography, mut tracker: Tracker) -> i32 {
+    zeros
+        .iter()
+        .map(|&loc| {
+            tracker.clear();
+            walk(map, loc, &mut tracker);
+            tracker.len() as i32
+        })
+        .sum()
+}
+
+fn solve_one(topography: &Topography) -> i32 {
+    solve(topography, Tracker::Set(HashSet::new()))
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(topography: &Topography) -> i32 {
+    solve(topography, Tracker::Vec(Vec::new()))
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 10;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Topography;
+
+    fn parse(input: &str) -> Result<Topography, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Topography) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Topography) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
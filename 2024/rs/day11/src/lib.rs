@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+fn transform_value(value: &String) -> Box<dyn Iterator<Item = String>> {
+    if value == "0" {
+        Box::new(["1".to_owned()].into_iter())
+    } else if value.len().is_multiple_of(2) {
+        let half = value.len() / 2;
+        let mut second: String = value[half..].chars().skip_while(|&ch| ch == '0').collect();
+        if second.is_empty() {
+            second.push('0');
+        }
+        Box::new([value[0..half].to_owned(), second].into_iter())
+    } else {
+        let value = value.parse::<u64>().unwrap() * 2024;
+        Box::new([value.to_string()].into_iter())
+    }
+}
+
+fn blink(
+    value: &str,
+    counts: &HashMap<String, Vec<String>>,
+    cache: &mut HashMap<(String, usize), usize>,
+    times: usize,
+) -> usize {
+    let values = counts.get(value).unwrap();
+
+    if let Some(&result) = cache.get(&(value.to_owned(), times)) {
+        return result;
+    }
+
+    let result = if times == 0 {
+        values.len()
+    } else {
+        values
+            .iter()
+            .map(|v| blink(v, counts, cache, times - 1))
+            .sum::<usize>()
+    };
+
+    cache.insert((value.to_owned(), times), result);
+
+    result
+}
+
+fn parse(input: &str) -> Vec<String> {
+    input.split_whitespace().map(|s| s.to_owned()).collect()
+}
+
+fn solve(input: &[String], times: usize) -> usize {
+    let mut stack = input.to_vec();
+    let mut steps = HashMap::new();
+
+    while let Some(value) = stack.pop() {
+        if steps.contains_key(&value) {
+            continue;
+        }
+
+        let new_stones: Vec<_> = transform_value(&value).collect();
+        steps.insert(value, new_stones.clone());
+        stack.extend(new_stones);
+    }
+
+    let mut cache = HashMap::new();
+
+    input
+        .iter()
+        .map(|v| blink(v, &steps, &mut cache, times))
+        .sum()
+}
+
+fn solve_one(stones: &[String]) -> usize {
+    solve(stones, 24)
+}
+
+pub fn part_one(input: &str) -> usize {
+    solve_one(&parse(input))
+}
+
+fn solve_two(stones: &[String]) -> usize {
+    solve(stones, 74)
+}
+
+pub fn part_two(input: &str) -> usize {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 11;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn part_one(input: &str) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> usize {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<String>) -> usize {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<String>) -> usize {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,180 @@
+use std::collections::HashSet;
+use utils::{Coord, Direction, Map};
+
+fn parse(input: &str) -> Map<char> {
+    Map::from(
+        input
+            .trim()
+            .lines()
+            .map(|line| line.chars().collect())
+            .collect::<Vec<_>>(),
+    )
+}
+
+fn flood_fill(map: &Map<char>, visited: &mut HashSet<Coord>, coord: Coord, expected: char) -> i32 {
+    let mut stack = vec![coord];
+
+    let mut perimeter = 0;
+    let mut area = 0;
+
+    while let Some(coord) = stack.pop() {
+        if !visited.insert(coord) {
+            continue;
+        }
+
+        area += 1;
+
+        for dir in Direction::all() {
+            let next = coord.translate(dir);
+
+            let neighbor = map.get(next).copied().unwrap_or_default();
+
+            if neighbor == expected {
+                stack.push(next);
+            } else {
+                perimeter += 1;
+            }
+        }
+    }
+
+    area * perimeter
+}
+
+fn solve<F>(map: &Map<char>, fun: F) -> i32
+where
+    F: Fn(&Map<char>, &mut HashSet<Coord>, Coord, char) -> i32,
+{
+    let mut visited = HashSet::new();
+
+    map.iter_coords()
+        .flat_map(|c| {
+            (!visited.contains(&c)).then(|| fun(map, &mut visited, c, map.get(c).copied().unwrap()))
+        })
+        .sum()
+}
+
+fn solve_one(map: &Map<char>) -> i32 {
+    solve(map, flood_fill)
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn walk_perimeter(map: &Map<char>, visited: &mut HashSet<Coord>, start: Coord, ch: char) -> i32 {
+    let mut stack = vec![start];
+
+    let mut area = 0;
+
+    let mut edges = HashSet::new();
+
+    while let Some(coord) = stack.pop() {
+        if !visited.insert(coord) {
+            continue;
+        }
+
+        area += 1;
+
+        for dir in Direction::all() {
+            let next = coord.translate(dir);
+
+            let neighbor = map.get(next).copied().unwrap_or_default();
+
+            if neighbor == ch {
+                stack.push(next);
+            } else {
+                edges.insert(coord);
+            }
+        }
+    }
+
+    let mut sides = 0;
+
+    while !edges.is_empty() {
+        let start = edges.iter().next().copied().unwrap();
+
+        let start_dir = Direction::all()
+            .into_iter()
+            .find(|d| {
+                let next = start.translate(*d);
+                map.get(next).copied().unwrap_or_default() != ch
+            })
+            .unwrap()
+            .turn_left();
+
+        let mut current_loc = start;
+        let mut current_dir = start_dir;
+        let mut started = false;
+
+        while !started || current_loc != start || current_dir != start_dir {
+            started = true;
+            edges.remove(&current_loc);
+            let ahead = current_loc.translate(current_dir);
+            if map.get(ahead).copied().unwrap_or_default() != ch {
+                current_dir = current_dir.turn_left();
+                sides += 1;
+
+                continue;
+            }
+
+            let to_right = map
+                .get(ahead.translate(current_dir.turn_right()))
+                .copied()
+                .unwrap_or_default();
+
+            if to_right == ch {
+                current_dir = current_dir.turn_right();
+                current_loc = ahead.translate(current_dir);
+                sides += 1;
+            } else {
+                current_loc = ahead;
+            }
+        }
+    }
+
+    area * sides
+}
+
+fn solve_two(map: &Map<char>) -> i32 {
+    solve(map, walk_perimeter)
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 12;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Map<char>;
+
+    fn parse(input: &str) -> Result<Map<char>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Map<char>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Map<char>) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -0,0 +1,143 @@
+use fraction::Fraction;
+use regex::Regex;
+
+/// How far a button moves the claw.
+#[derive(Debug, Clone, Copy)]
+pub struct Button {
+    dx: i32,
+    dy: i32,
+}
+
+impl From<Button> for Fraction {
+    fn from(value: Button) -> Self {
+        Self::from(value.dy) / value.dx
+    }
+}
+
+impl From<&str> for Button {
+    fn from(value: &str) -> Self {
+        let button_re = Regex::new(r"Button [AB]: X\+(\d+), Y\+(\d+)").unwrap();
+        let caps = button_re.captures(value).unwrap();
+        Button {
+            dx: caps.get(1).unwrap().as_str().parse().unwrap(),
+            dy: caps.get(2).unwrap().as_str().parse().unwrap(),
+        }
+    }
+}
+
+/// A claw machine's two buttons and the location of its prize.
+pub type Machine = (Button, Button, (u64, u64));
+
+fn parse(input: &str) -> Vec<Machine> {
+    let prize_re = Regex::new(r"Prize: X=(\d+), Y=(\d+)").unwrap();
+
+    input
+        .trim()
+        .split("\n\n")
+        .map(|game| {
+            let mut split = game.split("\n");
+            let a = Button::from(split.next().unwrap());
+            let b = Button::from(split.next().unwrap());
+            let caps = prize_re.captures(split.next().unwrap()).unwrap();
+            (
+                a,
+                b,
+                (
+                    caps.get(1).unwrap().as_str().parse().unwrap(),
+                    caps.get(2).unwrap().as_str().parse().unwrap(),
+                ),
+            )
+        })
+        .collect()
+}
+
+fn solve<F>(machines: &[Machine], fun: F) -> u64
+where
+    F: Fn((u64, u64)) -> (u64, u64),
+{
+    machines
+        .iter()
+        .map(|&(a, b, target)| (a, b, fun(target)))
+        .flat_map(|(a, b, target)| {
+            let a_y_intercept = find_y_intercept(a, (0, 0));
+            let b_y_intercept = find_y_intercept(b, target);
+
+            let x = (b_y_intercept - a_y_intercept) / (Fraction::from(a) - Fraction::from(b));
+            if x.denom().copied()? != 1 {
+                return None;
+            }
+
+            let a_presses = x / a.dx;
+            if a_presses.denom().copied()? != 1 {
+                return None;
+            }
+
+            let b_presses = (Fraction::from(target.0) - x) / b.dx;
+            if b_presses.denom().copied()? != 1 {
+                return None;
+            }
+
+            debug_assert!(a_presses * a.dy + b_presses * b.dy == Fraction::from(target.1));
+
+            Some(a_presses.numer().copied()? * 3 + b_presses.numer().copied()?)
+        })
+        .sum()
+}
+
+fn solve_one(machines: &[Machine]) -> u64 {
+    solve(machines, |el| el)
+}
+
+pub fn part_one(input: &str) -> u64 {
+    solve_one(&parse(input))
+}
+
+fn find_y_intercept(button: Button, target: (u64, u64)) -> Fraction {
+    Fraction::from(target.1) - Fraction::from(button.dy) * target.0 / button.dx
+}
+
+fn solve_two(machines: &[Machine]) -> u64 {
+    solve(machines, |el| {
+        (el.0 + 10000000000000, el.1 + 10000000000000)
+    })
+}
+
+pub fn part_two(input: &str) -> u64 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 13;
+
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn part_one(input: &str) -> u64 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> u64 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Vec<Machine>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Machine>) -> u64 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Machine>) -> u64 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
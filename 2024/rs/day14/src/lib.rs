@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+use utils::{Coord, CopyRange};
+
+use regex::Regex;
+
+/// A robot's position and velocity.
+pub type Robot = (Coord, Coord);
+
+fn parse(input: &str) -> Vec<Robot> {
+    let re = Regex::new(r"p=(-?\d+),(-?\d+) v=(-?\d+),(-?\d+)").unwrap();
+
+    input
+        .lines()
+        .map(|line| {
+            let caps = re.captures(line).unwrap();
+            (
+                Coord::new(caps[1].parse().unwrap(), caps[2].parse().unwrap()),
+                Coord::new(caps[3].parse().unwrap(), caps[4].parse().unwrap()),
+            )
+        })
+        .collect()
+}
+
+const WIDTH: i32 = 101;
+const HEIGHT: i32 = 103;
+
+fn safety_factor<const WIDTH: i32, const HEIGHT: i32>(robots: &[Robot]) -> i32 {
+    let mut map = HashMap::new();
+
+    for &(p, v) in robots {
+        let mut x = (p.x + v.x * 100) % WIDTH;
+        if x < 0 {
+            x += WIDTH;
+        }
+        let mut y = (p.y + v.y * 100) % HEIGHT;
+        if y < 0 {
+            y += HEIGHT;
+        }
+
+        map.entry((x, y)).and_modify(|v| *v += 1).or_insert(1);
+    }
+
+    let left_x = CopyRange::new(0, WIDTH / 2 - 1);
+    let right_x = CopyRange::new(WIDTH / 2 + 1, WIDTH);
+    let bottom_y = CopyRange::new(0, HEIGHT / 2 - 1);
+    let top_y = CopyRange::new(HEIGHT / 2 + 1, HEIGHT);
+
+    let quad_1 = (right_x, bottom_y);
+    let quad_2 = (right_x, top_y);
+    let quad_3 = (left_x, top_y);
+    let quad_4 = (left_x, bottom_y);
+
+    let mut quad_map = HashMap::new();
+
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            if let Some(n) = map.get(&(x, y)) {
+                if quad_1.0.contains(&x) && quad_1.1.contains(&y) {
+                    *quad_map.entry(quad_1).or_insert(0) += n;
+                } else if quad_2.0.contains(&x) && quad_2.1.contains(&y) {
+                    *quad_map.entry(quad_2).or_insert(0) += n;
+                } else if quad_3.0.contains(&x) && quad_3.1.contains(&y) {
+                    *quad_map.entry(quad_3).or_insert(0) += n;
+                } else if quad_4.0.contains(&x) && quad_4.1.contains(&y) {
+                    *quad_map.entry(quad_4).or_insert(0) += n;
+                }
+            }
+        }
+    }
+
+    quad_map.into_values().product()
+}
+
+fn solve_one(robots: &[Robot]) -> i32 {
+    safety_factor::<WIDTH, HEIGHT>(robots)
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(robots: &[Robot]) -> i32 {
+    let mut data = robots.to_vec();
+
+    let mut min = i32::MAX;
+    let middle = Coord::new(WIDTH / 2, HEIGHT / 2);
+
+    for i in 1.. {
+        let mut sum = 0;
+
+        for (p, v) in &mut data {
+            p.x = (p.x + v.x) % WIDTH;
+            if p.x < 0 {
+                p.x += WIDTH;
+            }
+            p.y = (p.y + v.y) % HEIGHT;
+            if p.y < 0 {
+                p.y += HEIGHT;
+            }
+
+            sum += p.manhattan_distance(middle);
+        }
+
+        min = min.min(sum / data.len() as i32);
+
+        if min == 30 {
+            return i;
+        }
+    }
+
+    unreachable!()
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 14;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Vec<Robot>;
+
+    fn parse(input: &str) -> Result<Vec<Robot>, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Vec<Robot>) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Vec<Robot>) -> i32 {
+        solve_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_safety_factor() {
+        let input = "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+";
+        assert_eq!(safety_factor::<11, 7>(&parse(input)), 12);
+    }
+}
@@ -0,0 +1,225 @@
+use utils::{Coord, Direction, Map};
+
+/// A tile of the warehouse.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Empty,
+    Wall,
+    Box,
+    Robot,
+    BoxLeft,
+    BoxRight,
+}
+
+impl From<Tile> for char {
+    fn from(t: Tile) -> Self {
+        match t {
+            Tile::Empty => '.',
+            Tile::Wall => '#',
+            Tile::Box => 'O',
+            Tile::Robot => '@',
+            Tile::BoxLeft => '[',
+            Tile::BoxRight => ']',
+        }
+    }
+}
+
+impl std::fmt::Debug for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c: char = (*self).into();
+        write!(f, "{}", c)
+    }
+}
+
+impl From<char> for Tile {
+    fn from(c: char) -> Self {
+        match c {
+            '.' => Self::Empty,
+            '#' => Self::Wall,
+            'O' => Self::Box,
+            '@' => Self::Robot,
+            '[' => Self::BoxLeft,
+            ']' => Self::BoxRight,
+            _ => panic!("Invalid tile: {}", c),
+        }
+    }
+}
+
+/// The warehouse as drawn, the same warehouse twice as wide, and the robot's moves.
+pub struct Warehouse {
+    narrow: Map<Tile>,
+    wide: Map<Tile>,
+    moves: Vec<Direction>,
+}
+
+fn parse(input: &str) -> Warehouse {
+    let mut parts = input.trim().split("\n\n");
+
+    let map = parts.next().unwrap();
+
+    let narrow = Map::from(
+        map.lines()
+            .map(|line| line.chars().map(Tile::from).collect::<Vec<_>>())
+            .collect::<Vec<_>>(),
+    );
+
+    let wide = Map::from(
+        map.lines()
+            .map(|line| {
+                line.chars()
+                    .flat_map(|ch| match ch {
+                        '.' => ['.', '.'],
+                        '#' => ['#', '#'],
+                        'O' => ['[', ']'],
+                        '@' => ['@', '.'],
+                        _ => panic!("Invalid tile: {}", ch),
+                    })
+                    .map(Tile::from)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>(),
+    );
+
+    let moves = parts
+        .next()
+        .unwrap()
+        .chars()
+        .filter(|ch| *ch != '\n')
+        .map(|ch| match ch {
+            '^' => Direction::Up,
+            'v' => Direction::Down,
+            '<' => Direction::Left,
+            '>' => Direction::Right,
+            _ => panic!("Invalid move: {:?}", ch),
+        })
+        .collect();
+
+    Warehouse {
+        narrow,
+        wide,
+        moves,
+    }
+}
+
+fn step(map: &mut Map<Tile>, loc: Coord, dir: Direction, swap: bool) -> bool {
+    let next = loc + dir;
+
+    match map.get(next).copied().unwrap() {
+        Tile::Empty => {
+            if swap {
+                map.swap(loc, next);
+            }
+            true
+        }
+        Tile::BoxLeft | Tile::BoxRight
+            if matches!(dir, Direction::Left | Direction::Right) && step(map, next, dir, swap) =>
+        {
+            if swap {
+                map.swap(loc, next);
+            }
+            true
+        }
+        Tile::BoxLeft | Tile::BoxRight if matches!(dir, Direction::Left | Direction::Right) => {
+            false
+        }
+        tile @ (Tile::BoxLeft | Tile::BoxRight) => {
+            let pair = next
+                + if tile == Tile::BoxLeft {
+                    Direction::Right
+                } else {
+                    Direction::Left
+                };
+            if step(map, next, dir, swap) && step(map, pair, dir, swap) {
+                if swap {
+                    map.swap(loc, next);
+                }
+                true
+            } else {
+                false
+            }
+        }
+        Tile::Box if step(map, next, dir, swap) => {
+            if swap {
+                map.swap(loc, next);
+            }
+            true
+        }
+        _ => false,
+    }
+}
+
+fn solve_one(Warehouse { narrow, moves, .. }: &Warehouse) -> i32 {
+    let mut map = narrow.clone();
+
+    let mut loc = map.iter().find(|(_, &tile)| tile == Tile::Robot).unwrap().0;
+
+    for &dir in moves {
+        if step(&mut map, loc, dir, true) {
+            loc += dir;
+        }
+    }
+
+    map.iter()
+        .flat_map(|(coord, &tile)| (tile == Tile::Box).then(|| coord.y * 100 + coord.x))
+        .sum()
+}
+
+pub fn part_one(input: &str) -> i32 {
+    solve_one(&parse(input))
+}
+
+fn solve_two(Warehouse { wide, moves, .. }: &Warehouse) -> i32 {
+    let mut map = wide.clone();
+
+    let mut loc = map.iter().find(|(_, &tile)| tile == Tile::Robot).unwrap().0;
+
+    for &dir in moves {
+        if step(&mut map, loc, dir, false) && step(&mut map, loc, dir, true) {
+            loc += dir;
+        }
+    }
+
+    map.iter()
+        .flat_map(|(coord, &tile)| (tile == Tile::BoxLeft).then(|| coord.y * 100 + coord.x))
+        .sum()
+}
+
+pub fn part_two(input: &str) -> i32 {
+    solve_two(&parse(input))
+}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 15;
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> i32 {
+        part_two(input)
+    }
+}
+
+impl aoc_core::Parse for Solver {
+    type Input = Warehouse;
+
+    fn parse(input: &str) -> Result<Warehouse, aoc_core::Error> {
+        Ok(parse(input))
+    }
+
+    fn solve_one(input: &Warehouse) -> i32 {
+        solve_one(input)
+    }
+
+    fn solve_two(input: &Warehouse) -> i32 {
+        solve_two(input)
+    }
+}
+
+aoc_core::examples!(Solver);
@@ -84,6 +84,7 @@ impl std::ops::AddAssign<Direction> for Coord {
     }
 }
 
+#[derive(Clone)]
 pub struct Map<T: Debug> {
     data: Vec<Vec<T>>,
 }
@@ -0,0 +1,107 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{Parse, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// Untimed runs to warm caches and the branch predictor.
+    pub warmup: u32,
+    pub iterations: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: 20,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "no samples");
+        samples.sort();
+
+        // nearest-rank percentiles, so every statistic is a real sample
+        let percentile = |p: f64| {
+            let rank = (p * samples.len() as f64).ceil() as usize;
+            samples[rank.max(1) - 1]
+        };
+
+        Self {
+            median: percentile(0.5),
+            p95: percentile(0.95),
+        }
+    }
+}
+
+pub fn measure<T>(config: &Config, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+
+    let samples = (0..config.iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(samples)
+}
+
+/// The timings of one day. Parsing is only timed on its own for days
+/// implementing [`Parse`], everywhere else it is part of each part's time.
+#[derive(Debug, Clone, Copy)]
+pub struct Report {
+    pub parse: Option<Stats>,
+    pub part_one: Stats,
+    pub part_two: Option<Stats>,
+}
+
+pub fn run<S: Solution>(config: &Config, input: &str) -> Report {
+    Report {
+        parse: None,
+        part_one: measure(config, || S::part_one(input)),
+        part_two: (S::PARTS == 2).then(|| measure(config, || S::part_two(input))),
+    }
+}
+
+/// Panics with the error when the input does not parse.
+pub fn run_parsed<S: Parse>(config: &Config, input: &str) -> Report {
+    let parsed = S::parse(input).unwrap_or_else(|err| panic!("{}", err));
+
+    Report {
+        parse: Some(measure(config, || S::parse(input))),
+        part_one: measure(config, || S::solve_one(&parsed)),
+        part_two: (S::PARTS == 2).then(|| measure(config, || S::solve_two(&parsed))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = (1..=20).rev().map(Duration::from_millis).collect();
+        let stats = Stats::from_samples(samples);
+        assert_eq!(stats.median, Duration::from_millis(10));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+
+        let stats = Stats::from_samples(vec![Duration::from_millis(7)]);
+        assert_eq!(stats.median, Duration::from_millis(7));
+        assert_eq!(stats.p95, Duration::from_millis(7));
+    }
+}
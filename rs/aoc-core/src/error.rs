@@ -0,0 +1,150 @@
+use std::{fmt, str::FromStr};
+
+/// Malformed input, reported instead of panicking so the other days still run.
+/// `line` and `column` are 1-based and filled in when they are known.
+/// Days whose parts return plain values still panic on malformed input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl Error {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    /// Points the error at `line`, unless it already points somewhere.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+            (Some(line), None) => write!(f, "line {}: ", line)?,
+            (None, Some(column)) => write!(f, "column {}: ", column)?,
+            (None, None) => (),
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Parses every line of `input`, pointing errors at the line they came from.
+pub fn parse_lines<T>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|err| err.at_line(i + 1)))
+        .collect()
+}
+
+/// The whitespace separated tokens of a line, so parsers can say which column is wrong.
+pub struct Tokens<'a> {
+    line: &'a str,
+    rest: &'a str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub text: &'a str,
+    pub column: usize,
+}
+
+impl<'a> Tokens<'a> {
+    pub fn new(line: &'a str) -> Self {
+        Self { line, rest: line }
+    }
+
+    fn column(&self, rest: &str) -> usize {
+        self.line.len() - rest.len() + 1
+    }
+
+    /// The next token, or an error saying the `expected` one is missing.
+    pub fn next(&mut self, expected: &str) -> Result<Token<'a>, Error> {
+        let rest = self
+            .rest
+            .trim_start_matches(|c: char| c.is_ascii_whitespace());
+        let end = rest
+            .find(|c: char| c.is_ascii_whitespace())
+            .unwrap_or(rest.len());
+        if end == 0 {
+            return Err(Error::new(format!("missing {}", expected)).at_column(self.column(rest)));
+        }
+
+        self.rest = &rest[end..];
+        Ok(Token {
+            text: &rest[..end],
+            column: self.column(rest),
+        })
+    }
+
+    /// Fails if anything is left on the line.
+    pub fn end(&mut self) -> Result<(), Error> {
+        match self.next("") {
+            Ok(token) => Err(token.error(format!("unexpected `{}`", token.text))),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+impl Token<'_> {
+    pub fn error(&self, message: impl Into<String>) -> Error {
+        Error::new(message).at_column(self.column)
+    }
+
+    pub fn parse<T: FromStr>(&self, expected: &str) -> Result<T, Error> {
+        self.text
+            .parse()
+            .map_err(|_| self.error(format!("invalid {} `{}`", expected, self.text)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens() {
+        let mut tokens = Tokens::new("addi  3 x");
+        let token = tokens.next("opcode").unwrap();
+        assert_eq!((token.text, token.column), ("addi", 1));
+        assert_eq!(tokens.next("a").unwrap().parse::<usize>("a"), Ok(3));
+
+        let err = tokens.next("b").unwrap().parse::<usize>("b").unwrap_err();
+        assert_eq!(err.to_string(), "column 9: invalid b `x`");
+        let err = tokens.next("c").unwrap_err().at_line(7);
+        assert_eq!(err.to_string(), "line 7, column 10: missing c");
+
+        assert!(Tokens::new("a ").end().is_err());
+        assert!(Tokens::new(" ").end().is_ok());
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let parse = |line: &str| Tokens::new(line).next("x")?.parse::<i32>("x");
+        assert_eq!(parse_lines("1\n2", parse), Ok(vec![1, 2]));
+        assert_eq!(
+            parse_lines("1\n\n3", parse).unwrap_err().to_string(),
+            "line 2, column 1: missing x"
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use std::{
+    fs,
+    io::Write,
+    path::Path,
+    process::{Command, Stdio},
+};
+
+use crate::{unescape, Answer, Error, Solution};
+
+/// A worked example from a puzzle description, stored as a file like
+///
+/// ```text
+/// part one: 7
+/// part two: 5
+/// ---
+/// <the example input, verbatim>
+/// ```
+///
+/// Either answer line may be left out when the example only applies to one part.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+    pub input: String,
+}
+
+impl Example {
+    pub fn parse(name: &str, contents: &str) -> Result<Self, String> {
+        let mut example = Example {
+            name: name.to_string(),
+            part_one: None,
+            part_two: None,
+            input: String::new(),
+        };
+
+        let mut rest = contents;
+        loop {
+            let (line, next) = match rest.split_once('\n') {
+                Some(split) => split,
+                None => return Err(format!("{}: missing --- before the input", name)),
+            };
+            rest = next;
+
+            let line = line.trim_end();
+            if line == "---" {
+                break;
+            }
+            match line.split_once(": ") {
+                Some(("part one", answer)) => example.part_one = Some(unescape(answer)),
+                Some(("part two", answer)) => example.part_two = Some(unescape(answer)),
+                _ => return Err(format!("{}: unexpected line: {}", name, line)),
+            }
+        }
+
+        if example.part_one.is_none() && example.part_two.is_none() {
+            return Err(format!("{}: no answers", name));
+        }
+        example.input = rest.to_string();
+        Ok(example)
+    }
+
+    pub fn load(dir: &Path) -> Vec<Self> {
+        let mut paths: Vec<_> = fs::read_dir(dir)
+            .unwrap_or_else(|err| panic!("{}: {}", dir.display(), err))
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+            .collect();
+        paths.sort();
+
+        paths
+            .iter()
+            .map(|path| {
+                let name = path.file_name().unwrap().to_string_lossy();
+                let contents = fs::read_to_string(path).unwrap();
+                Example::parse(&name, &contents).unwrap_or_else(|err| panic!("{}", err))
+            })
+            .collect()
+    }
+}
+
+/// Runs every example in `dir` and panics with all of the wrong answers.
+pub fn check<S: Solution>(dir: &str) {
+    let examples = Example::load(Path::new(dir));
+    assert!(!examples.is_empty(), "{}: no examples", dir);
+
+    let mut failures = Vec::new();
+    for example in &examples {
+        let mut compare =
+            |part, expected: &Option<String>, actual: &dyn Fn() -> Result<String, Error>| {
+                if let Some(expected) = expected {
+                    let actual = actual().unwrap_or_else(|err| format!("error: {}", err));
+                    if &actual != expected {
+                        failures.push(format!(
+                            "{} part {}:\n  expected: {:?}\n    actual: {:?}",
+                            example.name, part, expected, actual
+                        ));
+                    }
+                }
+            };
+        compare("one", &example.part_one, &|| {
+            S::part_one(&example.input).into_answer()
+        });
+        compare("two", &example.part_two, &|| {
+            S::part_two(&example.input).into_answer()
+        });
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+/// Runs the day binary `bin` on every example in `dir`, fed through `--stdin`
+/// with `args` added, and returns what each run printed to stdout. Meant for
+/// integration tests, where cargo sets `CARGO_BIN_EXE_<name>`.
+pub fn stdout(bin: &str, dir: &str, args: &[&str]) -> Vec<String> {
+    Example::load(Path::new(dir))
+        .iter()
+        .map(|example| {
+            let mut child = Command::new(bin)
+                .arg("--stdin")
+                .args(args)
+                .stdin(Stdio::piped())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::null())
+                .spawn()
+                .unwrap_or_else(|err| panic!("{}: {}", bin, err));
+            child
+                .stdin
+                .take()
+                .unwrap()
+                .write_all(example.input.as_bytes())
+                .unwrap();
+
+            let output = child.wait_with_output().unwrap();
+            String::from_utf8(output.stdout).unwrap()
+        })
+        .collect()
+}
+
+/// Generates a test running `check` on the day's `examples` directory.
+#[macro_export]
+macro_rules! examples {
+    ($solver:ty) => {
+        #[cfg(test)]
+        #[test]
+        fn examples() {
+            $crate::examples::check::<$solver>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let example = Example::parse("1.txt", "part two: 5\n---\n1\n2\n").unwrap();
+        assert_eq!(example.part_one, None);
+        assert_eq!(example.part_two.as_deref(), Some("5"));
+        assert_eq!(example.input, "1\n2\n");
+
+        let example = Example::parse("2.txt", "part one: #.\\n.#\n---\n").unwrap();
+        assert_eq!(example.part_one.as_deref(), Some("#.\n.#"));
+
+        assert!(Example::parse("3.txt", "1\n2\n").is_err());
+        assert!(Example::parse("4.txt", "---\n1\n").is_err());
+        assert!(Example::parse("5.txt", "part three: 1\n---\n1\n").is_err());
+    }
+}
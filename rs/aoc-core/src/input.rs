@@ -0,0 +1,140 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+use crate::Solution;
+
+const USAGE: &str = "expected one of --input <path>, --stdin or --user <name>";
+
+/// Where a puzzle input is read from at runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// `data.txt` in the current directory.
+    Default,
+    Path(PathBuf),
+    Stdin,
+    /// `<dir>/<user>/<year>/dayNN.txt`, where `<dir>` is `$AOC_INPUT_DIR` or `inputs`.
+    User(String),
+}
+
+impl Source {
+    /// Takes the input flags out of `args`, leaving everything else in place.
+    pub fn from_args(args: &mut Vec<String>) -> Result<Source, String> {
+        let mut source = Source::Default;
+        let mut i = 0;
+
+        while i < args.len() {
+            let next = match args[i].as_str() {
+                "--stdin" => {
+                    args.remove(i);
+                    Source::Stdin
+                }
+                flag @ ("--input" | "--user") => {
+                    let flag = flag.to_string();
+                    args.remove(i);
+                    if i == args.len() {
+                        return Err(format!("{} needs a value", flag));
+                    }
+                    let value = args.remove(i);
+                    if flag == "--input" {
+                        Source::Path(PathBuf::from(value))
+                    } else {
+                        Source::User(value)
+                    }
+                }
+                _ => {
+                    i += 1;
+                    continue;
+                }
+            };
+
+            if source != Source::Default {
+                return Err(USAGE.to_string());
+            }
+            source = next;
+        }
+
+        Ok(source)
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> Option<PathBuf> {
+        match self {
+            Source::Default => Some(PathBuf::from("data.txt")),
+            Source::Path(path) => Some(path.clone()),
+            Source::Stdin => None,
+            Source::User(user) => Some(user_path(user, year, day)),
+        }
+    }
+
+    pub fn read(&self, year: u16, day: u8) -> io::Result<String> {
+        match self.path(year, day) {
+            Some(path) => fs::read_to_string(&path)
+                .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", path.display(), err))),
+            None => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+pub fn user_path(user: &str, year: u16, day: u8) -> PathBuf {
+    let dir = env::var_os("AOC_INPUT_DIR").unwrap_or_else(|| "inputs".into());
+    PathBuf::from(dir)
+        .join(user)
+        .join(year.to_string())
+        .join(format!("day{:02}.txt", day))
+}
+
+/// Reads the input for a day binary according to its command line arguments.
+pub fn load<S: Solution>(mut args: Vec<String>) -> io::Result<String> {
+    let source = Source::from_args(&mut args)
+        .map_err(|message| io::Error::new(io::ErrorKind::InvalidInput, message))?;
+    if let Some(arg) = args.first() {
+        let message = format!("unexpected argument: {}", arg);
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, message));
+    }
+    source.read(S::YEAR, S::DAY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_from_args() {
+        let mut rest = args(&["run", "2019", "--user", "alice", "1"]);
+        assert_eq!(
+            Source::from_args(&mut rest),
+            Ok(Source::User("alice".to_string()))
+        );
+        assert_eq!(rest, args(&["run", "2019", "1"]));
+
+        let mut rest = args(&["--input", "big.txt"]);
+        assert_eq!(
+            Source::from_args(&mut rest),
+            Ok(Source::Path(PathBuf::from("big.txt")))
+        );
+        assert!(rest.is_empty());
+
+        assert_eq!(Source::from_args(&mut args(&[])), Ok(Source::Default));
+        assert_eq!(
+            Source::from_args(&mut args(&["--stdin"])),
+            Ok(Source::Stdin)
+        );
+        assert!(Source::from_args(&mut args(&["--user"])).is_err());
+        assert!(Source::from_args(&mut args(&["--stdin", "--input", "a"])).is_err());
+    }
+
+    #[test]
+    fn test_user_path() {
+        assert!(user_path("alice", 2019, 4).ends_with("alice/2019/day04.txt"));
+    }
+}
@@ -0,0 +1,197 @@
+use std::{
+    env, fmt, io, process,
+    time::{Duration, Instant},
+};
+
+use output::{Format, Record};
+
+pub mod bench;
+pub mod error;
+pub mod examples;
+pub mod input;
+pub mod output;
+
+pub use error::Error;
+
+/// A day's puzzle: the raw input goes in, a typed answer comes out of each part.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+    /// Day 25s only have a first part.
+    const PARTS: u8 = 2;
+
+    type PartOne: Answer;
+    type PartTwo: Answer;
+
+    fn part_one(input: &str) -> Self::PartOne;
+    fn part_two(input: &str) -> Self::PartTwo;
+}
+
+/// A day whose parts share a parsing step, split out so benchmarks can time
+/// parsing and solving separately. Days without it are benched whole, with
+/// parsing counted in each part.
+pub trait Parse: Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+    fn solve_one(input: &Self::Input) -> Self::PartOne;
+    fn solve_two(input: &Self::Input) -> Self::PartTwo;
+}
+
+/// What a part returns: anything printable, or a `Result` for days that
+/// report malformed input instead of panicking.
+pub trait Answer {
+    fn into_answer(self) -> Result<String, Error>;
+}
+
+macro_rules! display_answers {
+    ($($ty:ty),*) => {
+        $(
+            impl Answer for $ty {
+                fn into_answer(self) -> Result<String, Error> {
+                    Ok(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+display_answers!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, usize, char, String, NoAnswer);
+
+impl<T: fmt::Display> Answer for Result<T, Error> {
+    fn into_answer(self) -> Result<String, Error> {
+        self.map(|answer| answer.to_string())
+    }
+}
+
+/// The part two answer of puzzles that only have one part (every day 25).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoAnswer;
+
+impl fmt::Display for NoAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "-")
+    }
+}
+
+/// The `main` of every day binary: prints each answer with how long it took,
+/// as text or, with `--format json|csv`, one record per part.
+/// A single run is noisy, `aoc bench` gives numbers worth comparing.
+/// Errors go to stderr and make the process exit with status 1.
+pub fn run<S: Solution>() -> io::Result<()> {
+    let (input, format) = load::<S>()?;
+
+    let mut solved = print_part::<S, _>(format, 1, None, || S::part_one(&input));
+    if S::PARTS == 2 {
+        solved &= print_part::<S, _>(format, 2, None, || S::part_two(&input));
+    }
+
+    if !solved {
+        process::exit(1);
+    }
+    Ok(())
+}
+
+/// [`run`] for days implementing [`Parse`], which also reports the parse time.
+pub fn run_parsed<S: Parse>() -> io::Result<()> {
+    let (input, format) = load::<S>()?;
+
+    let start = Instant::now();
+    let parsed = S::parse(&input).unwrap_or_else(|err| {
+        eprintln!("{} day{:02}: {}", S::YEAR, S::DAY, err);
+        process::exit(1);
+    });
+    let parse = start.elapsed();
+    if format == Format::Text {
+        println!("Parse time: {} µs", parse.as_micros());
+    }
+
+    let mut solved = print_part::<S, _>(format, 1, Some(parse), || S::solve_one(&parsed));
+    if S::PARTS == 2 {
+        solved &= print_part::<S, _>(format, 2, Some(parse), || S::solve_two(&parsed));
+    }
+
+    if !solved {
+        process::exit(1);
+    }
+    Ok(())
+}
+
+fn load<S: Solution>() -> io::Result<(String, Format)> {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let format = Format::from_args(&mut args)
+        .map_err(|message| io::Error::new(io::ErrorKind::InvalidInput, message))?;
+    let input = input::load::<S>(args)?;
+
+    if let Some(header) = format.header() {
+        println!("{}", header);
+    }
+    Ok((input, format))
+}
+
+// returns whether the part produced an answer
+fn print_part<S: Solution, T: Answer>(
+    format: Format,
+    part: u8,
+    parse: Option<Duration>,
+    solve: impl FnOnce() -> T,
+) -> bool {
+    let start = Instant::now();
+    let answer = solve().into_answer();
+    let solve = start.elapsed();
+
+    let answer = match answer {
+        Ok(answer) => answer,
+        Err(err) => {
+            eprintln!("{} day{:02} part {}: {}", S::YEAR, S::DAY, part, err);
+            return false;
+        }
+    };
+
+    let record = Record {
+        year: S::YEAR,
+        day: S::DAY,
+        part,
+        answer,
+        parse,
+        solve,
+    };
+    println!("{}", format.format(&record));
+    true
+}
+
+/// Writes an answer on a single line, for answers drawn on a screen.
+pub fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+pub fn unescape(answer: &str) -> String {
+    let mut result = String::new();
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                result.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                result.push('\\');
+                chars.next();
+            }
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        for answer in ["1088", "# #\n  #", "a\\nb"] {
+            assert_eq!(unescape(&escape(answer)), answer);
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use std::time::Duration;
+
+/// How a day binary prints its results, picked with `--format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// `part 1: <answer>` followed by the time it took.
+    Text,
+    /// One JSON object per part.
+    Json,
+    /// A header, then one row per part.
+    Csv,
+}
+
+const FIELDS: &str = "year,day,part,answer,parse_ns,solve_ns";
+
+/// The result of one part. `parse` is only known for days implementing
+/// [`Parse`](crate::Parse), and is the same for both of their parts. When it
+/// is `None`, `parse_ns` is `null` in JSON and empty in CSV, so every day
+/// writes the same fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub parse: Option<Duration>,
+    pub solve: Duration,
+}
+
+impl Format {
+    /// Takes `--format <text|json|csv>` out of `args`, leaving everything else in place.
+    pub fn from_args(args: &mut Vec<String>) -> Result<Format, String> {
+        let Some(i) = args.iter().position(|arg| arg == "--format") else {
+            return Ok(Format::Text);
+        };
+        args.remove(i);
+        if i == args.len() {
+            return Err("--format needs a value".to_string());
+        }
+
+        match args.remove(i).as_str() {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            format => Err(format!("unknown format: {}", format)),
+        }
+    }
+
+    pub fn header(self) -> Option<&'static str> {
+        match self {
+            Format::Csv => Some(FIELDS),
+            Format::Text | Format::Json => None,
+        }
+    }
+
+    pub fn format(self, record: &Record) -> String {
+        let parse = record
+            .parse
+            .map_or(String::new(), |parse| parse.as_nanos().to_string());
+        let solve = record.solve.as_nanos();
+
+        match self {
+            Format::Text => {
+                let answer = &record.answer;
+                let answer = if answer.contains('\n') {
+                    format!("\n{}", answer.strip_suffix('\n').unwrap_or(answer))
+                } else {
+                    format!(" {}", answer)
+                };
+                format!(
+                    "part {}:{}\nTime elapsed: {} µs",
+                    record.part,
+                    answer,
+                    record.solve.as_micros()
+                )
+            }
+            Format::Json => format!(
+                r#"{{"year":{},"day":{},"part":{},"answer":{},"parse_ns":{},"solve_ns":{}}}"#,
+                record.year,
+                record.day,
+                record.part,
+                json_string(&record.answer),
+                if parse.is_empty() { "null" } else { &parse },
+                solve
+            ),
+            Format::Csv => format!(
+                "{},{},{},{},{},{}",
+                record.year,
+                record.day,
+                record.part,
+                csv_field(&record.answer),
+                parse,
+                solve
+            ),
+        }
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut result = String::from('"');
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if c.is_control() => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+// quoted as in RFC 4180 when it would otherwise break the row
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(answer: &str, parse: Option<u64>) -> Record {
+        Record {
+            year: 2019,
+            day: 8,
+            part: 2,
+            answer: answer.to_string(),
+            parse: parse.map(Duration::from_nanos),
+            solve: Duration::from_nanos(1500),
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(
+            Format::Json.format(&record("# #\n\"a\"", Some(20))),
+            r##"{"year":2019,"day":8,"part":2,"answer":"# #\n\"a\"","parse_ns":20,"solve_ns":1500}"##
+        );
+        assert_eq!(
+            Format::Json.format(&record("12", None)),
+            r#"{"year":2019,"day":8,"part":2,"answer":"12","parse_ns":null,"solve_ns":1500}"#
+        );
+        assert_eq!(
+            Format::Csv.format(&record("a,\"b\"", None)),
+            "2019,8,2,\"a,\"\"b\"\"\",,1500"
+        );
+        assert_eq!(
+            Format::Csv.format(&record("12", Some(20))),
+            "2019,8,2,12,20,1500"
+        );
+        assert_eq!(
+            Format::Text.format(&record("# #\n", None)),
+            "part 2:\n# #\nTime elapsed: 1 µs"
+        );
+    }
+
+    #[test]
+    fn test_header() {
+        assert_eq!(
+            Format::Csv.header(),
+            Some("year,day,part,answer,parse_ns,solve_ns")
+        );
+        assert_eq!(Format::Json.header(), None);
+    }
+
+    #[test]
+    fn test_from_args() {
+        let mut args = vec![
+            "--format".to_string(),
+            "csv".to_string(),
+            "--stdin".to_string(),
+        ];
+        assert_eq!(Format::from_args(&mut args), Ok(Format::Csv));
+        assert_eq!(args, vec!["--stdin".to_string()]);
+
+        assert_eq!(Format::from_args(&mut vec![]), Ok(Format::Text));
+        assert!(Format::from_args(&mut vec!["--format".to_string()]).is_err());
+        assert!(Format::from_args(&mut vec!["--format".to_string(), "xml".to_string()]).is_err());
+    }
+}
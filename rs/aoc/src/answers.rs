@@ -0,0 +1,73 @@
+use aoc_core::unescape;
+use std::{collections::HashMap, fs, path::Path};
+
+pub const DEFAULT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.txt");
+
+/// Known-correct answers, one `<year> <day> <part> <answer>` per line.
+/// Answers spanning several lines are written with `\n` escapes.
+pub struct Answers(HashMap<(u16, u8, u8), String>);
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut answers = HashMap::new();
+
+        for (i, line) in input.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.splitn(4, ' ');
+            let mut key = || fields.next().and_then(|field| field.parse::<u16>().ok());
+            let parsed = match (key(), key(), key()) {
+                (Some(year), Some(day @ 1..=25), Some(part @ 1..=2)) => fields
+                    .next()
+                    .map(|answer| ((year, day as u8, part as u8), answer)),
+                _ => None,
+            };
+
+            match parsed {
+                Some((key, answer)) => {
+                    if answers.insert(key, unescape(answer)).is_some() {
+                        return Err(format!("line {}: duplicate answer", i + 1));
+                    }
+                }
+                None => {
+                    return Err(format!(
+                        "line {}: expected <year> <day> <part> <answer>",
+                        i + 1
+                    ))
+                }
+            }
+        }
+
+        Ok(Self(answers))
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let input =
+            fs::read_to_string(path).map_err(|err| format!("{}: {}", path.display(), err))?;
+        Self::parse(&input).map_err(|err| format!("{}: {}", path.display(), err))
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&str> {
+        self.0.get(&(year, day, part)).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("# comment\n\n2019 4 1 2090\n2019 8 2 # #\\n##\n").unwrap();
+        assert_eq!(answers.get(2019, 4, 1), Some("2090"));
+        assert_eq!(answers.get(2019, 8, 2), Some("# #\n##"));
+        assert_eq!(answers.get(2019, 4, 2), None);
+
+        assert!(Answers::parse("2019 4 3 2090").is_err());
+        assert!(Answers::parse("2019 4 1").is_err());
+        assert!(Answers::parse("2019 4 1 1\n2019 4 1 2").is_err());
+    }
+}
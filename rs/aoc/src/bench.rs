@@ -0,0 +1,194 @@
+use aoc_core::bench::Stats;
+use std::{collections::BTreeMap, fmt, fs, path::Path, time::Duration};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    Part(u8),
+}
+
+impl Phase {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "parse" => Some(Phase::Parse),
+            "1" => Some(Phase::Part(1)),
+            "2" => Some(Phase::Part(2)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part(part) => write!(f, "{}", part),
+        }
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    if nanos < 1e3 {
+        format!("{} ns", nanos)
+    } else if nanos < 1e6 {
+        format!("{:.1} µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.2} ms", nanos / 1e6)
+    } else {
+        format!("{:.2} s", nanos / 1e9)
+    }
+}
+
+/// How much slower `current` is than `baseline`, in percent.
+pub fn change(baseline: &Stats, current: &Stats) -> f64 {
+    let baseline = baseline.median.as_nanos().max(1) as f64;
+    (current.median.as_nanos() as f64 / baseline - 1.0) * 100.0
+}
+
+/// Saved timings, one `<year> <day> <phase> <median ns> <p95 ns>` per line.
+#[derive(Default)]
+pub struct Results(BTreeMap<(u16, u8, Phase), Stats>);
+
+impl Results {
+    pub fn insert(&mut self, year: u16, day: u8, phase: Phase, stats: Stats) {
+        self.0.insert((year, day, phase), stats);
+    }
+
+    pub fn get(&self, year: u16, day: u8, phase: Phase) -> Option<&Stats> {
+        self.0.get(&(year, day, phase))
+    }
+
+    /// Drops every phase of one day, before it is benched again.
+    pub fn clear(&mut self, year: u16, day: u8) {
+        self.0.retain(|&(y, d, _), _| (y, d) != (year, day));
+    }
+
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut results = Self::default();
+
+        for (i, line) in input.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split(' ').collect();
+            let nanos = |field: &str| field.parse().ok().map(Duration::from_nanos);
+            let parsed = match fields[..] {
+                [year, day, phase, median, p95] => match (
+                    year.parse::<u16>(),
+                    day.parse::<u8>(),
+                    Phase::from_name(phase),
+                    nanos(median),
+                    nanos(p95),
+                ) {
+                    (Ok(year), Ok(day @ 1..=25), Some(phase), Some(median), Some(p95)) => {
+                        Some(((year, day, phase), Stats { median, p95 }))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            };
+
+            match parsed {
+                Some((key, stats)) => {
+                    if results.0.insert(key, stats).is_some() {
+                        return Err(format!("line {}: duplicate timing", i + 1));
+                    }
+                }
+                None => {
+                    return Err(format!(
+                        "line {}: expected <year> <day> <phase> <median ns> <p95 ns>",
+                        i + 1
+                    ))
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let input =
+            fs::read_to_string(path).map_err(|err| format!("{}: {}", path.display(), err))?;
+        Self::parse(&input).map_err(|err| format!("{}: {}", path.display(), err))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_string()).map_err(|err| format!("{}: {}", path.display(), err))
+    }
+}
+
+impl fmt::Display for Results {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "# <year> <day> <phase> <median ns> <p95 ns>, written by `aoc bench`"
+        )?;
+        for ((year, day, phase), stats) in &self.0 {
+            writeln!(
+                f,
+                "{} {} {} {} {}",
+                year,
+                day,
+                phase,
+                stats.median.as_nanos(),
+                stats.p95.as_nanos()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let stats = Stats {
+            median: Duration::from_nanos(1500),
+            p95: Duration::from_nanos(2100),
+        };
+        let mut results = Results::default();
+        results.insert(2019, 1, Phase::Parse, stats);
+        results.insert(2019, 1, Phase::Part(2), stats);
+
+        let parsed = Results::parse(&results.to_string()).unwrap();
+        assert_eq!(parsed.get(2019, 1, Phase::Parse), Some(&stats));
+        assert_eq!(parsed.get(2019, 1, Phase::Part(2)), Some(&stats));
+        assert_eq!(parsed.get(2019, 1, Phase::Part(1)), None);
+
+        assert!(Results::parse("2019 1 3 1500 2100").is_err());
+        assert!(Results::parse("2019 1 parse 1500").is_err());
+        assert!(Results::parse("2019 1 1 1 1\n2019 1 1 2 2").is_err());
+    }
+
+    #[test]
+    fn test_clear() {
+        let stats = Stats {
+            median: Duration::from_nanos(1500),
+            p95: Duration::from_nanos(2100),
+        };
+        let mut results = Results::default();
+        results.insert(2019, 1, Phase::Parse, stats);
+        results.insert(2019, 1, Phase::Part(1), stats);
+        results.insert(2019, 2, Phase::Part(1), stats);
+
+        results.clear(2019, 1);
+        assert_eq!(results.get(2019, 1, Phase::Parse), None);
+        assert_eq!(results.get(2019, 1, Phase::Part(1)), None);
+        assert_eq!(results.get(2019, 2, Phase::Part(1)), Some(&stats));
+    }
+
+    #[test]
+    fn test_change() {
+        let stats = |micros| Stats {
+            median: Duration::from_micros(micros),
+            p95: Duration::from_micros(micros),
+        };
+        assert_eq!(change(&stats(100), &stats(150)), 50.0);
+        assert_eq!(change(&stats(200), &stats(100)), -50.0);
+    }
+}
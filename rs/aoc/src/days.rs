@@ -0,0 +1,278 @@
+use aoc_core::{
+    bench::{self, Config, Report},
+    Answer, Error, Parse, Solution,
+};
+
+pub type Part = fn(&str) -> Result<String, Error>;
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub part_one: Part,
+    pub part_two: Option<Part>,
+    pub bench: fn(&Config, &str) -> Report,
+}
+
+fn part_one<S: Solution>(input: &str) -> Result<String, Error> {
+    S::part_one(input).into_answer()
+}
+
+fn part_two<S: Solution>(input: &str) -> Result<String, Error> {
+    S::part_two(input).into_answer()
+}
+
+impl Day {
+    const fn new<S: Solution>() -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            part_one: part_one::<S>,
+            part_two: if S::PARTS == 2 {
+                Some(part_two::<S>)
+            } else {
+                None
+            },
+            bench: bench::run::<S>,
+        }
+    }
+
+    // benchmarks time parsing apart from solving
+    const fn parsed<S: Parse>() -> Self {
+        Self {
+            bench: bench::run_parsed::<S>,
+            ..Self::new::<S>()
+        }
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day::parsed::<aoc2015_day01::Solver>(),
+    Day::parsed::<aoc2015_day02::Solver>(),
+    Day::parsed::<aoc2015_day03::Solver>(),
+    Day::parsed::<aoc2015_day04::Solver>(),
+    Day::parsed::<aoc2015_day05::Solver>(),
+    Day::parsed::<aoc2015_day06::Solver>(),
+    Day::parsed::<aoc2015_day07::Solver>(),
+    Day::parsed::<aoc2015_day08::Solver>(),
+    Day::parsed::<aoc2015_day09::Solver>(),
+    Day::parsed::<aoc2015_day10::Solver>(),
+    Day::parsed::<aoc2015_day11::Solver>(),
+    Day::parsed::<aoc2015_day12::Solver>(),
+    Day::parsed::<aoc2015_day13::Solver>(),
+    Day::parsed::<aoc2015_day14::Solver>(),
+    Day::parsed::<aoc2015_day15::Solver>(),
+    Day::parsed::<aoc2015_day16::Solver>(),
+    Day::parsed::<aoc2015_day17::Solver>(),
+    Day::parsed::<aoc2015_day18::Solver>(),
+    Day::parsed::<aoc2015_day19::Solver>(),
+    Day::parsed::<aoc2015_day20::Solver>(),
+    Day::parsed::<aoc2015_day21::Solver>(),
+    Day::parsed::<aoc2015_day22::Solver>(),
+    Day::parsed::<aoc2015_day23::Solver>(),
+    Day::parsed::<aoc2015_day24::Solver>(),
+    Day::parsed::<aoc2015_day25::Solver>(),
+    Day::parsed::<aoc2016_day01::Solver>(),
+    Day::parsed::<aoc2016_day02::Solver>(),
+    Day::parsed::<aoc2016_day03::Solver>(),
+    Day::parsed::<aoc2016_day04::Solver>(),
+    Day::parsed::<aoc2016_day05::Solver>(),
+    Day::parsed::<aoc2016_day06::Solver>(),
+    Day::parsed::<aoc2016_day07::Solver>(),
+    Day::parsed::<aoc2016_day08::Solver>(),
+    Day::parsed::<aoc2016_day09::Solver>(),
+    Day::parsed::<aoc2016_day10::Solver>(),
+    Day::parsed::<aoc2016_day11::Solver>(),
+    Day::parsed::<aoc2016_day12::Solver>(),
+    Day::parsed::<aoc2016_day13::Solver>(),
+    Day::parsed::<aoc2016_day14::Solver>(),
+    Day::parsed::<aoc2016_day15::Solver>(),
+    Day::parsed::<aoc2016_day16::Solver>(),
+    Day::parsed::<aoc2016_day17::Solver>(),
+    Day::parsed::<aoc2016_day18::Solver>(),
+    Day::parsed::<aoc2016_day19::Solver>(),
+    Day::parsed::<aoc2016_day20::Solver>(),
+    Day::parsed::<aoc2016_day21::Solver>(),
+    Day::parsed::<aoc2016_day22::Solver>(),
+    Day::parsed::<aoc2016_day23::Solver>(),
+    Day::parsed::<aoc2016_day24::Solver>(),
+    Day::parsed::<aoc2016_day25::Solver>(),
+    Day::parsed::<aoc2017_day01::Solver>(),
+    Day::parsed::<aoc2017_day02::Solver>(),
+    Day::parsed::<aoc2017_day03::Solver>(),
+    Day::parsed::<aoc2017_day04::Solver>(),
+    Day::parsed::<aoc2017_day05::Solver>(),
+    Day::parsed::<aoc2017_day06::Solver>(),
+    Day::parsed::<aoc2017_day07::Solver>(),
+    Day::parsed::<aoc2017_day08::Solver>(),
+    Day::parsed::<aoc2017_day09::Solver>(),
+    Day::parsed::<aoc2017_day10::Solver>(),
+    Day::parsed::<aoc2017_day11::Solver>(),
+    Day::parsed::<aoc2017_day12::Solver>(),
+    Day::parsed::<aoc2017_day13::Solver>(),
+    Day::parsed::<aoc2017_day14::Solver>(),
+    Day::parsed::<aoc2017_day15::Solver>(),
+    Day::parsed::<aoc2017_day16::Solver>(),
+    Day::parsed::<aoc2017_day17::Solver>(),
+    Day::parsed::<aoc2017_day18::Solver>(),
+    Day::parsed::<aoc2017_day19::Solver>(),
+    Day::parsed::<aoc2017_day20::Solver>(),
+    Day::parsed::<aoc2017_day21::Solver>(),
+    Day::parsed::<aoc2017_day22::Solver>(),
+    Day::parsed::<aoc2017_day23::Solver>(),
+    Day::parsed::<aoc2017_day24::Solver>(),
+    Day::parsed::<aoc2017_day25::Solver>(),
+    Day::parsed::<aoc2018_day01::Solver>(),
+    Day::parsed::<aoc2018_day02::Solver>(),
+    Day::parsed::<aoc2018_day03::Solver>(),
+    Day::parsed::<aoc2018_day04::Solver>(),
+    Day::parsed::<aoc2018_day05::Solver>(),
+    Day::parsed::<aoc2018_day06::Solver>(),
+    Day::parsed::<aoc2018_day07::Solver>(),
+    Day::parsed::<aoc2018_day08::Solver>(),
+    Day::parsed::<aoc2018_day09::Solver>(),
+    Day::parsed::<aoc2018_day10::Solver>(),
+    Day::parsed::<aoc2018_day11::Solver>(),
+    Day::parsed::<aoc2018_day12::Solver>(),
+    Day::parsed::<aoc2018_day13::Solver>(),
+    Day::parsed::<aoc2018_day14::Solver>(),
+    Day::parsed::<aoc2018_day15::Solver>(),
+    Day::parsed::<aoc2018_day16::Solver>(),
+    Day::parsed::<aoc2018_day17::Solver>(),
+    Day::parsed::<aoc2018_day18::Solver>(),
+    Day::parsed::<aoc2018_day19::Solver>(),
+    Day::parsed::<aoc2018_day20::Solver>(),
+    Day::parsed::<aoc2018_day21::Solver>(),
+    Day::parsed::<aoc2018_day22::Solver>(),
+    Day::parsed::<aoc2018_day23::Solver>(),
+    Day::parsed::<aoc2018_day24::Solver>(),
+    Day::parsed::<aoc2018_day25::Solver>(),
+    Day::parsed::<aoc2019_day01::Solver>(),
+    Day::parsed::<aoc2019_day02::Solver>(),
+    Day::parsed::<aoc2019_day03::Solver>(),
+    Day::parsed::<aoc2019_day04::Solver>(),
+    Day::parsed::<aoc2019_day05::Solver>(),
+    Day::parsed::<aoc2019_day06::Solver>(),
+    Day::parsed::<aoc2019_day07::Solver>(),
+    Day::parsed::<aoc2019_day08::Solver>(),
+    Day::parsed::<aoc2019_day09::Solver>(),
+    Day::parsed::<aoc2019_day10::Solver>(),
+    Day::parsed::<aoc2019_day11::Solver>(),
+    Day::parsed::<aoc2019_day12::Solver>(),
+    Day::parsed::<aoc2019_day13::Solver>(),
+    Day::parsed::<aoc2019_day14::Solver>(),
+    Day::parsed::<aoc2019_day15::Solver>(),
+    Day::parsed::<aoc2019_day16::Solver>(),
+    Day::parsed::<aoc2019_day17::Solver>(),
+    Day::parsed::<aoc2019_day18::Solver>(),
+    Day::parsed::<aoc2019_day19::Solver>(),
+    Day::parsed::<aoc2019_day20::Solver>(),
+    Day::parsed::<aoc2019_day21::Solver>(),
+    Day::parsed::<aoc2019_day22::Solver>(),
+    Day::parsed::<aoc2019_day23::Solver>(),
+    Day::parsed::<aoc2019_day24::Solver>(),
+    Day::parsed::<aoc2019_day25::Solver>(),
+    Day::parsed::<aoc2020_day01::Solver>(),
+    Day::parsed::<aoc2020_day02::Solver>(),
+    Day::parsed::<aoc2020_day03::Solver>(),
+    Day::parsed::<aoc2020_day04::Solver>(),
+    Day::parsed::<aoc2021_day01::Solver>(),
+    Day::parsed::<aoc2021_day02::Solver>(),
+    Day::parsed::<aoc2021_day03::Solver>(),
+    Day::parsed::<aoc2021_day04::Solver>(),
+    Day::parsed::<aoc2021_day05::Solver>(),
+    Day::parsed::<aoc2021_day06::Solver>(),
+    Day::parsed::<aoc2021_day07::Solver>(),
+    Day::parsed::<aoc2021_day08::Solver>(),
+    Day::parsed::<aoc2021_day09::Solver>(),
+    Day::parsed::<aoc2021_day10::Solver>(),
+    Day::parsed::<aoc2021_day11::Solver>(),
+    Day::parsed::<aoc2021_day12::Solver>(),
+    Day::parsed::<aoc2021_day13::Solver>(),
+    Day::parsed::<aoc2021_day14::Solver>(),
+    Day::parsed::<aoc2021_day15::Solver>(),
+    Day::parsed::<aoc2021_day16::Solver>(),
+    Day::parsed::<aoc2021_day17::Solver>(),
+    Day::parsed::<aoc2021_day18::Solver>(),
+    Day::parsed::<aoc2021_day19::Solver>(),
+    Day::parsed::<aoc2021_day20::Solver>(),
+    Day::parsed::<aoc2021_day21::Solver>(),
+    Day::parsed::<aoc2021_day22::Solver>(),
+    Day::parsed::<aoc2021_day23::Solver>(),
+    Day::parsed::<aoc2021_day24::Solver>(),
+    Day::parsed::<aoc2021_day25::Solver>(),
+    Day::parsed::<aoc2022_day01::Solver>(),
+    Day::parsed::<aoc2022_day02::Solver>(),
+    Day::parsed::<aoc2022_day03::Solver>(),
+    Day::parsed::<aoc2022_day04::Solver>(),
+    Day::parsed::<aoc2022_day05::Solver>(),
+    Day::parsed::<aoc2022_day06::Solver>(),
+    Day::parsed::<aoc2022_day07::Solver>(),
+    Day::parsed::<aoc2022_day08::Solver>(),
+    Day::parsed::<aoc2022_day09::Solver>(),
+    Day::parsed::<aoc2022_day10::Solver>(),
+    Day::parsed::<aoc2022_day11::Solver>(),
+    Day::parsed::<aoc2022_day12::Solver>(),
+    Day::parsed::<aoc2022_day13::Solver>(),
+    Day::parsed::<aoc2022_day14::Solver>(),
+    Day::parsed::<aoc2022_day15::Solver>(),
+    Day::parsed::<aoc2022_day16::Solver>(),
+    Day::parsed::<aoc2022_day17::Solver>(),
+    Day::parsed::<aoc2022_day18::Solver>(),
+    Day::parsed::<aoc2022_day19::Solver>(),
+    Day::parsed::<aoc2022_day20::Solver>(),
+    Day::parsed::<aoc2022_day21::Solver>(),
+    Day::parsed::<aoc2022_day22::Solver>(),
+    Day::parsed::<aoc2022_day23::Solver>(),
+    Day::parsed::<aoc2022_day24::Solver>(),
+    Day::parsed::<aoc2022_day25::Solver>(),
+    Day::parsed::<aoc2023_day01::Solver>(),
+    Day::parsed::<aoc2023_day02::Solver>(),
+    Day::parsed::<aoc2023_day03::Solver>(),
+    Day::parsed::<aoc2023_day04::Solver>(),
+    Day::parsed::<aoc2023_day05::Solver>(),
+    Day::parsed::<aoc2023_day06::Solver>(),
+    Day::parsed::<aoc2023_day07::Solver>(),
+    Day::parsed::<aoc2023_day08::Solver>(),
+    Day::parsed::<aoc2023_day09::Solver>(),
+    Day::parsed::<aoc2023_day10::Solver>(),
+    Day::parsed::<aoc2023_day11::Solver>(),
+    Day::parsed::<aoc2023_day12::Solver>(),
+    Day::parsed::<aoc2023_day13::Solver>(),
+    Day::parsed::<aoc2023_day14::Solver>(),
+    Day::parsed::<aoc2023_day15::Solver>(),
+    Day::parsed::<aoc2023_day16::Solver>(),
+    Day::parsed::<aoc2023_day17::Solver>(),
+    Day::parsed::<aoc2023_day18::Solver>(),
+    Day::parsed::<aoc2023_day19::Solver>(),
+    Day::parsed::<aoc2023_day20::Solver>(),
+    Day::parsed::<aoc2023_day21::Solver>(),
+    Day::parsed::<aoc2023_day22::Solver>(),
+    Day::parsed::<aoc2023_day23::Solver>(),
+    Day::parsed::<aoc2023_day24::Solver>(),
+    Day::parsed::<aoc2023_day25::Solver>(),
+    Day::parsed::<aoc2024_day01::Solver>(),
+    Day::parsed::<aoc2024_day02::Solver>(),
+    Day::parsed::<aoc2024_day03::Solver>(),
+    Day::parsed::<aoc2024_day04::Solver>(),
+    Day::parsed::<aoc2024_day05::Solver>(),
+    Day::parsed::<aoc2024_day06::Solver>(),
+    Day::parsed::<aoc2024_day07::Solver>(),
+    Day::parsed::<aoc2024_day08::Solver>(),
+    Day::parsed::<aoc2024_day09::Solver>(),
+    Day::parsed::<aoc2024_day10::Solver>(),
+    Day::parsed::<aoc2024_day11::Solver>(),
+    Day::parsed::<aoc2024_day12::Solver>(),
+    Day::parsed::<aoc2024_day13::Solver>(),
+    Day::parsed::<aoc2024_day14::Solver>(),
+    Day::parsed::<aoc2024_day15::Solver>(),
+    Day::parsed::<aoc2024_day16::Solver>(),
+    Day::parsed::<aoc2024_day17::Solver>(),
+    Day::parsed::<aoc2024_day18::Solver>(),
+    Day::parsed::<aoc2024_day19::Solver>(),
+    Day::parsed::<aoc2024_day20::Solver>(),
+    Day::parsed::<aoc2024_day21::Solver>(),
+    Day::parsed::<aoc2024_day22::Solver>(),
+    Day::parsed::<aoc2024_day23::Solver>(),
+    Day::parsed::<aoc2024_day24::Solver>(),
+    Day::parsed::<aoc2024_day25::Solver>(),
+];
@@ -0,0 +1,458 @@
+mod answers;
+mod bench;
+mod days;
+mod runner;
+mod scaffold;
+mod table;
+
+use answers::Answers;
+use aoc_core::{bench::Config, input::Source};
+use bench::{Phase, Results};
+use days::{Day, DAYS};
+use runner::{Outcome, Row, Timing};
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process,
+};
+use table::{Align, Table};
+
+const USAGE: &str = "usage:
+    aoc run <year> [day] [--input <path> | --stdin | --user <name>]
+    aoc run --all [--user <name>]
+    aoc verify <year> [day] [--input <path> | --stdin | --user <name>] [--answers <path>]
+    aoc verify --all [--user <name>] [--answers <path>]
+    aoc bench <year> [day] [--input <path> | --stdin | --user <name>] [bench options]
+    aoc bench --all [--user <name>] [bench options]
+    aoc new-day <year> <day>
+
+bench options:
+    --warmup <n>         untimed runs before measuring (default 3)
+    --iterations <n>     timed runs (default 20)
+    --save <path>        write the timings to a file, keeping other days in it
+    --baseline <path>    compare against timings saved earlier
+    --threshold <pct>    slowdown counted as a regression (default 10)
+
+parsing is timed on its own, so part timings do not include it.";
+
+enum Selection {
+    All,
+    Year(u16),
+    Day(u16, u8),
+}
+
+impl Selection {
+    fn matches(&self, day: &Day) -> bool {
+        match *self {
+            Selection::All => true,
+            Selection::Year(year) => day.year == year,
+            Selection::Day(year, n) => day.year == year && day.day == n,
+        }
+    }
+}
+
+struct BenchOptions {
+    config: Config,
+    save: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+}
+
+enum Command {
+    Run,
+    Verify(PathBuf),
+    Bench(BenchOptions),
+    NewDay,
+}
+
+fn take_flag(args: &mut Vec<String>, flag: &str) -> Result<Option<String>, String> {
+    match args.iter().position(|arg| arg == flag) {
+        Some(i) if i + 1 < args.len() => {
+            args.remove(i);
+            Ok(Some(args.remove(i)))
+        }
+        Some(_) => Err(format!("{} needs a value", flag)),
+        None => Ok(None),
+    }
+}
+
+fn parse_args(mut args: Vec<String>) -> Result<(Command, Selection, Source), String> {
+    let source = Source::from_args(&mut args)?;
+    let answers = take_flag(&mut args, "--answers")?;
+    let warmup = take_flag(&mut args, "--warmup")?;
+    let iterations = take_flag(&mut args, "--iterations")?;
+    let save = take_flag(&mut args, "--save")?;
+    let baseline = take_flag(&mut args, "--baseline")?;
+    let threshold = take_flag(&mut args, "--threshold")?;
+    let bench_flags = [&warmup, &iterations, &save, &baseline, &threshold];
+    let no_bench_flags = bench_flags.iter().all(|flag| flag.is_none());
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let (command, rest) = match args.split_first() {
+        Some((&"run", rest)) if answers.is_none() && no_bench_flags => (Command::Run, rest),
+        Some((&"verify", rest)) if no_bench_flags => {
+            let path = answers.unwrap_or_else(|| answers::DEFAULT_PATH.to_string());
+            (Command::Verify(PathBuf::from(path)), rest)
+        }
+        Some((&"bench", rest)) if answers.is_none() => {
+            let default = Config::default();
+            let options = BenchOptions {
+                config: Config {
+                    warmup: parse_number("--warmup", warmup, default.warmup)?,
+                    iterations: parse_number("--iterations", iterations, default.iterations)?,
+                },
+                save: save.map(PathBuf::from),
+                baseline: baseline.map(PathBuf::from),
+                threshold: parse_number("--threshold", threshold, 10.0)?,
+            };
+            if options.config.iterations == 0 {
+                return Err("--iterations must be at least 1".to_string());
+            }
+            (Command::Bench(options), rest)
+        }
+        Some((&"new-day", rest)) if answers.is_none() && no_bench_flags => (Command::NewDay, rest),
+        _ => return Err(USAGE.to_string()),
+    };
+
+    let selection = match rest {
+        ["--all"] => Selection::All,
+        [year] => Selection::Year(parse_year(year)?),
+        [year, day] => Selection::Day(parse_year(year)?, parse_day(day)?),
+        _ => return Err(USAGE.to_string()),
+    };
+
+    match (&command, &selection, &source) {
+        (Command::NewDay, Selection::Day(..), Source::Default) => Ok((command, selection, source)),
+        (Command::NewDay, ..) => Err(USAGE.to_string()),
+        (_, Selection::Day(..), _) | (_, _, Source::Default | Source::User(_)) => {
+            Ok((command, selection, source))
+        }
+        _ => Err("--input and --stdin can only be used with a single day".to_string()),
+    }
+}
+
+fn parse_year(year: &str) -> Result<u16, String> {
+    year.parse().map_err(|_| format!("invalid year: {}", year))
+}
+
+fn parse_number<T: std::str::FromStr>(
+    flag: &str,
+    value: Option<String>,
+    default: T,
+) -> Result<T, String> {
+    match value {
+        Some(value) => value
+            .parse()
+            .map_err(|_| format!("invalid {}: {}", flag, value)),
+        None => Ok(default),
+    }
+}
+
+fn parse_day(day: &str) -> Result<u8, String> {
+    match day.parse() {
+        Ok(n @ 1..=25) => Ok(n),
+        _ => Err(format!("invalid day: {}", day)),
+    }
+}
+
+fn print_answers(rows: &[Row]) {
+    let mut table = Table::new(vec![
+        ("Year", Align::Left),
+        ("Day", Align::Right),
+        ("Part", Align::Right),
+        ("Answer", Align::Left),
+        ("Time", Align::Left),
+    ]);
+
+    for row in rows {
+        table.push(vec![
+            row.year.to_string(),
+            row.day.to_string(),
+            row.part.to_string(),
+            row.outcome.answer(),
+            row.outcome.elapsed(),
+        ]);
+    }
+
+    table.print();
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Pass,
+    Mismatch,
+    Fail,
+    Unknown,
+    Skip,
+}
+
+impl Status {
+    fn of(outcome: &Outcome, expected: Option<&str>) -> Self {
+        match (outcome, expected) {
+            (Outcome::Solved(answer, _), Some(expected)) if answer == expected => Status::Pass,
+            (Outcome::Solved(..), Some(_)) => Status::Mismatch,
+            (Outcome::Solved(..), None) => Status::Unknown,
+            (Outcome::Failed(_) | Outcome::Panicked(_) | Outcome::BadInput(_), _) => Status::Fail,
+            (Outcome::MissingInput, _) => Status::Skip,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Mismatch => "mismatch",
+            Status::Fail => "fail",
+            Status::Unknown => "no answer",
+            Status::Skip => "skip",
+        }
+    }
+}
+
+// returns whether every day with a recorded answer still produces it
+fn print_verification(rows: &[Row], answers: &Answers) -> bool {
+    let mut table = Table::new(vec![
+        ("Year", Align::Left),
+        ("Day", Align::Right),
+        ("Part", Align::Right),
+        ("Status", Align::Left),
+        ("Answer", Align::Left),
+        ("Expected", Align::Left),
+        ("Time", Align::Left),
+    ]);
+    let mut statuses = Vec::new();
+
+    for row in rows {
+        let expected = answers.get(row.year, row.day, row.part);
+        let status = Status::of(&row.outcome, expected);
+        statuses.push(status);
+        table.push(vec![
+            row.year.to_string(),
+            row.day.to_string(),
+            row.part.to_string(),
+            status.label().to_string(),
+            row.outcome.answer(),
+            expected.unwrap_or("-").to_string(),
+            row.outcome.elapsed(),
+        ]);
+    }
+
+    table.print();
+
+    let count = |status| statuses.iter().filter(|s| **s == status).count();
+    println!();
+    println!(
+        "{} passed, {} mismatched, {} failed, {} without answer, {} skipped",
+        count(Status::Pass),
+        count(Status::Mismatch),
+        count(Status::Fail),
+        count(Status::Unknown),
+        count(Status::Skip)
+    );
+
+    count(Status::Mismatch) == 0 && count(Status::Fail) == 0
+}
+
+// returns whether no day got slower than the baseline by more than the threshold
+fn print_benchmarks(
+    days: &[&Day],
+    timings: &[Timing],
+    baseline: Option<&Results>,
+    threshold: f64,
+) -> bool {
+    let mut table = Table::new(vec![
+        ("Year", Align::Left),
+        ("Day", Align::Right),
+        ("Part", Align::Right),
+        ("Median", Align::Right),
+        ("p95", Align::Right),
+        ("Baseline", Align::Right),
+        ("Change", Align::Left),
+    ]);
+    let mut regressions = 0;
+
+    for (day, timing) in days.iter().zip(timings) {
+        let report = match timing {
+            Timing::Measured(report) => report,
+            Timing::Panicked(message) | Timing::BadInput(message) => {
+                let message = match timing {
+                    Timing::Panicked(_) => format!("panicked: {}", message),
+                    _ => format!("input error: {}", message),
+                };
+                table.push(vec![
+                    day.year.to_string(),
+                    day.day.to_string(),
+                    "-".to_string(),
+                    message,
+                    "-".to_string(),
+                    "-".to_string(),
+                    "-".to_string(),
+                ]);
+                continue;
+            }
+            Timing::MissingInput => continue,
+        };
+
+        let phases = [
+            (Phase::Parse, report.parse),
+            (Phase::Part(1), Some(report.part_one)),
+            (Phase::Part(2), report.part_two),
+        ];
+        for (phase, stats) in phases {
+            let Some(stats) = stats else { continue };
+            let previous = baseline.and_then(|results| results.get(day.year, day.day, phase));
+            let change = match previous {
+                Some(previous) => {
+                    let change = bench::change(previous, &stats);
+                    if change > threshold {
+                        regressions += 1;
+                        format!("{:+.1}% regression", change)
+                    } else {
+                        format!("{:+.1}%", change)
+                    }
+                }
+                None => "-".to_string(),
+            };
+
+            table.push(vec![
+                day.year.to_string(),
+                day.day.to_string(),
+                phase.to_string(),
+                bench::format_duration(stats.median),
+                bench::format_duration(stats.p95),
+                previous.map_or("-".to_string(), |previous| {
+                    bench::format_duration(previous.median)
+                }),
+                change,
+            ]);
+        }
+    }
+
+    table.print();
+
+    if baseline.is_some() {
+        println!();
+        println!(
+            "{} regression{} over {}%",
+            regressions,
+            if regressions == 1 { "" } else { "s" },
+            threshold
+        );
+    }
+
+    regressions == 0
+}
+
+fn bench(days: &[&Day], source: &Source, options: &BenchOptions) -> Result<bool, String> {
+    let baseline = match &options.baseline {
+        Some(path) => Some(Results::load(path)?),
+        None => None,
+    };
+
+    let timings: Vec<Timing> = days
+        .iter()
+        .map(|day| runner::bench_day(day, source, &options.config))
+        .collect();
+
+    // days that weren't benched this time keep their saved timings
+    if let Some(path) = &options.save {
+        let mut results = if path.exists() {
+            Results::load(path)?
+        } else {
+            Results::default()
+        };
+        for (day, timing) in days.iter().zip(&timings) {
+            if let Timing::Measured(report) = timing {
+                results.clear(day.year, day.day);
+                let phases = [
+                    (Phase::Parse, report.parse),
+                    (Phase::Part(1), Some(report.part_one)),
+                    (Phase::Part(2), report.part_two),
+                ];
+                for (phase, stats) in phases {
+                    if let Some(stats) = stats {
+                        results.insert(day.year, day.day, phase, stats);
+                    }
+                }
+            }
+        }
+        results.save(path)?;
+    }
+
+    let unread = timings
+        .iter()
+        .any(|timing| matches!(timing, Timing::BadInput(_)));
+
+    Ok(print_benchmarks(days, &timings, baseline.as_ref(), options.threshold) && !unread)
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (command, selection, source) = match parse_args(args) {
+        Ok(parsed) => parsed,
+        Err(message) => {
+            eprintln!("{}", message);
+            process::exit(2);
+        }
+    };
+
+    if let (Command::NewDay, Selection::Day(year, day)) = (&command, &selection) {
+        if let Err(message) = scaffold::new_day(Path::new(scaffold::ROOT), *year, *day) {
+            eprintln!("{}", message);
+            process::exit(1);
+        }
+        println!("created {}/rs/day{:02}", year, day);
+        return;
+    }
+
+    let answers = match &command {
+        Command::Run | Command::Bench(_) | Command::NewDay => None,
+        Command::Verify(path) => match Answers::load(path) {
+            Ok(answers) => Some(answers),
+            Err(message) => {
+                eprintln!("{}", message);
+                process::exit(2);
+            }
+        },
+    };
+
+    let days: Vec<&Day> = DAYS.iter().filter(|day| selection.matches(day)).collect();
+    if days.is_empty() {
+        eprintln!("no solutions found");
+        process::exit(1);
+    }
+
+    runner::silence_panics();
+
+    if let Command::Bench(options) = &command {
+        match bench(&days, &source, options) {
+            Ok(true) => return,
+            Ok(false) => process::exit(1),
+            Err(message) => {
+                eprintln!("{}", message);
+                process::exit(2);
+            }
+        }
+    }
+
+    let rows: Vec<Row> = days
+        .into_iter()
+        .flat_map(|day| runner::run_day(day, &source))
+        .collect();
+
+    match answers {
+        None => {
+            print_answers(&rows);
+            if rows
+                .iter()
+                .any(|row| matches!(row.outcome, Outcome::BadInput(_)))
+            {
+                process::exit(1);
+            }
+        }
+        Some(answers) => {
+            if !print_verification(&rows, &answers) {
+                process::exit(1);
+            }
+        }
+    }
+}
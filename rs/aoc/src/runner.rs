@@ -0,0 +1,186 @@
+use crate::days::{Day, Part};
+use aoc_core::{
+    bench::{Config, Report},
+    input::Source,
+    Error,
+};
+use std::{
+    fs, io,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+pub enum Outcome {
+    Solved(String, Duration),
+    Failed(Error),
+    Panicked(String),
+    MissingInput,
+    // the input asked for couldn't be read
+    BadInput(String),
+}
+
+impl Outcome {
+    pub fn answer(&self) -> String {
+        match self {
+            Outcome::Solved(answer, _) => answer.clone(),
+            Outcome::Failed(err) => format!("error: {}", err),
+            Outcome::Panicked(message) => format!("panicked: {}", message),
+            Outcome::MissingInput => "missing input".to_string(),
+            Outcome::BadInput(message) => format!("input error: {}", message),
+        }
+    }
+
+    pub fn elapsed(&self) -> String {
+        match self {
+            Outcome::Solved(_, elapsed) => format!("{} µs", elapsed.as_micros()),
+            _ => "-".to_string(),
+        }
+    }
+}
+
+pub struct Row {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Outcome,
+}
+
+fn input_path(day: &Day) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .join(day.year.to_string())
+        .join("rs")
+        .join(format!("day{:02}", day.day))
+        .join("data.txt")
+}
+
+// `None` when the day has no `data.txt`, which only skips it, as not every
+// day has its input checked out. Inputs asked for explicitly have to be read.
+fn read_input(day: &Day, source: &Source) -> Result<Option<String>, String> {
+    let input = match source {
+        Source::Default => {
+            let path = input_path(day);
+            match fs::read_to_string(&path) {
+                Ok(input) => input,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+                Err(err) => return Err(format!("{}: {}", path.display(), err)),
+            }
+        }
+        source => source
+            .read(day.year, day.day)
+            .map_err(|err| format!("{} day {}: {}", day.year, day.day, err))?,
+    };
+
+    if input.trim().is_empty() {
+        match source {
+            Source::Default => Ok(None),
+            _ => Err(format!("{} day {}: empty input", day.year, day.day)),
+        }
+    } else {
+        Ok(Some(input))
+    }
+}
+
+// runs `f`, turning a panic into its message
+fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default()
+    })
+}
+
+fn run_part(solve: Part, input: &str) -> Outcome {
+    let start = Instant::now();
+    match catch(|| solve(input)) {
+        Ok(Ok(answer)) => Outcome::Solved(answer, start.elapsed()),
+        Ok(Err(err)) => Outcome::Failed(err),
+        Err(message) => Outcome::Panicked(message),
+    }
+}
+
+// an input that can't be read fails only this day, so the others still run
+pub fn run_day(day: &Day, source: &Source) -> Vec<Row> {
+    let row = |part, outcome| Row {
+        year: day.year,
+        day: day.day,
+        part,
+        outcome,
+    };
+
+    let input = match read_input(day, source) {
+        Ok(Some(input)) => input,
+        Ok(None) => return vec![row(1, Outcome::MissingInput)],
+        Err(message) => return vec![row(1, Outcome::BadInput(message))],
+    };
+
+    let mut rows = vec![row(1, run_part(day.part_one, &input))];
+    if let Some(part_two) = day.part_two {
+        rows.push(row(2, run_part(part_two, &input)));
+    }
+    rows
+}
+
+pub enum Timing {
+    Measured(Report),
+    Panicked(String),
+    MissingInput,
+    BadInput(String),
+}
+
+pub fn bench_day(day: &Day, source: &Source, config: &Config) -> Timing {
+    let input = match read_input(day, source) {
+        Ok(Some(input)) => input,
+        Ok(None) => return Timing::MissingInput,
+        Err(message) => return Timing::BadInput(message),
+    };
+
+    match catch(|| (day.bench)(config, &input)) {
+        Ok(report) => Timing::Measured(report),
+        Err(message) => Timing::Panicked(message),
+    }
+}
+
+// panic messages are reported in the table instead
+pub fn silence_panics() {
+    panic::set_hook(Box::new(|_| {}));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::DAYS;
+    use std::env;
+
+    #[test]
+    fn test_read_input() {
+        let day = &DAYS[0];
+        let missing = Source::Path(PathBuf::from("/nonexistent/input.txt"));
+        assert!(read_input(day, &missing)
+            .unwrap_err()
+            .contains("/nonexistent/input.txt"));
+
+        let path = env::temp_dir().join(format!("aoc-empty-{}.txt", std::process::id()));
+        fs::write(&path, "\n").unwrap();
+        let empty = read_input(day, &Source::Path(path.clone()));
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            empty,
+            Err(format!("{} day {}: empty input", day.year, day.day))
+        );
+    }
+
+    #[test]
+    fn test_run_day() {
+        let missing = Source::Path(PathBuf::from("/nonexistent/input.txt"));
+        for day in &DAYS[..2] {
+            let rows = run_day(day, &missing);
+            assert_eq!(rows.len(), 1);
+            assert!(matches!(&rows[0].outcome, Outcome::BadInput(message)
+                if message.contains("/nonexistent/input.txt")));
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use std::{fs, path::Path};
+
+pub const ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../..");
+
+fn manifest(year: u16, day: u8, utils: bool) -> String {
+    let mut manifest = format!(
+        r#"[package]
+name = "aoc{year}-day{day:02}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc-core = {{ workspace = true }}
+"#
+    );
+    if utils {
+        manifest += &format!("utils = {{ package = \"aoc{year}-utils\", path = \"../utils\" }}\n");
+    }
+    manifest
+}
+
+fn lib(year: u16, day: u8) -> String {
+    format!(
+        r#"pub fn part_one(_input: &str) -> i32 {{
+    0
+}}
+
+pub fn part_two(_input: &str) -> i32 {{
+    0
+}}
+
+pub struct Solver;
+
+impl aoc_core::Solution for Solver {{
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn part_one(input: &str) -> i32 {{
+        part_one(input)
+    }}
+
+    fn part_two(input: &str) -> i32 {{
+        part_two(input)
+    }}
+}}
+
+impl aoc_core::Parse for Solver {{
+    type Input = ();
+
+    fn parse(_input: &str) -> Result<(), aoc_core::Error> {{
+        Ok(())
+    }}
+
+    fn solve_one(_input: &()) -> i32 {{
+        0
+    }}
+
+    fn solve_two(_input: &()) -> i32 {{
+        0
+    }}
+}}
+
+aoc_core::examples!(Solver);
+"#
+    )
+}
+
+fn main_rs(year: u16, day: u8) -> String {
+    format!(
+        r#"use aoc{year}_day{day:02}::Solver;
+
+fn main() -> std::io::Result<()> {{
+    aoc_core::run_parsed::<Solver>()
+}}
+"#
+    )
+}
+
+// replace the answers and paste the example input below the ---
+const EXAMPLE: &str = "part one: 0\npart two: 0\n---\n";
+
+// the part of a line naming a day crate, e.g. `aoc2019_day05::Solver>(),`
+fn crate_name(line: &str) -> Option<&str> {
+    let start = line.find("aoc20")?;
+    let name = &line[start..];
+    (name.get(7..11) == Some("-day") || name.get(7..11) == Some("_day")).then_some(name)
+}
+
+// inserts `line` among the lines naming day crates, keeping them sorted
+fn insert_sorted(contents: &str, line: &str) -> Result<String, String> {
+    let name = crate_name(line).unwrap();
+    let mut lines: Vec<&str> = contents.lines().collect();
+
+    let entries: Vec<usize> = (0..lines.len())
+        .filter(|&i| crate_name(lines[i]).is_some())
+        .collect();
+    let Some(&last) = entries.last() else {
+        return Err("no days are registered".to_string());
+    };
+    if entries.iter().any(|&i| crate_name(lines[i]) == Some(name)) {
+        return Err(format!("{} is already registered", line.trim()));
+    }
+
+    let index = entries
+        .into_iter()
+        .find(|&i| crate_name(lines[i]).unwrap() > name)
+        .unwrap_or(last + 1);
+    lines.insert(index, line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+// the contents of `path` with the day added, so nothing is written on error
+fn register(path: &Path, line: &str) -> Result<String, String> {
+    fs::read_to_string(path)
+        .map_err(|err| err.to_string())
+        .and_then(|contents| insert_sorted(&contents, line))
+        .map_err(|err| format!("{}: {}", path.display(), err))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::create_dir_all(path.parent().unwrap())
+        .and_then(|_| fs::write(path, contents))
+        .map_err(|err| format!("{}: {}", path.display(), err))
+}
+
+/// Creates the crate for a day under `root`, wires in the year's utils crate
+/// when there is one and adds the day to the runner.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<(), String> {
+    let dir = root.join(format!("{}/rs/day{:02}", year, day));
+    if dir.join("Cargo.toml").exists() {
+        return Err(format!("{}/rs/day{:02} already exists", year, day));
+    }
+    let utils = root.join(format!("{}/rs/utils/Cargo.toml", year)).exists();
+
+    let runner_manifest = root.join("rs/aoc/Cargo.toml");
+    let dependencies = register(
+        &runner_manifest,
+        &format!("aoc{year}-day{day:02} = {{ path = \"../../{year}/rs/day{day:02}\" }}"),
+    )?;
+    let days_rs = root.join("rs/aoc/src/days.rs");
+    let days = register(
+        &days_rs,
+        &format!("    Day::parsed::<aoc{year}_day{day:02}::Solver>(),"),
+    )?;
+
+    write(&dir.join("Cargo.toml"), &manifest(year, day, utils))?;
+    write(&dir.join("src/lib.rs"), &lib(year, day))?;
+    write(&dir.join("src/main.rs"), &main_rs(year, day))?;
+    write(&dir.join("examples/1.txt"), EXAMPLE)?;
+    if !dir.join("data.txt").exists() {
+        write(&dir.join("data.txt"), "")?;
+    }
+    write(&runner_manifest, &dependencies)?;
+    write(&days_rs, &days)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_sorted() {
+        let days = "pub const DAYS: &[Day] = &[\n    Day::new::<aoc2019_day01::Solver>(),\n    Day::parsed::<aoc2019_day05::Solver>(),\n];\n";
+
+        let inserted = insert_sorted(days, "    Day::new::<aoc2019_day03::Solver>(),").unwrap();
+        assert_eq!(
+            inserted.lines().nth(2),
+            Some("    Day::new::<aoc2019_day03::Solver>(),")
+        );
+
+        let inserted = insert_sorted(days, "    Day::new::<aoc2020_day01::Solver>(),").unwrap();
+        assert_eq!(
+            inserted.lines().nth(3),
+            Some("    Day::new::<aoc2020_day01::Solver>(),")
+        );
+
+        assert!(insert_sorted(days, "    Day::new::<aoc2019_day05::Solver>(),").is_err());
+        assert!(insert_sorted("[dependencies]\n", "aoc2019-day01 = {}").is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-new-day-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        write(
+            &root.join("rs/aoc/Cargo.toml"),
+            "[dependencies]\naoc2030-day01 = { path = \"../../2030/rs/day01\" }\n",
+        )
+        .unwrap();
+        write(
+            &root.join("rs/aoc/src/days.rs"),
+            "pub const DAYS: &[Day] = &[\n    Day::parsed::<aoc2030_day01::Solver>(),\n];\n",
+        )
+        .unwrap();
+        write(&root.join("2030/rs/utils/Cargo.toml"), "").unwrap();
+
+        new_day(&root, 2030, 2).unwrap();
+
+        let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+        assert!(read("2030/rs/day02/Cargo.toml").contains("package = \"aoc2030-utils\""));
+        assert!(read("2030/rs/day02/src/lib.rs").contains("const DAY: u8 = 2;"));
+        assert!(read("2030/rs/day02/src/lib.rs").contains("impl aoc_core::Parse for Solver"));
+        assert!(read("2030/rs/day02/src/main.rs").contains("use aoc2030_day02::Solver;"));
+        assert!(read("rs/aoc/Cargo.toml")
+            .ends_with("aoc2030-day02 = { path = \"../../2030/rs/day02\" }\n"));
+        assert!(read("rs/aoc/src/days.rs").contains("Day::parsed::<aoc2030_day02::Solver>(),\n];"));
+        assert!(new_day(&root, 2030, 2).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
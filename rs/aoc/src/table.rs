@@ -0,0 +1,80 @@
+pub enum Align {
+    Left,
+    Right,
+}
+
+pub struct Table {
+    headers: Vec<(&'static str, Align)>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(headers: Vec<(&'static str, Align)>) -> Self {
+        Self {
+            headers,
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        assert_eq!(row.len(), self.headers.len());
+        self.rows.push(row);
+    }
+
+    fn widths(&self) -> Vec<usize> {
+        self.headers
+            .iter()
+            .enumerate()
+            .map(|(i, (header, _))| {
+                self.rows
+                    .iter()
+                    .flat_map(|row| row[i].lines())
+                    .map(|line| line.chars().count())
+                    .chain([header.len()])
+                    .max()
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    fn print_line(&self, widths: &[usize], cells: &[&str]) {
+        let line = self
+            .headers
+            .iter()
+            .zip(widths)
+            .zip(cells)
+            .map(|(((_, align), &width), cell)| match align {
+                Align::Left => format!("{:<width$}", cell, width = width),
+                Align::Right => format!("{:>width$}", cell, width = width),
+            })
+            .collect::<Vec<_>>()
+            .join(" | ");
+        println!("{}", line.trim_end());
+    }
+
+    // cells spanning several lines (e.g. answers drawn on a screen) continue
+    // on the following lines with the other columns left blank
+    pub fn print(&self) {
+        let widths = self.widths();
+        let headers: Vec<&str> = self.headers.iter().map(|(header, _)| *header).collect();
+        self.print_line(&widths, &headers);
+        let separator: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+        println!("{}", separator.join("-|-"));
+
+        for row in &self.rows {
+            let mut lines: Vec<_> = row.iter().map(|cell| cell.lines()).collect();
+            let height = row
+                .iter()
+                .map(|cell| cell.lines().count())
+                .max()
+                .unwrap_or(0);
+            for _ in 0..height.max(1) {
+                let cells: Vec<&str> = lines
+                    .iter_mut()
+                    .map(|cell| cell.next().unwrap_or(""))
+                    .collect();
+                self.print_line(&widths, &cells);
+            }
+        }
+    }
+}